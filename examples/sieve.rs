@@ -70,14 +70,7 @@ fn main() {
 				if n < 100 {
 					println!("Calculating {}…", n);
 				}
-				'inner:
-				for i in n .. {
-					let j = n * i;
-					if j >= max {
-						break 'inner;
-					}
-					bv.set(j, false);
-				}
+				bv.clear_multiples(n * n, n);
 			}
 		}
 		println!("Calculation complete!");
@@ -11,6 +11,23 @@ references to memory as the bare fundamental types. Instead, this module
 translates references to `SliceBit` into references to shared-mutable types as
 appropriate for the crate build configuration: either `Cell` in non-atomic
 builds, or `AtomicT` in atomic builds.
+
+# Model Checking
+
+Every `Access` type must have exactly the layout of its `BitStore` element,
+because `SliceBit` views ordinary `[T]` memory as `[T::Access]` by pointer cast
+rather than by constructing access objects. This rules out substituting the
+atomics of a model checker such as `loom`, which track each atomic as an object
+registered with the checker, and cannot be laid over existing integer memory.
+`BitStore` is sealed for the same reason: no other access type can be proven
+layout-compatible in general.
+
+Concurrent code built on aliased `SliceBit` regions can still be checked under
+Miri (`just miri`), which interprets the `AtomicT` operations here directly and
+reports data races between them and any unsynchronized access. All operations
+in this module use `Relaxed` ordering, as each bit region is written by only one
+handle; code that publishes bits from one thread to another must provide its own
+synchronization.
 !*/
 
 use crate::{
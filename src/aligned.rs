@@ -0,0 +1,346 @@
+/*! Element-aligned bit slices
+
+A `SliceBit` may begin and end partway through its storage elements, so every
+operation over one must handle partial edge elements. Many operations, and many
+foreign APIs, only make sense for slices that cover their elements exactly.
+
+The `AlignedBitSlice` type in this module is a view over a `SliceBit` whose
+first bit is the first bit of an element, and whose last bit is the last bit of
+an element. It can only be built by checking these conditions, so holding one is
+proof that the slice is a whole number of whole elements.
+!*/
+
+use crate::{
+	cursor::{
+		Cursor,
+		Local,
+	},
+	slice::SliceBit,
+	store::{
+		BitStore,
+		Word,
+	},
+};
+
+use core::{
+	convert::TryFrom,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	mem,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+	slice,
+};
+
+/** A `SliceBit` that exactly covers its storage elements.
+
+The slice begins at the front edge of its first element, and ends at the back
+edge of its last element. Element-wise operations over it need no edge
+handling, and its memory can be viewed as plain `[T]` or bytes.
+
+An `AlignedBitSlice` dereferences to its `SliceBit`, and so supports all of the
+ordinary slice operations. None of these can change the slice’s bounds.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the slice.
+- `T: BitStore`: The storage type of the slice.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::aligned::AlignedBitSlice;
+
+let src = [0x0Fu8, 0xF0];
+let bits = src.bits::<BigEndian>();
+
+let aligned = AlignedBitSlice::new(bits).unwrap();
+assert_eq!(aligned.as_slice(), &src);
+assert!(AlignedBitSlice::new(&bits[1 ..]).is_none());
+assert!(AlignedBitSlice::new(&bits[.. 12]).is_none());
+```
+**/
+#[repr(transparent)]
+pub struct AlignedBitSlice<C = Local, T = Word>
+where C: Cursor, T: BitStore {
+	/// The aligned slice.
+	bits: SliceBit<C, T>,
+}
+
+impl<C, T> AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	/// Checks that a bit slice is aligned, and views it as aligned.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: A bit slice.
+	///
+	/// # Returns
+	///
+	/// `bits` as an `AlignedBitSlice`, if it begins and ends on element edges.
+	pub fn new(bits: &SliceBit<C, T>) -> Option<&Self> {
+		if Self::is_aligned(bits) {
+			Some(unsafe { &*(bits as *const SliceBit<C, T> as *const Self) })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Checks that a mutable bit slice is aligned, and views it as aligned.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: A mutable bit slice.
+	///
+	/// # Returns
+	///
+	/// `bits` as a mutable `AlignedBitSlice`, if it begins and ends on element
+	/// edges.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::aligned::AlignedBitSlice;
+	///
+	/// let mut src = [0u16; 2];
+	/// let bits = src.bits_mut::<LittleEndian>();
+	/// let aligned = AlignedBitSlice::new_mut(&mut bits[16 ..]).unwrap();
+	/// aligned.set(0, true);
+	/// aligned.as_mut_slice()[0] |= 4;
+	/// assert_eq!(src, [0, 5]);
+	/// ```
+	pub fn new_mut(bits: &mut SliceBit<C, T>) -> Option<&mut Self> {
+		if Self::is_aligned(bits) {
+			Some(unsafe { &mut *(bits as *mut SliceBit<C, T> as *mut Self) })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Views an element slice as an aligned bit slice.
+	///
+	/// Element slices are always aligned, so this cannot fail.
+	///
+	/// # Parameters
+	///
+	/// - `slice`: The elements to view.
+	///
+	/// # Returns
+	///
+	/// An `AlignedBitSlice` over all of `slice`.
+	pub fn from_slice(slice: &[T]) -> &Self {
+		unsafe {
+			&*(SliceBit::<C, T>::from_slice(slice) as *const SliceBit<C, T>
+				as *const Self)
+		}
+	}
+
+	/// Views a mutable element slice as an aligned bit slice.
+	///
+	/// Element slices are always aligned, so this cannot fail.
+	///
+	/// # Parameters
+	///
+	/// - `slice`: The elements to view.
+	///
+	/// # Returns
+	///
+	/// A mutable `AlignedBitSlice` over all of `slice`.
+	pub fn from_slice_mut(slice: &mut [T]) -> &mut Self {
+		unsafe {
+			&mut *(SliceBit::<C, T>::from_slice_mut(slice) as *mut SliceBit<C, T>
+				as *mut Self)
+		}
+	}
+
+	/// Views the aligned slice as an ordinary bit slice.
+	pub fn as_bitslice(&self) -> &SliceBit<C, T> {
+		&self.bits
+	}
+
+	/// Views the aligned slice as an ordinary mutable bit slice.
+	pub fn as_mut_bitslice(&mut self) -> &mut SliceBit<C, T> {
+		&mut self.bits
+	}
+
+	/// Accesses the underlying elements.
+	///
+	/// Unlike `SliceBit::as_slice`, this never omits an edge element, as an
+	/// aligned slice has no partial edges.
+	///
+	/// # Returns
+	///
+	/// Every element that the slice covers.
+	pub fn as_slice(&self) -> &[T] {
+		unsafe { slice::from_raw_parts(self.elt_ptr(), self.elements()) }
+	}
+
+	/// Mutably accesses the underlying elements.
+	///
+	/// # Returns
+	///
+	/// Every element that the slice covers.
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		let elts = self.elements();
+		unsafe { slice::from_raw_parts_mut(self.elt_ptr() as *mut T, elts) }
+	}
+
+	/// Accesses the underlying memory as bytes.
+	///
+	/// The bytes are in the target’s memory order, so for storage types wider
+	/// than `u8`, their order depends on the target’s byte endianness.
+	///
+	/// # Returns
+	///
+	/// Every byte of every element that the slice covers.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::aligned::AlignedBitSlice;
+	///
+	/// let src = [0x0102u16.to_be(), 0x0304u16.to_be()];
+	/// let aligned = AlignedBitSlice::<BigEndian, u16>::from_slice(&src);
+	/// assert_eq!(aligned.as_bytes(), &[1, 2, 3, 4]);
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] {
+		unsafe {
+			slice::from_raw_parts(
+				self.elt_ptr() as *const u8,
+				self.elements() * mem::size_of::<T>(),
+			)
+		}
+	}
+
+	/// Counts the set bits in the slice, one whole element at a time.
+	///
+	/// # Returns
+	///
+	/// The number of set bits in the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::aligned::AlignedBitSlice;
+	///
+	/// let src = [0xF0F0u16, 0x0001];
+	/// let aligned = AlignedBitSlice::<Local, u16>::from_slice(&src);
+	/// assert_eq!(aligned.count_ones(), 9);
+	/// ```
+	pub fn count_ones(&self) -> usize {
+		self.as_slice().iter().map(|elt| elt.count_ones()).sum()
+	}
+
+	/// Counts the cleared bits in the slice, one whole element at a time.
+	///
+	/// # Returns
+	///
+	/// The number of cleared bits in the slice.
+	pub fn count_zeros(&self) -> usize {
+		self.as_slice().iter().map(|elt| elt.count_zeros()).sum()
+	}
+
+	/// Tests whether a bit slice begins and ends on element edges.
+	fn is_aligned(bits: &SliceBit<C, T>) -> bool {
+		*bits.bitptr().head() == 0 && bits.len() & T::MASK as usize == 0
+	}
+
+	/// Counts the elements that the slice covers.
+	fn elements(&self) -> usize {
+		self.bits.len() >> T::INDX
+	}
+
+	/// Gets a pointer to the first element, which is never null.
+	fn elt_ptr(&self) -> *const T {
+		self.bits.bitptr().pointer().r()
+	}
+}
+
+impl<C, T> AsRef<SliceBit<C, T>> for AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn as_ref(&self) -> &SliceBit<C, T> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, T> AsRef<[T]> for AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn as_ref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<C, T> Debug for AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("Aligned")?;
+		Debug::fmt(&self.bits, f)
+	}
+}
+
+impl<C, T> Deref for AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Target = SliceBit<C, T>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<C, T> DerefMut for AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+/** Checks that a bit slice is aligned.
+
+On failure, the original slice is returned as the error.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::aligned::AlignedBitSlice;
+use std::convert::TryFrom;
+
+let src = [0u8; 3];
+let bits = src.bits::<BigEndian>();
+assert!(<&AlignedBitSlice<_, _>>::try_from(&bits[8 ..]).is_ok());
+assert_eq!(<&AlignedBitSlice<_, _>>::try_from(&bits[4 ..]).unwrap_err().len(), 20);
+```
+**/
+impl<'a, C, T> TryFrom<&'a SliceBit<C, T>> for &'a AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Error = &'a SliceBit<C, T>;
+
+	fn try_from(bits: &'a SliceBit<C, T>) -> Result<Self, Self::Error> {
+		AlignedBitSlice::new(bits).ok_or(bits)
+	}
+}
+
+impl<'a, C, T> TryFrom<&'a mut SliceBit<C, T>> for &'a mut AlignedBitSlice<C, T>
+where C: Cursor, T: BitStore {
+	type Error = &'a mut SliceBit<C, T>;
+
+	fn try_from(bits: &'a mut SliceBit<C, T>) -> Result<Self, Self::Error> {
+		if AlignedBitSlice::is_aligned(bits) {
+			Ok(AlignedBitSlice::new_mut(bits).unwrap())
+		}
+		else {
+			Err(bits)
+		}
+	}
+}
@@ -0,0 +1,312 @@
+/*! Fixed-size bit arrays
+
+A `BitArray` owns a fixed number of storage elements by value, and is used as a
+`SliceBit` over all of them. It needs no allocator, so it is available without
+the `alloc` feature, and it can live on the stack, in a `static`, or inside
+another structure without indirection.
+
+The compilers that this crate supports do not have const generics, so the size
+of the array is carried by its storage type, rather than by a number: a
+`BitArray<C, [u16; 4]>` holds 64 bits. Any type that implements [`BitsMut`]
+can be the storage, which includes the fundamental integers, and arrays of
+them of up to 32 elements.
+
+[`BitsMut`]: ../bits/trait.BitsMut.html
+!*/
+
+use crate::{
+	bits::BitsMut,
+	cursor::{
+		Cursor,
+		Local,
+	},
+	slice::SliceBit,
+	store::{
+		BitStore,
+		Word,
+	},
+};
+
+use core::{
+	cmp::Ordering,
+	convert::{
+		AsMut,
+		AsRef,
+		From,
+	},
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	marker::PhantomData,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+/** A fixed-size array of bits, held by value.
+
+A `BitArray` dereferences to a `SliceBit` over every bit of its storage, and so
+supports all of the ordinary slice operations. It is `Copy` and `Default`
+whenever its storage is.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the array.
+- `A: BitsMut`: The storage of the array, such as `[u8; 4]` or `u32`. The array
+  holds exactly as many bits as the storage does.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+
+let mut flags = BitArray::<BigEndian, [u8; 2]>::default();
+assert_eq!(flags.len(), 16);
+flags.set(3, true);
+flags[8 ..].set_all(true);
+let copy = flags;
+assert_eq!(copy.into_inner(), [0b0001_0000, 0xFF]);
+assert_eq!(flags.count_ones(), 9);
+```
+**/
+pub struct BitArray<C = Local, A = [Word; 1]>
+where C: Cursor, A: BitsMut {
+	/// Cursor type for selecting bits inside an element.
+	_cursor: PhantomData<C>,
+	/// The storage elements.
+	data: A,
+}
+
+impl<C, A> BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	/// Wraps storage as a bit array.
+	///
+	/// # Parameters
+	///
+	/// - `data`: The storage, whose bits become the bits of the array.
+	///
+	/// # Returns
+	///
+	/// A bit array over every bit in `data`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = BitArray::<LittleEndian, _>::new([1u16, 0x8000]);
+	/// assert!(bits[0] && bits[31]);
+	/// assert_eq!(bits.count_ones(), 2);
+	/// ```
+	pub fn new(data: A) -> Self {
+		Self {
+			_cursor: PhantomData,
+			data,
+		}
+	}
+
+	/// Unwraps the bit array into its storage.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The storage of the array.
+	pub fn into_inner(self) -> A {
+		self.data
+	}
+
+	/// Borrows the storage of the bit array.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A reference to the storage of the array.
+	pub fn as_inner(&self) -> &A {
+		&self.data
+	}
+
+	/// Mutably borrows the storage of the bit array.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable reference to the storage of the array.
+	pub fn as_mut_inner(&mut self) -> &mut A {
+		&mut self.data
+	}
+
+	/// Views the bit array as a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `SliceBit` over every bit in the array.
+	pub fn as_bitslice(&self) -> &SliceBit<C, A::Store> {
+		self.data.bits()
+	}
+
+	/// Views the bit array as a mutable bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable `SliceBit` over every bit in the array.
+	pub fn as_mut_bitslice(&mut self) -> &mut SliceBit<C, A::Store> {
+		self.data.bits_mut()
+	}
+}
+
+impl<C, A> Clone for BitArray<C, A>
+where C: Cursor, A: BitsMut + Clone {
+	fn clone(&self) -> Self {
+		Self::new(self.data.clone())
+	}
+}
+
+impl<C, A> Copy for BitArray<C, A>
+where C: Cursor, A: BitsMut + Copy {}
+
+impl<C, A> Eq for BitArray<C, A>
+where C: Cursor, A: BitsMut {}
+
+impl<C, A> Ord for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.as_bitslice().cmp(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, B> PartialEq<BitArray<D, B>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, B: BitsMut {
+	fn eq(&self, rhs: &BitArray<D, B>) -> bool {
+		self.as_bitslice().eq(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, U> PartialEq<SliceBit<D, U>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, U: BitStore {
+	fn eq(&self, rhs: &SliceBit<D, U>) -> bool {
+		self.as_bitslice().eq(rhs)
+	}
+}
+
+impl<C, A, D, U> PartialEq<&SliceBit<D, U>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, U: BitStore {
+	fn eq(&self, rhs: &&SliceBit<D, U>) -> bool {
+		self.as_bitslice().eq(*rhs)
+	}
+}
+
+impl<C, A, D, U> PartialEq<BitArray<C, A>> for SliceBit<D, U>
+where C: Cursor, A: BitsMut, D: Cursor, U: BitStore {
+	fn eq(&self, rhs: &BitArray<C, A>) -> bool {
+		self.eq(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, B> PartialOrd<BitArray<D, B>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, B: BitsMut {
+	fn partial_cmp(&self, rhs: &BitArray<D, B>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, U> PartialOrd<SliceBit<D, U>> for BitArray<C, A>
+where C: Cursor, A: BitsMut, D: Cursor, U: BitStore {
+	fn partial_cmp(&self, rhs: &SliceBit<D, U>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs)
+	}
+}
+
+impl<C, A> AsMut<SliceBit<C, A::Store>> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn as_mut(&mut self) -> &mut SliceBit<C, A::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<C, A> AsRef<SliceBit<C, A::Store>> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn as_ref(&self) -> &SliceBit<C, A::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> From<A> for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn from(data: A) -> Self {
+		Self::new(data)
+	}
+}
+
+impl<C, A> Default for BitArray<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn default() -> Self {
+		Self::new(A::default())
+	}
+}
+
+impl<C, A> Debug for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("BitArray<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(A::Store::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Display for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Hash for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<C, A> Deref for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	type Target = SliceBit<C, A::Store>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> DerefMut for BitArray<C, A>
+where C: Cursor, A: BitsMut {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
@@ -0,0 +1,176 @@
+/*! Apache Arrow validity bitmaps
+
+This module converts between bit slices and the boolean and validity buffers of
+the `arrow-buffer` crate, so that columnar pipelines can use this crate’s
+algorithms on their nullability masks.
+
+Arrow packs boolean buffers into bytes, least significant bit first, which is
+exactly the layout of `SliceBit<LittleEndian, u8>`. An Arrow buffer can carry a
+bit offset into its bytes, which a bit slice also represents directly. As such:
+
+- any Arrow buffer can be viewed as a `&SliceBit<LittleEndian, u8>` without
+  copying;
+- a `VecBit<LittleEndian, u8>` can be moved into an Arrow buffer without
+  copying;
+- a slice of any other cursor or storage type, or with a nonzero head, is copied
+  into a new Arrow buffer.
+!*/
+
+#![cfg(feature = "arrow")]
+
+use crate::{
+	access::BitAccess,
+	cursor::{
+		Cursor,
+		LittleEndian,
+		Stride,
+	},
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+use alloc::vec::Vec;
+
+use core::mem;
+
+use arrow_buffer::{
+	BooleanBuffer,
+	Buffer,
+	NullBuffer,
+};
+
+impl SliceBit<LittleEndian, u8> {
+	/// Views an Arrow boolean buffer as a bit slice.
+	///
+	/// This does not copy. The slice begins at the buffer’s bit offset, and
+	/// has the buffer’s length.
+	///
+	/// # Parameters
+	///
+	/// - `buffer`: An Arrow boolean buffer.
+	///
+	/// # Returns
+	///
+	/// A bit slice over the live bits of `buffer`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "arrow")] {
+	/// use vecbit::prelude::*;
+	/// use arrow_buffer::{BooleanBuffer, Buffer};
+	///
+	/// let bytes = Buffer::from_vec(vec![0b1010_0110u8]);
+	/// let buffer = BooleanBuffer::new(bytes, 1, 6);
+	/// let bits = SliceBit::from_arrow(&buffer);
+	/// assert_eq!(bits, &0b1010_0110u8.bits::<LittleEndian>()[1 .. 7]);
+	/// assert_eq!(bits.count_ones(), 3);
+	/// # }
+	/// ```
+	pub fn from_arrow(buffer: &BooleanBuffer) -> &Self {
+		let offset = buffer.offset();
+		&Self::from_slice(buffer.values())[offset .. offset + buffer.len()]
+	}
+
+	/// Views an Arrow validity buffer as a bit slice.
+	///
+	/// Each bit is set where the corresponding value is valid, and cleared
+	/// where it is null. This does not copy.
+	///
+	/// # Parameters
+	///
+	/// - `nulls`: An Arrow validity buffer.
+	///
+	/// # Returns
+	///
+	/// A bit slice over the validity bits of `nulls`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "arrow")] {
+	/// use vecbit::prelude::*;
+	/// use arrow_buffer::{BooleanBuffer, Buffer, NullBuffer};
+	///
+	/// let nulls = NullBuffer::new(BooleanBuffer::new(
+	///   Buffer::from_vec(vec![0xFFu8, 0b0000_0101]),
+	///   4,
+	///   8,
+	/// ));
+	/// let valid = SliceBit::from_validity(&nulls);
+	/// assert_eq!(valid.count_zeros(), 2);
+	/// assert!(!valid[5] && !valid[7]);
+	/// # }
+	/// ```
+	pub fn from_validity(nulls: &NullBuffer) -> &Self {
+		Self::from_arrow(nulls.inner())
+	}
+}
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Copies the slice into a new Arrow boolean buffer.
+	///
+	/// `LittleEndian` slices that begin on an element edge are copied a whole
+	/// element at a time; all others are copied bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An Arrow boolean buffer, at offset zero, holding the bits of `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "arrow")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b1100_0000u8.bits::<BigEndian>();
+	/// let buffer = bits[.. 3].to_arrow();
+	/// assert_eq!(buffer.len(), 3);
+	/// assert_eq!(buffer.values(), &[0b0000_0011]);
+	/// # }
+	/// ```
+	pub fn to_arrow(&self) -> BooleanBuffer {
+		let bitptr = self.bitptr();
+		if Stride::of::<C, T>() != Some(Stride::Ascending) || *bitptr.head() != 0 {
+			return self.iter().collect::<VecBit<LittleEndian, u8>>().into();
+		}
+		//  An ascending element holds its bits in the same order as its value
+		//  does, so its little-endian bytes are already in Arrow order.
+		let width = mem::size_of::<T>();
+		let mut bytes = Vec::with_capacity(bitptr.elements() * width);
+		for elt in bitptr.as_access_slice() {
+			let elt: u64 = elt.load().into();
+			bytes.extend_from_slice(&elt.to_le_bytes()[.. width]);
+		}
+		bytes.truncate((self.len() + 7) / 8);
+		let mut bv = VecBit::<LittleEndian, u8>::from_vec(bytes);
+		unsafe { bv.set_len(self.len()); }
+		bv.into()
+	}
+}
+
+/// Moves a vector into an Arrow boolean buffer, without copying.
+///
+/// The dead bits after the end of the vector are cleared first.
+impl From<VecBit<LittleEndian, u8>> for BooleanBuffer {
+	fn from(mut bv: VecBit<LittleEndian, u8>) -> Self {
+		bv.mask_dead_bits();
+		let len = bv.len();
+		BooleanBuffer::new(Buffer::from_vec(bv.into_vec()), 0, len)
+	}
+}
+
+/// Moves a vector into an Arrow validity buffer, without copying.
+///
+/// Each set bit marks a valid value, and each cleared bit a null.
+impl From<VecBit<LittleEndian, u8>> for NullBuffer {
+	fn from(bv: VecBit<LittleEndian, u8>) -> Self {
+		NullBuffer::new(bv.into())
+	}
+}
@@ -0,0 +1,329 @@
+/*! One-dimensional cellular automata
+
+This module steps a bit slice, read as a row of cells, to its next generation
+under a rule over each cell’s neighborhood of three: the cell to its left, the
+cell itself, and the cell to its right. The cells past either end of the slice
+are treated as cleared.
+
+When the current and next generations begin at the same bit of an element, as
+every `VecBit` does, and use the `BigEndian` or `LittleEndian` cursor, the rule
+is applied to a whole element of cells at once. The left and right neighbors of
+an element’s cells are formed by shifting the element by one cell and carrying
+in the edge cell of the adjacent element, so that each element is read once and
+written once. All other slices are stepped one cell at a time.
+
+[`Automaton`] holds two vectors and steps between them without allocating.
+
+[`Automaton`]: struct.Automaton.html
+!*/
+
+use crate::{
+	access::BitAccess,
+	cursor::{
+		Cursor,
+		Stride,
+	},
+	gather::live,
+	indices::Indexable,
+	slice::SliceBit,
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use crate::{
+	cursor::Local,
+	store::Word,
+	vec::VecBit,
+};
+
+#[cfg(feature = "alloc")]
+use core::mem;
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Writes the next generation of the slice’s cells, computed by a bitwise
+	/// function of each cell’s neighborhood.
+	///
+	/// `func` receives three elements, `(left, cell, right)`, and returns an
+	/// element of next states. Each bit of `cell` is one cell, and the bits in
+	/// the same place in `left` and `right` are its neighbors. `func` must be
+	/// bitwise, so that each bit of its output depends only on the bits in
+	/// the same place of its inputs, because it may be called with a whole
+	/// element of cells or with a single cell repeated through an element.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: The current generation.
+	/// - `next`: The slice which receives the next generation.
+	/// - `func`: The rule, as a bitwise function of the left neighbors, the
+	///   cells, and the right neighbors.
+	///
+	/// # Panics
+	///
+	/// Panics if `next` is not the same length as `self`.
+	///
+	/// # Examples
+	///
+	/// Rule 90, where each cell becomes the exclusive or of its neighbors:
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let cur = 0b0001_0000u8;
+	/// let mut next = 0u8;
+	/// cur.bits::<BigEndian>().step_cells(
+	///   next.bits_mut::<BigEndian>(),
+	///   |l, _, r| l ^ r,
+	/// );
+	/// assert_eq!(next, 0b0010_1000);
+	/// ```
+	pub fn step_cells<F>(&self, next: &mut Self, func: F)
+	where F: Fn(T, T, T) -> T {
+		assert_eq!(
+			self.len(),
+			next.len(),
+			"Cannot step {} cells into {} cells",
+			self.len(),
+			next.len(),
+		);
+		let stride = match Stride::of::<C, T>() {
+			Some(stride) if *self.bitptr().head() == *next.bitptr().head() => {
+				stride
+			},
+			_ => {
+				let len = self.len();
+				let cell = |n: usize| T::bits(unsafe { self.get_unchecked(n) });
+				let dead = T::bits(false);
+				for n in 0 .. len {
+					let l = if n == 0 { dead } else { cell(n - 1) };
+					let r = if n + 1 == len { dead } else { cell(n + 1) };
+					let out = func(l, cell(n), r).get::<C>(0.idx());
+					unsafe { next.set_unchecked(n, out); }
+				}
+				return;
+			},
+		};
+		let elts = self.bitptr().as_access_slice();
+		let outs = next.bitptr().as_access_slice();
+		let load = |n: usize| match elts.get(n) {
+			Some(elt) => elt.load() & live(self, stride, n),
+			None => T::bits(false),
+		};
+		let (mut prev, mut cell) = (T::bits(false), load(0));
+		for (n, out) in outs.iter().enumerate() {
+			let after = load(n + 1);
+			let l = stride.to_back(cell, 1) | stride.to_front(prev, T::BITS - 1);
+			let r = stride.to_front(cell, 1) | stride.to_back(after, T::BITS - 1);
+			let mask = live(self, stride, n);
+			out.clear_bits(!mask);
+			out.set_bits(func(l, cell, r) & mask);
+			prev = cell;
+			cell = after;
+		}
+	}
+
+	/// Writes the next generation of the slice’s cells under an elementary
+	/// cellular automaton rule.
+	///
+	/// The rule is numbered as Wolfram numbers them: bit `4l + 2c + r` of
+	/// `rule` is the next state of a cell `c` whose left neighbor is `l` and
+	/// whose right neighbor is `r`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: The current generation.
+	/// - `next`: The slice which receives the next generation.
+	/// - `rule`: The rule number.
+	///
+	/// # Panics
+	///
+	/// Panics if `next` is not the same length as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let cur = [0u8, 0b0000_0001];
+	/// let mut next = [0u8; 2];
+	/// cur.bits::<BigEndian>().step_rule(next.bits_mut::<BigEndian>(), 30);
+	/// assert_eq!(next, [0, 0b0000_0011]);
+	/// ```
+	pub fn step_rule(&self, next: &mut Self, rule: u8) {
+		self.step_cells(next, |l, c, r| {
+			let pick = |elt: T, set: u8| if set != 0 { elt } else { !elt };
+			(0 .. 8u8)
+				.filter(|n| rule >> n & 1 != 0)
+				.fold(T::bits(false), |acc, n| {
+					acc | pick(l, n & 4) & pick(c, n & 2) & pick(r, n & 1)
+				})
+		});
+	}
+}
+
+/** A one-dimensional cellular automaton, stepped between two buffers.
+
+The automaton owns its current generation and a second vector of the same
+length. Each step writes the next generation into the second vector and then
+exchanges the two, so stepping never allocates.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the cell vectors.
+- `T: BitStore`: The storage type of the cell vectors.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::automaton::Automaton;
+
+let mut life = Automaton::new(vecbit![BigEndian, u8; 0, 0, 0, 1, 0, 0, 0], 90);
+life.step();
+assert_eq!(life.cells(), &vecbit![0, 0, 1, 0, 1, 0, 0]);
+life.step();
+assert_eq!(life.cells(), &vecbit![0, 1, 0, 0, 0, 1, 0]);
+assert_eq!(life.generation(), 2);
+```
+**/
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Automaton<C = Local, T = Word>
+where C: Cursor, T: BitStore {
+	/// The current generation.
+	cells: VecBit<C, T>,
+	/// The buffer into which the next generation is written.
+	next: VecBit<C, T>,
+	/// The elementary rule number.
+	rule: u8,
+	/// The number of steps taken.
+	generation: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> Automaton<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs an automaton from its first generation and a rule.
+	///
+	/// # Parameters
+	///
+	/// - `cells`: The first generation.
+	/// - `rule`: The elementary rule number, as in [`SliceBit::step_rule`].
+	///
+	/// # Returns
+	///
+	/// An automaton at generation zero.
+	///
+	/// [`SliceBit::step_rule`]: ../slice/struct.SliceBit.html#method.step_rule
+	pub fn new(cells: VecBit<C, T>, rule: u8) -> Self {
+		let next = cells.clone();
+		Self { cells, next, rule, generation: 0 }
+	}
+
+	/// Borrows the current generation.
+	pub fn cells(&self) -> &SliceBit<C, T> {
+		&self.cells
+	}
+
+	/// Mutably borrows the current generation, to edit cells between steps.
+	pub fn cells_mut(&mut self) -> &mut SliceBit<C, T> {
+		&mut self.cells
+	}
+
+	/// Gets the elementary rule number.
+	pub fn rule(&self) -> u8 {
+		self.rule
+	}
+
+	/// Counts the steps taken since construction.
+	pub fn generation(&self) -> usize {
+		self.generation
+	}
+
+	/// Advances the automaton by one generation.
+	pub fn step(&mut self) {
+		self.cells.step_rule(&mut self.next, self.rule);
+		mem::swap(&mut self.cells, &mut self.next);
+		self.generation += 1;
+	}
+
+	/// Unwraps the current generation.
+	pub fn into_cells(self) -> VecBit<C, T> {
+		self.cells
+	}
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::*;
+	use crate::{
+		fixtures::each_subslice,
+		prelude::*,
+	};
+
+	fn model<C, T>(cells: &SliceBit<C, T>, rule: u8) -> VecBit<C, T>
+	where C: Cursor, T: BitStore {
+		let len = cells.len();
+		let cell = |n: usize| n < len && cells[n];
+		(0 .. len).map(|n| {
+			let (l, r) = (n > 0 && cell(n - 1), cell(n + 1));
+			let pattern = (l as u8) << 2 | (cell(n) as u8) << 1 | r as u8;
+			rule >> pattern & 1 == 1
+		}).collect()
+	}
+
+	fn check<C, T>(orig: &[T])
+	where C: Cursor, T: BitStore {
+		let total = orig.len() * T::BITS as usize;
+		let src = SliceBit::<C, T>::from_slice(orig);
+		let mut dst = orig.to_vec();
+		each_subslice(src, T::BITS as usize + 1, 5, |range, cur| {
+			let len = cur.len();
+			for &rule in &[30, 90, 110, 184, 255] {
+				for shift in 0 .. 2 {
+					dst.iter_mut().for_each(|elt| *elt = !T::bits(false));
+					let all = SliceBit::<C, T>::from_slice_mut(&mut dst);
+					let lo = range.start + shift;
+					if lo + len > total {
+						continue;
+					}
+					let next = &mut all[lo .. lo + len];
+					cur.step_rule(next, rule);
+					assert_eq!(*next, model(cur, rule), "{:?} {}", range, rule);
+					assert!(all[.. lo].all());
+					assert!(all[lo + len ..].all());
+				}
+			}
+		});
+	}
+
+	#[test]
+	fn matches_model() {
+		check::<BigEndian, u8>(&[0x5A, 0xC3, 0x0F, 0xF0, 0x99, 0x66]);
+		check::<LittleEndian, u32>(&[0x1234_5678, 0x9ABC_DEF0, 0x0F0F_F0F0]);
+		check::<BigEndian, u64>(&[0x1234_5678_9ABC_DEF0, 0x0F0F_F0F0_3C3C_C3C3]);
+	}
+
+	#[test]
+	fn double_buffered() {
+		let mut cells = vecbit![LittleEndian, u16; 0; 100];
+		cells.set(50, true);
+		let mut life = Automaton::new(cells.clone(), 110);
+		for _ in 0 .. 40 {
+			life.step();
+			cells = model(&cells, 110);
+			assert_eq!(life.cells(), &cells);
+		}
+		assert_eq!(life.generation(), 40);
+		assert_eq!(life.into_cells(), cells);
+	}
+
+	#[test]
+	#[should_panic]
+	fn length_mismatch() {
+		let cur = 0u8;
+		let mut next = 0u8;
+		cur.bits::<BigEndian>()
+			.step_rule(&mut next.bits_mut::<BigEndian>()[.. 7], 30);
+	}
+}
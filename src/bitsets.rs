@@ -0,0 +1,147 @@
+/*! Conversions with other bit-set crates
+
+This module converts between the `vecbit` types and the set types of the
+`fixedbitset` and `bit-set` crates, each governed by a feature of the same name,
+so that code which straddles these crates can migrate one piece at a time.
+
+Both crates store their bits in `u32` blocks, least significant bit first, which
+is the layout of `SliceBit<LittleEndian, u32>`. The conversions preserve the
+length of the set’s domain as well as its members: a bit is set in the output
+exactly when its index is in the input set. Only the runs of set bits in the
+input are copied; runs of cleared bits are skipped a whole element at a time.
+!*/
+
+#![cfg(all(
+	feature = "alloc",
+	any(feature = "bit-set", feature = "fixedbitset"),
+))]
+
+use crate::{
+	cursor::{
+		Cursor,
+		LittleEndian,
+	},
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+#[cfg(feature = "bit-set")]
+use bit_set::BitSet;
+
+#[cfg(feature = "fixedbitset")]
+use fixedbitset::FixedBitSet;
+
+/// Builds a vector from the `u32` blocks of another crate’s bit set.
+fn from_blocks<C, T>(blocks: &[u32], len: usize) -> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	let bits = &SliceBit::<LittleEndian, u32>::from_slice(blocks)[.. len];
+	VecBit::from_ranges(len, bits.iter_set_ranges())
+}
+
+/// Copies a bit slice into a `FixedBitSet` of the same length.
+#[cfg(feature = "fixedbitset")]
+impl<C, T> From<&SliceBit<C, T>> for FixedBitSet
+where C: Cursor, T: BitStore {
+	/// Copies the set bits of a slice into a new `FixedBitSet`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "fixedbitset")] {
+	/// use vecbit::prelude::*;
+	/// use fixedbitset::FixedBitSet;
+	///
+	/// let bv = vecbit![0, 1, 1, 0, 0, 1];
+	/// let set = FixedBitSet::from(bv.as_bitslice());
+	/// assert_eq!(set.len(), 6);
+	/// assert_eq!(set.ones().collect::<Vec<_>>(), [1, 2, 5]);
+	/// # }
+	/// ```
+	fn from(bits: &SliceBit<C, T>) -> Self {
+		let mut out = FixedBitSet::with_capacity(bits.len());
+		for range in bits.iter_set_ranges() {
+			out.insert_range(range);
+		}
+		out
+	}
+}
+
+/// Copies a `FixedBitSet` into a vector of the same length.
+#[cfg(feature = "fixedbitset")]
+impl<C, T> From<&FixedBitSet> for VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Copies the members of a `FixedBitSet` into a new vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "fixedbitset")] {
+	/// use vecbit::prelude::*;
+	/// use fixedbitset::FixedBitSet;
+	///
+	/// let mut set = FixedBitSet::with_capacity(10);
+	/// set.insert(3);
+	/// set.insert(9);
+	/// let bv = VecBit::<BigEndian, u8>::from(&set);
+	/// assert_eq!(bv.as_slice(), &[0b0001_0000, 0b0100_0000]);
+	/// # }
+	/// ```
+	fn from(set: &FixedBitSet) -> Self {
+		from_blocks(set.as_slice(), set.len())
+	}
+}
+
+/// Copies a bit slice into a `BitSet` whose capacity is the slice’s length.
+#[cfg(feature = "bit-set")]
+impl<C, T> From<&SliceBit<C, T>> for BitSet
+where C: Cursor, T: BitStore {
+	/// Copies the indices of the set bits of a slice into a new `BitSet`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "bit-set")] {
+	/// use vecbit::prelude::*;
+	/// use bit_set::BitSet;
+	///
+	/// let bv = vecbit![0, 1, 1, 0, 0, 1, 0];
+	/// let set = BitSet::from(bv.as_bitslice());
+	/// assert_eq!(set.len(), 3);
+	/// assert!(set.contains(5));
+	/// # }
+	/// ```
+	fn from(bits: &SliceBit<C, T>) -> Self {
+		let mut out = BitSet::with_capacity(bits.len());
+		for n in bits.iter_set_ranges().flatten() {
+			out.insert(n);
+		}
+		out
+	}
+}
+
+/// Copies a `BitSet` into a vector as long as the set’s capacity.
+#[cfg(feature = "bit-set")]
+impl<C, T> From<&BitSet> for VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Copies the members of a `BitSet` into a new vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "bit-set")] {
+	/// use vecbit::prelude::*;
+	/// use bit_set::BitSet;
+	///
+	/// let mut set = BitSet::with_capacity(8);
+	/// set.insert(0);
+	/// set.insert(6);
+	/// let bv = VecBit::<BigEndian, u8>::from(&set);
+	/// assert_eq!(bv.as_slice(), &[0b1000_0010]);
+	/// # }
+	/// ```
+	fn from(set: &BitSet) -> Self {
+		let bits = set.get_ref();
+		from_blocks(bits.storage(), bits.len())
+	}
+}
@@ -11,6 +11,7 @@ use crate::{
 		Cursor,
 		Local,
 	},
+	indices::Indexable,
 	pointer::BitPtr,
 	slice::SliceBit,
 	store::{
@@ -254,6 +255,11 @@ where C: Cursor, T: BitStore {
 
 	/// Removes the `BitBox` wrapper from a `Box<[T]>`.
 	///
+	/// If the `BitBox` does not end on an element edge, then the dead bits
+	/// after its end are exposed in the last element, and may hold any value.
+	/// Use [`try_into_boxed_slice`] or [`into_boxed_slice_zeroed`] when the
+	/// elements are passed on to code that must not observe them.
+	///
 	/// # Parameters
 	///
 	/// - `self`
@@ -273,6 +279,9 @@ where C: Cursor, T: BitStore {
 	/// let slice = bb.into_boxed_slice();
 	/// assert_eq!(slice.len(), 2);
 	/// ```
+	///
+	/// [`into_boxed_slice_zeroed`]: #method.into_boxed_slice_zeroed
+	/// [`try_into_boxed_slice`]: #method.try_into_boxed_slice
 	pub fn into_boxed_slice(self) -> Box<[T]> {
 		let slice = self.pointer.as_mut_slice();
 		let (data, elts) = (slice.as_mut_ptr(), slice.len());
@@ -282,6 +291,83 @@ where C: Cursor, T: BitStore {
 		out
 	}
 
+	/// Removes the `BitBox` wrapper from a `Box<[T]>`, if the `BitBox` has no
+	/// dead bits.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The `Box<[T]>` underneath `self`, if `self` ends on an element edge, or
+	/// else `self` unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bb = bitbox![BigEndian, u8; 1; 16];
+	/// assert_eq!(&*bb.try_into_boxed_slice().unwrap(), &[!0, !0]);
+	///
+	/// let bb = bitbox![BigEndian, u8; 1; 12];
+	/// assert_eq!(bb.try_into_boxed_slice().unwrap_err().len(), 12);
+	/// ```
+	pub fn try_into_boxed_slice(self) -> Result<Box<[T]>, Self> {
+		if self.len() & T::MASK as usize == 0 {
+			Ok(self.into_boxed_slice())
+		}
+		else {
+			Err(self)
+		}
+	}
+
+	/// Removes the `BitBox` wrapper from a `Box<[T]>`, clearing the dead bits
+	/// after its end.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The `Box<[T]>` underneath `self`, in which every bit after the last
+	/// live bit is cleared.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![BigEndian, u8; 1; 16];
+	/// bv.truncate(12);
+	/// let bb = bv.into_boxed_bitslice();
+	/// assert_eq!(&*bb.into_boxed_slice_zeroed(), &[!0, 0xF0]);
+	/// ```
+	pub fn into_boxed_slice_zeroed(mut self) -> Box<[T]> {
+		self.mask_dead_bits();
+		self.into_boxed_slice()
+	}
+
+	/// Clears the dead bits after the end of the `BitBox`.
+	///
+	/// See [`VecBit::mask_dead_bits`] for which operations may dirty them.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// [`VecBit::mask_dead_bits`]: ../vec/struct.VecBit.html#method.mask_dead_bits
+	pub fn mask_dead_bits(&mut self) {
+		let tail = *self.pointer.tail();
+		if let Some(last) = self.as_mut_slice().last_mut() {
+			for n in tail .. T::BITS {
+				last.set::<C>(n.idx(), false);
+			}
+		}
+	}
+
 	/// Constructs a `BitBox` from a raw `BitPtr`.
 	///
 	/// After calling this function, the raw pointer is owned by the resulting
@@ -554,6 +640,34 @@ where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
 	}
 }
 
+impl<A, B, C, D> PartialEq<&SliceBit<C, D>> for BitBox<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &&SliceBit<C, D>) -> bool {
+		self.as_bitslice().eq(*rhs)
+	}
+}
+
+impl<A, B, C, D> PartialEq<BitBox<C, D>> for &SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitBox<C, D>) -> bool {
+		(*self).eq(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialEq<VecBit<C, D>> for BitBox<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &VecBit<C, D>) -> bool {
+		self.as_bitslice().eq(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialEq<BitBox<C, D>> for VecBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &BitBox<C, D>) -> bool {
+		self.as_bitslice().eq(rhs.as_bitslice())
+	}
+}
+
 impl<A, B, C, D> PartialOrd<BitBox<C, D>> for BitBox<A, B>
 where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
 	fn partial_cmp(&self, rhs: &BitBox<C, D>) -> Option<Ordering> {
@@ -575,6 +689,34 @@ where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
 	}
 }
 
+impl<A, B, C, D> PartialOrd<&SliceBit<C, D>> for BitBox<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &&SliceBit<C, D>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(*rhs)
+	}
+}
+
+impl<A, B, C, D> PartialOrd<BitBox<C, D>> for &SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &BitBox<C, D>) -> Option<Ordering> {
+		(*self).partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialOrd<VecBit<C, D>> for BitBox<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &VecBit<C, D>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D> PartialOrd<BitBox<C, D>> for VecBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &BitBox<C, D>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs.as_bitslice())
+	}
+}
+
 impl<C, T> AsMut<SliceBit<C, T>> for BitBox<C, T>
 where C: Cursor, T: BitStore {
 	fn as_mut(&mut self) -> &mut SliceBit<C, T> {
@@ -0,0 +1,173 @@
+/*! `bytes` crate buffers as bit slices
+
+This module lets network code that receives its data in the `Bytes` and
+`BytesMut` buffers of the `bytes` crate parse bit-level fields out of them in
+place, without copying the buffer into a `VecBit`.
+
+- `Bytes` implements [`Bits`], and `BytesMut` implements [`Bits`] and
+  [`BitsMut`], so a borrowed buffer can be viewed as a `SliceBit<C, u8>` with
+  `.bits::<C>()` or `.bits_mut::<C>()`.
+- [`BitBytes`] is a [`SharedBits`] handle whose buffer is a `Bytes`. Like
+  `Bytes` itself, it can be cloned and narrowed without copying the buffer, so
+  a parsed region can outlive the borrow of the buffer it came from. It
+  requires the `alloc` feature as well.
+
+This module is governed by the `bytes` feature.
+
+[`BitBytes`]: type.BitBytes.html
+[`Bits`]: ../bits/trait.Bits.html
+[`BitsMut`]: ../bits/trait.BitsMut.html
+[`SharedBits`]: ../shared/struct.SharedBits.html
+!*/
+
+#![cfg(feature = "bytes")]
+
+use crate::{
+	bits::{
+		Bits,
+		BitsMut,
+	},
+	cursor::{
+		Cursor,
+		Portable,
+	},
+	slice::SliceBit,
+};
+
+#[cfg(feature = "alloc")]
+use crate::shared::{
+	SharedBits,
+	SharedBuffer,
+};
+
+use bytes::{
+	Bytes,
+	BytesMut,
+};
+
+impl Bits for Bytes {
+	type Store = u8;
+
+	/// Views the bytes of the buffer as a bit slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "bytes")] {
+	/// use vecbit::prelude::*;
+	/// use bytes::Bytes;
+	///
+	/// let packet = Bytes::from(vec![0x45u8, 0x00]);
+	/// let version = &packet.bits::<BigEndian>()[.. 4];
+	/// assert_eq!(version, &0x40u8.bits::<BigEndian>()[.. 4]);
+	/// # }
+	/// ```
+	fn bits<C>(&self) -> &SliceBit<C, u8>
+	where C: Cursor {
+		SliceBit::from_slice(self)
+	}
+}
+
+impl Bits for BytesMut {
+	type Store = u8;
+
+	fn bits<C>(&self) -> &SliceBit<C, u8>
+	where C: Cursor {
+		SliceBit::from_slice(self)
+	}
+}
+
+impl BitsMut for BytesMut {
+	/// Views the bytes of the buffer as a mutable bit slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "bytes")] {
+	/// use vecbit::prelude::*;
+	/// use bytes::BytesMut;
+	///
+	/// let mut buf = BytesMut::from(&[0u8; 2][..]);
+	/// buf.bits_mut::<BigEndian>()[4 .. 12].set_all(true);
+	/// assert_eq!(&buf[..], &[0x0F, 0xF0]);
+	/// # }
+	/// ```
+	fn bits_mut<C>(&mut self) -> &mut SliceBit<C, u8>
+	where C: Cursor {
+		SliceBit::from_slice_mut(self)
+	}
+}
+
+/** A region of bits within a shared `Bytes` buffer.
+
+This is a [`SharedBits`] handle whose reference-counted buffer is the `Bytes`
+handle itself, so it has all of the methods and traits of `SharedBits`. Cloning
+it and narrowing it do not copy the buffer. It dereferences to
+`SliceBit<C, u8>`, so every read-only slice method is available on it.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor with which the bytes are read as bits. This defaults
+  to `Portable`, so that a buffer is read the same way on every target.
+
+# Examples
+
+```rust
+# #[cfg(feature = "bytes")] {
+use vecbit::prelude::*;
+use vecbit::buffers::BitBytes;
+use bytes::Bytes;
+
+let mut frame = BitBytes::<BigEndian>::from(Bytes::from(vec![0xA5u8, 0x3C]));
+let flags = frame.split_to(3);
+assert_eq!(flags, &0xA0u8.bits::<BigEndian>()[.. 3]);
+assert_eq!(frame.len(), 13);
+assert_eq!(&frame.bytes()[..], &[0xA5, 0x3C]);
+# }
+```
+
+[`SharedBits`]: ../shared/struct.SharedBits.html
+**/
+#[cfg(feature = "alloc")]
+pub type BitBytes<C = Portable> = SharedBits<C, u8, Bytes>;
+
+#[cfg(feature = "alloc")]
+impl<C> SharedBuffer<C> for Bytes
+where C: Cursor {
+	type Store = u8;
+
+	fn bits(&self) -> &SliceBit<C, u8> {
+		SliceBit::from_slice(self)
+	}
+
+	fn ptr_eq(&self, other: &Self) -> bool {
+		self.as_ptr() == other.as_ptr() && self.len() == other.len()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C> SharedBits<C, u8, Bytes>
+where C: Cursor {
+	/// Borrows the whole underlying buffer, including any bytes outside the
+	/// region.
+	pub fn bytes(&self) -> &Bytes {
+		self.buffer()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C> From<Bytes> for SharedBits<C, u8, Bytes>
+where C: Cursor {
+	/// Takes ownership of a buffer, to be read as bits.
+	///
+	/// # Parameters
+	///
+	/// - `bytes`: A buffer handle.
+	///
+	/// # Returns
+	///
+	/// A region covering every bit in `bytes`.
+	fn from(bytes: Bytes) -> Self {
+		Self::whole(bytes)
+	}
+}
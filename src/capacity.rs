@@ -0,0 +1,193 @@
+/*! Capacity arithmetic.
+
+The owning handles allocate memory in whole `T` elements, but measure their
+contents in bits. This module collects the conversions between the two units in
+one place, so that the allocation paths (`with_capacity`, `reserve`, `push`, and
+the constructor macros) all agree on how many elements a given bit count needs.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	indices::BitTail,
+	pointer::BitPtr,
+	store::BitStore,
+	vec::CapacityError,
+};
+
+use alloc::{
+	alloc::{
+		alloc,
+		Layout,
+	},
+	vec::Vec,
+};
+
+use core::mem;
+
+/** Computes the number of elements required to hold a number of bits.
+
+The bits are assumed to begin at the front edge of the first element.
+
+# Parameters
+
+- `bits`: The number of bits to store.
+
+# Returns
+
+The minimum number of `T` elements that can hold `bits` bits. This is `0` when
+`bits` is `0`.
+
+# Type Parameters
+
+- `T: BitStore`: The storage element type.
+**/
+#[inline]
+pub(crate) fn elts_for_bits<T>(bits: usize) -> usize
+where T: BitStore {
+	let full = bits >> T::INDX;
+	let partial = (bits & T::MASK as usize != 0) as usize;
+	full + partial
+}
+
+/** Computes the number of dead bits remaining in the last element of a region.
+
+# Parameters
+
+- `tail`: The tail index of a region. `0` marks a region that touches no
+  elements, and `T::BITS` marks a region whose last element is full.
+
+# Returns
+
+The number of bits that can be written after `tail` without touching a new
+element.
+
+# Type Parameters
+
+- `T: BitStore`: The storage element type.
+**/
+#[inline]
+pub(crate) fn bits_free_in_tail<T>(tail: BitTail<T>) -> usize
+where T: BitStore {
+	match *tail {
+		0 => 0,
+		t => (T::BITS - t) as usize,
+	}
+}
+
+/** Computes the number of new elements needed to grow a region by some bits.
+
+# Parameters
+
+- `tail`: The tail index of the region being grown.
+- `additional`: The number of bits to be appended after `tail`.
+
+# Returns
+
+The number of elements that must be added after the current last element in
+order to hold `additional` more bits.
+
+# Type Parameters
+
+- `T: BitStore`: The storage element type.
+**/
+#[inline]
+pub(crate) fn elts_to_grow<T>(tail: BitTail<T>, additional: usize) -> usize
+where T: BitStore {
+	elts_for_bits::<T>(additional.saturating_sub(bits_free_in_tail(tail)))
+}
+
+/** Allocates an empty vector of elements, reporting failure rather than
+aborting.
+
+# Parameters
+
+- `elements`: The number of elements for which to allocate space.
+
+# Returns
+
+An empty `Vec` with exactly `elements` capacity.
+
+# Errors
+
+- `CapacityError::Overflow` if `elements` exceeds `BitPtr::<T>::MAX_ELTS`, or
+  the allocation size overflows `usize`.
+- `CapacityError::Alloc` if the allocator cannot provide the memory.
+
+# Type Parameters
+
+- `T: BitStore`: The storage element type.
+**/
+pub(crate) fn try_allocate<T>(elements: usize) -> Result<Vec<T>, CapacityError>
+where T: BitStore {
+	if elements > BitPtr::<T>::MAX_ELTS {
+		return Err(CapacityError::Overflow);
+	}
+	if elements == 0 {
+		return Ok(Vec::new());
+	}
+	let layout = elements.checked_mul(mem::size_of::<T>())
+		.and_then(|size| {
+			Layout::from_size_align(size, mem::align_of::<T>()).ok()
+		})
+		.ok_or(CapacityError::Overflow)?;
+	//  The block is allocated exactly as `Vec::<T>::with_capacity` would, so
+	//  the `Vec` may take ownership of it.
+	let ptr = unsafe { alloc(layout) } as *mut T;
+	if ptr.is_null() {
+		return Err(CapacityError::Alloc { elements });
+	}
+	Ok(unsafe { Vec::from_raw_parts(ptr, 0, elements) })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indices::Indexable;
+
+	#[test]
+	fn elts_for_bits_u8() {
+		assert_eq!(elts_for_bits::<u8>(0), 0);
+		assert_eq!(elts_for_bits::<u8>(1), 1);
+		assert_eq!(elts_for_bits::<u8>(8), 1);
+		assert_eq!(elts_for_bits::<u8>(9), 2);
+		assert_eq!(elts_for_bits::<u8>(16), 2);
+		assert_eq!(elts_for_bits::<u8>(17), 3);
+	}
+
+	#[test]
+	fn elts_for_bits_u32() {
+		assert_eq!(elts_for_bits::<u32>(0), 0);
+		assert_eq!(elts_for_bits::<u32>(31), 1);
+		assert_eq!(elts_for_bits::<u32>(32), 1);
+		assert_eq!(elts_for_bits::<u32>(33), 2);
+	}
+
+	#[test]
+	fn bits_free() {
+		assert_eq!(bits_free_in_tail::<u8>(0u8.tail()), 0);
+		assert_eq!(bits_free_in_tail::<u8>(1u8.tail()), 7);
+		assert_eq!(bits_free_in_tail::<u8>(7u8.tail()), 1);
+		assert_eq!(bits_free_in_tail::<u8>(8u8.tail()), 0);
+
+		assert_eq!(bits_free_in_tail::<u16>(16u8.tail()), 0);
+		assert_eq!(bits_free_in_tail::<u16>(4u8.tail()), 12);
+	}
+
+	#[test]
+	fn grow() {
+		//  An empty region needs fresh elements for everything.
+		assert_eq!(elts_to_grow::<u8>(0u8.tail(), 0), 0);
+		assert_eq!(elts_to_grow::<u8>(0u8.tail(), 1), 1);
+		assert_eq!(elts_to_grow::<u8>(0u8.tail(), 9), 2);
+
+		//  A partial tail absorbs bits before requesting new elements.
+		assert_eq!(elts_to_grow::<u8>(3u8.tail(), 5), 0);
+		assert_eq!(elts_to_grow::<u8>(3u8.tail(), 6), 1);
+
+		//  A full tail (issue #15) must not be treated as a head index.
+		assert_eq!(elts_to_grow::<u8>(8u8.tail(), 0), 0);
+		assert_eq!(elts_to_grow::<u8>(8u8.tail(), 8), 1);
+		assert_eq!(elts_to_grow::<u8>(8u8.tail(), 16), 2);
+	}
+}
@@ -0,0 +1,296 @@
+/*! C ABI for a minimal bit buffer
+
+This module exports `extern "C"` functions over an owned bit vector, so that C
+and C++ programs, and Python through `cffi`, can use this crate’s bit handling
+without writing their own unsafe bridge. It is governed by the `capi` feature.
+
+The vector is `VecBit<LittleEndian, u8>`: bit `n` of the buffer lives in byte
+`n / 8`, at the bit whose value is `1 << (n % 8)`. Foreign code sees it only
+through an opaque pointer, which it receives from [`vecbit_new`] or
+[`vecbit_from_bytes`] and must release with [`vecbit_free`].
+
+Functions that take an index report, rather than panic on, an index out of
+bounds, since a panic may not unwind into foreign code. Functions that take a
+length report a length longer than any buffer can hold in the same way. Should
+a panic occur regardless, the process is aborted rather than unwound, which is
+why this feature requires `std`. The dead bits after the end of the buffer are
+always cleared, so the bytes exposed by [`vecbit_as_bytes`] can be hashed or
+compared without masking.
+
+A C header for these functions is:
+
+```c
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+typedef struct vecbit vecbit;
+
+vecbit *vecbit_new(size_t len);
+vecbit *vecbit_from_bytes(const uint8_t *bytes, size_t len);
+void vecbit_free(vecbit *bits);
+size_t vecbit_len(const vecbit *bits);
+int32_t vecbit_get(const vecbit *bits, size_t index);
+bool vecbit_set(vecbit *bits, size_t index, bool value);
+bool vecbit_push(vecbit *bits, bool value);
+size_t vecbit_count_ones(const vecbit *bits);
+const uint8_t *vecbit_as_bytes(const vecbit *bits, size_t *len);
+```
+
+[`vecbit_as_bytes`]: fn.vecbit_as_bytes.html
+[`vecbit_free`]: fn.vecbit_free.html
+[`vecbit_from_bytes`]: fn.vecbit_from_bytes.html
+[`vecbit_new`]: fn.vecbit_new.html
+!*/
+
+#![cfg(feature = "capi")]
+
+use crate::{
+	cursor::LittleEndian,
+	pointer::BitPtr,
+	vec::VecBit,
+};
+
+use alloc::{
+	boxed::Box,
+	vec,
+};
+
+use core::{
+	ptr,
+	slice,
+};
+
+use std::{
+	panic::{
+		self,
+		AssertUnwindSafe,
+	},
+	process,
+};
+
+/// The bit vector behind the opaque handle.
+pub type CBits = VecBit<LittleEndian, u8>;
+
+/// Runs the body of an exported function, aborting the process if it panics.
+///
+/// Unwinding out of an `extern "C"` function is undefined behavior, so every
+/// export runs inside this guard.
+fn guard<F, R>(func: F) -> R
+where F: FnOnce() -> R {
+	panic::catch_unwind(AssertUnwindSafe(func))
+		.unwrap_or_else(|_| process::abort())
+}
+
+/// Counts the bytes that hold `len` bits, without overflowing.
+fn bytes_for(len: usize) -> usize {
+	len / 8 + (len % 8 != 0) as usize
+}
+
+/// Creates a buffer of cleared bits.
+///
+/// # Parameters
+///
+/// - `len`: The number of bits in the buffer.
+///
+/// # Returns
+///
+/// A handle to a new buffer of `len` cleared bits, or null if `len` is longer
+/// than any buffer can hold. A handle must be released with [`vecbit_free`].
+///
+/// [`vecbit_free`]: fn.vecbit_free.html
+#[no_mangle]
+pub extern "C" fn vecbit_new(len: usize) -> *mut CBits {
+	if len > BitPtr::<u8>::MAX_BITS {
+		return ptr::null_mut();
+	}
+	guard(|| {
+		let mut bv = CBits::from_vec(vec![0; bytes_for(len)]);
+		unsafe { bv.set_len(len); }
+		Box::into_raw(Box::new(bv))
+	})
+}
+
+/// Creates a buffer by copying packed bytes.
+///
+/// # Parameters
+///
+/// - `bytes`: The first of `(len + 7) / 8` bytes, least significant bit first.
+///   This may be null if `len` is zero.
+/// - `len`: The number of bits to copy.
+///
+/// # Returns
+///
+/// A handle to a new buffer of `len` bits, or null if `len` is longer than any
+/// buffer can hold. A handle must be released with [`vecbit_free`]. Any bits
+/// after `len` in the last byte are not copied.
+///
+/// # Safety
+///
+/// `bytes` must point to at least `(len + 7) / 8` readable bytes.
+///
+/// [`vecbit_free`]: fn.vecbit_free.html
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_from_bytes(
+	bytes: *const u8,
+	len: usize,
+) -> *mut CBits {
+	if len > BitPtr::<u8>::MAX_BITS {
+		return ptr::null_mut();
+	}
+	guard(|| {
+		let elts = bytes_for(len);
+		let src = match elts {
+			0 => &[][..],
+			_ => slice::from_raw_parts(bytes, elts),
+		};
+		let mut bv = CBits::from_vec(src.to_vec());
+		bv.set_len(len);
+		bv.mask_dead_bits();
+		Box::into_raw(Box::new(bv))
+	})
+}
+
+/// Releases a buffer.
+///
+/// # Parameters
+///
+/// - `bits`: A handle to release. This does nothing if `bits` is null.
+///
+/// # Safety
+///
+/// `bits` must be null, or a handle that has not already been released.
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_free(bits: *mut CBits) {
+	if !bits.is_null() {
+		guard(|| drop(Box::from_raw(bits)));
+	}
+}
+
+/// Counts the bits in a buffer.
+///
+/// # Safety
+///
+/// `bits` must be a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_len(bits: *const CBits) -> usize {
+	guard(|| (*bits).len())
+}
+
+/// Reads a bit.
+///
+/// # Parameters
+///
+/// - `bits`: A buffer.
+/// - `index`: The index of the bit to read.
+///
+/// # Returns
+///
+/// `1` if the bit is set, `0` if it is cleared, and `-1` if `index` is out of
+/// bounds.
+///
+/// # Safety
+///
+/// `bits` must be a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_get(bits: *const CBits, index: usize) -> i32 {
+	guard(|| match (*bits).get(index) {
+		Some(bit) => bit as i32,
+		None => -1,
+	})
+}
+
+/// Writes a bit.
+///
+/// # Parameters
+///
+/// - `bits`: A buffer.
+/// - `index`: The index of the bit to write.
+/// - `value`: The value to write.
+///
+/// # Returns
+///
+/// Whether `index` is in bounds. The buffer is unchanged if it is not.
+///
+/// # Safety
+///
+/// `bits` must be a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_set(
+	bits: *mut CBits,
+	index: usize,
+	value: bool,
+) -> bool {
+	let bits = &mut *bits;
+	if index >= bits.len() {
+		return false;
+	}
+	guard(|| bits.set(index, value));
+	true
+}
+
+/// Appends a bit to the end of a buffer.
+///
+/// This may reallocate the buffer, invalidating any pointer previously
+/// returned by [`vecbit_as_bytes`].
+///
+/// # Returns
+///
+/// Whether the bit was appended. The buffer is unchanged if it is already as
+/// long as any buffer can be.
+///
+/// # Safety
+///
+/// `bits` must be a live handle.
+///
+/// [`vecbit_as_bytes`]: fn.vecbit_as_bytes.html
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_push(bits: *mut CBits, value: bool) -> bool {
+	let bits = &mut *bits;
+	if bits.len() >= BitPtr::<u8>::MAX_BITS {
+		return false;
+	}
+	guard(|| bits.push(value));
+	true
+}
+
+/// Counts the set bits in a buffer.
+///
+/// # Safety
+///
+/// `bits` must be a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_count_ones(bits: *const CBits) -> usize {
+	guard(|| (*bits).count_ones())
+}
+
+/// Borrows the packed bytes of a buffer.
+///
+/// # Parameters
+///
+/// - `bits`: A buffer.
+/// - `len`: If not null, receives the number of bytes, `(n + 7) / 8` for a
+///   buffer of `n` bits.
+///
+/// # Returns
+///
+/// A pointer to the first byte of the buffer, least significant bit first, or
+/// null if the buffer is empty. Any bits after the end of the buffer are
+/// cleared. The pointer is valid until the buffer is released or grown.
+///
+/// # Safety
+///
+/// `bits` must be a live handle, and `len` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn vecbit_as_bytes(
+	bits: *const CBits,
+	len: *mut usize,
+) -> *const u8 {
+	guard(|| {
+		let bytes = (*bits).as_slice();
+		if !len.is_null() {
+			*len = bytes.len();
+		}
+		if bytes.is_empty() { ptr::null() } else { bytes.as_ptr() }
+	})
+}
@@ -0,0 +1,157 @@
+/*! Constant-time bit access
+
+The ordinary accessors of `SliceBit` are free to branch on the values of the
+bits they touch, and its comparisons stop at the first difference they find.
+That leaks the contents of the slice through timing, which is unacceptable when
+the slice holds a secret, such as a key mask in a cryptographic protocol.
+
+The methods in this module touch memory and execute instructions that depend
+only on the indices and lengths involved, never on the values of the bits. The
+indices and lengths are treated as public: they are bounds-checked, and may
+cause panics, as usual.
+
+This protects the operations themselves. The `bool` that they produce is still
+a secret, and callers must take care not to branch on it in turn.
+!*/
+
+use crate::{
+	access::BitAccess,
+	cursor::Cursor,
+	slice::{
+		check_index,
+		split_elements,
+		SliceBit,
+	},
+	store::BitStore,
+};
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Gets the bit value at an index, without branching on it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to retrieve. This is not secret.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is not less than `self.len()`. With the `no_panic`
+	/// feature, it panics only in debug builds, and otherwise produces `false`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b0010_0000u8.bits::<BigEndian>();
+	/// assert!(bits.get_ct(2));
+	/// assert!(!bits.get_ct(3));
+	/// ```
+	pub fn get_ct(&self, index: usize) -> bool {
+		check_index(index, self.len())
+			&& unsafe { self.get_ct_unchecked(index) }
+	}
+
+	/// Sets the bit value at an index, without branching on it.
+	///
+	/// The element holding the bit is always written, with the same sequence
+	/// of operations for either value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The bit index to set. This is not secret.
+	/// - `value`: The value to write into the bit.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is not less than `self.len()`. With the `no_panic`
+	/// feature, it panics only in debug builds, and otherwise does nothing.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut elt = 0u8;
+	/// elt.bits_mut::<LittleEndian>().set_ct(1, true);
+	/// elt.bits_mut::<LittleEndian>().set_ct(7, false);
+	/// assert_eq!(elt, 0b0000_0010);
+	/// ```
+	pub fn set_ct(&mut self, index: usize, value: bool) {
+		if !check_index(index, self.len()) {
+			return;
+		}
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		let elt = unsafe { &*bitptr.pointer().a().offset(elt) };
+		elt.clear_bit::<C>(bit);
+		elt.set_bits(T::from(value as u8) << *C::at(bit));
+	}
+
+	/// Tests two slices for equality, without stopping at a difference.
+	///
+	/// Every bit of both slices is inspected, whether or not they are equal.
+	/// Their lengths are not secret, and slices of different lengths compare
+	/// unequal at once.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The slice to compare against `self`.
+	///
+	/// # Returns
+	///
+	/// Whether `self` and `other` hold the same bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let key = [0x5Au8, 0xC3];
+	/// let guess = [0x5Au8, 0xC7];
+	/// let key = key.bits::<BigEndian>();
+	/// let guess = guess.bits::<BigEndian>();
+	/// assert!(!key.ct_eq(guess));
+	/// assert!(key[.. 12].ct_eq(&guess[.. 12]));
+	/// assert!(!key.ct_eq(&guess[.. 12]));
+	/// ```
+	pub fn ct_eq(&self, other: &Self) -> bool {
+		let len = self.len();
+		if len != other.len() {
+			return false;
+		}
+		let diff = |n| unsafe {
+			(self.get_ct_unchecked(n) ^ other.get_ct_unchecked(n)) as u8
+		};
+		if *self.bitptr().head() != *other.bitptr().head() {
+			return (0 .. len).fold(0, |acc, n| acc | diff(n)) == 0;
+		}
+		let (lead, elts, rest) = split_elements(self);
+		let elts_a = &self.as_total_slice()[elts.clone()];
+		let elts_b = &other.as_total_slice()[elts];
+		let bits = lead.chain(rest).fold(0, |acc, n| acc | diff(n));
+		let elts = elts_a.iter()
+			.zip(elts_b)
+			.fold(T::bits(false), |acc, (a, b)| acc | (a.load() ^ b.load()));
+		(bits == 0) & (elts == T::bits(false))
+	}
+
+	/// Gets a bit by shifting it out of its element.
+	///
+	/// # Safety
+	///
+	/// `index` must be less than `self.len()`.
+	unsafe fn get_ct_unchecked(&self, index: usize) -> bool {
+		let bitptr = self.bitptr();
+		let (elt, bit) = bitptr.head().offset(index as isize);
+		let elt = (&*bitptr.pointer().a().offset(elt)).load();
+		(elt >> *C::at(bit)) & T::from(1u8) == T::from(1u8)
+	}
+}
@@ -0,0 +1,182 @@
+/*! Counting representations of bitmaps
+
+A bitmap records whether each position is present. A counting representation
+widens each position into a small counter, of a fixed number of bits, which
+records how many times it is present. This is the storage of a counting Bloom
+filter: each insertion adds the bitmap of the inserted key into the counters,
+each removal subtracts it, and the filter’s bitmap is the set of nonzero
+counters.
+
+Counters are laid out one after another, each with its most significant bit
+first, as [`VecBit::from_symbols`] writes and [`SliceBit::iter_chunks_as`]
+reads them. Counter `i` of width `w` occupies the bits `w * i .. w * (i + 1)`.
+
+[`SliceBit::iter_chunks_as`]: ../slice/struct.SliceBit.html#method.iter_chunks_as
+[`VecBit::from_symbols`]: ../vec/struct.VecBit.html#method.from_symbols
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::Cursor,
+	fields::ChunkTail,
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Widens the slice into counters, each holding 1 where the slice is set
+	/// and 0 where it is clear.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The number of bits in each counter.
+	///
+	/// # Returns
+	///
+	/// A vector of `self.len()` counters.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or greater than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = vecbit![BigEndian, u8; 1, 0, 1];
+	/// let counts = bits.to_counts(4);
+	/// assert_eq!(counts.as_slice(), &[0b0001_0000, 0b0001_0000]);
+	/// assert_eq!(counts.len(), 12);
+	/// ```
+	pub fn to_counts(&self, width: u8) -> VecBit<C, T> {
+		check_width(width);
+		let width = width as usize;
+		let mut out = VecBit::with_capacity(self.len() * width);
+		for bit in self.iter() {
+			out.resize(out.len() + width - 1, false);
+			out.push(bit);
+		}
+		out
+	}
+
+	/// Adds counters into the counters in the slice, stopping each at its
+	/// maximum value rather than overflowing.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A sequence of counters.
+	/// - `other`: A sequence of as many counters of the same width, such as one
+	///   produced by [`to_counts`].
+	/// - `width`: The number of bits in each counter.
+	///
+	/// # Returns
+	///
+	/// The number of counters that saturated, whose sums did not fit.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or greater than 64, if the length of `self` is
+	/// not a multiple of `width`, or if `other` has a different length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut counts = vecbit![1, 0,  1, 1,  0, 0];
+	/// let key = vecbit![1, 1, 0].to_counts(2);
+	/// assert_eq!(counts.saturating_add_counts(&key, 2), 1);
+	/// assert_eq!(counts, vecbit![1, 1,  1, 1,  0, 0]);
+	/// ```
+	///
+	/// [`to_counts`]: #method.to_counts
+	pub fn saturating_add_counts<D, U>(
+		&mut self,
+		other: &SliceBit<D, U>,
+		width: u8,
+	) -> usize
+	where D: Cursor, U: BitStore {
+		check_width(width);
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Counter sequences must have the same length",
+		);
+		let max = !0u64 >> (64 - width);
+		let addends = other.iter_chunks_as::<u64>(width, ChunkTail::Exact)
+			.expect("Counter sequences must be a whole number of counters");
+		let mut saturated = 0;
+		for (n, add) in addends.enumerate() {
+			let range = n * width as usize .. (n + 1) * width as usize;
+			let old = self[range.clone()].iter()
+				.fold(0u64, |acc, bit| acc << 1 | bit as u64);
+			let new = match old.checked_add(add) {
+				Some(sum) if sum <= max => sum,
+				_ => {
+					saturated += 1;
+					max
+				},
+			};
+			if new != old {
+				let counter = &mut self[range];
+				for (k, shift) in (0 .. width).rev().enumerate() {
+					counter.set(k, new >> shift & 1 == 1);
+				}
+			}
+		}
+		saturated
+	}
+}
+
+impl<C, T> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Narrows a sequence of counters into the bitmap of those that are not
+	/// zero.
+	///
+	/// # Parameters
+	///
+	/// - `counts`: A sequence of counters.
+	/// - `width`: The number of bits in each counter.
+	///
+	/// # Returns
+	///
+	/// A bitmap with one bit per counter, set where the counter is nonzero.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or greater than 64, or if the length of
+	/// `counts` is not a multiple of `width`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let counts = vecbit![0, 0, 1,  0, 0, 0,  1, 1, 0];
+	/// let bits = VecBit::<Local, Word>::from_counts(&counts, 3);
+	/// assert_eq!(bits, vecbit![1, 0, 1]);
+	/// ```
+	pub fn from_counts<D, U>(counts: &SliceBit<D, U>, width: u8) -> Self
+	where D: Cursor, U: BitStore {
+		check_width(width);
+		assert_eq!(
+			counts.len() % width as usize,
+			0,
+			"Counter sequences must be a whole number of counters",
+		);
+		counts.chunks(width as usize).map(SliceBit::any).collect()
+	}
+}
+
+fn check_width(width: u8) {
+	assert!(
+		width != 0 && width <= 64,
+		"Counter width {} is not in 1 ..= 64",
+		width,
+	);
+}
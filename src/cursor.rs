@@ -6,14 +6,23 @@ electrical position (indicated by the `BitPos` type) within that element, and
 also defines the order of traversal over an element.
 
 The only requirement on implementors of `Cursor` is that the transform function
-from cursor (`BitIdx`) to position (`BitPos`) is *total* (every integer in the
-domain `0 .. T::BITS` is used) and *unique* (each cursor maps to one and only
-one position, and each position is mapped by one and only one cursor).
-Contiguity is not required.
+from cursor (`BitIdx`) to position (`BitPos`) is a *pure bijection* on the
+domain `0 .. T::BITS`: it is *total* (every integer in the domain is used),
+*unique* (each cursor maps to one and only one position, and each position is
+mapped by one and only one cursor), and *pure* (the same cursor always maps to
+the same position). Contiguity is not required.
+
+Beyond the two primary orderings, this module provides the `Reversed` adapter,
+which walks an element in the opposite order of another cursor, and the
+`Permuted` cursor, which reads its ordering from a lookup table. These allow
+interleaved layouts, such as the bit-reversed addressing used by FFT buffers, to
+be described without writing a `Cursor` implementation by hand.
 
 `Cursor` is a stateless trait, and implementors should be zero-sized types.
 !*/
 
+use core::marker::PhantomData;
+
 use crate::{
 	indices::{
 		BitIdx,
@@ -214,6 +223,351 @@ ordering is chosen as an uninformed default.
 #[cfg(not(any(target_endian = "big", target_endian = "little")))]
 pub type Local = LittleEndian;
 
+/** A bit ordering for data that leaves the program.
+
+`Local` names a different cursor on targets of different byte endianness, so
+bits written under it on one target may be read in the wrong order on another.
+`Portable` is always `LittleEndian`, under which bit `n` of an element is the
+bit with numeric value `1 << n` on every target.
+**/
+pub type Portable = LittleEndian;
+
+/** Traverses an element in the opposite order of another cursor.
+
+The first bit of `Reversed<C>` is the last bit of `C`, and so on, so that
+`Reversed<BigEndian>` walks the same positions as `LittleEndian`.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor whose ordering is reversed.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::cursor::Reversed;
+
+let src = [0b1000_0010u8];
+let bits = src.bits::<Reversed<BigEndian>>();
+assert!(bits[1]);
+assert!(bits[7]);
+assert_eq!(bits, src.bits::<LittleEndian>());
+```
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Reversed<C>(PhantomData<C>)
+where C: Cursor;
+
+impl<C> Cursor for Reversed<C>
+where C: Cursor {
+	const TYPENAME: &'static str = "Reversed";
+
+	/// Maps a semantic count to the position that `C` gives to the count the
+	/// same distance from the back of the element.
+	fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+	where T: BitStore {
+		C::at::<T>((T::MASK - *cursor).idx())
+	}
+
+	fn mask<T>(cursor: BitIdx<T>) -> BitMask<T>
+	where T: BitStore {
+		C::mask::<T>((T::MASK - *cursor).idx())
+	}
+}
+
+/** A source of lookup tables for the `Permuted` cursor.
+
+Implementors describe a bit ordering as a table for each element width they
+support. The table for a width of `n` bits has `n` entries, and entry `i` is
+the electrical position of the bit at semantic index `i`.
+
+Each table **must** be a permutation of `0 .. n`: every position appears in it
+exactly once. The table for a width **must** be the same on every call. Tables
+that break these rules violate the `Cursor` contract.
+
+# Examples
+
+This ordering walks a byte from most to least significant bit, but with each
+pair of adjacent bits exchanged.
+
+```rust
+use vecbit::prelude::*;
+use vecbit::cursor::{
+	Permuted,
+	PermutationTable,
+};
+
+struct PairSwap;
+
+impl PermutationTable for PairSwap {
+	const TYPENAME: &'static str = "PairSwap";
+
+	fn table(width: u8) -> &'static [u8] {
+		match width {
+			8 => &[6, 7, 4, 5, 2, 3, 0, 1],
+			_ => unimplemented!("PairSwap only supports bytes"),
+		}
+	}
+}
+
+let src = [0b0100_0000u8];
+let bits = src.bits::<Permuted<PairSwap>>();
+assert!(bits[0]);
+assert_eq!(bits.count_ones(), 1);
+```
+**/
+pub trait PermutationTable {
+	/// Name of the ordering, for use in text display.
+	const TYPENAME: &'static str;
+
+	/// Produces the lookup table for an element width.
+	///
+	/// # Parameters
+	///
+	/// - `width`: The number of bits in the element, as `T::BITS`.
+	///
+	/// # Returns
+	///
+	/// A table of `width` electrical positions, indexed by semantic count.
+	///
+	/// # Panics
+	///
+	/// Implementors may panic if they do not support `width`.
+	fn table(width: u8) -> &'static [u8];
+}
+
+/** Traverses an element in an order read from a lookup table.
+
+# Type Parameters
+
+- `P: PermutationTable`: The provider of the ordering.
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Permuted<P>(PhantomData<P>)
+where P: PermutationTable;
+
+impl<P> Cursor for Permuted<P>
+where P: PermutationTable {
+	const TYPENAME: &'static str = P::TYPENAME;
+
+	/// Maps a semantic count to the position at its index in `P`’s table.
+	///
+	/// # Panics
+	///
+	/// Panics if the table for `T` is too short, or if its entry for `cursor`
+	/// is not a position in `T`.
+	fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+	where T: BitStore {
+		let table = P::table(T::BITS);
+		debug_assert_eq!(
+			table.len(),
+			T::BITS as usize,
+			"Permutation table for {} must have one entry per bit",
+			P::TYPENAME,
+		);
+		let place = table[*cursor as usize];
+		assert!(
+			place < T::BITS,
+			"Permutation table for {} maps {} to {}, outside the width {}",
+			P::TYPENAME,
+			*cursor,
+			place,
+			T::BITS,
+		);
+		place.pos()
+	}
+}
+
+/** Verifies that a `Cursor` implementation upholds its contract for a type.
+
+This exhaustively checks every semantic index in `T`, so that implementors of
+custom cursors can test them with the same rules that the library relies on:
+
+- `C::at` produces a position inside `T`, and produces it consistently;
+- `C::mask` produces a one-hot value, with its bit at the position from `C::at`;
+- no two indices map to the same position, so every position in `T` is used.
+
+This function is available with the `testkit` feature.
+
+# Panics
+
+Panics, naming the cursor, the type, and the offending index, at the first
+broken rule.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor under test.
+- `T: BitStore`: The element type over which `C` is tested.
+
+# Examples
+
+```rust
+# #[cfg(feature = "testkit")] {
+use vecbit::prelude::*;
+use vecbit::cursor::{
+	Reversed,
+	test_cursor,
+};
+
+test_cursor::<BigEndian, u8>();
+test_cursor::<Reversed<LittleEndian>, u32>();
+# }
+```
+**/
+#[cfg(any(test, feature = "testkit"))]
+pub fn test_cursor<C, T>()
+where C: Cursor, T: BitStore {
+	let zero = T::from(0);
+	let mut seen = zero;
+	for n in 0 .. T::BITS {
+		let pos = *C::at::<T>(n.idx());
+		assert!(
+			pos < T::BITS,
+			"{}::at::<{}>({}) produced {}, outside the type",
+			C::TYPENAME,
+			T::TYPENAME,
+			n,
+			pos,
+		);
+		assert_eq!(
+			pos,
+			*C::at::<T>(n.idx()),
+			"{}::at::<{}>({}) is not pure",
+			C::TYPENAME,
+			T::TYPENAME,
+			n,
+		);
+		let mask = *C::mask::<T>(n.idx());
+		assert_eq!(
+			mask.count_ones(),
+			1,
+			"{}::mask::<{}>({}) is not one-hot",
+			C::TYPENAME,
+			T::TYPENAME,
+			n,
+		);
+		assert!(
+			mask == T::from(1) << pos,
+			"{}::mask::<{}>({}) disagrees with position {}",
+			C::TYPENAME,
+			T::TYPENAME,
+			n,
+			pos,
+		);
+		assert!(
+			seen & mask == zero,
+			"{}::at::<{}>({}) reuses position {}",
+			C::TYPENAME,
+			T::TYPENAME,
+			n,
+			pos,
+		);
+		seen |= mask;
+	}
+}
+
+/** The electrical direction in which a contiguous `Cursor` walks an element.
+
+`Cursor` implementors are not required to be contiguous, but the two provided
+orderings are. When a cursor is contiguous, moving every bit in an element by
+one cursor step is a single shift of the element, rather than a walk over each
+bit in it.
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Stride {
+	/// Each successive cursor is one position lower, as in `BigEndian`.
+	Descending,
+	/// Each successive cursor is one position higher, as in `LittleEndian`.
+	Ascending,
+}
+
+impl Stride {
+	/// Detects whether a cursor is contiguous over an element type.
+	///
+	/// # Returns
+	///
+	/// The direction in which `C` walks `T` if every cursor maps to the
+	/// position adjacent to that of its predecessor, or `None` if `C` is not
+	/// contiguous.
+	///
+	/// # Type Parameters
+	///
+	/// - `C: Cursor`: The cursor under test.
+	/// - `T: BitStore`: The element type over which `C` is tested.
+	pub(crate) fn of<C, T>() -> Option<Self>
+	where C: Cursor, T: BitStore {
+		let first = *C::at::<T>(0u8.idx());
+		let stride = if first == T::MASK {
+			Stride::Descending
+		}
+		else if first == 0 {
+			Stride::Ascending
+		}
+		else {
+			return None;
+		};
+		for n in 1 .. T::BITS {
+			let pos = *C::at::<T>(n.idx());
+			let expected = match stride {
+				Stride::Descending => T::MASK - n,
+				Stride::Ascending => n,
+			};
+			if pos != expected {
+				return None;
+			}
+		}
+		Some(stride)
+	}
+
+	/// Names the provided cursor whose layout a cursor has over an element
+	/// type.
+	///
+	/// Serialized forms record this name, rather than `Cursor::TYPENAME`, which
+	/// is only a display name, and may be shared by cursors of different
+	/// layouts.
+	///
+	/// # Returns
+	///
+	/// `BigEndian::TYPENAME` or `LittleEndian::TYPENAME` if `C` places the bits
+	/// of `T` as that cursor does, or `None` otherwise.
+	#[cfg(any(feature = "alloc", feature = "serde"))]
+	pub(crate) fn tag<C, T>() -> Option<&'static str>
+	where C: Cursor, T: BitStore {
+		Self::of::<C, T>().map(|stride| match stride {
+			Stride::Descending => BigEndian::TYPENAME,
+			Stride::Ascending => LittleEndian::TYPENAME,
+		})
+	}
+
+	/// Moves every bit in an element some cursor steps towards the back.
+	///
+	/// The bits under the last `by` cursors are discarded, and the bits under
+	/// the first `by` cursors are cleared.
+	///
+	/// `by` must be less than `T::BITS`.
+	pub(crate) fn to_back<T>(self, elt: T, by: u8) -> T
+	where T: BitStore {
+		match self {
+			Stride::Descending => elt >> by,
+			Stride::Ascending => elt << by,
+		}
+	}
+
+	/// Moves every bit in an element some cursor steps towards the front.
+	///
+	/// The bits under the first `by` cursors are discarded, and the bits under
+	/// the last `by` cursors are cleared.
+	///
+	/// `by` must be less than `T::BITS`.
+	pub(crate) fn to_front<T>(self, elt: T, by: u8) -> T
+	where T: BitStore {
+		match self {
+			Stride::Descending => elt << by,
+			Stride::Ascending => elt >> by,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -491,4 +845,109 @@ mod tests {
 		assert_eq!(LittleEndian::at::<u64>(62u8.idx()), 62u8.pos());
 		assert_eq!(LittleEndian::at::<u64>(63u8.idx()), 63u8.pos());
 	}
+
+	/// A cursor that swaps the two halves of each element.
+	struct Halves;
+
+	impl Cursor for Halves {
+		const TYPENAME: &'static str = "Halves";
+
+		fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+		where T: BitStore {
+			((*cursor + (T::BITS >> 1)) & T::MASK).pos()
+		}
+	}
+
+	/// Bit-reversed addressing, as used to reorder FFT buffers.
+	struct BitReversal;
+
+	impl PermutationTable for BitReversal {
+		const TYPENAME: &'static str = "BitReversal";
+
+		fn table(width: u8) -> &'static [u8] {
+			match width {
+				8 => &[0, 4, 2, 6, 1, 5, 3, 7],
+				16 => &[0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15],
+				_ => unimplemented!(),
+			}
+		}
+	}
+
+	#[test]
+	fn bijections() {
+		test_cursor::<BigEndian, u8>();
+		test_cursor::<BigEndian, u64>();
+		test_cursor::<LittleEndian, u32>();
+		test_cursor::<Halves, u16>();
+		test_cursor::<Reversed<BigEndian>, u16>();
+		test_cursor::<Reversed<Halves>, u32>();
+		test_cursor::<Permuted<BitReversal>, u8>();
+		test_cursor::<Permuted<BitReversal>, u16>();
+	}
+
+	/// Maps the first two indices to the same position.
+	struct Collides;
+
+	impl Cursor for Collides {
+		const TYPENAME: &'static str = "Collides";
+
+		fn at<T>(cursor: BitIdx<T>) -> BitPos<T>
+		where T: BitStore {
+			(*cursor).saturating_sub(1).pos()
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Collides::at::<u8>(1) reuses position 0")]
+	fn collision() {
+		test_cursor::<Collides, u8>();
+	}
+
+	#[test]
+	fn reversed() {
+		for n in 0 .. 8u8 {
+			assert_eq!(
+				Reversed::<BigEndian>::at::<u8>(n.idx()),
+				LittleEndian::at::<u8>(n.idx()),
+			);
+			assert_eq!(
+				Reversed::<Reversed<Halves>>::at::<u8>(n.idx()),
+				Halves::at::<u8>(n.idx()),
+			);
+		}
+	}
+
+	#[test]
+	fn permuted() {
+		assert_eq!(Permuted::<BitReversal>::at::<u8>(1u8.idx()), 4u8.pos());
+		assert_eq!(Permuted::<BitReversal>::at::<u16>(3u8.idx()), 12u8.pos());
+		assert_eq!(Permuted::<BitReversal>::TYPENAME, "BitReversal");
+	}
+
+	#[test]
+	fn stride() {
+		assert_eq!(Stride::of::<BigEndian, u8>(), Some(Stride::Descending));
+		assert_eq!(Stride::of::<BigEndian, u64>(), Some(Stride::Descending));
+		assert_eq!(Stride::of::<LittleEndian, u8>(), Some(Stride::Ascending));
+		assert_eq!(Stride::of::<LittleEndian, u64>(), Some(Stride::Ascending));
+		assert_eq!(Stride::of::<Halves, u16>(), None);
+		assert_eq!(
+			Stride::of::<Reversed<BigEndian>, u32>(),
+			Some(Stride::Ascending),
+		);
+		assert_eq!(Stride::of::<Permuted<BitReversal>, u8>(), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn stride_shift() {
+		let s = Stride::Descending;
+		assert_eq!(s.to_back(0b1000_0001u8, 1), 0b0100_0000);
+		assert_eq!(s.to_front(0b1000_0001u8, 1), 0b0000_0010);
+		assert_eq!(s.to_back(0b1000_0001u8, 3), 0b0001_0000);
+		let s = Stride::Ascending;
+		assert_eq!(s.to_back(0b1000_0001u8, 1), 0b0000_0010);
+		assert_eq!(s.to_front(0b1000_0001u8, 1), 0b0100_0000);
+		assert_eq!(s.to_front(0b1000_0001u8, 3), 0b0001_0000);
+	}
 }
@@ -0,0 +1,271 @@
+/*! Cyclic views of a `SliceBit`
+
+A [`CycleBits`] treats a slice as one period of an endlessly repeating pattern.
+Indices past the end of the slice wrap around to its start, so ring buffers,
+repeated test patterns, and circular correlations can read across the seam
+without copying the slice twice.
+
+Iteration over a cycle never runs forever: every iterator is given the number
+of bits, or of whole periods, that it produces.
+
+[`CycleBits`]: struct.CycleBits.html
+!*/
+
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+};
+
+use core::{
+	iter::FusedIterator,
+	ops::Index,
+};
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Views the slice as one period of a repeating pattern.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A view whose indices wrap modulo the length of the slice.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is empty, as an empty pattern has no bits to repeat.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = &0b1100_0000u8.bits::<BigEndian>()[.. 3];
+	/// let ring = bits.cycle();
+	/// assert!(ring[0] && ring[4] && !ring[5]);
+	/// assert_eq!(ring.iter(2).collect::<Vec<_>>(), [
+	///   true, true, false, true, true, false,
+	/// ]);
+	/// ```
+	pub fn cycle(&self) -> CycleBits<C, T> {
+		assert!(!self.is_empty(), "Cannot cycle an empty slice");
+		CycleBits { inner: self }
+	}
+}
+
+/** A view of a `SliceBit` that repeats endlessly.
+
+This is produced by [`SliceBit::cycle`].
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+
+[`SliceBit::cycle`]: ../slice/struct.SliceBit.html#method.cycle
+**/
+#[derive(Debug)]
+pub struct CycleBits<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// One period of the pattern. This is never empty.
+	inner: &'a SliceBit<C, T>,
+}
+
+impl<'a, C, T> CycleBits<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Gets one period of the pattern.
+	pub fn as_bitslice(&self) -> &'a SliceBit<C, T> {
+		self.inner
+	}
+
+	/// Gets the length of one period of the pattern.
+	pub fn period(&self) -> usize {
+		self.inner.len()
+	}
+
+	/// Reads a bit, wrapping the index into the period.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: Any index.
+	///
+	/// # Returns
+	///
+	/// The bit at `index % self.period()`.
+	pub fn get(&self, index: usize) -> bool {
+		self.inner[index % self.period()]
+	}
+
+	/// Reads a bit, clamping the index to the last bit of the period.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: Any index.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, or the last bit of the period if `index` is past it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b0000_0001u8.bits::<BigEndian>();
+	/// let ring = bits.cycle();
+	/// assert!(!ring.get(14));
+	/// assert!(ring.get_saturating(14));
+	/// ```
+	pub fn get_saturating(&self, index: usize) -> bool {
+		let last = self.period() - 1;
+		self.inner[if index > last { last } else { index }]
+	}
+
+	/// Iterates over some whole periods of the pattern.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `cycles`: The number of periods to produce.
+	///
+	/// # Returns
+	///
+	/// An iterator over `cycles * self.period()` bits, starting at index 0.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits overflows `usize`.
+	pub fn iter(&self, cycles: usize) -> CycleIter<'a, C, T> {
+		let count = cycles.checked_mul(self.period())
+			.expect("Cycle length overflows usize");
+		self.iter_from(0, count)
+	}
+
+	/// Iterates over some bits of the pattern, from any starting index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `start`: The index of the first bit, which is wrapped into the period.
+	/// - `count`: The number of bits to produce.
+	///
+	/// # Returns
+	///
+	/// An iterator over the `count` bits at `start`, `start + 1`, and onwards.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b1000_0000u8.bits::<BigEndian>();
+	/// let ring = bits.cycle();
+	/// let seam = ring.iter_from(14, 4).collect::<Vec<_>>();
+	/// assert_eq!(seam, [false, false, true, false]);
+	/// ```
+	pub fn iter_from(&self, start: usize, count: usize) -> CycleIter<'a, C, T> {
+		CycleIter {
+			inner: self.inner,
+			pos: start % self.period(),
+			left: count,
+		}
+	}
+}
+
+impl<'a, C, T> Clone for CycleBits<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, C, T> Copy for CycleBits<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Index<usize> for CycleBits<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Output = bool;
+
+	/// Looks up a bit, wrapping the index into the period.
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.inner[index % self.period()]
+	}
+}
+
+/** Iterator over a bounded run of a `CycleBits` pattern.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+**/
+#[derive(Debug)]
+pub struct CycleIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// One period of the pattern.
+	inner: &'a SliceBit<C, T>,
+	/// The index in `inner` of the next bit.
+	pos: usize,
+	/// The number of bits left to produce.
+	left: usize,
+}
+
+impl<'a, C, T> Clone for CycleIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn clone(&self) -> Self {
+		Self { inner: self.inner, pos: self.pos, left: self.left }
+	}
+}
+
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for CycleIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for CycleIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for CycleIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.left == 0 {
+			return None;
+		}
+		self.left -= 1;
+		let bit = self.inner[self.pos];
+		self.pos += 1;
+		if self.pos == self.inner.len() {
+			self.pos = 0;
+		}
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.left, Some(self.left))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.left {
+			self.left = 0;
+			return None;
+		}
+		self.left -= n;
+		self.pos = (self.pos + n % self.inner.len()) % self.inner.len();
+		self.next()
+	}
+}
@@ -0,0 +1,138 @@
+/*! Multi-line dumps of a `SliceBit`
+
+The `Debug` and `Display` renderings of a slice put every bit on one line,
+which is unreadable for buffers longer than a few elements. A [`Dump`] lays the
+bits out like a hexdump: a fixed number of bits per line, split into groups,
+with each line labeled by the bit offset at which it begins.
+
+The dump is written directly into the formatter, without building a string, so
+it is available without an allocator.
+
+[`Dump`]: struct.Dump.html
+!*/
+
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the slice as an offset-annotated, grouped, multi-line dump.
+	///
+	/// Each line holds `width` bits, split by spaces into groups of `group`
+	/// bits, and begins with the index of its first bit. The indices are
+	/// written in decimal and right-aligned, so that the bits of every line
+	/// line up. Lines are separated by newlines, with none after the last; an
+	/// empty slice renders as nothing.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The number of bits on each line.
+	/// - `group`: The number of bits in each group. Groups restart at the
+	///   start of each line.
+	///
+	/// # Returns
+	///
+	/// A value whose `Display` implementation renders the dump.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` or `group` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0x4Bu8, 0x21, 0xF0];
+	/// let bits = &src.bits::<BigEndian>()[.. 22];
+	/// assert_eq!(
+	///   format!("{}", bits.dump(8, 4)),
+	///   " 0: 0100 1011\n 8: 0010 0001\n16: 1111 00",
+	/// );
+	/// # }
+	/// ```
+	pub fn dump(&self, width: usize, group: usize) -> Dump<C, T> {
+		assert!(width != 0, "Dump lines cannot be empty");
+		assert!(group != 0, "Dump groups cannot be empty");
+		Dump { bits: self, width, group }
+	}
+}
+
+/** A multi-line rendering of a `SliceBit`.
+
+This is produced by [`SliceBit::dump`], and renders through its `Display`
+implementation.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+
+[`SliceBit::dump`]: ../slice/struct.SliceBit.html#method.dump
+**/
+pub struct Dump<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The bits being rendered.
+	bits: &'a SliceBit<C, T>,
+	/// The number of bits on each line. This is never zero.
+	width: usize,
+	/// The number of bits in each group. This is never zero.
+	group: usize,
+}
+
+impl<'a, C, T> Clone for Dump<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, C, T> Copy for Dump<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Display for Dump<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let len = self.bits.len();
+		if len == 0 {
+			return Ok(());
+		}
+		//  The widest offset label is that of the last line.
+		let last = (len - 1) / self.width * self.width;
+		let mut digits = 1;
+		let mut rest = last / 10;
+		while rest != 0 {
+			digits += 1;
+			rest /= 10;
+		}
+		for (line, start) in (0 .. len).step_by(self.width).enumerate() {
+			if line != 0 {
+				f.write_str("\n")?;
+			}
+			write!(f, "{:>1$}:", start, digits)?;
+			let end = len.min(start + self.width);
+			for (n, bit) in self.bits[start .. end].iter().enumerate() {
+				if n % self.group == 0 {
+					f.write_str(" ")?;
+				}
+				f.write_str(if bit { "1" } else { "0" })?;
+			}
+		}
+		Ok(())
+	}
+}
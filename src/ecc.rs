@@ -0,0 +1,335 @@
+/*! Error-correcting codes
+
+This module encodes bit sequences with small block codes, and decodes them
+after they may have been corrupted, for simulations of memory scrubbing and of
+noisy channels.
+
+- Hamming(7,4) codes each nibble of four data bits as seven bits, and corrects
+  any single flipped bit in each seven.
+- Extended Hamming(8,4), or SECDED, adds an eighth parity bit to each Hamming
+  block, so that it also detects, without correcting, any two flipped bits.
+- Parity blocks append one even-parity bit to each block of data, and detect any
+  odd number of flipped bits in a block.
+
+Each code block is laid out in the order of its bit positions, as counted in
+the usual descriptions of the codes: the Hamming block is `p1 p2 d1 p4 d2 d3
+d4`, and the SECDED block is that block followed by the parity of all seven.
+
+Encoders panic if the data is not a whole number of blocks, since its length is
+under the caller’s control. Decoders report bad lengths as errors, since their
+input is usually received from elsewhere.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+/// The reasons a code sequence can fail to decode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EccError {
+	/// The sequence is not a whole number of code blocks.
+	Length {
+		/// The length of the sequence.
+		len: usize,
+		/// The length of a code block.
+		block: usize,
+	},
+	/// The code block at this index holds an error that the code detects but
+	/// cannot correct.
+	Uncorrectable(usize),
+}
+
+impl Display for EccError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			EccError::Length { len, block } => write!(
+				f,
+				"{} bits is not a whole number of {}-bit code blocks",
+				len,
+				block,
+			),
+			EccError::Uncorrectable(n) => {
+				write!(f, "code block {} has an uncorrectable error", n)
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EccError {}
+
+/// Encodes data with the Hamming(7,4) code.
+///
+/// # Parameters
+///
+/// - `data`: The data bits.
+///
+/// # Returns
+///
+/// Seven code bits for every four data bits.
+///
+/// # Panics
+///
+/// Panics if the length of `data` is not a multiple of four.
+///
+/// # Examples
+///
+/// ```rust
+/// use vecbit::prelude::*;
+/// use vecbit::ecc;
+///
+/// let data = vecbit![BigEndian, u8; 1, 0, 1, 1];
+/// let mut code = ecc::hamming_encode(&data);
+/// assert_eq!(code, vecbit![0, 1, 1, 0, 0, 1, 1]);
+///
+/// code.set(4, true);
+/// let (back, fixed) = ecc::hamming_decode(&code).unwrap();
+/// assert_eq!((back, fixed), (data, 1));
+/// ```
+pub fn hamming_encode<C, T>(data: &SliceBit<C, T>) -> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	encode(data, 7)
+}
+
+/// Decodes a Hamming(7,4) code sequence, correcting one error in each block.
+///
+/// # Parameters
+///
+/// - `code`: The code bits.
+///
+/// # Returns
+///
+/// The four data bits of every seven code bits, and the number of blocks in
+/// which a bit was corrected. Two or more errors in one block are miscorrected,
+/// and not reported.
+///
+/// # Errors
+///
+/// Returns `EccError::Length` if the length of `code` is not a multiple of
+/// seven.
+pub fn hamming_decode<C, T>(code: &SliceBit<C, T>)
+-> Result<(VecBit<C, T>, usize), EccError>
+where C: Cursor, T: BitStore {
+	decode(code, 7)
+}
+
+/// Encodes data with the extended Hamming(8,4) code.
+///
+/// # Parameters
+///
+/// - `data`: The data bits.
+///
+/// # Returns
+///
+/// Eight code bits for every four data bits.
+///
+/// # Panics
+///
+/// Panics if the length of `data` is not a multiple of four.
+///
+/// # Examples
+///
+/// ```rust
+/// use vecbit::prelude::*;
+/// use vecbit::ecc::{self, EccError};
+///
+/// let data = vecbit![BigEndian, u8; 1, 0, 1, 1, 0, 0, 1, 0];
+/// let mut code = ecc::secded_encode(&data);
+/// code.set(2, false);
+/// assert_eq!(ecc::secded_decode(&code), Ok((data, 1)));
+///
+/// code.set(12, true);
+/// code.set(14, true);
+/// assert_eq!(ecc::secded_decode(&code), Err(EccError::Uncorrectable(1)));
+/// ```
+pub fn secded_encode<C, T>(data: &SliceBit<C, T>) -> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	encode(data, 8)
+}
+
+/// Decodes an extended Hamming(8,4) code sequence, correcting one error and
+/// detecting two errors in each block.
+///
+/// # Parameters
+///
+/// - `code`: The code bits.
+///
+/// # Returns
+///
+/// The four data bits of every eight code bits, and the number of blocks in
+/// which a bit was corrected.
+///
+/// # Errors
+///
+/// Returns `EccError::Length` if the length of `code` is not a multiple of
+/// eight, and `EccError::Uncorrectable` with the index of the first block that
+/// holds two errors.
+pub fn secded_decode<C, T>(code: &SliceBit<C, T>)
+-> Result<(VecBit<C, T>, usize), EccError>
+where C: Cursor, T: BitStore {
+	decode(code, 8)
+}
+
+/// Appends an even-parity bit to each block of data.
+///
+/// # Parameters
+///
+/// - `data`: The data bits.
+/// - `block`: The number of data bits in each block.
+///
+/// # Returns
+///
+/// Each block of `data`, followed by a bit that makes the number of set bits in
+/// the block and its parity bit even.
+///
+/// # Panics
+///
+/// Panics if `block` is zero, or the length of `data` is not a multiple of it.
+///
+/// # Examples
+///
+/// ```rust
+/// use vecbit::prelude::*;
+/// use vecbit::ecc;
+///
+/// let data = vecbit![BigEndian, u8; 1, 1, 0, 1, 0, 0];
+/// let code = ecc::parity_encode(&data, 3);
+/// assert_eq!(code, vecbit![1, 1, 0, 0, 1, 0, 0, 1]);
+/// assert_eq!(ecc::parity_decode(&code, 3), Ok(data));
+/// ```
+pub fn parity_encode<C, T>(data: &SliceBit<C, T>, block: usize) -> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	assert_ne!(block, 0, "Parity blocks cannot be empty");
+	assert_eq!(
+		data.len() % block,
+		0,
+		"{} bits is not a whole number of {}-bit blocks",
+		data.len(),
+		block,
+	);
+	let mut out = VecBit::with_capacity(data.len() + data.len() / block);
+	for chunk in data.chunks(block) {
+		out.extend(chunk.iter());
+		out.push(chunk.count_ones() & 1 == 1);
+	}
+	out
+}
+
+/// Checks and removes the parity bit of each block.
+///
+/// # Parameters
+///
+/// - `code`: The code bits.
+/// - `block`: The number of data bits in each block, not counting its parity
+///   bit.
+///
+/// # Returns
+///
+/// The data bits of every block.
+///
+/// # Errors
+///
+/// Returns `EccError::Length` if the length of `code` is not a multiple of
+/// `block + 1`, and `EccError::Uncorrectable` with the index of the first block
+/// whose parity is odd.
+///
+/// # Panics
+///
+/// Panics if `block` is zero.
+pub fn parity_decode<C, T>(code: &SliceBit<C, T>, block: usize)
+-> Result<VecBit<C, T>, EccError>
+where C: Cursor, T: BitStore {
+	assert_ne!(block, 0, "Parity blocks cannot be empty");
+	check_len(code, block + 1)?;
+	let mut out = VecBit::with_capacity(code.len() / (block + 1) * block);
+	for (n, chunk) in code.chunks(block + 1).enumerate() {
+		if chunk.count_ones() & 1 == 1 {
+			return Err(EccError::Uncorrectable(n));
+		}
+		out.extend(chunk[.. block].iter());
+	}
+	Ok(out)
+}
+
+/// The positions, within a Hamming block, of the data bits.
+const DATA: [usize; 4] = [2, 4, 5, 6];
+
+/// Encodes data in Hamming blocks of seven or eight bits.
+fn encode<C, T>(data: &SliceBit<C, T>, block: usize) -> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	assert_eq!(
+		data.len() % 4,
+		0,
+		"{} bits is not a whole number of nibbles",
+		data.len(),
+	);
+	let mut out = VecBit::with_capacity(data.len() / 4 * block);
+	for nibble in data.chunks(4) {
+		let mut code = [false; 8];
+		for (&at, bit) in DATA.iter().zip(nibble.iter()) {
+			code[at] = bit;
+		}
+		//  Each parity bit, at position `p` counting from one, covers the
+		//  positions whose numbers have bit `p` set.
+		for &p in &[1, 2, 4] {
+			code[p - 1] = (1 ..= 7)
+				.filter(|pos| pos & p != 0 && *pos != p)
+				.fold(false, |acc, pos| acc ^ code[pos - 1]);
+		}
+		code[7] = code[.. 7].iter().fold(false, |acc, &bit| acc ^ bit);
+		out.extend(code[.. block].iter().cloned());
+	}
+	out
+}
+
+/// Decodes Hamming blocks of seven or eight bits.
+fn decode<C, T>(code: &SliceBit<C, T>, block: usize)
+-> Result<(VecBit<C, T>, usize), EccError>
+where C: Cursor, T: BitStore {
+	check_len(code, block)?;
+	let mut out = VecBit::with_capacity(code.len() / block * 4);
+	let mut fixed = 0;
+	for (n, chunk) in code.chunks(block).enumerate() {
+		let mut bits = [false; 8];
+		for (slot, bit) in bits.iter_mut().zip(chunk.iter()) {
+			*slot = bit;
+		}
+		//  The syndrome is the position, counting from one, of a single error.
+		let syndrome = (1 ..= 7usize)
+			.filter(|pos| bits[pos - 1])
+			.fold(0, |acc, pos| acc ^ pos);
+		let odd = block == 8 && bits.iter().filter(|&&bit| bit).count() & 1 == 1;
+		match (syndrome, block == 8, odd) {
+			(0, _, false) => {},
+			//  Only the extra parity bit is wrong.
+			(0, true, true) => fixed += 1,
+			(_, true, false) => return Err(EccError::Uncorrectable(n)),
+			(pos, _, _) => {
+				bits[pos - 1] = !bits[pos - 1];
+				fixed += 1;
+			},
+		}
+		out.extend(DATA.iter().map(|&at| bits[at]));
+	}
+	Ok((out, fixed))
+}
+
+/// Checks that a code sequence is a whole number of blocks.
+fn check_len<C, T>(code: &SliceBit<C, T>, block: usize) -> Result<(), EccError>
+where C: Cursor, T: BitStore {
+	if code.len() % block != 0 {
+		return Err(EccError::Length { len: code.len(), block });
+	}
+	Ok(())
+}
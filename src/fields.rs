@@ -9,6 +9,7 @@ use crate::{
 	access::BitAccess,
 	cursor::{
 		BigEndian,
+		Cursor,
 		LittleEndian,
 	},
 	domain::*,
@@ -16,6 +17,24 @@ use crate::{
 	store::BitStore,
 };
 
+#[cfg(feature = "alloc")]
+use crate::vec::{
+	VecBit,
+	VecBitBuilder,
+};
+
+use core::{
+	cmp::min,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	iter::FusedIterator,
+	marker::PhantomData,
+	ops::Range,
+};
+
 /** Permit a specific `SliceBit` to be used for C-style bitfield access.
 
 Cursors that permit batched access to regions of memory are enabled to load data
@@ -367,6 +386,697 @@ where T: BitStore {
 	}
 }
 
+/// How [`SliceBit::iter_chunks_as`] treats a trailing group that is shorter than
+/// the requested width.
+///
+/// [`SliceBit::iter_chunks_as`]: ../slice/struct.SliceBit.html#method.iter_chunks_as
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ChunkTail {
+	/// The slice must divide evenly into groups; any remainder is an error.
+	Exact,
+	/// A trailing partial group is skipped.
+	Drop,
+	/// A trailing partial group is filled out with zeros after its last bit, and
+	/// decoded as a full-width group.
+	Pad,
+	/// A trailing partial group is decoded as a narrower value.
+	Keep,
+}
+
+/// The reasons [`SliceBit::iter_chunks_as`] can refuse to decode a slice.
+///
+/// [`SliceBit::iter_chunks_as`]: ../slice/struct.SliceBit.html#method.iter_chunks_as
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChunksAsError {
+	/// The group width is zero, or wider than the destination integer.
+	Width {
+		/// The requested group width.
+		width: u8,
+		/// The width of the destination integer.
+		max: u8,
+	},
+	/// The slice does not divide evenly into groups, under [`ChunkTail::Exact`].
+	///
+	/// [`ChunkTail::Exact`]: enum.ChunkTail.html#variant.Exact
+	Inexact {
+		/// The length of the slice.
+		len: usize,
+		/// The number of bits left over after the last whole group.
+		rem: usize,
+	},
+}
+
+impl Display for ChunksAsError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			ChunksAsError::Width { width, max } => write!(
+				f,
+				"Cannot decode {}-bit groups into a {}-bit integer",
+				width,
+				max,
+			),
+			ChunksAsError::Inexact { len, rem } => write!(
+				f,
+				"{} bits leaves {} bits after the last whole group",
+				len,
+				rem,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChunksAsError {}
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Decodes successive groups of `width` bits into integers.
+	///
+	/// This is `chunks(width)` followed by decoding each chunk, with the width
+	/// and the length of the slice checked before any group is produced. The
+	/// first bit of each group is the most significant bit of its value, which
+	/// is the order in which [`VecBitBuilder::push_bits`] writes them, and the
+	/// order that [`BitField::load`] uses on `BigEndian` slices.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The number of bits in each group.
+	/// - `tail`: The treatment of a trailing group shorter than `width`.
+	///
+	/// # Returns
+	///
+	/// An iterator over the decoded groups.
+	///
+	/// # Errors
+	///
+	/// - `ChunksAsError::Width` if `width` is zero or greater than `U::BITS`.
+	/// - `ChunksAsError::Inexact` if `tail` is `ChunkTail::Exact` and the
+	///   length of the slice is not a multiple of `width`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U: BitStore`: The integer type into which groups are decoded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::fields::ChunkTail;
+	///
+	/// let bits = &[0b1011_0010u8, 0b1100_0000].bits::<LittleEndian>()[.. 10];
+	/// //  `LittleEndian` bits of the bytes, read back five at a time.
+	/// let groups = bits.iter_chunks_as::<u8>(5, ChunkTail::Exact)
+	///   .unwrap()
+	///   .collect::<Vec<_>>();
+	/// assert_eq!(groups, [0b01001, 0b10100]);
+	///
+	/// let bits = 0b101_10u8.bits::<BigEndian>();
+	/// let pad = bits.iter_chunks_as::<u8>(3, ChunkTail::Pad).unwrap();
+	/// assert_eq!(pad.collect::<Vec<_>>(), [0, 5, 4]);
+	/// let keep = bits.iter_chunks_as::<u8>(3, ChunkTail::Keep).unwrap();
+	/// assert_eq!(keep.collect::<Vec<_>>(), [0, 5, 2]);
+	/// assert!(bits.iter_chunks_as::<u8>(3, ChunkTail::Exact).is_err());
+	/// assert!(bits.iter_chunks_as::<u8>(9, ChunkTail::Drop).is_err());
+	/// ```
+	///
+	/// [`BitField::load`]: ../fields/trait.BitField.html#tymethod.load
+	/// [`VecBitBuilder::push_bits`]: ../vec/struct.VecBitBuilder.html#method.push_bits
+	pub fn iter_chunks_as<U>(&self, width: u8, tail: ChunkTail)
+	-> Result<ChunksAs<C, T, U>, ChunksAsError>
+	where U: BitStore {
+		if width == 0 || width > U::BITS {
+			return Err(ChunksAsError::Width { width, max: U::BITS });
+		}
+		let len = self.len();
+		let rem = len % width as usize;
+		let inner = match tail {
+			ChunkTail::Exact if rem != 0 => {
+				return Err(ChunksAsError::Inexact { len, rem });
+			},
+			ChunkTail::Drop => &self[.. len - rem],
+			_ => self,
+		};
+		Ok(ChunksAs {
+			inner,
+			width,
+			pad: tail == ChunkTail::Pad,
+			_ty: PhantomData,
+		})
+	}
+}
+
+/// The reasons [`VecBit::from_symbols`] can refuse to pack a sequence.
+///
+/// [`VecBit::from_symbols`]: ../vec/struct.VecBit.html#method.from_symbols
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SymbolError {
+	/// The symbol width is zero, or wider than the symbol type.
+	Width {
+		/// The requested symbol width.
+		width: u8,
+		/// The width of the symbol type.
+		max: u8,
+	},
+	/// A symbol has set bits above the symbol width.
+	Overflow {
+		/// The position of the symbol in the sequence.
+		index: usize,
+		/// The value of the symbol.
+		value: u64,
+	},
+}
+
+impl Display for SymbolError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			SymbolError::Width { width, max } => write!(
+				f,
+				"Cannot encode {}-bit symbols from a {}-bit integer",
+				width,
+				max,
+			),
+			SymbolError::Overflow { index, value } => write!(
+				f,
+				"Symbol {} has value {}, which does not fit in the width",
+				index,
+				value,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SymbolError {}
+
+#[cfg(feature = "alloc")]
+impl<C, T> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Packs a sequence of integer symbols, each into a fixed number of bits.
+	///
+	/// This is the reverse of [`SliceBit::iter_chunks_as`]: each symbol is
+	/// written with its most significant bit first, in `width` bits.
+	///
+	/// # Parameters
+	///
+	/// - `iter`: The symbols to pack.
+	/// - `width`: The number of bits in each symbol.
+	///
+	/// # Returns
+	///
+	/// A `VecBit` of `width` bits per symbol.
+	///
+	/// # Errors
+	///
+	/// - `SymbolError::Width` if `width` is zero or greater than `U::BITS`.
+	/// - `SymbolError::Overflow` for the first symbol whose value needs more
+	///   than `width` bits.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item = U>`: A source of symbols.
+	/// - `U: BitStore`: The integer type of the symbols.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::fields::SymbolError;
+	///
+	/// //  `A`, `C`, `G`, `T` in two bits each.
+	/// let dna = b"GATTACA".iter().map(|&b| match b {
+	///   b'A' => 0u8, b'C' => 1, b'G' => 2, _ => 3,
+	/// });
+	/// let bv = VecBit::<BigEndian, u8>::from_symbols(dna, 2).unwrap();
+	/// assert_eq!(bv.len(), 14);
+	/// assert_eq!(bv.as_slice(), &[0b10_00_11_11, 0b00_01_00_00]);
+	///
+	/// assert_eq!(
+	///   VecBit::<BigEndian, u8>::from_symbols(vec![63u8, 64], 6),
+	///   Err(SymbolError::Overflow { index: 1, value: 64 }),
+	/// );
+	/// ```
+	///
+	/// [`SliceBit::iter_chunks_as`]: ../slice/struct.SliceBit.html#method.iter_chunks_as
+	pub fn from_symbols<I, U>(iter: I, width: u8) -> Result<Self, SymbolError>
+	where I: IntoIterator<Item = U>, U: BitStore {
+		if width == 0 || width > U::BITS {
+			return Err(SymbolError::Width { width, max: U::BITS });
+		}
+		let iter = iter.into_iter();
+		let mut out = VecBitBuilder::with_capacity(
+			iter.size_hint().0.saturating_mul(width as usize),
+		);
+		for (index, sym) in iter.enumerate() {
+			let value: u64 = sym.into();
+			if width < 64 && value >> width != 0 {
+				return Err(SymbolError::Overflow { index, value });
+			}
+			out.push_bits(value, width);
+		}
+		Ok(out.build())
+	}
+}
+
+/** Iterator over groups of bits decoded as integers.
+
+This is produced by [`SliceBit::iter_chunks_as`].
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+- `U: BitStore`: The integer type into which groups are decoded.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+
+[`SliceBit::iter_chunks_as`]: ../slice/struct.SliceBit.html#method.iter_chunks_as
+**/
+#[derive(Clone, Debug)]
+pub struct ChunksAs<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {
+	/// The bits not yet decoded.
+	inner: &'a SliceBit<C, T>,
+	/// The width of each group.
+	width: u8,
+	/// Whether a trailing partial group is padded to full width.
+	pad: bool,
+	_ty: PhantomData<U>,
+}
+
+/// Mark that the iterator has an exact size.
+impl<'a, C, T, U> ExactSizeIterator for ChunksAs<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T, U> FusedIterator for ChunksAs<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {}
+
+impl<'a, C, T, U> Iterator for ChunksAs<'a, C, T, U>
+where C: Cursor, T: 'a + BitStore, U: BitStore {
+	type Item = U;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.inner.is_empty() {
+			return None;
+		}
+		let size = min(self.inner.len(), self.width as usize);
+		let (head, rest) = self.inner.split_at(size);
+		self.inner = rest;
+		let value = head.iter().fold(U::from(0), |acc, bit| {
+			acc << 1 | U::from(bit as u8)
+		});
+		Some(if self.pad {
+			value << (self.width - size as u8)
+		}
+		else {
+			value
+		})
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let width = self.width as usize;
+		let len = (self.inner.len() + width - 1) / width;
+		(len, Some(len))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		match n.checked_mul(self.width as usize) {
+			Some(skip) if skip < self.inner.len() => {
+				self.inner = &self.inner[skip ..];
+				self.next()
+			},
+			_ => {
+				self.inner = SliceBit::empty();
+				None
+			},
+		}
+	}
+}
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore, SliceBit<C, T>: BitField<T> {
+	/// Views the slice as an array of equally spaced fields.
+	///
+	/// Field `i` occupies `width` bits starting at `offset + i * stride`, which
+	/// is the layout of one column in a table of fixed-size records. The array
+	/// holds every field that fits entirely inside the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `offset`: The index of the first bit of field 0.
+	/// - `width`: The number of bits in each field.
+	/// - `stride`: The distance, in bits, between the starts of consecutive
+	///   fields.
+	///
+	/// # Returns
+	///
+	/// A view which loads the fields through [`BitField`].
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or wider than `T`, or if `stride` is less than
+	/// `width`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// //  Three 8-bit records, each a 3-bit tag and a 5-bit value.
+	/// let data = [0b001_00111u8, 0b010_01000, 0b100_11111];
+	/// let bits = data.bits::<BigEndian>();
+	/// let tags = bits.field_array(0, 3, 8);
+	/// let values = bits.field_array(3, 5, 8);
+	/// assert_eq!(tags.len(), 3);
+	/// assert_eq!(tags.iter().collect::<Vec<_>>(), [1, 2, 4]);
+	/// assert_eq!(values.get(1), Some(8));
+	/// assert_eq!(values.get(3), None);
+	/// ```
+	///
+	/// [`BitField`]: ../fields/trait.BitField.html
+	pub fn field_array(&self, offset: usize, width: u8, stride: usize)
+	-> FieldArray<C, T> {
+		let shape = Shape::new::<T>(self.len(), offset, width, stride);
+		FieldArray { bits: self, shape }
+	}
+
+	/// Views the slice as a mutable array of equally spaced fields.
+	///
+	/// This is [`field_array`] with the addition of [`FieldArrayMut::set`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `offset`: The index of the first bit of field 0.
+	/// - `width`: The number of bits in each field.
+	/// - `stride`: The distance, in bits, between the starts of consecutive
+	///   fields.
+	///
+	/// # Returns
+	///
+	/// A view which loads and stores the fields through [`BitField`].
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or wider than `T`, or if `stride` is less than
+	/// `width`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut data = [0u16; 2];
+	/// {
+	///   let bits = data.bits_mut::<LittleEndian>();
+	///   let mut nibbles = bits.field_array_mut(2, 4, 6);
+	///   assert_eq!(nibbles.len(), 5);
+	///   for n in 0 .. nibbles.len() {
+	///     nibbles.set(n, 0xF);
+	///   }
+	/// }
+	/// assert_eq!(data, [0xCF3C, 0x3CF3]);
+	/// ```
+	///
+	/// [`BitField`]: ../fields/trait.BitField.html
+	/// [`FieldArrayMut::set`]: ../fields/struct.FieldArrayMut.html#method.set
+	/// [`field_array`]: #method.field_array
+	pub fn field_array_mut(&mut self, offset: usize, width: u8, stride: usize)
+	-> FieldArrayMut<C, T> {
+		let shape = Shape::new::<T>(self.len(), offset, width, stride);
+		FieldArrayMut { bits: self, shape }
+	}
+}
+
+/// The placement of the fields in a field array.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Shape {
+	offset: usize,
+	width: usize,
+	stride: usize,
+	len: usize,
+}
+
+impl Shape {
+	fn new<T>(bits: usize, offset: usize, width: u8, stride: usize) -> Self
+	where T: BitStore {
+		assert!(
+			width != 0 && width <= T::BITS,
+			"Cannot access {}-bit fields through a {}-bit element",
+			width,
+			T::BITS,
+		);
+		let width = width as usize;
+		assert!(
+			stride >= width,
+			"Field stride {} is less than the field width {}",
+			stride,
+			width,
+		);
+		let len = match bits.checked_sub(offset + width) {
+			Some(spare) => spare / stride + 1,
+			None => 0,
+		};
+		Self { offset, width, stride, len }
+	}
+
+	fn range(&self, index: usize) -> Range<usize> {
+		let start = self.offset + index * self.stride;
+		start .. start + self.width
+	}
+}
+
+/** A read view of equally spaced fields in a `SliceBit`.
+
+This is produced by [`SliceBit::field_array`].
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+
+[`SliceBit::field_array`]: ../slice/struct.SliceBit.html#method.field_array
+**/
+#[derive(Debug)]
+pub struct FieldArray<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	bits: &'a SliceBit<C, T>,
+	shape: Shape,
+}
+
+impl<'a, C, T> FieldArray<'a, C, T>
+where C: Cursor, T: 'a + BitStore, SliceBit<C, T>: BitField<T> {
+	/// Counts the fields in the array.
+	pub fn len(&self) -> usize {
+		self.shape.len
+	}
+
+	/// Tests if the array has no fields.
+	pub fn is_empty(&self) -> bool {
+		self.shape.len == 0
+	}
+
+	/// Gets the width of each field.
+	pub fn width(&self) -> u8 {
+		self.shape.width as u8
+	}
+
+	/// Gets the bits of a field.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index of the field.
+	///
+	/// # Returns
+	///
+	/// The bits of the field at `index`, if it is in bounds.
+	pub fn field(&self, index: usize) -> Option<&'a SliceBit<C, T>> {
+		if index < self.shape.len {
+			Some(&self.bits[self.shape.range(index)])
+		}
+		else {
+			None
+		}
+	}
+
+	/// Loads a field.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index of the field.
+	///
+	/// # Returns
+	///
+	/// The value of the field at `index`, if it is in bounds.
+	pub fn get(&self, index: usize) -> Option<T> {
+		self.field(index).and_then(BitField::load)
+	}
+
+	/// Iterates over the values of the fields.
+	pub fn iter(&self) -> FieldIter<'a, C, T> {
+		FieldIter { array: *self, range: 0 .. self.shape.len }
+	}
+}
+
+impl<'a, C, T> Clone for FieldArray<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, C, T> Copy for FieldArray<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> IntoIterator for FieldArray<'a, C, T>
+where C: Cursor, T: 'a + BitStore, SliceBit<C, T>: BitField<T> {
+	type Item = T;
+	type IntoIter = FieldIter<'a, C, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/** A write view of equally spaced fields in a `SliceBit`.
+
+This is produced by [`SliceBit::field_array_mut`].
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+
+[`SliceBit::field_array_mut`]: ../slice/struct.SliceBit.html#method.field_array_mut
+**/
+#[derive(Debug)]
+pub struct FieldArrayMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	bits: &'a mut SliceBit<C, T>,
+	shape: Shape,
+}
+
+impl<'a, C, T> FieldArrayMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore, SliceBit<C, T>: BitField<T> {
+	/// Borrows the array as a read view.
+	pub fn as_field_array(&self) -> FieldArray<C, T> {
+		FieldArray { bits: self.bits, shape: self.shape }
+	}
+
+	/// Counts the fields in the array.
+	pub fn len(&self) -> usize {
+		self.shape.len
+	}
+
+	/// Tests if the array has no fields.
+	pub fn is_empty(&self) -> bool {
+		self.shape.len == 0
+	}
+
+	/// Loads a field.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index of the field.
+	///
+	/// # Returns
+	///
+	/// The value of the field at `index`, if it is in bounds.
+	pub fn get(&self, index: usize) -> Option<T> {
+		self.as_field_array().get(index)
+	}
+
+	/// Stores a field.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The index of the field.
+	/// - `value`: The new value of the field. Only its low `width` bits are
+	///   stored.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds.
+	pub fn set(&mut self, index: usize, value: T) {
+		let len = self.shape.len;
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		self.bits[self.shape.range(index)].store(value);
+	}
+}
+
+/** Iterator over the values in a `FieldArray`.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+**/
+#[derive(Debug)]
+pub struct FieldIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	array: FieldArray<'a, C, T>,
+	range: Range<usize>,
+}
+
+impl<'a, C, T> Clone for FieldIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn clone(&self) -> Self {
+		Self { array: self.array, range: self.range.clone() }
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for FieldIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore, SliceBit<C, T>: BitField<T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.range.next_back().and_then(|n| self.array.get(n))
+	}
+}
+
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for FieldIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore, SliceBit<C, T>: BitField<T> {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for FieldIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore, SliceBit<C, T>: BitField<T> {}
+
+impl<'a, C, T> Iterator for FieldIter<'a, C, T>
+where C: Cursor, T: 'a + BitStore, SliceBit<C, T>: BitField<T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.range.next().and_then(|n| self.array.get(n))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.range.size_hint()
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.range.nth(n).and_then(|n| self.array.get(n))
+	}
+}
+
 /** Safely compute an LS-edge bitmask for a value of some length.
 
 The shift operators panic when the shift amount equals or exceeds the type
@@ -471,4 +1181,22 @@ mod tests {
 
 		assert!(bits[5 .. 5].load().is_none());
 	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn nth() {
+		use crate::fixtures::check_nth;
+
+		let data = [0x5AC3u16, 0x0FF0, 0x9669, 0x1234];
+		let bits = data.bits::<BigEndian>();
+		for width in 1 .. 9 {
+			let keep = bits[3 ..].iter_chunks_as::<u8>(width, ChunkTail::Keep);
+			check_nth(keep.unwrap());
+			let pad = bits[1 ..].iter_chunks_as::<u8>(width, ChunkTail::Pad);
+			check_nth(pad.unwrap());
+			for stride in width as usize .. 12 {
+				check_nth(bits.field_array(2, width, stride).iter());
+			}
+		}
+	}
 }
@@ -0,0 +1,101 @@
+/*! Fixtures shared by unit tests
+
+Many operations take a faster path a whole element at a time when their slices
+begin or end on an element edge, and fall back to a bit-by-bit path otherwise.
+Their tests compare both paths against a bit-by-bit model over sub-slices at
+every offset within the first element, which these fixtures produce.
+!*/
+
+use crate::{
+	cursor::{
+		Cursor,
+		PermutationTable,
+	},
+	slice::SliceBit,
+	store::BitStore,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	fmt::Debug,
+	ops::Range,
+};
+
+/// A byte ordering that is neither of the contiguous cursors, so that the
+/// element-wise paths built on those cursors are not taken.
+pub struct PairSwap;
+
+impl PermutationTable for PairSwap {
+	const TYPENAME: &'static str = "PairSwap";
+
+	fn table(width: u8) -> &'static [u8] {
+		match width {
+			8 => &[6, 7, 4, 5, 2, 3, 0, 1],
+			_ => unimplemented!("PairSwap only supports bytes"),
+		}
+	}
+}
+
+/// Checks every sub-slice of a slice that begins near its front.
+///
+/// # Parameters
+///
+/// - `bits`: The slice to divide.
+/// - `heads`: The number of start indices to try, from `0`.
+/// - `step`: The distance between the end indices to try, from each start.
+/// - `check`: Called with the range of each sub-slice, and the sub-slice.
+pub fn each_subslice<C, T, F>(
+	bits: &SliceBit<C, T>,
+	heads: usize,
+	step: usize,
+	mut check: F,
+)
+where C: Cursor, T: BitStore, F: FnMut(Range<usize>, &SliceBit<C, T>) {
+	let len = bits.len();
+	for start in 0 .. heads.min(len + 1) {
+		for end in (start ..= len).step_by(step) {
+			check(start .. end, &bits[start .. end]);
+		}
+	}
+}
+
+/// Checks writes to every sub-slice of some elements that begins within the
+/// first element, and that the writes touch no bit outside their sub-slice.
+///
+/// Each sub-slice is taken from a fresh copy of `orig`.
+///
+/// # Parameters
+///
+/// - `orig`: The elements to divide.
+/// - `step`: The distance between the end indices to try, from each start.
+/// - `write`: Called with the range of each sub-slice, and the sub-slice.
+pub fn each_subslice_mut<C, T, F>(orig: &[T], step: usize, mut write: F)
+where C: Cursor, T: BitStore, F: FnMut(Range<usize>, &mut SliceBit<C, T>) {
+	let orig_bits = SliceBit::<C, T>::from_slice(orig);
+	let mut copy = orig.to_vec();
+	each_subslice(orig_bits, T::BITS as usize + 1, step, |range, _| {
+		copy.copy_from_slice(orig);
+		let bits = SliceBit::<C, T>::from_slice_mut(&mut copy);
+		write(range.clone(), &mut bits[range.clone()]);
+		assert_eq!(bits[.. range.start], orig_bits[.. range.start]);
+		assert_eq!(bits[range.end ..], orig_bits[range.end ..]);
+	});
+}
+
+/// Checks that skipping ahead with `nth` agrees with stepping through `next`,
+/// for every skip distance, including distances that overshoot, and that the
+/// iterator resumes from the same place afterward.
+pub fn check_nth<I>(iter: I)
+where I: Iterator + Clone, I::Item: Debug + PartialEq {
+	let all = iter.clone().collect::<Vec<_>>();
+	for n in 0 ..= all.len() + 1 {
+		let mut fast = iter.clone();
+		let mut slow = iter.clone();
+		for _ in 0 .. n {
+			slow.next();
+		}
+		assert_eq!(fast.nth(n), slow.next());
+		assert_eq!(fast.collect::<Vec<_>>(), slow.collect::<Vec<_>>());
+	}
+}
@@ -0,0 +1,352 @@
+/*! Combining many bit slices at once
+
+This module merges any number of bit slices into one vector, either with a
+single Boolean operator, such as the union of the posting bitmaps for every
+term in a query, or by counting votes. Folding the slices pairwise would build
+a temporary vector for every step; these functions instead visit each element
+position once, combine that element of every slice, and write the result once.
+
+The slices are treated as sets of indices. The result is as long as the longest
+slice, and the bits past the end of a shorter slice are treated as cleared.
+Slices that begin on an element edge, as every `VecBit` and `BitBox` does, are
+read a whole element at a time; others are read bit by bit.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	access::BitAccess,
+	cursor::Cursor,
+	indices::Indexable,
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+use alloc::vec::Vec;
+
+use core::cmp::min;
+
+/// Computes the union of many slices (`A ∪ B ∪ …`).
+///
+/// # Parameters
+///
+/// - `slices`: The slices to combine.
+///
+/// # Returns
+///
+/// A vector as long as the longest slice, with each bit set if it is set in
+/// any slice. If `slices` is empty, the vector is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vecbit::prelude::*;
+/// use vecbit::fold_ops::or_all;
+///
+/// let maps = vec![
+///   vecbit![BigEndian, u8; 1, 0, 0, 0],
+///   vecbit![BigEndian, u8; 0, 0, 1],
+///   vecbit![BigEndian, u8; 0, 0, 0, 0, 0, 1],
+/// ];
+/// let any = or_all(maps.iter().map(|bv| bv.as_bitslice()));
+/// assert_eq!(any, vecbit![1, 0, 1, 0, 0, 1]);
+/// ```
+pub fn or_all<'a, C, T, I>(slices: I) -> VecBit<C, T>
+where
+	C: Cursor + 'a,
+	T: BitStore + 'a,
+	I: IntoIterator<Item = &'a SliceBit<C, T>>,
+{
+	fold_all(slices, |a, b| a | b)
+}
+
+/// Computes the intersection of many slices (`A ∩ B ∩ …`).
+///
+/// # Parameters
+///
+/// - `slices`: The slices to combine.
+///
+/// # Returns
+///
+/// A vector as long as the longest slice, with each bit set if it is set in
+/// every slice. Bits past the end of the shortest slice are cleared. If
+/// `slices` is empty, the vector is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vecbit::prelude::*;
+/// use vecbit::fold_ops::and_all;
+///
+/// let a = [0b1110_0111u8, 0xFF];
+/// let b = [0b0111_1110u8, 0x0F];
+/// let c = [0b1111_1111u8];
+/// let all = and_all(vec![
+///   a.bits::<BigEndian>(),
+///   b.bits::<BigEndian>(),
+///   &c.bits::<BigEndian>()[1 ..],
+/// ]);
+/// assert_eq!(all.len(), 16);
+/// assert_eq!(all.as_slice(), &[0b0110_0110, 0]);
+/// ```
+pub fn and_all<'a, C, T, I>(slices: I) -> VecBit<C, T>
+where
+	C: Cursor + 'a,
+	T: BitStore + 'a,
+	I: IntoIterator<Item = &'a SliceBit<C, T>>,
+{
+	fold_all(slices, |a, b| a & b)
+}
+
+/// Computes the parity of many slices (`A ⊕ B ⊕ …`).
+///
+/// # Parameters
+///
+/// - `slices`: The slices to combine.
+///
+/// # Returns
+///
+/// A vector as long as the longest slice, with each bit set if it is set in
+/// an odd number of slices. If `slices` is empty, the vector is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vecbit::prelude::*;
+/// use vecbit::fold_ops::xor_all;
+///
+/// let src = [0b1100u8, 0b1010, 0b1001];
+/// let odd = xor_all(src.iter().map(|b| &b.bits::<LittleEndian>()[.. 4]));
+/// assert_eq!(odd.as_slice(), &[0b1111]);
+/// ```
+pub fn xor_all<'a, C, T, I>(slices: I) -> VecBit<C, T>
+where
+	C: Cursor + 'a,
+	T: BitStore + 'a,
+	I: IntoIterator<Item = &'a SliceBit<C, T>>,
+{
+	fold_all(slices, |a, b| a ^ b)
+}
+
+/// Sets each bit that is set in at least some number of slices.
+///
+/// The counts are kept a whole element at a time, as binary counters that are
+/// sliced across elements: the `n`th counter element holds bit `n` of the count
+/// for every bit position in the element. Each slice is added to the counters
+/// with a ripple carry, and the counters are compared against `threshold`
+/// without being unpacked.
+///
+/// # Parameters
+///
+/// - `slices`: The slices to combine.
+/// - `threshold`: The number of slices in which a bit must be set for it to be
+///   set in the result.
+///
+/// # Returns
+///
+/// A vector as long as the longest slice, with each bit set if it is set in at
+/// least `threshold` slices. If `slices` is empty, the vector is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vecbit::prelude::*;
+/// use vecbit::fold_ops::majority;
+///
+/// let votes = vec![
+///   vecbit![BigEndian, u8; 1, 1, 0, 0, 1],
+///   vecbit![BigEndian, u8; 1, 0, 1, 0],
+///   vecbit![BigEndian, u8; 1, 1, 0, 1, 1],
+/// ];
+/// let slices = || votes.iter().map(|bv| bv.as_bitslice());
+/// assert_eq!(majority(slices(), 2), vecbit![1, 1, 0, 0, 1]);
+/// assert_eq!(majority(slices(), 3), vecbit![1, 0, 0, 0, 0]);
+/// ```
+pub fn majority<'a, C, T, I>(slices: I, threshold: usize) -> VecBit<C, T>
+where
+	C: Cursor + 'a,
+	T: BitStore + 'a,
+	I: IntoIterator<Item = &'a SliceBit<C, T>>,
+{
+	let slices = slices.into_iter().collect::<Vec<_>>();
+	let len = slices.iter().map(|s| s.len()).max().unwrap_or(0);
+	let zero = T::bits(false);
+	let mut counters = Vec::new();
+	let elts = (0 .. len).step_by(T::BITS as usize).map(|from| {
+		counters.clear();
+		for slice in &slices {
+			let mut carry = load(slice, from);
+			for counter in counters.iter_mut() {
+				if carry == zero {
+					break;
+				}
+				let sum = *counter ^ carry;
+				carry &= *counter;
+				*counter = sum;
+			}
+			if carry != zero {
+				counters.push(carry);
+			}
+		}
+		at_least(&counters, threshold)
+	}).collect::<Vec<T>>();
+	let mut out = VecBit::from_vec(elts);
+	unsafe { out.set_len(len); }
+	//  A threshold of zero is met by the dead bits too.
+	out.mask_dead_bits();
+	out
+}
+
+/// Compares sliced binary counters against a threshold.
+///
+/// `counters[n]` holds bit `n` of the count for each bit position. The result
+/// has each bit set where the count is at least `threshold`.
+fn at_least<T>(counters: &[T], threshold: usize) -> T
+where T: BitStore {
+	let width = 0usize.count_zeros() - threshold.leading_zeros();
+	//  `above` marks the positions whose count already exceeds the threshold
+	//  in the bits seen so far, and `equal` those whose count matches it.
+	let mut above = T::bits(false);
+	let mut equal = T::bits(true);
+	for n in (0 .. width.max(counters.len() as u32)).rev() {
+		let counter = counters.get(n as usize).cloned()
+			.unwrap_or_else(|| T::bits(false));
+		if threshold >> n & 1 == 1 {
+			equal &= counter;
+		}
+		else {
+			above |= equal & counter;
+			equal &= !counter;
+		}
+	}
+	above | equal
+}
+
+/// Combines an element position of every slice with an operator, and collects
+/// the results.
+fn fold_all<'a, C, T, I, F>(slices: I, op: F) -> VecBit<C, T>
+where
+	C: Cursor + 'a,
+	T: BitStore + 'a,
+	I: IntoIterator<Item = &'a SliceBit<C, T>>,
+	F: Fn(T, T) -> T,
+{
+	let slices = slices.into_iter().collect::<Vec<_>>();
+	let len = slices.iter().map(|s| s.len()).max().unwrap_or(0);
+	let elts = (0 .. len).step_by(T::BITS as usize).map(|from| {
+		slices.iter()
+			.map(|s| load(s, from))
+			.fold(None, |acc, elt| Some(match acc {
+				Some(acc) => op(acc, elt),
+				None => elt,
+			}))
+			.unwrap_or_else(|| T::bits(false))
+	}).collect::<Vec<T>>();
+	let mut out = VecBit::from_vec(elts);
+	unsafe { out.set_len(len); }
+	out
+}
+
+/// Reads the bits of a slice from `from` up to the next element’s worth into
+/// an element, with the bits past the end of the slice cleared.
+///
+/// `from` must be a multiple of `T::BITS`.
+fn load<C, T>(slice: &SliceBit<C, T>, from: usize) -> T
+where C: Cursor, T: BitStore {
+	let mut elt = T::bits(false);
+	if from >= slice.len() {
+		return elt;
+	}
+	let count = min(slice.len() - from, T::BITS as usize) as u8;
+	if *slice.bitptr().head() == 0 {
+		let full = slice.as_total_slice()[from >> T::INDX].load();
+		if count == T::BITS {
+			return full;
+		}
+		for n in 0 .. count {
+			elt.set::<C>(n.idx(), full.get::<C>(n.idx()));
+		}
+	}
+	else {
+		for n in 0 .. count {
+			let bit = unsafe { slice.get_unchecked(from + n as usize) };
+			elt.set::<C>(n.idx(), bit);
+		}
+	}
+	elt
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	fn model<C, T, F>(slices: &[&SliceBit<C, T>], op: F) -> VecBit<C, T>
+	where C: Cursor, T: BitStore, F: Fn(bool, bool) -> bool {
+		let len = slices.iter().map(|s| s.len()).max().unwrap_or(0);
+		let bit = |s: &SliceBit<C, T>, n: usize| n < s.len() && s[n];
+		(0 .. len).map(|n| {
+			slices[1 ..]
+				.iter()
+				.fold(bit(slices[0], n), |acc, s| op(acc, bit(s, n)))
+		}).collect()
+	}
+
+	fn votes<C, T>(slices: &[&SliceBit<C, T>], threshold: usize) -> VecBit<C, T>
+	where C: Cursor, T: BitStore {
+		let len = slices.iter().map(|s| s.len()).max().unwrap_or(0);
+		(0 .. len).map(|n| {
+			slices.iter().filter(|s| n < s.len() && s[n]).count() >= threshold
+		}).collect()
+	}
+
+	fn check<C, T>(src: &[T])
+	where C: Cursor, T: BitStore {
+		let bits = SliceBit::<C, T>::from_slice(src);
+		let total = bits.len();
+		let owned = (0 .. 5)
+			.map(|n| bits[n * 5 .. total - n * 7].to_owned())
+			.collect::<Vec<_>>();
+		for count in 1 .. owned.len() + 1 {
+			for skew in 0 .. 3 {
+				let mut slices = owned[.. count]
+					.iter()
+					.map(|bv| bv.as_bitslice())
+					.collect::<Vec<_>>();
+				slices.push(&bits[skew * 5 .. total - skew * 3]);
+				let each = || slices.iter().cloned();
+				assert_eq!(or_all(each()), model(&slices, |a, b| a | b));
+				assert_eq!(and_all(each()), model(&slices, |a, b| a & b));
+				assert_eq!(xor_all(each()), model(&slices, |a, b| a ^ b));
+				for threshold in 0 .. slices.len() + 2 {
+					assert_eq!(
+						majority(each(), threshold),
+						votes(&slices, threshold),
+						"{} of {}",
+						threshold,
+						slices.len(),
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn matches_model() {
+		check::<BigEndian, u8>(&[0x5A, 0xC3, 0x0F, 0xF0, 0x99, 0x66, 0x12]);
+		check::<LittleEndian, u32>(&[0x1234_5678, 0x9ABC_DEF0, 0x0F0F_F0F0]);
+		check::<BigEndian, u64>(&[0x1234_5678_9ABC_DEF0, 0x0F0F_F0F0_3C3C_C3C3]);
+	}
+
+	#[test]
+	fn dead_bits_cleared() {
+		let a = vecbit![BigEndian, u8; 1; 3];
+		let b = bitbox![BigEndian, u8; 1; 5];
+		let or = or_all([a.as_bitslice(), &b[..]].iter().cloned());
+		assert_eq!(or.as_slice(), &[0b1111_1000]);
+		assert!(or_all(Vec::<&SliceBit>::new()).is_empty());
+		let all = majority(Some(a.as_bitslice()), 0);
+		assert_eq!(all.as_slice(), &[0b1110_0000]);
+	}
+}
@@ -0,0 +1,258 @@
+/*! Splitting a bit stream into frames
+
+This module holds `FrameSplitter`, which accepts a bit stream in pieces of any
+size, as they arrive, and yields the complete frames found in it as owned
+vectors. It is the glue between a transport that delivers bits and a decoder
+that expects one frame at a time.
+
+Frames are delimited in one of two ways, chosen by [`Framing`]:
+
+- fixed length, where every frame has the same number of bits;
+- sync word, where every frame begins with a known bit pattern, and runs until
+  the next occurrence of that pattern. Bits that arrive before the first sync
+  word are discarded.
+
+The splitter buffers only the bits that have not yet been emitted, and copies
+each frame out of its buffer once.
+
+[`Framing`]: enum.Framing.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		Cursor,
+		Local,
+	},
+	slice::SliceBit,
+	store::{
+		BitStore,
+		Word,
+	},
+	vec::VecBit,
+};
+
+use alloc::borrow::ToOwned;
+
+/// The ways in which a bit stream can be divided into frames.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Framing<C = Local, T = Word>
+where C: Cursor, T: BitStore {
+	/// Every frame is this many bits long.
+	Fixed(usize),
+	/// Every frame begins with this pattern, and ends where the next
+	/// occurrence of it begins.
+	Sync(VecBit<C, T>),
+}
+
+/** Divides a bit stream into owned frames.
+
+Bits are supplied with [`push`], and complete frames are taken with
+[`next_frame`], or by iterating the splitter. A frame is complete once the bits
+after it have arrived: for fixed framing, once the frame’s last bit arrives,
+and for sync framing, once the next sync word arrives. The iterator therefore
+ends whenever the buffered bits hold no complete frame, and resumes after more
+bits are pushed.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the frames.
+- `T: BitStore`: The storage type of the frames.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::frames::FrameSplitter;
+
+let sync = vecbit![BigEndian, u8; 1, 1, 1, 0];
+let mut frames = FrameSplitter::sync(sync);
+frames.push(&vecbit![BigEndian, u8; 0, 1, 1, 1, 0, 1, 0]);
+assert!(frames.next_frame().is_none());
+frames.push(&vecbit![BigEndian, u8; 1, 1, 1, 0, 0, 0]);
+assert_eq!(frames.next_frame(), Some(vecbit![BigEndian, u8; 1, 1, 1, 0, 1, 0]));
+assert_eq!(frames.finish(), Some(vecbit![BigEndian, u8; 1, 1, 1, 0, 0, 0]));
+```
+
+[`next_frame`]: #method.next_frame
+[`push`]: #method.push
+**/
+#[derive(Clone, Debug)]
+pub struct FrameSplitter<C = Local, T = Word>
+where C: Cursor, T: BitStore {
+	/// How the stream is divided.
+	framing: Framing<C, T>,
+	/// The bits that have been pushed, but not yet emitted or discarded.
+	buffer: VecBit<C, T>,
+	/// The index in `buffer` of the first bit not yet emitted or discarded.
+	read: usize,
+}
+
+impl<C, T> FrameSplitter<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs a splitter with some framing.
+	///
+	/// # Parameters
+	///
+	/// - `framing`: How to divide the stream.
+	///
+	/// # Panics
+	///
+	/// Panics if the framing has a frame length of zero or an empty sync word.
+	pub fn new(framing: Framing<C, T>) -> Self {
+		match &framing {
+			Framing::Fixed(len) => {
+				assert!(*len != 0, "Frames must be at least one bit long");
+			},
+			Framing::Sync(word) => {
+				assert!(!word.is_empty(), "The sync word must not be empty");
+			},
+		}
+		Self { framing, buffer: VecBit::new(), read: 0 }
+	}
+
+	/// Constructs a splitter for frames of a fixed length.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The length, in bits, of every frame.
+	///
+	/// # Panics
+	///
+	/// Panics if `len` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::frames::FrameSplitter;
+	///
+	/// let mut frames = FrameSplitter::<BigEndian, u8>::fixed(3);
+	/// frames.push(&0b1100_1011u8.bits::<BigEndian>()[..]);
+	/// let all = frames.by_ref().collect::<Vec<_>>();
+	/// assert_eq!(all, [
+	/// 	vecbit![BigEndian, u8; 1, 1, 0],
+	/// 	vecbit![BigEndian, u8; 0, 1, 0],
+	/// ]);
+	/// assert_eq!(frames.finish(), Some(vecbit![BigEndian, u8; 1, 1]));
+	/// ```
+	pub fn fixed(len: usize) -> Self {
+		Self::new(Framing::Fixed(len))
+	}
+
+	/// Constructs a splitter for frames that each begin with a sync word.
+	///
+	/// # Parameters
+	///
+	/// - `word`: The pattern that begins every frame.
+	///
+	/// # Panics
+	///
+	/// Panics if `word` is empty.
+	pub fn sync(word: VecBit<C, T>) -> Self {
+		Self::new(Framing::Sync(word))
+	}
+
+	/// Borrows the framing of the splitter.
+	pub fn framing(&self) -> &Framing<C, T> {
+		&self.framing
+	}
+
+	/// Borrows the bits that have been pushed but not yet emitted.
+	pub fn buffered(&self) -> &SliceBit<C, T> {
+		&self.buffer[self.read ..]
+	}
+
+	/// Appends bits to the stream.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `bits`: The next bits of the stream. This is not required to have
+	///   the same cursor or storage types as the splitter.
+	pub fn push<D, U>(&mut self, bits: &SliceBit<D, U>)
+	where D: Cursor, U: BitStore {
+		//  Emitted bits are dropped from the buffer only once they are at
+		//  least half of it, so that each bit is moved a bounded number of
+		//  times.
+		if self.read != 0 && self.read >= self.buffer.len() / 2 {
+			self.buffer.drain(.. self.read);
+			self.read = 0;
+		}
+		self.buffer.extend(bits.iter());
+	}
+
+	/// Takes the next complete frame from the stream.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The next complete frame, if the buffered bits hold one.
+	pub fn next_frame(&mut self) -> Option<VecBit<C, T>> {
+		let (from, len) = match &self.framing {
+			Framing::Fixed(len) => {
+				if self.buffered().len() < *len {
+					return None;
+				}
+				(self.read, *len)
+			},
+			Framing::Sync(word) => {
+				let pending = &self.buffer[self.read ..];
+				let start = match pending.find(word) {
+					Some(at) => self.read + at,
+					None => {
+						//  Keep only the bits that may begin a sync word that
+						//  has not yet fully arrived.
+						let keep = word.len() - 1;
+						self.read = self.buffer.len() - keep.min(pending.len());
+						return None;
+					},
+				};
+				self.read = start;
+				let after = start + word.len();
+				let len = self.buffer[after ..].find(word)? + word.len();
+				(start, len)
+			},
+		};
+		self.read = from + len;
+		Some(self.buffer[from .. from + len].to_owned())
+	}
+
+	/// Ends the stream, and takes its final frame.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The bits after the last complete frame, if there are any. With fixed
+	/// framing, these are shorter than a frame. With sync framing, these are
+	/// the final frame, which is complete only if no bits were lost at the end
+	/// of the stream; bits that do not begin with the sync word are
+	/// discarded.
+	pub fn finish(mut self) -> Option<VecBit<C, T>> {
+		if let Framing::Sync(word) = &self.framing {
+			let start = self.buffer[self.read ..].find(word)?;
+			self.read += start;
+		}
+		if self.read == self.buffer.len() {
+			return None;
+		}
+		self.buffer.drain(.. self.read);
+		Some(self.buffer)
+	}
+}
+
+impl<C, T> Iterator for FrameSplitter<C, T>
+where C: Cursor, T: BitStore {
+	type Item = VecBit<C, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.next_frame()
+	}
+}
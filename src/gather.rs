@@ -0,0 +1,647 @@
+/*! Compressing and expanding bits under a mask
+
+This module provides the bit-slice forms of the parallel bit extract and deposit
+instructions (`PEXT` and `PDEP` on x86). Gathering compresses the bits of a
+slice that lie under the set bits of a mask into a dense sequence, and
+scattering expands a dense sequence back out into the positions under a mask.
+These are the primitives behind rank/select structures and the selection
+vectors of columnar query engines.
+
+The same operations are also provided for lists of indices, rather than masks,
+for engines that reorder a bitmap by a list of row numbers.
+
+When the slice uses the `BigEndian` or `LittleEndian` cursor, and the slice and
+its mask begin at the same bit of an element, each element is compressed or
+expanded at once. On x86-64 targets compiled with the `bmi2` target feature,
+this uses the `PEXT` and `PDEP` instructions; elsewhere, it uses a portable
+loop over only the set bits of each mask element. All other slices are handled
+one bit at a time.
+!*/
+
+use crate::{
+	access::BitAccess,
+	cursor::{
+		Cursor,
+		Stride,
+	},
+	slice::SliceBit,
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use {
+	crate::vec::VecBit,
+	alloc::vec::Vec,
+};
+
+use core::mem;
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Compresses the bits selected by a mask into a new vector.
+	///
+	/// This is a parallel bit extract: the bits of `self` at the indices where
+	/// `mask` is set are collected, in order, and the rest are discarded.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `mask`: A slice of the same length as `self`, which is set at each
+	///   index to keep.
+	///
+	/// # Returns
+	///
+	/// A vector of `mask.count_ones()` bits, holding the selected bits of
+	/// `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if `mask` is not the same length as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let data = 0b1011_0010u8;
+	/// let mask = 0b1111_0001u8;
+	/// let bits = data.bits::<BigEndian>();
+	/// let out = bits.gather(mask.bits::<BigEndian>());
+	/// assert_eq!(out, vecbit![1, 0, 1, 1, 0]);
+	/// # }
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn gather(&self, mask: &Self) -> VecBit<C, T> {
+		assert_eq!(
+			self.len(),
+			mask.len(),
+			"Cannot gather {} bits under a mask of {} bits",
+			self.len(),
+			mask.len(),
+		);
+		let stride = match Stride::of::<C, T>() {
+			Some(stride) if *self.bitptr().head() == *mask.bitptr().head() => {
+				stride
+			},
+			_ => return self.iter()
+				.zip(mask.iter())
+				.filter(|&(_, keep)| keep)
+				.map(|(bit, _)| bit)
+				.collect(),
+		};
+		let mut out = Vec::<T>::new();
+		let mut len = 0usize;
+		let elts = self.bitptr().as_access_slice();
+		let masks = mask.bitptr().as_access_slice();
+		for (n, (elt, keep)) in elts.iter().zip(masks).enumerate() {
+			let keep = keep.load() & live(self, stride, n);
+			let count = keep.count_ones() as u8;
+			if count == 0 {
+				continue;
+			}
+			let packed = narrow::<T>(pext(elt.load().into(), keep.into()));
+			//  Move the packed bits from the low edge to the first `count`
+			//  cursor positions.
+			let packed = match stride {
+				Stride::Ascending => packed,
+				Stride::Descending => packed << (T::BITS - count),
+			};
+			//  Append the bits to the end of `out`, splitting them across the
+			//  last element and a new one if they do not fit.
+			let shift = (len & T::MASK as usize) as u8;
+			if shift == 0 {
+				out.push(packed);
+			}
+			else {
+				*out.last_mut().unwrap() |= stride.to_back(packed, shift);
+				if shift + count > T::BITS {
+					out.push(stride.to_front(packed, T::BITS - shift));
+				}
+			}
+			len += count as usize;
+		}
+		let mut out = VecBit::from_vec(out);
+		unsafe { out.set_len(len); }
+		out
+	}
+
+	/// Expands a dense sequence of bits into the positions selected by a mask.
+	///
+	/// This is a parallel bit deposit: the bits of `src` are written, in order,
+	/// into `self` at each index where `mask` is set. The bits of `self` where
+	/// `mask` is cleared are left unchanged.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `mask`: A slice of the same length as `self`, which is set at each
+	///   index to overwrite.
+	/// - `src`: The bits to write. There must be exactly one for each set bit
+	///   in `mask`.
+	///
+	/// # Panics
+	///
+	/// Panics if `mask` is not the same length as `self`, or if `src` is not
+	/// as long as the number of set bits in `mask`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let mask = 0b1010_0110u8;
+	/// let src = 0b1101_0000u8;
+	/// data.bits_mut::<BigEndian>().scatter(
+	///   mask.bits::<BigEndian>(),
+	///   &src.bits::<BigEndian>()[.. 4],
+	/// );
+	/// assert_eq!(data, 0b1010_0010);
+	/// ```
+	pub fn scatter(&mut self, mask: &Self, src: &Self) {
+		assert_eq!(
+			self.len(),
+			mask.len(),
+			"Cannot scatter into {} bits under a mask of {} bits",
+			self.len(),
+			mask.len(),
+		);
+		assert_eq!(
+			src.len(),
+			mask.count_ones(),
+			"Cannot scatter {} bits into the {} bits set in the mask",
+			src.len(),
+			mask.count_ones(),
+		);
+		let stride = match Stride::of::<C, T>() {
+			Some(stride) if *self.bitptr().head() == *mask.bitptr().head() => {
+				stride
+			},
+			_ => {
+				let mut src = src.iter();
+				for n in 0 .. self.len() {
+					if unsafe { mask.get_unchecked(n) } {
+						unsafe { self.set_unchecked(n, src.next().unwrap()); }
+					}
+				}
+				return;
+			},
+		};
+		let mut from = 0usize;
+		let elts = self.bitptr().as_access_slice();
+		let masks = mask.bitptr().as_access_slice();
+		for (n, (elt, keep)) in elts.iter().zip(masks).enumerate() {
+			let keep = keep.load() & live(self, stride, n);
+			let count = keep.count_ones() as u8;
+			if count == 0 {
+				continue;
+			}
+			let packed = read_run(src, stride, from, count);
+			//  Move the bits from the first `count` cursor positions to the low
+			//  edge.
+			let packed = match stride {
+				Stride::Ascending => packed,
+				Stride::Descending => packed >> (T::BITS - count),
+			};
+			let spread = narrow::<T>(pdep(packed.into(), keep.into()));
+			elt.clear_bits(!keep);
+			elt.set_bits(spread);
+			from += count as usize;
+		}
+	}
+
+	/// Collects the bits at a list of indices into a new vector.
+	///
+	/// The indices may be in any order, and may repeat. Bit `n` of the output
+	/// is the bit of `self` at `indices[n]`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `indices`: The indices in `self` of the bits to collect.
+	///
+	/// # Returns
+	///
+	/// A vector of `indices.len()` bits.
+	///
+	/// # Panics
+	///
+	/// Panics if any index is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b1100_1010u8.bits::<BigEndian>();
+	/// let rows = bits.select_indices(&[7, 0, 4, 4, 1]);
+	/// assert_eq!(rows, vecbit![0, 1, 1, 1, 1]);
+	/// # }
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn select_indices(&self, indices: &[usize]) -> VecBit<C, T> {
+		let len = self.len();
+		indices.iter().map(|&n| {
+			assert!(n < len, "Index {} out of bounds: {}", n, len);
+			unsafe { self.get_unchecked(n) }
+		}).collect()
+	}
+
+	/// Writes a run of bits to a list of indices.
+	///
+	/// This is the inverse of [`select_indices`]: bit `n` of `src` is written
+	/// into `self` at `indices[n]`. The bits of `self` at indices not in the
+	/// list are left unchanged. If an index repeats, the last bit written to it
+	/// remains.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `indices`: The indices in `self` to overwrite.
+	/// - `src`: The bits to write. There must be exactly one for each index.
+	///
+	/// # Panics
+	///
+	/// Panics if `src` is not as long as `indices`, or if any index is out of
+	/// bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let src = 0b1011_0000u8;
+	/// data.bits_mut::<BigEndian>().scatter_indices(
+	///   &[6, 1, 3, 4],
+	///   &src.bits::<BigEndian>()[.. 4],
+	/// );
+	/// assert_eq!(data, 0b0001_1010);
+	/// ```
+	///
+	/// [`select_indices`]: #method.select_indices
+	pub fn scatter_indices(&mut self, indices: &[usize], src: &Self) {
+		assert_eq!(
+			src.len(),
+			indices.len(),
+			"Cannot scatter {} bits to {} indices",
+			src.len(),
+			indices.len(),
+		);
+		let len = self.len();
+		for (&n, bit) in indices.iter().zip(src.iter()) {
+			assert!(n < len, "Index {} out of bounds: {}", n, len);
+			unsafe { self.set_unchecked(n, bit); }
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Removes every bit selected by a mask, keeping the rest in order.
+	///
+	/// This is an in-place [`gather`] of the bits where `mask` is cleared, and
+	/// has the effect of calling [`remove`] on each selected index, but takes
+	/// a single pass over the vector. It uses the same element-wise compression
+	/// as [`gather`], and writes the kept bits back into the front of the
+	/// vector’s own buffer, without allocating.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `mask`: A slice of the same length as `self`, which is set at each
+	///   index to remove.
+	///
+	/// # Panics
+	///
+	/// Panics if `mask` is not the same length as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![BigEndian, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	/// let mask = vecbit![BigEndian, u8; 0, 1, 1, 0, 0, 1, 0, 0, 1];
+	/// bv.remove_masked(&mask);
+	/// assert_eq!(bv, vecbit![1, 1, 0, 1, 0]);
+	/// ```
+	///
+	/// [`gather`]: ../slice/struct.SliceBit.html#method.gather
+	/// [`remove`]: #method.remove
+	pub fn remove_masked(&mut self, mask: &SliceBit<C, T>) {
+		let len = self.len();
+		assert_eq!(
+			len,
+			mask.len(),
+			"Cannot remove from {} bits under a mask of {} bits",
+			len,
+			mask.len(),
+		);
+		let bits = self.as_bitslice();
+		let head = *bits.bitptr().head() as usize;
+		let stride = match Stride::of::<C, T>() {
+			Some(stride) if head == *mask.bitptr().head() as usize => stride,
+			_ => {
+				let mut kept = 0;
+				for n in 0 .. len {
+					if !mask[n] {
+						let bit = self[n];
+						unsafe { self.set_unchecked(kept, bit); }
+						kept += 1;
+					}
+				}
+				return self.truncate(kept);
+			},
+		};
+		let elts = bits.bitptr().as_access_slice();
+		let masks = mask.bitptr().as_access_slice();
+		//  The kept bits are written behind the element being read, or into
+		//  it after it has been read, so no bit is overwritten before it is
+		//  read.
+		let mut kept = 0usize;
+		for (n, (elt, drop)) in elts.iter().zip(masks).enumerate() {
+			let keep = !drop.load() & live(bits, stride, n);
+			let count = keep.count_ones() as u8;
+			if count == 0 {
+				continue;
+			}
+			let packed = narrow::<T>(pext(elt.load().into(), keep.into()));
+			let packed = match stride {
+				Stride::Ascending => packed,
+				Stride::Descending => packed << (T::BITS - count),
+			};
+			let at = head + kept;
+			let (out, shift) = (at >> T::INDX, (at & T::MASK as usize) as u8);
+			let prev = elts[out].load() & front::<T>(stride, shift);
+			elts[out].store(prev | stride.to_back(packed, shift));
+			if shift + count > T::BITS {
+				elts[out + 1].store(stride.to_front(packed, T::BITS - shift));
+			}
+			kept += count as usize;
+		}
+		self.truncate(kept);
+	}
+}
+
+/// Marks the first `count` cursor positions in an element of a contiguous
+/// cursor.
+fn front<T>(stride: Stride, count: u8) -> T
+where T: BitStore {
+	match count {
+		0 => T::bits(false),
+		c => stride.to_front(T::bits(true), T::BITS - c),
+	}
+}
+
+/// Marks the live bits of an element in a slice of a contiguous cursor.
+pub(crate) fn live<C, T>(slice: &SliceBit<C, T>, stride: Stride, n: usize) -> T
+where C: Cursor, T: BitStore {
+	let bitptr = slice.bitptr();
+	let from = if n == 0 { *bitptr.head() } else { 0 };
+	let upto = if n + 1 == bitptr.elements() { *bitptr.tail() } else { T::BITS };
+	front::<T>(stride, upto) & !front::<T>(stride, from)
+}
+
+/// Reads up to an element’s worth of bits from any offset in a slice of a
+/// contiguous cursor, into the first `count` cursor positions of an element.
+///
+/// The rest of the element is cleared.
+fn read_run<C, T>(src: &SliceBit<C, T>, stride: Stride, at: usize, count: u8) -> T
+where C: Cursor, T: BitStore {
+	let bitptr = src.bitptr();
+	let elts = bitptr.as_access_slice();
+	let at = *bitptr.head() as usize + at;
+	let (elt, shift) = (at >> T::INDX, (at & T::MASK as usize) as u8);
+	let mut out = stride.to_front(elts[elt].load(), shift);
+	if shift + count > T::BITS {
+		out |= stride.to_back(elts[elt + 1].load(), T::BITS - shift);
+	}
+	out & front::<T>(stride, count)
+}
+
+/// Truncates a `u64` into a storage element, keeping its low bits.
+fn narrow<T>(word: u64) -> T
+where T: BitStore {
+	let width = mem::size_of::<T>();
+	(0 .. width).rev().fold(T::bits(false), |elt, n| {
+		let byte = T::from((word >> (n * 8)) as u8);
+		//  Shifting by the full width of `u8` is an overflow.
+		if width == 1 { byte } else { elt << 8u8 | byte }
+	})
+}
+
+/// Collects the bits of `value` under the set bits of `mask` into the low bits
+/// of the output.
+#[cfg(feature = "alloc")]
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+fn pext(value: u64, mask: u64) -> u64 {
+	unsafe { core::arch::x86_64::_pext_u64(value, mask) }
+}
+
+/// Collects the bits of `value` under the set bits of `mask` into the low bits
+/// of the output.
+#[cfg(feature = "alloc")]
+#[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+fn pext(value: u64, mut mask: u64) -> u64 {
+	let (mut out, mut bit) = (0, 1);
+	while mask != 0 {
+		let low = mask & mask.wrapping_neg();
+		if value & low != 0 {
+			out |= bit;
+		}
+		mask ^= low;
+		bit <<= 1;
+	}
+	out
+}
+
+/// Spreads the low bits of `value` into the positions of the set bits of
+/// `mask`.
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+fn pdep(value: u64, mask: u64) -> u64 {
+	unsafe { core::arch::x86_64::_pdep_u64(value, mask) }
+}
+
+/// Spreads the low bits of `value` into the positions of the set bits of
+/// `mask`.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+fn pdep(value: u64, mut mask: u64) -> u64 {
+	let (mut out, mut bit) = (0, 1);
+	while mask != 0 {
+		let low = mask & mask.wrapping_neg();
+		if value & bit != 0 {
+			out |= low;
+		}
+		mask ^= low;
+		bit <<= 1;
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[cfg(feature = "alloc")]
+	use crate::{
+		cursor::{
+			BigEndian,
+			LittleEndian,
+			Permuted,
+		},
+		fixtures::{
+			each_subslice,
+			PairSwap,
+		},
+		prelude::*,
+	};
+
+	const DATA: [u16; 4] = [0xB5C3, 0x0FF0, 0x9249, 0x7E81];
+	const MASK: [u16; 4] = [0xF0A5, 0xFFFF, 0x0000, 0x3C5A];
+
+	#[cfg(feature = "alloc")]
+	fn check_gather<C>()
+	where C: Cursor {
+		let data = DATA.bits::<C>();
+		let masks = MASK.bits::<C>();
+		each_subslice(&masks[.. 63], 20, 7, |range, mask| {
+			for skew in 0 .. 2 {
+				let bits = &data[range.start + skew .. range.end + skew];
+				let expected = bits.iter()
+					.zip(mask.iter())
+					.filter(|&(_, keep)| keep)
+					.map(|(bit, _)| bit)
+					.collect::<VecBit<C, u16>>();
+				let gathered = bits.gather(mask);
+				assert_eq!(gathered, expected, "{:?} + {}", range, skew);
+
+				let mut out = [0x5A5Au16; 4];
+				let mut expected = out;
+				let src = &data[3 .. 3 + gathered.len()];
+				{
+					let expected = &mut expected.bits_mut::<C>()[range.clone()];
+					let mut src = src.iter();
+					for n in 0 .. mask.len() {
+						if mask[n] {
+							expected.set(n, src.next().unwrap());
+						}
+					}
+				}
+				out.bits_mut::<C>()[range.clone()].scatter(mask, src);
+				assert_eq!(out, expected, "{:?} + {}", range, skew);
+			}
+		});
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn gather_big_endian() {
+		check_gather::<BigEndian>();
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn gather_little_endian() {
+		check_gather::<LittleEndian>();
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn round_trip() {
+		let data = DATA.bits::<LittleEndian>();
+		let mask = MASK.bits::<LittleEndian>();
+		let mut out = [0u16; 4];
+		out.bits_mut::<LittleEndian>().scatter(mask, &data.gather(mask));
+		for n in 0 .. 4 {
+			assert_eq!(out[n], DATA[n] & MASK[n]);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn index_lists() {
+		let data = DATA.bits::<BigEndian>();
+		let rows = (0 .. 64).rev().step_by(3).collect::<Vec<usize>>();
+		let selected = data.select_indices(&rows);
+		assert_eq!(selected.len(), rows.len());
+		for (n, &row) in rows.iter().enumerate() {
+			assert_eq!(selected[n], data[row]);
+		}
+
+		let mut out = [0u16; 4];
+		out.bits_mut::<BigEndian>().scatter_indices(&rows, &selected);
+		for n in 0 .. 64 {
+			let expected = rows.contains(&n) && data[n];
+			assert_eq!(out.bits::<BigEndian>()[n], expected);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	#[should_panic(expected = "Index 64 out of bounds: 64")]
+	fn index_out_of_bounds() {
+		DATA.bits::<BigEndian>().select_indices(&[0, 64]);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn remove_masked() {
+		fn check<C, T>(data: &SliceBit<C, T>, masks: &SliceBit<C, T>)
+		where C: Cursor, T: BitStore {
+			each_subslice(data, 1, 1, |range, bits| {
+				for shift in 0 .. 3 {
+					let mask = &masks[shift .. shift + range.end];
+					let mut bv = VecBit::from_bitslice(bits);
+					let expected = bv.iter()
+						.zip(mask.iter())
+						.filter(|&(_, drop)| !drop)
+						.map(|(bit, _)| bit)
+						.collect::<VecBit<C, T>>();
+					bv.remove_masked(mask);
+					assert_eq!(bv, expected);
+				}
+			});
+		}
+
+		let data = [0x5AC3u16, 0x0FF0, 0x9669, 0x1234];
+		let masks = [0x0FF0u16, 0xA5A5, 0xFFFF, 0x0000, 0x8001];
+		check(data.bits::<BigEndian>(), masks.bits::<BigEndian>());
+		check(data.bits::<LittleEndian>(), masks.bits::<LittleEndian>());
+		let data = [0x5Au8, 0xC3, 0x0F, 0xF0, 0x96, 0x69];
+		let masks = [0x0Fu8, 0xF0, 0xA5, 0xA5, 0xFF, 0x00, 0x81];
+		check(data.bits::<BigEndian>(), masks.bits::<BigEndian>());
+		check(
+			data.bits::<Permuted<PairSwap>>(),
+			masks.bits::<Permuted<PairSwap>>(),
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	#[should_panic]
+	fn remove_masked_length() {
+		let mut bv = vecbit![0, 1, 0];
+		bv.remove_masked(&vecbit![1, 0]);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn pext_pdep() {
+		assert_eq!(pext(0b1011_0110, 0b1111_0000), 0b1011);
+		assert_eq!(pext(0b1011_0110, 0b0101_0101), 0b0110);
+		assert_eq!(pext(!0, !0), !0);
+		assert_eq!(pdep(0b1011, 0b1111_0000), 0b1011_0000);
+		assert_eq!(pdep(0b0110, 0b0101_0101), 0b0001_0100);
+		assert_eq!(pdep(!0, 1 << 63), 1 << 63);
+	}
+
+	#[test]
+	fn narrowing() {
+		assert_eq!(narrow::<u8>(0x1234), 0x34);
+		assert_eq!(narrow::<u16>(0x1_2345), 0x2345);
+		assert_eq!(narrow::<u32>(!0), !0);
+	}
+}
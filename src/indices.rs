@@ -7,9 +7,16 @@ first bit past the end of a memory region and are not required to exist in
 hardware (`BitTail`), specific bit positions that may be accessed by machine
 instructions (`BitPos`), and element values that mask one or more bits of
 interest (`BitMask`).
+
+It also provides `BitIndex`, an opt-in index into a whole bit slice, which keeps
+bit indices apart from other integers such as element indices and counts.
 !*/
 
-use crate::store::BitStore;
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+};
 
 use core::{
 	marker::PhantomData,
@@ -509,6 +516,85 @@ where T: BitStore {
 	}
 }
 
+/** A bit index into a `SliceBit`, checked against the slice when made.
+
+Plain `usize` bit indices are easily confused with element indices, byte
+offsets, and counts. Code that wants the type system to keep them apart can use
+`BitIndex` instead: it can only be made by checking an index against a slice,
+and `SliceBit` accepts it through `Index` and `SliceBit::get_at`.
+
+A `BitIndex` does not remember which slice it was checked against, so using it
+on a different slice is still bounds-checked.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::indices::BitIndex;
+
+let bits = 0b0010_0000u8.bits::<BigEndian>();
+let idx = BitIndex::new(2, bits).unwrap();
+assert!(bits[idx]);
+assert_eq!(*idx, 2);
+assert!(BitIndex::new(8, bits).is_none());
+```
+**/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BitIndex {
+	/// Semantic index within a slice.
+	index: usize,
+}
+
+impl BitIndex {
+	/// Checks an index against a slice.
+	///
+	/// # Parameters
+	///
+	/// - `index`: A semantic bit index.
+	/// - `bits`: The slice into which `index` is to point.
+	///
+	/// # Returns
+	///
+	/// A `BitIndex` of `index`, if it is in bounds for `bits`.
+	pub fn new<C, T>(index: usize, bits: &SliceBit<C, T>) -> Option<Self>
+	where C: Cursor, T: BitStore {
+		if index < bits.len() {
+			Some(Self { index })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Produces the last index of a slice.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice whose last index is produced.
+	///
+	/// # Returns
+	///
+	/// The index of the last bit in `bits`, if it is not empty.
+	pub fn last<C, T>(bits: &SliceBit<C, T>) -> Option<Self>
+	where C: Cursor, T: BitStore {
+		bits.len().checked_sub(1).map(|index| Self { index })
+	}
+}
+
+impl Deref for BitIndex {
+	type Target = usize;
+
+	fn deref(&self) -> &Self::Target {
+		&self.index
+	}
+}
+
+impl From<BitIndex> for usize {
+	fn from(idx: BitIndex) -> Self {
+		idx.index
+	}
+}
+
 /** Internal convenience trait for wrapping numbers with appropriate markers.
 
 This trait must only be used on values that are known to be valid for their
@@ -0,0 +1,235 @@
+/*! Block interleaving
+
+This module spreads the bits of a sequence apart, so that a burst of errors in
+transmission is scattered across many code blocks once the sequence is put back
+in order, where a block code can correct each error separately.
+
+A block interleaver arranges each block of `rows × cols` bits as a matrix,
+writing the bits in row by row, and reading them out column by column. The
+columns are read in ascending order, or in any other order that the
+[`Interleaver`] is given. Deinterleaving reverses this.
+
+[`Interleaver`]: struct.Interleaver.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+use alloc::{
+	vec,
+	vec::Vec,
+};
+
+/** A block interleaver of a fixed shape.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::interleave::Interleaver;
+
+let il = Interleaver::new(2, 3).with_column_order(vec![2, 0, 1]);
+let bits = vecbit![BigEndian, u8; 1, 1, 0, 0, 0, 1];
+let out = il.interleave(&bits);
+//  Rows `110` and `001`, read as columns 2, 0, 1.
+assert_eq!(out, vecbit![0, 1, 1, 0, 1, 0]);
+assert_eq!(il.deinterleave(&out), bits);
+```
+**/
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Interleaver {
+	/// The number of rows in each block.
+	rows: usize,
+	/// The order in which the columns of each block are read.
+	order: Vec<usize>,
+}
+
+impl Interleaver {
+	/// Constructs an interleaver that reads columns in ascending order.
+	///
+	/// # Parameters
+	///
+	/// - `rows`: The number of rows in each block.
+	/// - `cols`: The number of columns in each block.
+	///
+	/// # Panics
+	///
+	/// Panics if `rows` or `cols` is zero.
+	pub fn new(rows: usize, cols: usize) -> Self {
+		assert!(
+			rows != 0 && cols != 0,
+			"Cannot interleave in blocks of {} × {} bits",
+			rows,
+			cols,
+		);
+		Self { rows, order: (0 .. cols).collect() }
+	}
+
+	/// Sets the order in which the columns of each block are read.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `order`: The index of each column, in the order in which it is read.
+	///
+	/// # Panics
+	///
+	/// Panics if `order` is not a permutation of `0 .. cols`.
+	pub fn with_column_order(mut self, order: Vec<usize>) -> Self {
+		let cols = self.cols();
+		let mut seen = vec![false; cols];
+		let valid = order.len() == cols && order.iter().all(|&c| {
+			c < cols && !core::mem::replace(&mut seen[c], true)
+		});
+		assert!(valid, "{:?} is not a permutation of 0 .. {}", order, cols);
+		self.order = order;
+		self
+	}
+
+	/// Gets the number of rows in each block.
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns in each block.
+	pub fn cols(&self) -> usize {
+		self.order.len()
+	}
+
+	/// Gets the number of bits in each block.
+	pub fn block_len(&self) -> usize {
+		self.rows * self.cols()
+	}
+
+	/// Interleaves a sequence, block by block.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bits`: The sequence to interleave.
+	///
+	/// # Returns
+	///
+	/// The bits of each block, read column by column.
+	///
+	/// # Panics
+	///
+	/// Panics if the length of `bits` is not a multiple of the block length.
+	pub fn interleave<C, T>(&self, bits: &SliceBit<C, T>) -> VecBit<C, T>
+	where C: Cursor, T: BitStore {
+		self.check(bits.len());
+		let cols = self.cols();
+		let mut out = VecBit::with_capacity(bits.len());
+		for block in bits.chunks(self.block_len()) {
+			for &col in &self.order {
+				out.extend((0 .. self.rows).map(|row| block[row * cols + col]));
+			}
+		}
+		out
+	}
+
+	/// Restores a sequence from its interleaved form, block by block.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bits`: The interleaved sequence.
+	///
+	/// # Returns
+	///
+	/// The bits of each block, back in row order.
+	///
+	/// # Panics
+	///
+	/// Panics if the length of `bits` is not a multiple of the block length.
+	pub fn deinterleave<C, T>(&self, bits: &SliceBit<C, T>) -> VecBit<C, T>
+	where C: Cursor, T: BitStore {
+		self.check(bits.len());
+		let (rows, cols) = (self.rows, self.cols());
+		let mut out = VecBit::with_capacity(bits.len());
+		out.resize(bits.len(), false);
+		let blocks = out.chunks_mut(self.block_len())
+			.zip(bits.chunks(self.block_len()));
+		for (dst, src) in blocks {
+			for (k, &col) in self.order.iter().enumerate() {
+				for row in 0 .. rows {
+					dst.set(row * cols + col, src[k * rows + row]);
+				}
+			}
+		}
+		out
+	}
+
+	fn check(&self, len: usize) {
+		assert_eq!(
+			len % self.block_len(),
+			0,
+			"{} bits is not a whole number of {}-bit blocks",
+			len,
+			self.block_len(),
+		);
+	}
+}
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Interleaves the slice in blocks of `rows × cols` bits, writing rows and
+	/// reading columns.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rows`: The number of rows in each block.
+	/// - `cols`: The number of columns in each block.
+	///
+	/// # Returns
+	///
+	/// The bits of each block, read column by column.
+	///
+	/// # Panics
+	///
+	/// Panics if `rows` or `cols` is zero, or the length of the slice is not a
+	/// multiple of `rows × cols`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b1110_0100u8.bits::<BigEndian>();
+	/// let spread = bits.interleave(2, 4);
+	/// assert_eq!(spread, vecbit![1, 0, 1, 1, 1, 0, 0, 0]);
+	/// assert_eq!(spread.deinterleave(2, 4), bits);
+	/// ```
+	pub fn interleave(&self, rows: usize, cols: usize) -> VecBit<C, T> {
+		Interleaver::new(rows, cols).interleave(self)
+	}
+
+	/// Reverses [`interleave`] with the same shape.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rows`: The number of rows in each block.
+	/// - `cols`: The number of columns in each block.
+	///
+	/// # Returns
+	///
+	/// The bits of each block, back in row order.
+	///
+	/// # Panics
+	///
+	/// Panics if `rows` or `cols` is zero, or the length of the slice is not a
+	/// multiple of `rows × cols`.
+	///
+	/// [`interleave`]: #method.interleave
+	pub fn deinterleave(&self, rows: usize, cols: usize) -> VecBit<C, T> {
+		Interleaver::new(rows, cols).deinterleave(self)
+	}
+}
@@ -0,0 +1,473 @@
+/*! Runtime bit layouts
+
+This module describes the named fields of a bit sequence at run time, for tools
+that learn a record’s shape from configuration rather than from source code. A
+[`BitLayout`] maps each field name to a [`Field`]: an offset and a width within
+a slice, and whether the field holds a signed integer.
+
+Fields are read and written with their first bit most significant, as they
+appear in a protocol diagram and as [`VecBitBuilder::push_bits`] writes them.
+This is independent of the cursor and storage types of the slice. Fields may
+overlap, so that a layout can describe alternate views of the same bits.
+
+[`BitLayout`]: struct.BitLayout.html
+[`Field`]: struct.Field.html
+[`VecBitBuilder::push_bits`]: ../vec/struct.VecBitBuilder.html#method.push_bits
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+};
+
+use alloc::{
+	string::{
+		String,
+		ToString,
+	},
+	vec::Vec,
+};
+
+use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	ops::Range,
+};
+
+/** A named run of bits within a layout.
+
+# Examples
+
+```rust
+use vecbit::layout::Field;
+
+let ttl = Field::unsigned("ttl", 64, 8);
+assert_eq!(ttl.name(), "ttl");
+assert_eq!(ttl.range(), 64 .. 72);
+assert!(!ttl.is_signed());
+```
+**/
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Field {
+	/// The name by which the field is looked up.
+	name: String,
+	/// The index of the field’s first bit.
+	offset: usize,
+	/// The number of bits in the field.
+	width: u8,
+	/// Whether the field holds a two’s-complement signed integer.
+	signed: bool,
+}
+
+impl Field {
+	/// Describes a field that holds an unsigned integer.
+	///
+	/// # Parameters
+	///
+	/// - `name`: The name of the field.
+	/// - `offset`: The index of the field’s first bit.
+	/// - `width`: The number of bits in the field.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or greater than 64.
+	pub fn unsigned(name: &str, offset: usize, width: u8) -> Self {
+		Self::new(name, offset, width, false)
+	}
+
+	/// Describes a field that holds a two’s-complement signed integer.
+	///
+	/// # Parameters
+	///
+	/// - `name`: The name of the field.
+	/// - `offset`: The index of the field’s first bit.
+	/// - `width`: The number of bits in the field, including the sign bit.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or greater than 64.
+	pub fn signed(name: &str, offset: usize, width: u8) -> Self {
+		Self::new(name, offset, width, true)
+	}
+
+	fn new(name: &str, offset: usize, width: u8, signed: bool) -> Self {
+		assert!(
+			width != 0 && width <= 64,
+			"Field {} has width {}, not between 1 and 64",
+			name,
+			width,
+		);
+		Self { name: name.to_string(), offset, width, signed }
+	}
+
+	/// Borrows the name of the field.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Gets the index of the field’s first bit.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Gets the number of bits in the field.
+	pub fn width(&self) -> u8 {
+		self.width
+	}
+
+	/// Tests whether the field holds a signed integer.
+	pub fn is_signed(&self) -> bool {
+		self.signed
+	}
+
+	/// Gets the range of indices that the field covers.
+	pub fn range(&self) -> Range<usize> {
+		self.offset .. self.offset + self.width as usize
+	}
+
+	/// Reads the field out of a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bits`: The slice holding the field.
+	///
+	/// # Returns
+	///
+	/// The value of the field, sign-extended if it is signed, or an error if
+	/// `bits` is too short to hold it.
+	pub fn read<C, T>(&self, bits: &SliceBit<C, T>)
+	-> Result<FieldValue, LayoutError>
+	where C: Cursor, T: BitStore {
+		let raw = self.bits(bits)?.iter()
+			.fold(0u64, |acc, bit| acc << 1 | bit as u64);
+		if !self.signed {
+			return Ok(FieldValue::Unsigned(raw));
+		}
+		let shift = 64 - self.width;
+		Ok(FieldValue::Signed((raw << shift) as i64 >> shift))
+	}
+
+	/// Writes a value into the field within a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bits`: The slice holding the field.
+	/// - `value`: The value to write. It must be representable in the width
+	///   and signedness of the field.
+	///
+	/// # Returns
+	///
+	/// An error if `bits` is too short to hold the field, or `value` does not
+	/// fit in it. The slice is unchanged if this returns an error.
+	pub fn write<C, T>(&self, bits: &mut SliceBit<C, T>, value: FieldValue)
+	-> Result<(), LayoutError>
+	where C: Cursor, T: BitStore {
+		if !self.fits(value) {
+			return Err(LayoutError::Overflow(self.name.clone()));
+		}
+		let raw = match value {
+			FieldValue::Unsigned(v) => v,
+			FieldValue::Signed(v) => v as u64,
+		};
+		let field = self.bits_mut(bits)?;
+		let width = field.len();
+		for n in 0 .. width {
+			field.set(n, raw >> (width - 1 - n) & 1 == 1);
+		}
+		Ok(())
+	}
+
+	/// Tests whether a value is representable in the field.
+	fn fits(&self, value: FieldValue) -> bool {
+		let bits = u32::from(self.width);
+		match (value, self.signed) {
+			(FieldValue::Unsigned(v), false) => bits == 64 || v >> bits == 0,
+			(FieldValue::Unsigned(v), true) => v >> (bits - 1) == 0,
+			(FieldValue::Signed(v), false) => {
+				v >= 0 && (bits == 64 || v >> bits == 0)
+			},
+			(FieldValue::Signed(v), true) => {
+				let rest = v >> (bits - 1);
+				rest == 0 || rest == -1
+			},
+		}
+	}
+
+	/// Narrows a slice to the field.
+	fn bits<'a, C, T>(&self, bits: &'a SliceBit<C, T>)
+	-> Result<&'a SliceBit<C, T>, LayoutError>
+	where C: Cursor, T: BitStore {
+		let range = self.range();
+		if range.end > bits.len() {
+			return Err(self.out_of_bounds(bits.len()));
+		}
+		Ok(&bits[range])
+	}
+
+	/// Narrows a mutable slice to the field.
+	fn bits_mut<'a, C, T>(&self, bits: &'a mut SliceBit<C, T>)
+	-> Result<&'a mut SliceBit<C, T>, LayoutError>
+	where C: Cursor, T: BitStore {
+		let range = self.range();
+		if range.end > bits.len() {
+			return Err(self.out_of_bounds(bits.len()));
+		}
+		Ok(&mut bits[range])
+	}
+
+	fn out_of_bounds(&self, len: usize) -> LayoutError {
+		LayoutError::OutOfBounds { end: self.range().end, len }
+	}
+}
+
+/// The value of a field, as determined by its signedness.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FieldValue {
+	/// The value of an unsigned field.
+	Unsigned(u64),
+	/// The value of a signed field.
+	Signed(i64),
+}
+
+impl Display for FieldValue {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			FieldValue::Unsigned(v) => Display::fmt(v, f),
+			FieldValue::Signed(v) => Display::fmt(v, f),
+		}
+	}
+}
+
+impl From<u64> for FieldValue {
+	fn from(value: u64) -> Self {
+		FieldValue::Unsigned(value)
+	}
+}
+
+impl From<i64> for FieldValue {
+	fn from(value: i64) -> Self {
+		FieldValue::Signed(value)
+	}
+}
+
+/// The reasons a layout operation can fail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LayoutError {
+	/// A field with this name is already in the layout.
+	Duplicate(String),
+	/// No field with this name is in the layout.
+	Unknown(String),
+	/// A field extends past the end of the slice.
+	OutOfBounds {
+		/// The index one past the field’s last bit.
+		end: usize,
+		/// The length of the slice.
+		len: usize,
+	},
+	/// The value does not fit in the named field.
+	Overflow(String),
+}
+
+impl Display for LayoutError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			LayoutError::Duplicate(name) => {
+				write!(f, "field {} is already in the layout", name)
+			},
+			LayoutError::Unknown(name) => {
+				write!(f, "field {} is not in the layout", name)
+			},
+			LayoutError::OutOfBounds { end, len } => write!(
+				f,
+				"field ending at {} does not fit in {} bits",
+				end,
+				len,
+			),
+			LayoutError::Overflow(name) => {
+				write!(f, "value does not fit in field {}", name)
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LayoutError {}
+
+/** A set of named fields within a bit sequence.
+
+Fields are kept in the order in which they are inserted, which is the order in
+which [`values`] visits them.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::layout::{BitLayout, Field, FieldValue};
+
+let mut layout = BitLayout::new();
+layout.insert(Field::unsigned("kind", 0, 3)).unwrap();
+layout.insert(Field::signed("delta", 3, 5)).unwrap();
+
+let mut bits = vecbit![BigEndian, u8; 0; 8];
+layout.set(&mut bits, "kind", 5u64).unwrap();
+layout.set(&mut bits, "delta", -3i64).unwrap();
+assert_eq!(bits.as_slice(), &[0b101_11101]);
+
+let values = layout.values(&bits).collect::<Vec<_>>();
+assert_eq!(values, [
+  ("kind", FieldValue::Unsigned(5)),
+  ("delta", FieldValue::Signed(-3)),
+]);
+```
+
+[`values`]: #method.values
+**/
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BitLayout {
+	/// The fields, in insertion order.
+	fields: Vec<Field>,
+}
+
+impl BitLayout {
+	/// Constructs a layout with no fields.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Constructs a layout from a sequence of fields.
+	///
+	/// # Parameters
+	///
+	/// - `fields`: The fields of the layout.
+	///
+	/// # Returns
+	///
+	/// The layout, or an error if two fields have the same name.
+	pub fn from_fields<I>(fields: I) -> Result<Self, LayoutError>
+	where I: IntoIterator<Item = Field> {
+		let mut out = Self::new();
+		for field in fields {
+			out.insert(field)?;
+		}
+		Ok(out)
+	}
+
+	/// Adds a field to the layout.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `field`: The new field.
+	///
+	/// # Returns
+	///
+	/// An error if the layout already has a field of the same name.
+	pub fn insert(&mut self, field: Field) -> Result<(), LayoutError> {
+		if self.field(&field.name).is_some() {
+			return Err(LayoutError::Duplicate(field.name));
+		}
+		self.fields.push(field);
+		Ok(())
+	}
+
+	/// Looks up a field by name.
+	pub fn field(&self, name: &str) -> Option<&Field> {
+		self.fields.iter().find(|field| field.name == name)
+	}
+
+	/// Iterates over the fields, in insertion order.
+	pub fn fields(&self) -> impl Iterator<Item = &Field> {
+		self.fields.iter()
+	}
+
+	/// Gets the number of bits that a slice needs to hold every field.
+	pub fn len(&self) -> usize {
+		self.fields.iter().map(|field| field.range().end).max().unwrap_or(0)
+	}
+
+	/// Tests whether the layout has no fields.
+	pub fn is_empty(&self) -> bool {
+		self.fields.is_empty()
+	}
+
+	/// Reads a named field out of a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bits`: The slice holding the field.
+	/// - `name`: The name of the field.
+	///
+	/// # Returns
+	///
+	/// The value of the field, or an error if the layout has no such field or
+	/// `bits` is too short to hold it.
+	pub fn get<C, T>(&self, bits: &SliceBit<C, T>, name: &str)
+	-> Result<FieldValue, LayoutError>
+	where C: Cursor, T: BitStore {
+		self.lookup(name)?.read(bits)
+	}
+
+	/// Writes a value into a named field within a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bits`: The slice holding the field.
+	/// - `name`: The name of the field.
+	/// - `value`: The value to write.
+	///
+	/// # Returns
+	///
+	/// An error if the layout has no such field, `bits` is too short to hold
+	/// it, or `value` does not fit in it.
+	pub fn set<C, T, V>(&self, bits: &mut SliceBit<C, T>, name: &str, value: V)
+	-> Result<(), LayoutError>
+	where C: Cursor, T: BitStore, V: Into<FieldValue> {
+		self.lookup(name)?.write(bits, value.into())
+	}
+
+	/// Reads every field out of a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `bits`: The slice holding the fields.
+	///
+	/// # Returns
+	///
+	/// An iterator over the names and values of the fields, in insertion
+	/// order.
+	///
+	/// # Panics
+	///
+	/// Panics if `bits` is shorter than [`len`].
+	///
+	/// [`len`]: #method.len
+	pub fn values<'a, C, T>(&'a self, bits: &'a SliceBit<C, T>)
+	-> impl Iterator<Item = (&'a str, FieldValue)> + 'a
+	where C: Cursor, T: BitStore {
+		assert!(
+			self.len() <= bits.len(),
+			"A layout of {} bits does not fit in {} bits",
+			self.len(),
+			bits.len(),
+		);
+		self.fields.iter().map(move |field| {
+			let value = field.read(bits).expect("Fields are in bounds");
+			(field.name(), value)
+		})
+	}
+
+	fn lookup(&self, name: &str) -> Result<&Field, LayoutError> {
+		self.field(name).ok_or_else(|| LayoutError::Unknown(name.to_string()))
+	}
+}
@@ -35,6 +35,7 @@ elements as a slice.
 !*/
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![cfg_attr(debug_assertions, warn(missing_docs))]
 #![cfg_attr(not(debug_assertions), deny(missing_docs))]
 
@@ -44,6 +45,33 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate core;
 
+#[cfg(feature = "arrow")]
+extern crate arrow_buffer;
+
+#[cfg(feature = "bit-set")]
+extern crate bit_set;
+
+#[cfg(feature = "bytes")]
+extern crate bytes;
+
+#[cfg(feature = "fixedbitset")]
+extern crate fixedbitset;
+
+#[cfg(feature = "wasm")]
+extern crate js_sys;
+
+#[cfg(feature = "mmap")]
+extern crate memmap;
+
+#[cfg(feature = "nom")]
+extern crate nom;
+
+#[cfg(feature = "python")]
+extern crate pyo3;
+
+#[cfg(feature = "rand")]
+extern crate rand;
+
 #[cfg(feature = "serde")]
 extern crate serde;
 
@@ -54,12 +82,48 @@ extern crate serde_test;
 mod macros;
 
 mod access;
+pub mod aligned;
+pub mod array;
+#[cfg(feature = "arrow")]
+mod arrow;
+pub mod automaton;
 pub mod bits;
+mod bitsets;
+#[cfg(feature = "bytes")]
+pub mod buffers;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "alloc")]
+mod capacity;
+mod consttime;
+#[cfg(feature = "alloc")]
+pub mod counts;
 pub mod cursor;
+pub mod cycle;
 mod domain;
+pub mod dump;
+#[cfg(feature = "alloc")]
+pub mod ecc;
+#[cfg(feature = "alloc")]
+pub mod fold_ops;
+#[cfg(feature = "alloc")]
+pub mod frames;
+mod gather;
 pub mod indices;
+#[cfg(feature = "alloc")]
+pub mod interleave;
 pub mod fields;
+#[cfg(all(test, feature = "alloc"))]
+mod fixtures;
+#[cfg(feature = "alloc")]
+pub mod layout;
+#[cfg(feature = "alloc")]
+pub mod packed;
 mod pointer;
+#[cfg(feature = "alloc")]
+pub mod patch;
+#[cfg(feature = "alloc")]
+mod planes;
 pub mod prelude;
 pub mod slice;
 pub mod store;
@@ -67,17 +131,49 @@ pub mod store;
 #[cfg(feature = "alloc")]
 pub mod boxed;
 
+#[cfg(feature = "alloc")]
+pub mod shared;
+
+#[cfg(feature = "alloc")]
+pub mod smallvec;
+
+#[cfg(feature = "alloc")]
+pub mod snapshot;
+
 #[cfg(feature = "alloc")]
 pub mod vec;
 
+#[cfg(feature = "alloc")]
+pub mod versioned;
+
+#[cfg(feature = "mmap")]
+pub mod mmap;
+
+#[cfg(feature = "nom")]
+mod parsing;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "rand")]
+mod random;
+
 #[cfg(feature = "serde")]
 mod serdes;
 
+#[cfg(feature = "simd")]
+mod simd;
+
+#[cfg(feature = "alloc")]
+pub mod web;
+
 /// Expose crate internals for use in doctests and external tests.
 #[cfg(feature = "testing")]
 pub mod testing {
 	pub use crate::{
 		access::*,
+		aligned::*,
+		array::*,
 		bits::*,
 		boxed::*,
 		cursor::*,
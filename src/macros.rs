@@ -1,7 +1,7 @@
 /*! Utility macros for constructing data structures and implementing bulk types.
 
-The only public macro is `vecbit`; this module also provides convenience macros
-for code generation.
+The public macros are `vecbit`, `bitbox`, and `static_bits`; this module also
+provides convenience macros for code generation.
 !*/
 
 /** Construct a `VecBit` out of a literal array in source code, like `vec!`.
@@ -99,11 +99,7 @@ macro_rules! vecbit {
 
 	( __bv_impl__ $cursor:path , $bits:ty ; $( $val:expr ),* ) => {{
 		let init: &[bool] = &[ $( $val != 0 ),* ];
-		let mut bv = $crate::vec::VecBit::<$cursor, $bits>::with_capacity(
-			init.len(),
-		);
-		bv.extend(init.iter().copied());
-		bv
+		$crate::vec::VecBit::<$cursor, $bits>::from_bools(init)
 	}};
 
 	//  `[$val; $rep]` can just allocate a slab of at least `$rep` bits and then
@@ -174,6 +170,70 @@ macro_rules! bitbox {
 	};
 }
 
+/** Define functions that build global bit tables on first use.
+
+Each function defined in this macro runs its body at most once, the first time
+that it is called, and stores the result for the rest of the program. Every
+call, including the first, returns a shared reference to the stored bits. If
+several threads call the function at once, only one runs the body, and the rest
+wait for it to finish.
+
+The body is written as the body of an ordinary function, and may produce either
+a `VecBit` or a `BitBox`. The declared return type of the function must be
+`&'static SliceBit<C, T>`, naming the cursor and storage types of the table.
+
+This replaces the `lazy_static` or `OnceCell` wrapper that lookup tables would
+otherwise need, and is available with the `std` feature.
+
+# Examples
+
+```rust
+# #[cfg(feature = "std")] {
+use vecbit::prelude::*;
+use vecbit::static_bits;
+
+static_bits! {
+	/// The bytes that are ASCII digits.
+	pub fn ascii_digits() -> &'static SliceBit<BigEndian, u8> {
+		let mut table = vecbit![BigEndian, u8; 0; 256];
+		for byte in b'0' ..= b'9' {
+			table.set(byte as usize, true);
+		}
+		table
+	}
+}
+
+assert!(ascii_digits()[b'7' as usize]);
+assert!(!ascii_digits()[b'x' as usize]);
+assert_eq!(ascii_digits().as_ptr(), ascii_digits().as_ptr());
+# }
+```
+**/
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! static_bits {
+	( $(
+		$( #[$attr:meta] )*
+		$vis:vis fn $name:ident ( )
+		-> &'static SliceBit< $cursor:ty , $bits:ty >
+		$body:block
+	)+ ) => { $(
+		$( #[$attr] )*
+		$vis fn $name() -> &'static $crate::slice::SliceBit<$cursor, $bits> {
+			static ONCE: ::std::sync::Once = ::std::sync::Once::new();
+			static mut BITS: Option<$crate::boxed::BitBox<$cursor, $bits>> =
+				None;
+			unsafe {
+				ONCE.call_once(|| BITS = Some($crate::boxed::BitBox::from($body)));
+				match BITS {
+					Some(ref bits) => bits,
+					None => unreachable!("The table is built before use"),
+				}
+			}
+		}
+	)+ };
+}
+
 #[doc(hidden)]
 macro_rules! __bitslice_shift {
 	( $( $t:ty ),+ ) => { $(
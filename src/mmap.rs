@@ -0,0 +1,200 @@
+/*! Memory-mapped bit slices
+
+This module wraps a read-only memory map, from the `memmap` crate, as a bit
+slice. It is intended for bitmaps that live in files too large to read into
+memory, such as on-disk bitmap indices.
+
+The `MappedBits` type owns the map, and lends out a `&SliceBit` that cannot
+outlive it. The map is checked once, when the `MappedBits` is built, for the
+alignment and length that the storage type requires.
+!*/
+
+#![cfg(feature = "mmap")]
+
+use crate::{
+	cursor::{
+		Cursor,
+		Portable,
+	},
+	pointer::BitPtr,
+	slice::SliceBit,
+	store::{
+		BitStore,
+		PortableWord,
+	},
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+	mem,
+	ops::Deref,
+	slice,
+};
+
+use memmap::Mmap;
+
+/** A read-only memory map, viewed as a bit slice.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the slice. This defaults to `Portable`, so
+  that a file is read the same way on every target.
+- `T: BitStore`: The storage type of the slice. The map must be aligned to `T`,
+  and its length must be a whole number of `T` elements. Maps are page-aligned,
+  so this is only a concern for maps whose length is not a multiple of the
+  width of `T`.
+
+# Examples
+
+```rust
+# #[cfg(feature = "mmap")] {
+use vecbit::prelude::*;
+use vecbit::mmap::MappedBits;
+use memmap::Mmap;
+use std::{fs::{self, File}, io::Write};
+
+let path = std::env::temp_dir().join("vecbit-mmap-doctest");
+File::create(&path).unwrap().write_all(&[0b1000_0001, 0xFF]).unwrap();
+let map = unsafe { Mmap::map(&File::open(&path).unwrap()) }.unwrap();
+
+let bits = MappedBits::<BigEndian>::new(map).unwrap();
+assert_eq!(bits.len(), 16);
+assert_eq!(bits.count_ones(), 10);
+assert!(bits[0] && bits[7]);
+# drop(bits);
+# fs::remove_file(&path).unwrap();
+# }
+```
+**/
+pub struct MappedBits<C = Portable, T = PortableWord>
+where C: Cursor, T: BitStore {
+	/// Cursor type for selecting bits inside an element.
+	_cursor: PhantomData<C>,
+	/// Storage type of the elements in the map.
+	_store: PhantomData<T>,
+	/// The map, which must remain alive for as long as the bits are lent out.
+	map: Mmap,
+	/// The number of live bits at the front of the map.
+	len: usize,
+}
+
+impl<C, T> MappedBits<C, T>
+where C: Cursor, T: BitStore {
+	/// Views the whole of a memory map as a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `map`: The memory map to view.
+	///
+	/// # Returns
+	///
+	/// A bit slice over every bit in the map, or the map itself if it is not
+	/// aligned to `T`, is not a whole number of `T` elements long, or is too
+	/// long for a bit slice to describe.
+	pub fn new(map: Mmap) -> Result<Self, Mmap> {
+		let len = (map.len() / mem::size_of::<T>()).saturating_mul(T::BITS as usize);
+		Self::with_len(map, len)
+	}
+
+	/// Views the front of a memory map as a bit slice.
+	///
+	/// Files that store a bitmap often round it up to a whole number of bytes
+	/// or elements; this permits the dead bits at the end to be excluded.
+	///
+	/// # Parameters
+	///
+	/// - `map`: The memory map to view.
+	/// - `len`: The number of bits, from the front of the map, to view.
+	///
+	/// # Returns
+	///
+	/// A bit slice over the first `len` bits in the map, or the map itself if
+	/// it is not aligned to `T`, is not a whole number of `T` elements long, or
+	/// is too short to hold `len` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "mmap")] {
+	/// use vecbit::prelude::*;
+	/// use vecbit::mmap::MappedBits;
+	/// use memmap::Mmap;
+	/// use std::{fs::{self, File}, io::Write};
+	///
+	/// let path = std::env::temp_dir().join("vecbit-mmap-with-len-doctest");
+	/// File::create(&path).unwrap().write_all(&[0; 6]).unwrap();
+	/// let open = || unsafe { Mmap::map(&File::open(&path).unwrap()) }.unwrap();
+	///
+	/// let bits = MappedBits::<LittleEndian, u8>::with_len(open(), 45).unwrap();
+	/// assert_eq!(bits.len(), 45);
+	/// assert!(MappedBits::<LittleEndian, u8>::with_len(open(), 49).is_err());
+	/// assert!(MappedBits::<LittleEndian, u32>::new(open()).is_err());
+	/// # drop(bits);
+	/// # fs::remove_file(&path).unwrap();
+	/// # }
+	/// ```
+	pub fn with_len(map: Mmap, len: usize) -> Result<Self, Mmap> {
+		let width = mem::size_of::<T>();
+		let elts = map.len() / width;
+		if map.as_ptr() as usize % mem::align_of::<T>() != 0
+			|| map.len() % width != 0
+			|| elts > BitPtr::<T>::MAX_ELTS
+			|| len > BitPtr::<T>::MAX_BITS
+			|| len > elts.saturating_mul(T::BITS as usize)
+		{
+			return Err(map);
+		}
+		Ok(Self {
+			_cursor: PhantomData,
+			_store: PhantomData,
+			map,
+			len,
+		})
+	}
+
+	/// Views the map as a bit slice.
+	///
+	/// The slice borrows from `self`, and so the map cannot be unmapped while
+	/// the slice is alive.
+	pub fn as_bitslice(&self) -> &SliceBit<C, T> {
+		let elts = self.map.len() / mem::size_of::<T>();
+		let data = unsafe {
+			slice::from_raw_parts(self.map.as_ptr() as *const T, elts)
+		};
+		&SliceBit::from_slice(data)[.. self.len]
+	}
+
+	/// Unwraps the memory map.
+	pub fn into_inner(self) -> Mmap {
+		self.map
+	}
+}
+
+impl<C, T> AsRef<SliceBit<C, T>> for MappedBits<C, T>
+where C: Cursor, T: BitStore {
+	fn as_ref(&self) -> &SliceBit<C, T> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, T> Debug for MappedBits<C, T>
+where C: Cursor, T: BitStore {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("Mapped")?;
+		Debug::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, T> Deref for MappedBits<C, T>
+where C: Cursor, T: BitStore {
+	type Target = SliceBit<C, T>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
@@ -0,0 +1,520 @@
+/*! Vectors of small fixed-width symbols
+
+Sequences drawn from small alphabets, such as nucleotides (`A`, `C`, `G`, `T` in
+two bits) or IUPAC ambiguity codes and hexadecimal digits (four bits), are
+commonly packed several symbols to a byte. This module provides
+[`PackedVec`], which stores such a sequence in a `VecBit` and indexes it by
+symbol rather than by bit, and [`PackedSlice`], a borrowed view into one.
+
+The symbol width is chosen by a marker type, [`Bits2`] or [`Bits4`]. Symbols
+are packed from the most significant end of each byte, so the bytes of a
+`PackedVec` read in the same order as the symbols when printed in binary or
+hexadecimal.
+
+[`Bits2`]: struct.Bits2.html
+[`Bits4`]: struct.Bits4.html
+[`PackedSlice`]: struct.PackedSlice.html
+[`PackedVec`]: struct.PackedVec.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::BigEndian,
+	fields::{
+		BitField,
+		ChunkTail,
+		ChunksAs,
+	},
+	slice::{
+		resolve_range,
+		SliceBit,
+	},
+	vec::VecBit,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::{
+		FromIterator,
+		FusedIterator,
+	},
+	marker::PhantomData,
+	ops::RangeBounds,
+};
+
+mod seal {
+	pub trait Sealed {}
+}
+
+/// A symbol width usable in a [`PackedVec`].
+///
+/// This is implemented only for [`Bits2`] and [`Bits4`], whose widths evenly
+/// divide a byte.
+///
+/// [`Bits2`]: struct.Bits2.html
+/// [`Bits4`]: struct.Bits4.html
+/// [`PackedVec`]: struct.PackedVec.html
+pub trait SymbolWidth: seal::Sealed {
+	/// The number of bits in each symbol.
+	const BITS: u8;
+}
+
+/// Marks two-bit symbols, such as nucleotides.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Bits2;
+
+/// Marks four-bit symbols, such as nibbles or IUPAC nucleotide codes.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Bits4;
+
+impl seal::Sealed for Bits2 {}
+impl seal::Sealed for Bits4 {}
+
+impl SymbolWidth for Bits2 {
+	const BITS: u8 = 2;
+}
+
+impl SymbolWidth for Bits4 {
+	const BITS: u8 = 4;
+}
+
+/// A vector of two-bit symbols.
+pub type Vec2 = PackedVec<Bits2>;
+
+/// A vector of four-bit symbols.
+pub type Vec4 = PackedVec<Bits4>;
+
+/** A growable vector of fixed-width symbols.
+
+# Type Parameters
+
+- `W: SymbolWidth`: The width of each symbol.
+
+# Examples
+
+```rust
+use vecbit::packed::Vec2;
+
+let code = |b| match b { b'A' => 0, b'C' => 1, b'G' => 2, _ => 3 };
+let mut seq = b"GATTACA".iter().map(|&b| code(b)).collect::<Vec2>();
+assert_eq!(seq.len(), 7);
+assert_eq!(seq.get(2), Some(3));
+
+seq.set(0, code(b'C'));
+seq.push(code(b'G'));
+assert_eq!(seq.as_bits().as_slice(), &[0b01_00_11_11, 0b00_01_00_10]);
+assert_eq!(seq.slice(4 ..).iter().collect::<Vec<_>>(), [0, 1, 0, 2]);
+```
+**/
+pub struct PackedVec<W>
+where W: SymbolWidth {
+	/// The symbols, `W::BITS` bits apiece.
+	bits: VecBit<BigEndian, u8>,
+	_width: PhantomData<W>,
+}
+
+impl<W> PackedVec<W>
+where W: SymbolWidth {
+	/// Constructs an empty vector.
+	pub fn new() -> Self {
+		Self::from_bits_unchecked(VecBit::new())
+	}
+
+	/// Constructs an empty vector with space for some symbols.
+	///
+	/// # Parameters
+	///
+	/// - `capacity`: The number of symbols that the vector can hold before it
+	///   reallocates.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self::from_bits_unchecked(
+			VecBit::with_capacity(capacity * W::BITS as usize),
+		)
+	}
+
+	/// Reinterprets a bit vector as a sequence of symbols.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The packed symbols.
+	///
+	/// # Returns
+	///
+	/// The symbols in `bits`, if its length is a whole number of symbols.
+	pub fn from_bits(bits: VecBit<BigEndian, u8>) -> Option<Self> {
+		if bits.len() % W::BITS as usize == 0 {
+			Some(Self::from_bits_unchecked(bits))
+		}
+		else {
+			None
+		}
+	}
+
+	/// Gets the packed bits of the vector.
+	pub fn as_bits(&self) -> &SliceBit<BigEndian, u8> {
+		&self.bits
+	}
+
+	/// Unwraps the packed bits of the vector.
+	pub fn into_bits(self) -> VecBit<BigEndian, u8> {
+		self.bits
+	}
+
+	/// Borrows the whole vector as a slice of symbols.
+	pub fn as_packed_slice(&self) -> PackedSlice<W> {
+		PackedSlice { bits: &self.bits, _width: PhantomData }
+	}
+
+	/// Counts the symbols in the vector.
+	pub fn len(&self) -> usize {
+		self.bits.len() / W::BITS as usize
+	}
+
+	/// Tests if the vector has no symbols.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Gets a symbol, if it is in bounds.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The symbol index to read.
+	///
+	/// # Returns
+	///
+	/// The symbol at `index`, in the low bits of a byte.
+	pub fn get(&self, index: usize) -> Option<u8> {
+		self.as_packed_slice().get(index)
+	}
+
+	/// Sets a symbol.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The symbol index to write.
+	/// - `value`: The new symbol.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds, or `value` does not fit in
+	/// `W::BITS` bits.
+	pub fn set(&mut self, index: usize, value: u8) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		check::<W>(value);
+		let w = W::BITS as usize;
+		self.bits[index * w .. (index + 1) * w].store(value);
+	}
+
+	/// Appends a symbol.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The new symbol.
+	///
+	/// # Panics
+	///
+	/// Panics if `value` does not fit in `W::BITS` bits.
+	pub fn push(&mut self, value: u8) {
+		check::<W>(value);
+		for n in (0 .. W::BITS).rev() {
+			self.bits.push(value >> n & 1 == 1);
+		}
+	}
+
+	/// Removes the last symbol, if any.
+	pub fn pop(&mut self) -> Option<u8> {
+		let last = self.len().checked_sub(1)?;
+		let out = self.get(last);
+		self.bits.truncate(last * W::BITS as usize);
+		out
+	}
+
+	/// Removes all symbols.
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Iterates over the symbols.
+	pub fn iter(&self) -> Symbols {
+		self.as_packed_slice().iter()
+	}
+
+	/// Borrows a range of the symbols.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The symbol indices to borrow.
+	///
+	/// # Panics
+	///
+	/// Panics if `range` is out of bounds.
+	pub fn slice<R>(&self, range: R) -> PackedSlice<W>
+	where R: RangeBounds<usize> {
+		self.as_packed_slice().slice(range)
+	}
+
+	fn from_bits_unchecked(bits: VecBit<BigEndian, u8>) -> Self {
+		Self { bits, _width: PhantomData }
+	}
+}
+
+impl<W> Clone for PackedVec<W>
+where W: SymbolWidth {
+	fn clone(&self) -> Self {
+		Self::from_bits_unchecked(self.bits.clone())
+	}
+}
+
+impl<W> Debug for PackedVec<W>
+where W: SymbolWidth {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		self.as_packed_slice().fmt(f)
+	}
+}
+
+impl<W> Default for PackedVec<W>
+where W: SymbolWidth {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<W> Eq for PackedVec<W>
+where W: SymbolWidth {}
+
+impl<W> Extend<u8> for PackedVec<W>
+where W: SymbolWidth {
+	fn extend<I>(&mut self, src: I)
+	where I: IntoIterator<Item = u8> {
+		let iter = src.into_iter();
+		self.bits.reserve(iter.size_hint().0 * W::BITS as usize);
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+impl<W> FromIterator<u8> for PackedVec<W>
+where W: SymbolWidth {
+	fn from_iter<I>(src: I) -> Self
+	where I: IntoIterator<Item = u8> {
+		let mut out = Self::new();
+		out.extend(src);
+		out
+	}
+}
+
+impl<'a, W> IntoIterator for &'a PackedVec<W>
+where W: SymbolWidth {
+	type Item = u8;
+	type IntoIter = Symbols<'a>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<W> PartialEq for PackedVec<W>
+where W: SymbolWidth {
+	fn eq(&self, rhs: &Self) -> bool {
+		self.bits == rhs.bits
+	}
+}
+
+/** A borrowed range of fixed-width symbols.
+
+This is produced by [`PackedVec::slice`] and [`PackedVec::as_packed_slice`].
+
+# Type Parameters
+
+- `W: SymbolWidth`: The width of each symbol.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `PackedVec`.
+
+[`PackedVec::as_packed_slice`]: struct.PackedVec.html#method.as_packed_slice
+[`PackedVec::slice`]: struct.PackedVec.html#method.slice
+**/
+pub struct PackedSlice<'a, W>
+where W: SymbolWidth {
+	/// The symbols, `W::BITS` bits apiece.
+	bits: &'a SliceBit<BigEndian, u8>,
+	_width: PhantomData<W>,
+}
+
+impl<'a, W> PackedSlice<'a, W>
+where W: SymbolWidth {
+	/// Gets the packed bits of the slice.
+	pub fn as_bits(&self) -> &'a SliceBit<BigEndian, u8> {
+		self.bits
+	}
+
+	/// Counts the symbols in the slice.
+	pub fn len(&self) -> usize {
+		self.bits.len() / W::BITS as usize
+	}
+
+	/// Tests if the slice has no symbols.
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Gets a symbol, if it is in bounds.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The symbol index to read.
+	///
+	/// # Returns
+	///
+	/// The symbol at `index`, in the low bits of a byte.
+	pub fn get(&self, index: usize) -> Option<u8> {
+		if index >= self.len() {
+			return None;
+		}
+		let w = W::BITS as usize;
+		self.bits[index * w .. (index + 1) * w].load()
+	}
+
+	/// Iterates over the symbols.
+	pub fn iter(&self) -> Symbols<'a> {
+		Symbols {
+			inner: self.bits.iter_chunks_as(W::BITS, ChunkTail::Exact)
+				.expect("A packed slice holds a whole number of symbols"),
+		}
+	}
+
+	/// Borrows a range of the symbols.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The symbol indices to borrow.
+	///
+	/// # Panics
+	///
+	/// Panics if `range` is out of bounds.
+	pub fn slice<R>(&self, range: R) -> Self
+	where R: RangeBounds<usize> {
+		let range = resolve_range(range, self.len());
+		let w = W::BITS as usize;
+		Self {
+			bits: &self.bits[range.start * w .. range.end * w],
+			_width: PhantomData,
+		}
+	}
+
+	/// Copies the symbols into a new vector.
+	pub fn to_packed_vec(&self) -> PackedVec<W> {
+		PackedVec::from_bits_unchecked(VecBit::from_bitslice(self.bits))
+	}
+}
+
+impl<'a, W> Clone for PackedSlice<'a, W>
+where W: SymbolWidth {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, W> Copy for PackedSlice<'a, W>
+where W: SymbolWidth {}
+
+impl<'a, W> Debug for PackedSlice<'a, W>
+where W: SymbolWidth {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<'a, W> Eq for PackedSlice<'a, W>
+where W: SymbolWidth {}
+
+impl<'a, W> IntoIterator for PackedSlice<'a, W>
+where W: SymbolWidth {
+	type Item = u8;
+	type IntoIter = Symbols<'a>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, W> PartialEq for PackedSlice<'a, W>
+where W: SymbolWidth {
+	fn eq(&self, rhs: &Self) -> bool {
+		self.bits == rhs.bits
+	}
+}
+
+/** Iterator over the symbols of a `PackedVec` or `PackedSlice`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying symbols.
+**/
+#[derive(Clone, Debug)]
+pub struct Symbols<'a> {
+	inner: ChunksAs<'a, BigEndian, u8, u8>,
+}
+
+/// Mark that the iterator has an exact size.
+impl<'a> ExactSizeIterator for Symbols<'a> {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a> FusedIterator for Symbols<'a> {}
+
+impl<'a> Iterator for Symbols<'a> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.inner.nth(n)
+	}
+}
+
+fn check<W>(value: u8)
+where W: SymbolWidth {
+	assert!(
+		value >> W::BITS == 0,
+		"Symbol {} does not fit in {} bits",
+		value,
+		W::BITS,
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::fixtures::check_nth;
+
+	#[test]
+	fn nth() {
+		let mut vec = Vec4::new();
+		for sym in (0 .. 37u8).map(|n| n % 16) {
+			vec.push(sym);
+		}
+		check_nth(vec.iter());
+		check_nth(vec.slice(5 .. 30).iter());
+	}
+}
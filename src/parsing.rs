@@ -0,0 +1,192 @@
+/*! `nom`-powered parsing
+
+This module implements the `nom` input traits for `&SliceBit`, so that `nom`
+parsers can consume bit slices directly. The parsers see bit-level input, with
+every length and index counted in bits, and can use any cursor or storage type,
+where `nom`’s own bit mode only reads `&[u8]` most significant bit first.
+
+Parsers that match a fixed pattern, such as `tag`, take the pattern as another
+`&SliceBit`, which may have a different cursor and storage type than the input.
+!*/
+
+#![cfg(feature = "nom")]
+
+use crate::{
+	cursor::Cursor,
+	slice::{
+		Iter,
+		SliceBit,
+	},
+	store::BitStore,
+};
+
+use core::{
+	iter::Enumerate,
+	ops::{
+		Range,
+		RangeFrom,
+		RangeFull,
+		RangeTo,
+	},
+};
+
+use nom::{
+	Compare,
+	CompareResult,
+	InputIter,
+	InputLength,
+	InputTake,
+	Slice,
+	UnspecializedInput,
+};
+
+impl<'a, C, T> InputLength for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn input_len(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<'a, C, T> InputIter for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+	type Iter = Enumerate<Iter<'a, C, T>>;
+	type IterElem = Iter<'a, C, T>;
+
+	fn iter_indices(&self) -> Self::Iter {
+		self.iter().enumerate()
+	}
+
+	fn iter_elements(&self) -> Self::IterElem {
+		self.iter()
+	}
+
+	fn position<P>(&self, predicate: P) -> Option<usize>
+	where P: Fn(Self::Item) -> bool {
+		self.iter().position(predicate)
+	}
+
+	fn slice_index(&self, count: usize) -> Option<usize> {
+		if count <= self.len() {
+			Some(count)
+		}
+		else {
+			None
+		}
+	}
+}
+
+impl<'a, C, T> InputTake for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn take(&self, count: usize) -> Self {
+		&self[.. count]
+	}
+
+	/// Splits the input, producing the remainder before the taken prefix, as
+	/// `nom` requires.
+	fn take_split(&self, count: usize) -> (Self, Self) {
+		let (prefix, suffix) = self.split_at(count);
+		(suffix, prefix)
+	}
+}
+
+/// Permits `nom` to provide its generic `InputTakeAtPosition` implementation,
+/// which powers combinators such as `take_while`.
+impl<'a, C, T> UnspecializedInput for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, 'b, C, T, D, U> Compare<&'b SliceBit<D, U>> for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore, D: Cursor, U: 'b + BitStore {
+	/// Tests whether the input begins with a pattern.
+	///
+	/// If the input is shorter than the pattern, but is a prefix of it, then
+	/// more input is needed to decide.
+	fn compare(&self, pattern: &'b SliceBit<D, U>) -> CompareResult {
+		if self.len() < pattern.len() {
+			if pattern.starts_with(*self) {
+				CompareResult::Incomplete
+			}
+			else {
+				CompareResult::Error
+			}
+		}
+		else if self.starts_with(pattern) {
+			CompareResult::Ok
+		}
+		else {
+			CompareResult::Error
+		}
+	}
+
+	/// Bits have no case, so this is the same as `compare`.
+	fn compare_no_case(&self, pattern: &'b SliceBit<D, U>) -> CompareResult {
+		self.compare(pattern)
+	}
+}
+
+macro_rules! nom_slice {
+	( $( $range:ty ),+ ) => { $(
+		impl<'a, C, T> Slice<$range> for &'a SliceBit<C, T>
+		where C: Cursor, T: 'a + BitStore {
+			fn slice(&self, range: $range) -> Self {
+				&self[range]
+			}
+		}
+	)+ };
+}
+
+nom_slice!(Range<usize>, RangeFrom<usize>, RangeFull, RangeTo<usize>);
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+	use nom::{
+		Compare,
+		CompareResult,
+		InputIter,
+		InputTake,
+		bytes::complete::{
+			tag,
+			take,
+		},
+		error::ErrorKind,
+	};
+
+	#[test]
+	fn compare() {
+		let src = [0b1011_0000u8];
+		let bits = &src.bits::<BigEndian>()[.. 4];
+		let pat = 0b1101u16.bits::<LittleEndian>();
+		assert_eq!(bits.compare(&pat[.. 3]), CompareResult::Ok);
+		assert_eq!(bits.compare(&pat[.. 4]), CompareResult::Ok);
+		assert_eq!(bits.compare(&pat[1 .. 3]), CompareResult::Error);
+		assert_eq!(bits.compare(&pat[.. 6]), CompareResult::Incomplete);
+		let zeros = 0u8.bits::<BigEndian>();
+		assert_eq!(bits.compare(&zeros[.. 6]), CompareResult::Error);
+	}
+
+	#[test]
+	fn take_split() {
+		let bits = 0b0011_1100u8.bits::<BigEndian>();
+		let (rest, head) = bits.take_split(3);
+		assert_eq!(head, &bits[.. 3]);
+		assert_eq!(rest, &bits[3 ..]);
+		assert_eq!(bits.slice_index(8), Some(8));
+		assert_eq!(bits.slice_index(9), None);
+		assert_eq!(bits.position(|bit| bit), Some(2));
+	}
+
+	#[test]
+	fn parsers() {
+		let src = [0b1010_1100u8, 0b0101_0000];
+		let bits = src.bits::<BigEndian>();
+		let magic = &0b101u8.bits::<LittleEndian>()[.. 3];
+
+		let (rest, found) = tag::<_, _, (_, ErrorKind)>(magic)(bits).unwrap();
+		assert_eq!(found.len(), 3);
+		let (rest, field) = take::<_, _, (_, ErrorKind)>(5usize)(rest).unwrap();
+		assert_eq!(field, &bits[3 .. 8]);
+		assert_eq!(rest.len(), 8);
+		assert!(tag::<_, _, (_, ErrorKind)>(magic)(rest).is_err());
+	}
+}
@@ -0,0 +1,408 @@
+/*! Differences between bit sequences
+
+This module records the changes that turn one bit sequence into another, so
+that a large bitmap can be replicated by sending only what changed. A
+[`BitPatch`] is built by [`SliceBit::diff`] and applied by [`VecBit::apply`].
+
+A patch is a list of hunks. Each hunk is a range of indices and the XOR of the
+old and new bits over that range; bits beyond the end of the old sequence are
+recorded as their new values. Runs of changed bits that are separated by only a
+few unchanged bits are merged into one hunk, since each hunk costs a few bytes
+of framing.
+
+# Format
+
+[`BitPatch::to_bytes`] writes a patch compactly. All integers are unsigned
+LEB128 variable-length integers.
+
+| Contents                                                                |
+|:------------------------------------------------------------------------|
+| The format version, currently [`VERSION`], as a single byte.             |
+| The length of the old sequence.                                         |
+| The length of the new sequence.                                         |
+| The number of hunks.                                                    |
+| For each hunk, the number of bits between the end of the previous hunk, |
+| or the start of the sequence, and the start of this hunk.               |
+| … then the length of the hunk, in bits.                                 |
+| … then the XOR bits of the hunk, packed least significant bit first.    |
+
+[`BitPatch`]: struct.BitPatch.html
+[`BitPatch::to_bytes`]: struct.BitPatch.html#method.to_bytes
+[`SliceBit::diff`]: ../slice/struct.SliceBit.html#method.diff
+[`VERSION`]: constant.VERSION.html
+[`VecBit::apply`]: ../vec/struct.VecBit.html#method.apply
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	access::BitAccess,
+	cursor::{
+		Cursor,
+		LittleEndian,
+	},
+	pointer::BitPtr,
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	ops::Range,
+};
+
+/// The patch format version that this crate writes.
+pub const VERSION: u8 = 1;
+
+/// Changed runs closer together than this many bits share a hunk.
+const MERGE_GAP: usize = 32;
+
+/// The reasons a patch can be rejected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PatchError {
+	/// The patch was made against a sequence of a different length.
+	Length {
+		/// The length of the sequence that the patch was made against.
+		expected: usize,
+		/// The length of the sequence being patched.
+		found: usize,
+	},
+	/// The patch was written in an unknown format version.
+	Version(u8),
+	/// An integer in the patch is too large, or a length is longer than any
+	/// bit sequence can be.
+	Overflow,
+	/// A hunk extends past the end of the new sequence.
+	Range,
+	/// The input ends before the patch does.
+	Truncated,
+	/// The input continues after the patch ends.
+	Trailing,
+}
+
+impl Display for PatchError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			PatchError::Length { expected, found } => write!(
+				f,
+				"patch expects {} bits, but found {}",
+				expected,
+				found,
+			),
+			PatchError::Version(v) => write!(f, "unknown patch version {}", v),
+			PatchError::Overflow => f.write_str("patch integer is too large"),
+			PatchError::Range => f.write_str("patch hunk is out of bounds"),
+			PatchError::Truncated => f.write_str("patch is truncated"),
+			PatchError::Trailing => f.write_str("patch has trailing bytes"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatchError {}
+
+/** The changes that turn one bit sequence into another.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+
+let old = vecbit![BigEndian, u8; 0; 200];
+let mut new = old.clone();
+new.set(3, true);
+new.set(150, true);
+new.push(true);
+
+let patch = old.diff(&new);
+let ranges = patch.changed_ranges().collect::<Vec<_>>();
+assert_eq!(ranges, [3 .. 4, 150 .. 151, 200 .. 201]);
+
+let mut copy = old.clone();
+copy.apply(&patch).unwrap();
+assert_eq!(copy, new);
+```
+**/
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BitPatch {
+	/// The length of the old sequence.
+	from_len: usize,
+	/// The length of the new sequence.
+	to_len: usize,
+	/// The hunks, in ascending order, as start indices and XOR bits.
+	hunks: Vec<(usize, VecBit<LittleEndian, u8>)>,
+}
+
+impl BitPatch {
+	/// Gets the length of the sequence that the patch applies to.
+	pub fn source_len(&self) -> usize {
+		self.from_len
+	}
+
+	/// Gets the length of the sequence that the patch produces.
+	pub fn target_len(&self) -> usize {
+		self.to_len
+	}
+
+	/// Tests whether the patch changes nothing.
+	pub fn is_empty(&self) -> bool {
+		self.hunks.is_empty() && self.from_len == self.to_len
+	}
+
+	/// Iterates over the ranges of the hunks, in ascending order.
+	///
+	/// Each range holds at least one changed bit, and may hold unchanged bits
+	/// between the changed runs that it merges.
+	pub fn changed_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+		self.hunks.iter().map(|(start, bits)| *start .. *start + bits.len())
+	}
+
+	/// Counts the bits that differ between the two sequences over their common
+	/// length, plus the set bits after the end of the old sequence.
+	pub fn count_changed(&self) -> usize {
+		self.hunks.iter().map(|(_, bits)| bits.count_ones()).sum()
+	}
+
+	/// Writes the patch in the format described in the [module docs].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The serialized patch.
+	///
+	/// [module docs]: ../patch/index.html
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut out = Vec::new();
+		out.push(VERSION);
+		write_uint(&mut out, self.from_len);
+		write_uint(&mut out, self.to_len);
+		write_uint(&mut out, self.hunks.len());
+		let mut end = 0;
+		for (start, bits) in &self.hunks {
+			write_uint(&mut out, start - end);
+			write_uint(&mut out, bits.len());
+			let mut bits = bits.clone();
+			bits.mask_dead_bits();
+			out.extend_from_slice(bits.as_slice());
+			end = start + bits.len();
+		}
+		out
+	}
+
+	/// Reads a patch back from the format described in the [module docs].
+	///
+	/// # Parameters
+	///
+	/// - `bytes`: A serialized patch, as produced by [`to_bytes`].
+	///
+	/// # Returns
+	///
+	/// The patch, or the reason that it could not be read.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::patch::{BitPatch, PatchError};
+	///
+	/// let patch = vecbit![0, 0, 1].diff(&vecbit![1, 0, 1, 1]);
+	/// let bytes = patch.to_bytes();
+	/// assert_eq!(BitPatch::from_bytes(&bytes), Ok(patch));
+	/// assert_eq!(
+	///   BitPatch::from_bytes(&bytes[.. bytes.len() - 1]),
+	///   Err(PatchError::Truncated),
+	/// );
+	/// ```
+	///
+	/// [`to_bytes`]: #method.to_bytes
+	/// [module docs]: ../patch/index.html
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, PatchError> {
+		let mut rest = bytes;
+		match take(&mut rest, 1)?[0] {
+			VERSION => {},
+			v => return Err(PatchError::Version(v)),
+		}
+		let from_len = read_uint(&mut rest)?;
+		let to_len = read_uint(&mut rest)?;
+		//  Hunks lie within `to_len`, so this also bounds the hunk lengths.
+		if cmp::max(from_len, to_len) > BitPtr::<u8>::MAX_BITS {
+			return Err(PatchError::Overflow);
+		}
+		let count = read_uint(&mut rest)?;
+		let mut hunks = Vec::new();
+		let mut end = 0usize;
+		for _ in 0 .. count {
+			let start = end.checked_add(read_uint(&mut rest)?)
+				.ok_or(PatchError::Overflow)?;
+			let len = read_uint(&mut rest)?;
+			end = start.checked_add(len).ok_or(PatchError::Overflow)?;
+			if end > to_len {
+				return Err(PatchError::Range);
+			}
+			let data = take(&mut rest, len / 8 + (len % 8 != 0) as usize)?;
+			let mut bits = VecBit::from_vec(data.to_vec());
+			bits.truncate(len);
+			hunks.push((start, bits));
+		}
+		if !rest.is_empty() {
+			return Err(PatchError::Trailing);
+		}
+		Ok(Self { from_len, to_len, hunks })
+	}
+
+	/// Adds the changed runs of a slice to the patch, as hunks beginning at
+	/// `offset`.
+	fn push_runs<C, T>(&mut self, offset: usize, bits: &SliceBit<C, T>)
+	where C: Cursor, T: BitStore {
+		let mut pending: Option<Range<usize>> = None;
+		for run in bits.iter_set_ranges() {
+			pending = match pending {
+				Some(ref span) if run.start - span.end < MERGE_GAP => {
+					Some(span.start .. run.end)
+				},
+				Some(span) => {
+					self.push_hunk(offset, bits, span);
+					Some(run)
+				},
+				None => Some(run),
+			};
+		}
+		if let Some(span) = pending {
+			self.push_hunk(offset, bits, span);
+		}
+	}
+
+	fn push_hunk<C, T>(
+		&mut self,
+		offset: usize,
+		bits: &SliceBit<C, T>,
+		span: Range<usize>,
+	)
+	where C: Cursor, T: BitStore {
+		let start = offset + span.start;
+		self.hunks.push((start, bits[span].iter().collect()));
+	}
+}
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Finds the changes that turn this slice into another.
+	///
+	/// When both slices begin on an element edge, their common prefix is
+	/// compared a whole element at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&self`: The old sequence.
+	/// - `other`: The new sequence.
+	///
+	/// # Returns
+	///
+	/// A patch that [`VecBit::apply`] uses to turn a copy of `self` into a copy
+	/// of `other`.
+	///
+	/// [`VecBit::apply`]: ../vec/struct.VecBit.html#method.apply
+	pub fn diff(&self, other: &Self) -> BitPatch {
+		use core::cmp::min;
+		let common = min(self.len(), other.len());
+		let (old, new) = (&self[.. common], &other[.. common]);
+		let xor = if *old.bitptr().head() == 0 && *new.bitptr().head() == 0 {
+			let elts = old.as_total_slice().iter()
+				.zip(new.as_total_slice())
+				.map(|(a, b)| a.load() ^ b.load())
+				.collect::<Vec<T>>();
+			let mut xor = VecBit::<C, T>::from_vec(elts);
+			xor.truncate(common);
+			xor
+		}
+		else {
+			old.iter().zip(new.iter()).map(|(a, b)| a ^ b).collect()
+		};
+		let mut patch = BitPatch {
+			from_len: self.len(),
+			to_len: other.len(),
+			hunks: Vec::new(),
+		};
+		patch.push_runs(0, &xor);
+		patch.push_runs(common, &other[common ..]);
+		patch
+	}
+}
+
+impl<C, T> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Applies a patch, turning the vector into the sequence that the patch
+	/// was made from.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: A copy of the old sequence.
+	/// - `patch`: A patch made by [`SliceBit::diff`].
+	///
+	/// # Returns
+	///
+	/// An error if the vector does not have the length of the old sequence.
+	/// The vector is unchanged if this returns an error.
+	///
+	/// [`SliceBit::diff`]: ../slice/struct.SliceBit.html#method.diff
+	pub fn apply(&mut self, patch: &BitPatch) -> Result<(), PatchError> {
+		if self.len() != patch.from_len {
+			return Err(PatchError::Length {
+				expected: patch.from_len,
+				found: self.len(),
+			});
+		}
+		self.resize(patch.to_len, false);
+		for (start, bits) in &patch.hunks {
+			self[*start .. *start + bits.len()] ^= bits.iter();
+		}
+		Ok(())
+	}
+}
+
+/// Writes an unsigned LEB128 integer.
+fn write_uint(out: &mut Vec<u8>, mut value: usize) {
+	while value >= 0x80 {
+		out.push(value as u8 | 0x80);
+		value >>= 7;
+	}
+	out.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 integer.
+fn read_uint(rest: &mut &[u8]) -> Result<usize, PatchError> {
+	let mut value = 0usize;
+	let mut shift = 0u32;
+	loop {
+		let byte = take(rest, 1)?[0];
+		let low = (byte & 0x7F) as usize;
+		if shift >= usize::max_value().count_ones() || (low << shift) >> shift != low {
+			return Err(PatchError::Overflow);
+		}
+		value |= low << shift;
+		if byte & 0x80 == 0 {
+			return Ok(value);
+		}
+		shift += 7;
+	}
+}
+
+fn take<'a>(rest: &mut &'a [u8], count: usize) -> Result<&'a [u8], PatchError> {
+	if rest.len() < count {
+		return Err(PatchError::Truncated);
+	}
+	let (head, tail) = rest.split_at(count);
+	*rest = tail;
+	Ok(head)
+}
@@ -0,0 +1,234 @@
+/*! Bit-plane slicing
+
+This module splits a sequence of integers into bit planes: plane `k` holds bit
+`k` (of value `1 << k`) of every integer, in order. Bit-plane coders in image
+compression work one plane at a time, from most significant to least, and a
+bitmap index over an integer column is a set of bit planes.
+
+Integers are read eight at a time. Bit `k` of each of the eight is moved to the
+bottom of its own byte lane of a `u64`, and one multiplication gathers the
+eight lanes into a single byte, which is a column of the 8×8 bit transpose of
+the eight integers. With the `BigEndian` and `LittleEndian` cursors, each byte
+is then stored into the output a whole byte at a time.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::{
+		Cursor,
+		Stride,
+	},
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+use alloc::vec::Vec;
+
+impl<C, T> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Extracts one bit plane from a slice of integers.
+	///
+	/// # Parameters
+	///
+	/// - `values`: The integers to slice.
+	/// - `plane`: The significance of the bit to take from each integer. Plane
+	///   `0` is the least significant bit.
+	///
+	/// # Returns
+	///
+	/// A vector of `values.len()` bits, whose bit `n` is bit `plane` of
+	/// `values[n]`.
+	///
+	/// # Panics
+	///
+	/// Panics if `plane` is not less than `U::BITS`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U: BitStore`: The integer type of the values.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let pixels = [0x80u8, 0x7F, 0xC0, 0x01, 0xFF];
+	/// let msb = VecBit::<BigEndian, u8>::from_plane(&pixels, 7);
+	/// assert_eq!(msb, vecbit![1, 0, 1, 0, 1]);
+	/// let lsb = VecBit::<BigEndian, u8>::from_plane(&pixels, 0);
+	/// assert_eq!(lsb, vecbit![0, 1, 0, 1, 1]);
+	/// ```
+	pub fn from_plane<U>(values: &[U], plane: u8) -> Self
+	where U: BitStore {
+		assert!(
+			plane < U::BITS,
+			"Plane {} out of bounds: {}",
+			plane,
+			U::BITS,
+		);
+		let stride = match Stride::of::<C, T>() {
+			Some(stride) => stride,
+			None => return values.iter()
+				.map(|&v| Into::<u64>::into(v) >> plane & 1 == 1)
+				.collect(),
+		};
+		let elts = values.chunks(T::BITS as usize).map(|chunk| {
+			chunk.chunks(8).enumerate().fold(T::bits(false), |elt, (n, group)| {
+				let byte = column(group, plane);
+				//  Put the first value of the group at the front of the
+				//  element, then move it back to the group’s place.
+				let byte = match stride {
+					Stride::Ascending => T::from(byte),
+					Stride::Descending => T::from(reverse(byte)) << (T::BITS - 8),
+				};
+				elt | stride.to_back(byte, n as u8 * 8)
+			})
+		}).collect::<Vec<T>>();
+		let mut out = Self::from_vec(elts);
+		unsafe { out.set_len(values.len()); }
+		out
+	}
+}
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Extracts one bit plane from a slice of packed records.
+	///
+	/// The slice is read as a sequence of records, each `width` bits long,
+	/// and the bit at `offset` within each record is collected.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The length, in bits, of each record.
+	/// - `offset`: The index, within each record, of the bit to collect.
+	///
+	/// # Returns
+	///
+	/// A vector with one bit per record.
+	///
+	/// # Panics
+	///
+	/// Panics if `width` is zero or does not divide the slice’s length, or if
+	/// `offset` is not less than `width`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// //  Four 3-bit records: 101, 110, 011, 001.
+	/// let bits = &0b1011_1001_1001_0000u16.bits::<BigEndian>()[.. 12];
+	/// assert_eq!(bits.plane(3, 0), vecbit![1, 1, 0, 0]);
+	/// assert_eq!(bits.plane(3, 2), vecbit![1, 0, 1, 1]);
+	/// ```
+	pub fn plane(&self, width: usize, offset: usize) -> VecBit<C, T> {
+		assert!(
+			width != 0 && self.len() % width == 0,
+			"Cannot split {} bits into {}-bit records",
+			self.len(),
+			width,
+		);
+		assert!(
+			offset < width,
+			"Offset {} out of bounds: {}",
+			offset,
+			width,
+		);
+		self.iter().skip(offset).step_by(width).collect()
+	}
+}
+
+/// Collects bit `plane` of up to eight integers into a byte, with the first
+/// integer in the least significant bit.
+fn column<U>(group: &[U], plane: u8) -> u8
+where U: BitStore {
+	//  Bit `plane` of each integer goes to the bottom of its own byte lane.
+	let lanes = group.iter().enumerate().fold(0u64, |lanes, (n, &v)| {
+		lanes | (Into::<u64>::into(v) >> plane & 1) << (n * 8)
+	});
+	//  Lane `n` is multiplied into bit `56 + n`, and no two partial products
+	//  share a bit, so there are no carries.
+	(lanes.wrapping_mul(0x0102_0408_1020_4080) >> 56) as u8
+}
+
+/// Reverses the order of the bits in a byte.
+pub(crate) fn reverse(byte: u8) -> u8 {
+	(0 .. 8).fold(0, |out, n| out | (byte >> n & 1) << (7 - n))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	fn check<C, T, U>(values: &[U])
+	where C: Cursor, T: BitStore, U: BitStore {
+		for len in 0 .. values.len() {
+			let values = &values[.. len];
+			for plane in 0 .. U::BITS {
+				let bv = VecBit::<C, T>::from_plane(values, plane);
+				let model = values.iter()
+					.map(|&v| Into::<u64>::into(v) >> plane & 1 == 1)
+					.collect::<VecBit<C, T>>();
+				assert_eq!(bv, model, "{} values, plane {}", len, plane);
+				assert_eq!(bv.as_slice(), model.as_slice());
+			}
+		}
+	}
+
+	#[test]
+	fn matches_model() {
+		let bytes = (0 .. 150u32)
+			.map(|n| (n.wrapping_mul(0x9E37_79B9) >> 13) as u8)
+			.collect::<Vec<_>>();
+		let words = (0 .. 150u64)
+			.map(|n| n.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+			.collect::<Vec<_>>();
+		check::<BigEndian, u8, u8>(&bytes);
+		check::<LittleEndian, u8, u8>(&bytes);
+		check::<BigEndian, u64, u8>(&bytes);
+		check::<LittleEndian, u32, u64>(&words);
+		check::<BigEndian, u16, u64>(&words);
+		check::<Local, Word, u64>(&words);
+	}
+
+	#[test]
+	fn records() {
+		let values = [0b101u8, 0b110, 0b011, 0b001, 0b111];
+		let mut packed = vecbit![BigEndian, u8;];
+		for &v in &values {
+			for n in 0 .. 3 {
+				packed.push(v >> n & 1 == 1);
+			}
+		}
+		for plane in 0 .. 3 {
+			assert_eq!(
+				packed.plane(3, plane as usize),
+				VecBit::<BigEndian, u8>::from_plane(&values, plane),
+			);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn plane_out_of_bounds() {
+		VecBit::<BigEndian, u8>::from_plane(&[0u16], 16);
+	}
+
+	#[test]
+	fn columns() {
+		assert_eq!(column(&[1u8, 0, 1, 1, 0, 0, 0, 1], 0), 0b1000_1101);
+		assert_eq!(column(&[!0u64; 8], 63), 0xFF);
+		assert_eq!(column(&[0x10u16, 0x10, 0x00], 4), 0b0000_0011);
+		assert_eq!(column::<u32>(&[], 4), 0);
+	}
+
+	#[test]
+	fn reversal() {
+		assert_eq!(reverse(0b1000_0110), 0b0110_0001);
+		assert_eq!(reverse(0xFF), 0xFF);
+	}
+}
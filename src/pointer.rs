@@ -338,7 +338,9 @@ where T: BitStore {
 	pub fn empty() -> Self {
 		Self {
 			_ty: PhantomData,
-			ptr: NonNull::dangling(),
+			//  The dangling pointer must be aligned to `T`, so that its low
+			//  bits do not leak into the head index.
+			ptr: NonNull::<T>::dangling().cast(),
 			len: 0,
 		}
 	}
@@ -377,7 +379,7 @@ where T: BitStore {
 		Self {
 			_ty: PhantomData,
 			ptr: NonNull::new(ptr.w() as *mut u8)
-				.unwrap_or_else(NonNull::dangling),
+				.unwrap_or_else(|| NonNull::<T>::dangling().cast()),
 			len: 0,
 		}
 	}
@@ -872,7 +874,7 @@ where T: BitStore {
 		let src = unsafe { &*(bs as *const SliceBit<C, T> as *const [()]) };
 		let ptr = Pointer::from(src.as_ptr() as *const u8);
 		let (ptr, len) = match (ptr.w(), src.len()) {
-			(_, 0) => (NonNull::dangling(), 0),
+			(_, 0) => (NonNull::<T>::dangling().cast(), 0),
 			(p, _) if p.is_null() => unreachable!("Rust forbids null refs"),
 			(p, l) => (unsafe { NonNull::new_unchecked(p) }, l),
 		};
@@ -1058,6 +1060,15 @@ mod tests {
 		assert_eq!(*bp.tail(), 2);
 	}
 
+	#[test]
+	fn empty_head_is_zero() {
+		assert_eq!(*BitPtr::<u8>::empty().head(), 0);
+		assert_eq!(*BitPtr::<u16>::empty().head(), 0);
+		assert_eq!(*BitPtr::<u32>::empty().head(), 0);
+		#[cfg(target_pointer_width = "64")]
+		assert_eq!(*BitPtr::<u64>::empty().head(), 0);
+	}
+
 	#[cfg(not(miri))]
 	#[test]
 	#[should_panic]
@@ -5,6 +5,7 @@ This collects the general public API into a single spot for inclusion, as
 !*/
 
 pub use crate::{
+	array::BitArray,
 	bits::{
 		Bits,
 		BitsMut,
@@ -14,11 +15,13 @@ pub use crate::{
 		BigEndian,
 		LittleEndian,
 		Local,
+		Portable,
 	},
 	fields::BitField,
 	slice::SliceBit,
 	store::{
 		BitStore,
+		PortableWord,
 		Word,
 	},
 };
@@ -28,5 +31,6 @@ pub use crate::{
 	bitbox,
 	vecbit,
 	boxed::BitBox,
+	smallvec::SmallVecBit,
 	vec::VecBit,
 };
@@ -0,0 +1,243 @@
+/*! Python bindings
+
+This module exposes `VecBit<LittleEndian, u8>` to Python as the `VecBit` class,
+through the `pyo3` crate, so that Python code can manipulate the same bitmaps
+that Rust code produces. It is governed by the `python` feature.
+
+An extension module built with `pyo3` adds the class with [`register`]:
+
+```rust,ignore
+use pyo3::prelude::*;
+
+#[pymodule]
+fn bitmaps(_py: Python, module: &PyModule) -> PyResult<()> {
+	vecbit::python::register(module)
+}
+```
+
+In Python, the class behaves as a fixed-length sequence of booleans:
+
+```python
+from bitmaps import VecBit
+
+bits = VecBit.from_bytes(b"\x0f\xf0", 12)
+assert len(bits) == 12 and bits.count() == 4
+assert bits[3] and not bits[-1]
+assert bits[2:6].to_bytes() == b"\x03"
+assert (bits ^ ~bits).count() == 12
+```
+
+The bytes are packed least significant bit first, which is the layout of
+`LittleEndian, u8`, and the layout used by NumPy’s `packbits` with
+`bitorder="little"`.
+
+[`register`]: fn.register.html
+!*/
+
+#![cfg(feature = "python")]
+
+use crate::{
+	cursor::LittleEndian,
+	slice::SliceBit,
+	vec::VecBit,
+};
+
+use alloc::{
+	borrow::ToOwned,
+	string::String,
+	vec,
+};
+
+use pyo3::{
+	exceptions::{
+		PyIndexError,
+		PyValueError,
+	},
+	prelude::*,
+	types::{
+		PyBytes,
+		PySlice,
+	},
+};
+
+use std::os::raw::c_long;
+
+/** A Python sequence of bits.
+
+This wraps a `VecBit<LittleEndian, u8>`, which Rust code can move in and out of
+it without copying.
+**/
+#[pyclass(name = "VecBit", module = "vecbit")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PyVecBit {
+	bits: VecBit<LittleEndian, u8>,
+}
+
+impl PyVecBit {
+	/// Borrows the wrapped vector.
+	pub fn as_bitslice(&self) -> &SliceBit<LittleEndian, u8> {
+		&self.bits
+	}
+
+	/// Unwraps the vector.
+	pub fn into_inner(self) -> VecBit<LittleEndian, u8> {
+		self.bits
+	}
+
+	/// Converts a Python index, which may count from the back, into a Rust
+	/// index.
+	fn index(&self, index: isize) -> PyResult<usize> {
+		let len = self.bits.len();
+		let idx = if index < 0 { index + len as isize } else { index };
+		if idx < 0 || idx as usize >= len {
+			return Err(PyIndexError::new_err("VecBit index out of range"));
+		}
+		Ok(idx as usize)
+	}
+
+	/// Checks that an operand of a bitwise operator has the same length.
+	fn same_len(&self, other: &Self) -> PyResult<()> {
+		if self.bits.len() != other.bits.len() {
+			return Err(PyValueError::new_err(format!(
+				"Cannot combine VecBits of lengths {} and {}",
+				self.bits.len(),
+				other.bits.len(),
+			)));
+		}
+		Ok(())
+	}
+}
+
+#[pymethods]
+impl PyVecBit {
+	/// Creates a vector of `len` bits, all set to `value`.
+	#[new]
+	#[pyo3(signature = (len = 0, value = false))]
+	fn new(len: usize, value: bool) -> Self {
+		let fill = if value { !0 } else { 0 };
+		let mut bits = VecBit::from_vec(vec![fill; (len + 7) / 8]);
+		unsafe { bits.set_len(len); }
+		bits.mask_dead_bits();
+		Self { bits }
+	}
+
+	/// Creates a vector from packed bytes, least significant bit first.
+	///
+	/// `len` defaults to every bit of `data`.
+	#[staticmethod]
+	#[pyo3(signature = (data, len = None))]
+	fn from_bytes(data: &[u8], len: Option<usize>) -> PyResult<Self> {
+		let max = data.len() * 8;
+		let len = len.unwrap_or(max);
+		if len > max {
+			return Err(PyValueError::new_err(format!(
+				"Cannot take {} bits from {} bytes",
+				len,
+				data.len(),
+			)));
+		}
+		Ok(Self { bits: SliceBit::from_slice(data)[.. len].to_owned() })
+	}
+
+	/// Copies the vector into packed bytes, least significant bit first.
+	///
+	/// The unused bits of the last byte are cleared.
+	fn to_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+		let mut bytes = self.bits.as_slice().to_vec();
+		let tail = self.bits.len() % 8;
+		if let (Some(last), true) = (bytes.last_mut(), tail != 0) {
+			*last &= (1 << tail) - 1;
+		}
+		PyBytes::new(py, &bytes)
+	}
+
+	/// Counts the bits equal to `value`.
+	#[pyo3(signature = (value = true))]
+	fn count(&self, value: bool) -> usize {
+		if value { self.bits.count_ones() } else { self.bits.count_zeros() }
+	}
+
+	fn __len__(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Reads one bit, as a `bool`, or a slice of bits, as a new `VecBit`.
+	fn __getitem__(&self, py: Python, index: &PyAny) -> PyResult<PyObject> {
+		let slice = match index.downcast::<PySlice>() {
+			Ok(slice) => slice.indices(self.bits.len() as c_long)?,
+			Err(_) => {
+				let idx = self.index(index.extract::<isize>()?)?;
+				return Ok(self.bits[idx].into_py(py));
+			},
+		};
+		let len = slice.slicelength as usize;
+		let start = slice.start;
+		let bits = if slice.step == 1 {
+			let start = start as usize;
+			self.bits[start .. start + len].to_owned()
+		}
+		else {
+			(0 .. len as isize)
+				.map(|n| self.bits[(start + n * slice.step) as usize])
+				.collect()
+		};
+		Ok(Py::new(py, Self { bits })?.into_py(py))
+	}
+
+	fn __setitem__(&mut self, index: isize, value: bool) -> PyResult<()> {
+		let idx = self.index(index)?;
+		self.bits.set(idx, value);
+		Ok(())
+	}
+
+	fn __and__(&self, other: PyRef<Self>) -> PyResult<Self> {
+		self.same_len(&other)?;
+		let mut bits = self.bits.clone();
+		bits.intersect_in_place(&other.bits);
+		Ok(Self { bits })
+	}
+
+	fn __or__(&self, other: PyRef<Self>) -> PyResult<Self> {
+		self.same_len(&other)?;
+		let mut bits = self.bits.clone();
+		bits.union_in_place(&other.bits);
+		Ok(Self { bits })
+	}
+
+	fn __xor__(&self, other: PyRef<Self>) -> PyResult<Self> {
+		self.same_len(&other)?;
+		let mut bits = self.bits.clone();
+		bits.symmetric_difference_in_place(&other.bits);
+		Ok(Self { bits })
+	}
+
+	fn __invert__(&self) -> Self {
+		Self { bits: !self.bits.clone() }
+	}
+
+	fn __eq__(&self, other: PyRef<Self>) -> bool {
+		self.bits == other.bits
+	}
+
+	fn __repr__(&self) -> String {
+		let bits = self.bits.iter()
+			.map(|bit| if bit { '1' } else { '0' })
+			.collect::<String>();
+		format!("VecBit('{}')", bits)
+	}
+}
+
+impl From<VecBit<LittleEndian, u8>> for PyVecBit {
+	fn from(bits: VecBit<LittleEndian, u8>) -> Self {
+		Self { bits }
+	}
+}
+
+/// Adds the `VecBit` class to a Python module.
+///
+/// # Parameters
+///
+/// - `module`: The module being initialized.
+pub fn register(module: &PyModule) -> PyResult<()> {
+	module.add_class::<PyVecBit>()
+}
@@ -0,0 +1,134 @@
+/*! `rand`-powered randomization
+
+This module provides random permutation and sampling of bit slices, using the
+random number generators from the `rand` crate.
+
+Bits have no identity beyond their value, so a uniform random permutation of a
+slice is exactly a uniform random choice of which positions hold its set bits.
+The methods here exploit this to draw far fewer random numbers than a
+general-purpose shuffle would.
+!*/
+
+#![cfg(feature = "rand")]
+
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use rand::Rng;
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Randomly permutes the bits in the slice, in place.
+	///
+	/// Every arrangement of the slice’s bits is equally likely. The slice keeps
+	/// its count of set bits, and only chooses new positions for them.
+	///
+	/// This uses Floyd’s sampling algorithm to place whichever of the set or
+	/// cleared bits is rarer, so it draws `min(ones, zeros)` random numbers
+	/// rather than one per bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rng`: The source of randomness.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "rand")] {
+	/// use vecbit::prelude::*;
+	/// use rand::rngs::mock::StepRng;
+	///
+	/// let mut src = [0xF0u8, 0x0F, 0x00, 0xFF];
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// bits.shuffle(&mut StepRng::new(3, 7));
+	/// assert_eq!(bits.count_ones(), 16);
+	/// # }
+	/// ```
+	pub fn shuffle<R>(&mut self, rng: &mut R)
+	where R: Rng + ?Sized {
+		let len = self.len();
+		let ones = self.count_ones();
+		//  Place the rarer bit value into a field of the common one.
+		let (rare, count) = if ones <= len - ones {
+			(true, ones)
+		}
+		else {
+			(false, len - ones)
+		};
+		self.set_all(!rare);
+		//  Floyd’s algorithm, using the slice itself as the chosen set. At each
+		//  step, no position at or above `n` has been chosen yet.
+		for n in len - count .. len {
+			let pick = rng.gen_range(0, n + 1);
+			let place = if self[pick] == rare { n } else { pick };
+			self.set(place, rare);
+		}
+	}
+
+	/// Selects random positions of set bits in the slice.
+	///
+	/// Each set of `k` set-bit positions is equally likely to be chosen. This
+	/// makes a single pass over the slice with reservoir sampling, so only the
+	/// `k` chosen positions are ever held in memory.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rng`: The source of randomness.
+	/// - `k`: The number of set-bit positions to select.
+	///
+	/// # Returns
+	///
+	/// The indices of the selected set bits, in ascending order. If the slice
+	/// has `k` or fewer set bits, then this is the index of every set bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(all(feature = "alloc", feature = "rand"))] {
+	/// use vecbit::prelude::*;
+	/// use rand::rngs::mock::StepRng;
+	///
+	/// let src = [0b0100_1001u8, 0b1000_0010];
+	/// let bits = src.bits::<BigEndian>();
+	/// let picks = bits.sample_ones(&mut StepRng::new(3, 7), 3);
+	/// assert_eq!(picks.len(), 3);
+	/// assert!(picks.windows(2).all(|w| w[0] < w[1]));
+	/// assert!(picks.iter().all(|&n| bits[n]));
+	///
+	/// assert_eq!(bits.sample_ones(&mut StepRng::new(3, 7), 10), [1, 4, 7, 8, 14]);
+	/// # }
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn sample_ones<R>(&self, rng: &mut R, k: usize) -> Vec<usize>
+	where R: Rng + ?Sized {
+		use core::cmp::min;
+		let mut out = Vec::with_capacity(min(k, self.len()));
+		if k == 0 {
+			return out;
+		}
+		let ones = self.iter()
+			.enumerate()
+			.filter_map(|(n, bit)| if bit { Some(n) } else { None });
+		for (seen, idx) in ones.enumerate() {
+			if seen < k {
+				out.push(idx);
+			}
+			else {
+				let slot = rng.gen_range(0, seen + 1);
+				if slot < k {
+					out[slot] = idx;
+				}
+			}
+		}
+		out.sort_unstable();
+		out
+	}
+}
@@ -5,12 +5,44 @@ This module implements the Serde traits for the `vecbit` types, as possible.
 Without an allocator, only `SliceBit` exists, and can only implement
 `Serialize`. With an allocator, the `BitBox` and `VecBit` types exist, and are
 able to implement `Deserialize` as well.
+
+Formats which report themselves as human-readable, such as JSON and TOML,
+receive the bits as a string of `0` and `1` characters in semantic order, so
+that masks in configuration files can be read and edited by hand. The string
+carries the exact length. When reading such a format, `_` may separate digits,
+and the structured form below is also accepted.
+
+All other formats receive a `BitSet` structure of the head index, the length in
+bits, the layout of the cursor, the width of the storage type, and the packed
+storage elements. The cursor and width let a reader whose types differ from the
+writer’s re-pack the bits into its own layout, so a producer may switch from
+`BigEndian, u8` to `LittleEndian, u64`, say, without breaking its consumers.
+
+Adding the cursor and width changed the wire format. Formats that describe
+their structures as maps of named fields, such as CBOR, can still read
+structures that lack the cursor and width, and take them to be in the reader’s
+own layout. Formats that do not describe themselves, such as `bincode`, read
+the structure as a sequence of exactly five fields, and cannot read the
+three-field structures that earlier versions wrote; those must be re-encoded.
+
+The cursor is recorded as `BigEndian` or `LittleEndian`, whichever places the
+bits of the storage type as the slice’s cursor does, and no other name is
+accepted on load. A slice whose cursor matches neither is written re-packed
+into the `LittleEndian` layout, or, without an allocator, refused.
+
+The structure records the types that `Local` and `Word` name on the writing
+target, not the aliases, so a reader on a target where they name other types
+re-packs the bits rather than misreading them. Elements written as `u64` can
+only be read on targets that have 64-bit pointers, and are rejected elsewhere.
 !*/
 
 #![cfg(all(feature = "serde"))]
 
 use crate::{
-	cursor::Cursor,
+	cursor::{
+		Cursor,
+		Stride,
+	},
 	slice::SliceBit,
 	store::BitStore,
 };
@@ -18,6 +50,11 @@ use crate::{
 #[cfg(feature = "alloc")]
 use crate::{
 	boxed::BitBox,
+	cursor::{
+		BigEndian,
+		LittleEndian,
+	},
+	indices::BitIdx,
 	pointer::BitPtr,
 	vec::VecBit,
 };
@@ -26,14 +63,16 @@ use crate::{
 use core::{
 	cmp,
 	convert::TryInto,
-	fmt::{
-		self,
-		Formatter,
-	},
 	marker::PhantomData,
 	mem,
 };
 
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
 use serde::{
 	Serialize,
 	ser::{
@@ -47,6 +86,7 @@ use serde::{
 	Deserialize,
 	de::{
 		self,
+		DeserializeSeed,
 		Deserializer,
 		Error,
 		MapAccess,
@@ -79,39 +119,59 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 	type Value = BitBox<C, T>;
 
 	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
-		fmt.write_str("A BitSet data series")
+		fmt.write_str("A BitSet data series, or a string of 0 and 1 digits")
+	}
+
+	/// Visit a string of `0` and `1` characters, in semantic order. Each `_`
+	/// character is skipped, so that long strings may be grouped for reading.
+	fn visit_str<E>(self, text: &str) -> Result<Self::Value, E>
+	where E: Error {
+		let mut bv = VecBit::<C, T>::with_capacity(text.len());
+		for byte in text.bytes() {
+			match byte {
+				b'0' => bv.push(false),
+				b'1' => bv.push(true),
+				b'_' => {},
+				_ => return Err(Error::invalid_value(
+					Unexpected::Str(text),
+					&self,
+				)),
+			}
+		}
+		Ok(bv.into_boxed_bitslice())
 	}
 
 	/// Visit a sequence of anonymous data elements. These must be in the order
-	/// `usize', `u8`, `u8`, `[T]`.
+	/// `u8`, `usize`, `str`, `u8`, `[_]`.
+	///
+	/// The three-field sequences of earlier versions, which lack the cursor and
+	/// width, are rejected, as a sequence cannot mark which fields it omits.
 	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
 	where V: SeqAccess<'de> {
 		let head: u8 = seq.next_element()?
 			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
 		let bits: usize = seq.next_element()?
 			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
-		let data: Box<[T]> = seq.next_element()?
+		let order = seq.next_element_seed(OrderSeed::<C, T>::new())?
 			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
-
-		let bitptr = BitPtr::new(
-			data.as_ptr(),
-			head.try_into().map_err(|_| Error::invalid_value(
-				Unexpected::Unsigned(head as u64),
-				&self,
-			))?,
-			bits,
-		);
-		mem::forget(data);
-		Ok(unsafe { BitBox::from_raw(bitptr) })
+		let width: u8 = seq.next_element()?
+			.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+		let data = seq.next_element_seed(PayloadSeed::<T>::new(order, width))?
+			.ok_or_else(|| de::Error::invalid_length(4, &self))?;
+		data.build(head, bits)
 	}
 
 	/// Visit a map of named data elements. These may be in any order, and must
-	/// be the pairs `head: u8`, `bits: usize`, and `data: [T]`.
+	/// be the pairs `head: u8`, `bits: usize`, and `data: [_]`, optionally with
+	/// `cursor: str` and `width: u8`. The cursor and width, if present, must
+	/// precede the data.
 	fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
 	where V: MapAccess<'de> {
 		let mut head: Option<u8> = None;
 		let mut bits: Option<usize> = None;
-		let mut data: Option<Box<[T]>> = None;
+		let mut order: Option<Order> = None;
+		let mut width: Option<u8> = None;
+		let mut data: Option<(Payload<T>, Order, u8)> = None;
 
 		while let Some(key) = map.next_key()? {
 			match key {
@@ -121,42 +181,248 @@ where C: Cursor, T: BitStore + Deserialize<'de> {
 				"bits" => if bits.replace(map.next_value()?).is_some() {
 					return Err(de::Error::duplicate_field("bits"));
 				},
-				"data" => if data.replace(map.next_value()?).is_some() {
-					return Err(de::Error::duplicate_field("data"));
+				"cursor" => {
+					let seed = OrderSeed::<C, T>::new();
+					if order.replace(map.next_value_seed(seed)?).is_some() {
+						return Err(de::Error::duplicate_field("cursor"));
+					}
 				},
-				f => return Err(de::Error::unknown_field(
-					f, &["head", "bits", "data"]
-				)),
+				"width" => if width.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("width"));
+				},
+				"data" => {
+					let o = order.unwrap_or(Order::Target);
+					let w = width.unwrap_or(T::BITS);
+					let payload = map.next_value_seed(PayloadSeed::new(o, w))?;
+					if data.replace((payload, o, w)).is_some() {
+						return Err(de::Error::duplicate_field("data"));
+					}
+				},
+				f => return Err(de::Error::unknown_field(f, FIELDS)),
 			}
 		}
 		let head = head.ok_or_else(|| de::Error::missing_field("head"))?;
 		let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
-		let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
-
-		let bitptr = BitPtr::new(
-			data.as_ptr(),
-			head.try_into().map_err(|_| Error::invalid_value(
-				Unexpected::Unsigned(head as u64),
-				&self,
-			))?,
-			cmp::min(bits, data.len() * T::BITS as usize),
-		);
-		mem::forget(data);
-		Ok(unsafe { BitBox::from_raw(bitptr) })
+		let (data, o, w) = data
+			.ok_or_else(|| de::Error::missing_field("data"))?;
+		//  The data was read in the layout known when it arrived.
+		if (o, w) != (order.unwrap_or(o), width.unwrap_or(w)) {
+			return Err(de::Error::custom(
+				"the cursor and width must precede the data",
+			));
+		}
+		data.build(head, bits)
+	}
+}
+
+/// The names of the fields of a `BitSet`, in the order in which they are
+/// written. The cursor and width precede the data, so that formats which do not
+/// describe themselves can read the elements at the width they were written.
+#[cfg(feature = "alloc")]
+const FIELDS: &[&str] = &["head", "bits", "cursor", "width", "data"];
+
+/// The bit order in which a `BitSet` was written, as far as it can be read.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Order {
+	/// The layout of the type being deserialized.
+	Target,
+	/// The `BigEndian` cursor.
+	BigEndian,
+	/// The `LittleEndian` cursor.
+	LittleEndian,
+}
+
+/// Reads a cursor layout, relative to the layout that the cursor `C` has over
+/// the storage type `T` of the type being deserialized.
+///
+/// Only the `BigEndian` and `LittleEndian` layouts are written, since cursor
+/// type names do not identify layouts.
+#[cfg(feature = "alloc")]
+struct OrderSeed<C, T>
+where C: Cursor, T: BitStore {
+	_cursor: PhantomData<C>,
+	_storage: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> OrderSeed<C, T>
+where C: Cursor, T: BitStore {
+	fn new() -> Self {
+		OrderSeed { _cursor: PhantomData, _storage: PhantomData }
 	}
 }
 
+#[cfg(feature = "alloc")]
+impl<'de, C, T> DeserializeSeed<'de> for OrderSeed<C, T>
+where C: Cursor, T: BitStore {
+	type Value = Order;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_str(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C, T> Visitor<'de> for OrderSeed<C, T>
+where C: Cursor, T: BitStore {
+	type Value = Order;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("BigEndian or LittleEndian")
+	}
+
+	fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+	where E: Error {
+		let order = match name {
+			"BigEndian" => Order::BigEndian,
+			"LittleEndian" => Order::LittleEndian,
+			_ => return Err(Error::invalid_value(Unexpected::Str(name), &self)),
+		};
+		if Stride::tag::<C, T>() == Some(name) {
+			return Ok(Order::Target);
+		}
+		Ok(order)
+	}
+}
+
+/// The storage elements of a `BitSet`, at the width at which they were written.
+#[cfg(feature = "alloc")]
+enum Payload<T>
+where T: BitStore {
+	/// Elements written in the layout of the type being deserialized.
+	Native(Box<[T]>),
+	U8(Order, Box<[u8]>),
+	U16(Order, Box<[u16]>),
+	U32(Order, Box<[u32]>),
+	#[cfg(target_pointer_width = "64")]
+	U64(Order, Box<[u64]>),
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Payload<T>
+where T: BitStore {
+	/// Builds a `BitBox` from the elements, re-packing them if they were
+	/// written in a different layout.
+	fn build<C, E>(self, head: u8, bits: usize) -> Result<BitBox<C, T>, E>
+	where C: Cursor, E: Error {
+		match self {
+			Payload::Native(data) => {
+				let head: BitIdx<T> = head.try_into()
+					.map_err(|_| bad_head(head))?;
+				let len = (data.len() * T::BITS as usize)
+					.saturating_sub(*head as usize);
+				let len = cmp::min(bits, len);
+				let bitptr = BitPtr::new(data.as_ptr(), head, len);
+				mem::forget(data);
+				Ok(unsafe { BitBox::from_raw(bitptr) })
+			},
+			Payload::U8(order, data) => repack(order, &data, head, bits),
+			Payload::U16(order, data) => repack(order, &data, head, bits),
+			Payload::U32(order, data) => repack(order, &data, head, bits),
+			#[cfg(target_pointer_width = "64")]
+			Payload::U64(order, data) => repack(order, &data, head, bits),
+		}
+	}
+}
+
+/// Reads the elements of a `BitSet` at the width at which they were written.
+#[cfg(feature = "alloc")]
+struct PayloadSeed<T>
+where T: BitStore {
+	order: Order,
+	width: u8,
+	_storage: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PayloadSeed<T>
+where T: BitStore {
+	fn new(order: Order, width: u8) -> Self {
+		PayloadSeed { order, width, _storage: PhantomData }
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeSeed<'de> for PayloadSeed<T>
+where T: BitStore + Deserialize<'de> {
+	type Value = Payload<T>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		let order = self.order;
+		if order == Order::Target && self.width == T::BITS {
+			return Deserialize::deserialize(deserializer).map(Payload::Native);
+		}
+		match self.width {
+			8 => Deserialize::deserialize(deserializer)
+				.map(|data| Payload::U8(order, data)),
+			16 => Deserialize::deserialize(deserializer)
+				.map(|data| Payload::U16(order, data)),
+			32 => Deserialize::deserialize(deserializer)
+				.map(|data| Payload::U32(order, data)),
+			#[cfg(target_pointer_width = "64")]
+			64 => Deserialize::deserialize(deserializer)
+				.map(|data| Payload::U64(order, data)),
+			w => Err(Error::invalid_value(
+				Unexpected::Unsigned(w as u64),
+				&"the width of a storage type on this target",
+			)),
+		}
+	}
+}
+
+/// Copies the bits of a `BitSet` written in a foreign layout into a `BitBox`.
+#[cfg(feature = "alloc")]
+fn repack<C, T, U, E>(order: Order, data: &[U], head: u8, bits: usize)
+-> Result<BitBox<C, T>, E>
+where C: Cursor, T: BitStore, U: BitStore, E: Error {
+	fn copy<C, D, T, U>(all: &SliceBit<D, U>, head: u8, bits: usize)
+	-> BitBox<C, T>
+	where C: Cursor, D: Cursor, T: BitStore, U: BitStore {
+		let rest = &all[cmp::min(head as usize, all.len()) ..];
+		let live = &rest[.. cmp::min(bits, rest.len())];
+		live.iter().collect::<VecBit<C, T>>().into_boxed_bitslice()
+	}
+
+	if head >= U::BITS {
+		return Err(bad_head(head));
+	}
+	Ok(match order {
+		Order::Target => copy(SliceBit::<C, U>::from_slice(data), head, bits),
+		Order::BigEndian => {
+			copy(SliceBit::<BigEndian, U>::from_slice(data), head, bits)
+		},
+		Order::LittleEndian => {
+			copy(SliceBit::<LittleEndian, U>::from_slice(data), head, bits)
+		},
+	})
+}
+
+/// Reports a head index that does not fit in the storage type it was written
+/// with.
+#[cfg(feature = "alloc")]
+fn bad_head<E>(head: u8) -> E
+where E: Error {
+	Error::invalid_value(
+		Unexpected::Unsigned(head as u64),
+		&"a bit index within the first storage element",
+	)
+}
+
 #[cfg(feature = "alloc")]
 impl<'de, C, T> Deserialize<'de> for BitBox<C, T>
 where C: Cursor, T: 'de + BitStore + Deserialize<'de> {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
+		//  Human-readable formats hold the bit string, but may also hold the
+		//  structure, which they received before the bit string existed.
+		if deserializer.is_human_readable() {
+			return deserializer.deserialize_any(BitBoxVisitor::new());
+		}
 		deserializer
-			.deserialize_struct(
-				"BitSet",
-				&["head", "bits", "data"],
-				BitBoxVisitor::new(),
-			)
+			.deserialize_struct("BitSet", FIELDS, BitBoxVisitor::new())
 	}
 }
 
@@ -173,11 +439,27 @@ impl<C, T> Serialize for SliceBit<C, T>
 where C: Cursor, T: BitStore + Serialize, T::Access: Serialize {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where S: Serializer {
+		if serializer.is_human_readable() {
+			return serializer.collect_str(&BitString(self));
+		}
+		let tag = match Stride::tag::<C, T>() {
+			Some(tag) => tag,
+			#[cfg(feature = "alloc")]
+			None => return self.iter()
+				.collect::<VecBit<LittleEndian, T>>()
+				.serialize(serializer),
+			#[cfg(not(feature = "alloc"))]
+			None => return Err(serde::ser::Error::custom(
+				"the cursor has neither the BigEndian nor the LittleEndian layout",
+			)),
+		};
 		let head = self.bitptr().head();
-		let mut state = serializer.serialize_struct("BitSet", 3)?;
+		let mut state = serializer.serialize_struct("BitSet", 5)?;
 
 		state.serialize_field("head", &*head)?;
 		state.serialize_field("bits", &(self.len() as u64))?;
+		state.serialize_field("cursor", tag)?;
+		state.serialize_field("width", &T::BITS)?;
 		state.serialize_field("data", self.as_total_slice())?;
 
 		state.end()
@@ -202,28 +484,71 @@ where C: Cursor, T: BitStore + Serialize, T::Access: Serialize {
 	}
 }
 
+/// Renders a `SliceBit` as its bits, in semantic order, with no separators.
+struct BitString<'a, C, T>(&'a SliceBit<C, T>)
+where C: Cursor, T: 'a + BitStore;
+
+impl<'a, C, T> Display for BitString<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		for bit in self.0 {
+			fmt.write_str(if bit { "1" } else { "0" })?;
+		}
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::prelude::*;
 	use serde_test::{
+		Configure,
 		Token,
 		assert_ser_tokens,
 	};
 	#[cfg(feature = "alloc")]
-	use serde_test::assert_de_tokens;
+	use serde_test::{
+		Compact,
+		Readable,
+		assert_de_tokens,
+		assert_de_tokens_error,
+		assert_tokens,
+	};
 
 	macro_rules! bvtok {
-		( s $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
+		(
+			s ( $cursor:expr, $width:expr )
+			$elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )*
+		) => {
 			&[
-				Token::Struct { name: "BitSet", len: 3, },
+				Token::Struct { name: "BitSet", len: 5, },
 				Token::Str("head"), Token::U8( $head ),
 				Token::Str("bits"), Token::U64( $bits ),
+				Token::Str("cursor"), Token::Str( $cursor ),
+				Token::Str("width"), Token::U8( $width ),
 				Token::Str("data"), Token::Seq { len: Some( $elts ) },
 				$( Token:: $ty ( $data ), )*
 				Token::SeqEnd,
 				Token::StructEnd,
 			]
 		};
+		(
+			d ( $cursor:expr, $width:expr )
+			$elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )*
+		) => {
+			&[
+				Token::Struct { name: "BitSet", len: 5, },
+				Token::BorrowedStr("head"), Token::U8( $head ),
+				Token::BorrowedStr("bits"), Token::U64( $bits ),
+				Token::BorrowedStr("cursor"), Token::BorrowedStr( $cursor ),
+				Token::BorrowedStr("width"), Token::U8( $width ),
+				Token::BorrowedStr("data"), Token::Seq { len: Some( $elts ) },
+				$( Token:: $ty ( $data ), )*
+				Token::SeqEnd,
+				Token::StructEnd,
+			]
+		};
+		//  Structures written without their layout.
 		( d $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
 			&[
 				Token::Struct { name: "BitSet", len: 3, },
@@ -241,10 +566,14 @@ mod tests {
 	fn empty() {
 		let slice = SliceBit::<BigEndian, u8>::empty();
 
-		assert_ser_tokens(&slice, bvtok![s 0, 0, 0, U8]);
+		assert_ser_tokens(
+			&slice.compact(),
+			bvtok![s ("BigEndian", 8) 0, 0, 0, U8],
+		);
+		assert_ser_tokens(&slice.readable(), &[Token::Str("")]);
 
 		#[cfg(feature = "alloc")]
-		assert_de_tokens(&vecbit![], bvtok![ d 0, 0, 0, U8 ]);
+		assert_de_tokens(&vecbit![].compact(), bvtok![ d 0, 0, 0, U8 ]);
 	}
 
 	#[cfg(feature = "alloc")]
@@ -252,13 +581,22 @@ mod tests {
 	fn small() {
 		let bv = vecbit![BigEndian, u8; 1; 5];
 		let bs = &bv[1 ..];
-		assert_ser_tokens(&bs, bvtok![s 1, 1, 4, U8, 0b1111_1000]);
+		assert_ser_tokens(
+			&bs.compact(),
+			bvtok![s ("BigEndian", 8) 1, 1, 4, U8, 0b1111_1000],
+		);
 
 		let bv = vecbit![LittleEndian, u16; 1; 12];
-		assert_ser_tokens(&bv, bvtok![s 1, 0, 12, U16, 0b00001111_11111111]);
+		assert_ser_tokens(
+			&bv.compact(),
+			bvtok![s ("LittleEndian", 16) 1, 0, 12, U16, 0b00001111_11111111],
+		);
 
 		let bb: BitBox<_, _> = vecbit![LittleEndian, u32; 1; 10].into();
-		assert_ser_tokens(&bb, bvtok![s 1, 0, 10, U32, 0x00_00_03_FF]);
+		assert_ser_tokens(
+			&bb.compact(),
+			bvtok![s ("LittleEndian", 32) 1, 0, 10, U32, 0x00_00_03_FF],
+		);
 	}
 
 	#[cfg(feature = "alloc")]
@@ -266,17 +604,120 @@ mod tests {
 	fn wide() {
 		let src: &[u8] = &[0, !0];
 		let bs = src.bits::<Local>();
-		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![s 2, 1, 14, U8, 0, !0]);
+		assert_ser_tokens(
+			&(&bs[1 .. 15]).compact(),
+			bvtok![s (Local::TYPENAME, 8) 2, 1, 14, U8, 0, !0],
+		);
 	}
 
 	#[cfg(feature = "alloc")]
 	#[test]
 	fn deser() {
-		let bv = vecbit![BigEndian, u8; 0, 1, 1, 0, 1, 0];
+		let bv = vecbit![BigEndian, u8; 0, 1, 1, 0, 1, 0].compact();
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1000]);
 		//  test that the bits outside the bits domain don't matter in deser
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1001]);
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1010]);
 		assert_de_tokens(&bv, bvtok![d 1, 0, 6, U8, 0b0110_1011]);
+		//  and that the layout may be given or left out
+		assert_de_tokens(
+			&bv,
+			bvtok![d ("BigEndian", 8) 1, 0, 6, U8, 0b0110_1011],
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn foreign() {
+		let bv = vecbit![LittleEndian, u16; 0, 1, 1, 0, 1, 0, 0, 1, 1, 1];
+		let bv = bv.compact();
+		let tokens = bvtok![s ("LittleEndian", 16) 1, 0, 10, U16, 918];
+		assert_ser_tokens(&bv, tokens);
+		let tokens = bvtok![d ("LittleEndian", 16) 1, 0, 10, U16, 918];
+		assert_de_tokens(&bv, tokens);
+		//  Narrower elements, in another order, from an offset slice.
+		assert_de_tokens(
+			&bv,
+			bvtok![d ("BigEndian", 8) 2, 3, 10, U8, 0b0000_1101, 0b0011_1000],
+		);
+		//  Dead bits in foreign elements don't matter either.
+		assert_de_tokens(
+			&bv,
+			bvtok![d ("BigEndian", 8) 2, 0, 10, U8, 0b0110_1001, 0b1111_1111],
+		);
+		//  Wider elements.
+		let tokens = bvtok![d ("LittleEndian", 32) 1, 0, 10, U32, 918];
+		assert_de_tokens(&bv, tokens);
+
+		type Target = Compact<VecBit<LittleEndian, u16>>;
+		assert_de_tokens_error::<Target>(
+			&[
+				Token::Struct { name: "BitSet", len: 5, },
+				Token::BorrowedStr("head"), Token::U8(0),
+				Token::BorrowedStr("bits"), Token::U64(8),
+				Token::BorrowedStr("cursor"), Token::BorrowedStr("Reversed"),
+			],
+			"invalid value: string \"Reversed\", expected BigEndian or \
+			LittleEndian",
+		);
+		assert_de_tokens_error::<Target>(
+			&[
+				Token::Struct { name: "BitSet", len: 5, },
+				Token::BorrowedStr("head"), Token::U8(0),
+				Token::BorrowedStr("bits"), Token::U64(8),
+				Token::BorrowedStr("cursor"), Token::BorrowedStr("BigEndian"),
+				Token::BorrowedStr("width"), Token::U8(12),
+				Token::BorrowedStr("data"),
+			],
+			"invalid value: integer `12`, expected the width of a storage \
+			type on this target",
+		);
+		assert_de_tokens_error::<Target>(
+			&[
+				Token::Struct { name: "BitSet", len: 5, },
+				Token::BorrowedStr("head"), Token::U8(0),
+				Token::BorrowedStr("bits"), Token::U64(8),
+				Token::BorrowedStr("data"), Token::Seq { len: Some(1) },
+				Token::U16(0),
+				Token::SeqEnd,
+				Token::BorrowedStr("cursor"), Token::BorrowedStr("BigEndian"),
+				Token::StructEnd,
+			],
+			"the cursor and width must precede the data",
+		);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn composite_cursor() {
+		use crate::cursor::Reversed;
+
+		let bv = vecbit![Reversed<LittleEndian>, u8; 1, 1, 0, 1].compact();
+		let tokens = bvtok![s ("BigEndian", 8) 1, 0, 4, U8, 0b1101_0000];
+		assert_ser_tokens(&bv, tokens);
+		let tokens = bvtok![d ("BigEndian", 8) 1, 0, 4, U8, 0b1101_0000];
+		assert_de_tokens(&bv, tokens);
+		//  The same name, under another layout, is re-packed.
+		let bv = vecbit![Reversed<BigEndian>, u8; 1, 1, 0, 1].compact();
+		assert_de_tokens(&bv, tokens);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn readable() {
+		let bv = vecbit![BigEndian, u8; 0, 1, 1, 0, 1, 0, 0, 1, 1].readable();
+		assert_tokens(&bv, &[Token::Str("011010011")]);
+		assert_de_tokens(&bv, &[Token::Str("0110_1001_1")]);
+		//  The structured form is still accepted.
+		assert_de_tokens(&bv, bvtok![d 2, 0, 9, U8, 0b0110_1001, 0b1000_0000]);
+
+		let bv = vecbit![LittleEndian, u16; 1; 20].readable();
+		assert_tokens(&bv, &[Token::Str("11111111111111111111")]);
+
+		assert_de_tokens_error::<Readable<VecBit<BigEndian, u8>>>(
+			&[Token::Str("0120")],
+			"invalid value: string \"0120\", expected A BitSet data series, \
+			or a string of 0 and 1 digits",
+		);
 	}
 }
@@ -0,0 +1,458 @@
+/*! Shared, immutable bit sequences
+
+This module holds `SharedBits`, a handle to a region of an immutable bit
+sequence whose buffer is reference counted. Cloning a handle, or narrowing it
+to a subregion, only adjusts the count and the region bounds; the bits are never
+copied. The handles can be sent to and shared between threads, so a parsed bit
+stream can be divided among many consumers without copying any part of it.
+
+This is the bit-level counterpart of the `Bytes` type in the `bytes` crate. The
+buffer is usually an `Arc<BitBox>`, but any reference-counted buffer that
+implements [`SharedBuffer`] can be shared the same way; the `bytes` feature uses
+this to share `Bytes` buffers themselves.
+
+[`SharedBuffer`]: trait.SharedBuffer.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	boxed::BitBox,
+	cursor::{
+		Cursor,
+		Local,
+	},
+	slice::{
+		resolve_range,
+		SliceBit,
+	},
+	store::{
+		BitStore,
+		Word,
+	},
+	vec::VecBit,
+};
+
+use alloc::{
+	borrow::Borrow,
+	sync::Arc,
+};
+
+use core::{
+	cmp::Ordering,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	marker::PhantomData,
+	ops::{
+		Deref,
+		RangeBounds,
+	},
+};
+
+/** A reference-counted buffer that `SharedBits` handles can share.
+
+Cloning the buffer must only adjust its reference count, so that cloning and
+narrowing a handle never copy any bits.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor with which the buffer is read as bits.
+**/
+pub trait SharedBuffer<C>: Clone
+where C: Cursor {
+	/// The storage type of the buffer.
+	type Store: BitStore;
+
+	/// Views the whole buffer as bits.
+	fn bits(&self) -> &SliceBit<C, Self::Store>;
+
+	/// Tests if two handles hold the same buffer.
+	fn ptr_eq(&self, other: &Self) -> bool;
+}
+
+impl<C, T> SharedBuffer<C> for Arc<BitBox<C, T>>
+where C: Cursor, T: BitStore {
+	type Store = T;
+
+	fn bits(&self) -> &SliceBit<C, T> {
+		self
+	}
+
+	fn ptr_eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(self, other)
+	}
+}
+
+/** A cheaply cloned handle to a region of a shared, immutable bit sequence.
+
+The buffer is held behind an `Arc`, and each handle records the region of the
+buffer that it can see. Cloning a handle and taking a subregion of one are
+constant-time operations that do not touch the buffer. The buffer is freed when
+the last handle to any of its regions is dropped.
+
+`SharedBits` dereferences to `SliceBit`, so every read-only slice method is
+available on it.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the shared bits.
+- `T: BitStore`: The storage type of the shared bits.
+- `B: SharedBuffer<C>`: The reference-counted buffer. This defaults to an
+  `Arc<BitBox<C, T>>`.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::shared::SharedBits;
+
+let bits = vecbit![BigEndian, u8; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+let packet = SharedBits::from(bits);
+let header = packet.slice(.. 4);
+let body = packet.slice(4 ..);
+assert_eq!(header, vecbit![1, 0, 1, 1]);
+assert_eq!(body.count_ones(), 2);
+assert_eq!(body.slice(3 ..), vecbit![0, 1]);
+```
+**/
+#[derive(Clone)]
+pub struct SharedBits<C = Local, T = Word, B = Arc<BitBox<C, T>>>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	_cursor: PhantomData<C>,
+	_store: PhantomData<T>,
+	/// The shared buffer.
+	data: B,
+	/// The index in `data` of the first bit of this handle’s region.
+	offset: usize,
+	/// The number of bits in this handle’s region.
+	len: usize,
+}
+
+impl<C, T> SharedBits<C, T>
+where C: Cursor, T: BitStore {
+	/// Moves a boxed bit slice into a shared buffer.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The bits to share. They can no longer be modified.
+	///
+	/// # Returns
+	///
+	/// A handle to the whole of `bits`.
+	pub fn new(bits: BitBox<C, T>) -> Self {
+		Self::whole(Arc::new(bits))
+	}
+}
+
+impl<C, T, B> SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	/// Makes a handle to the whole of a shared buffer.
+	///
+	/// # Parameters
+	///
+	/// - `data`: The buffer to share.
+	///
+	/// # Returns
+	///
+	/// A handle to every bit in `data`.
+	pub(crate) fn whole(data: B) -> Self {
+		let len = data.bits().len();
+		Self { _cursor: PhantomData, _store: PhantomData, data, offset: 0, len }
+	}
+
+	/// Borrows the whole shared buffer, including any bits outside the
+	/// handle’s region.
+	#[cfg(feature = "bytes")]
+	pub(crate) fn buffer(&self) -> &B {
+		&self.data
+	}
+
+	/// Borrows the handle’s region as a bit slice.
+	pub fn as_bitslice(&self) -> &SliceBit<C, T> {
+		&self.data.bits()[self.offset .. self.offset + self.len]
+	}
+
+	/// Produces a handle to a subregion, without copying.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of bits, within this handle’s region, for the new
+	///   handle to see.
+	///
+	/// # Returns
+	///
+	/// A handle to the bits of `self` in `range`, sharing the same buffer.
+	///
+	/// # Panics
+	///
+	/// Panics if the range runs from high to low, or extends beyond the end of
+	/// the handle’s region.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::shared::SharedBits;
+	///
+	/// let all = SharedBits::from(vecbit![BigEndian, u8; 0, 1, 1, 0, 1]);
+	/// let mid = all.slice(1 ..= 3);
+	/// assert_eq!(mid, vecbit![1, 1, 0]);
+	/// assert_eq!(mid.slice(1 ..), vecbit![1, 0]);
+	/// ```
+	pub fn slice<R>(&self, range: R) -> Self
+	where R: RangeBounds<usize> {
+		let range = resolve_range(range, self.len);
+		Self {
+			_cursor: PhantomData,
+			_store: PhantomData,
+			data: self.data.clone(),
+			offset: self.offset + range.start,
+			len: range.end - range.start,
+		}
+	}
+
+	/// Splits the handle in two at an index, without copying.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `at`: The index at which to split. `self` keeps the bits before it.
+	///
+	/// # Returns
+	///
+	/// A handle to the bits from `at` to the end of the region.
+	///
+	/// # Panics
+	///
+	/// Panics if `at` is greater than the length of the region.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::shared::SharedBits;
+	///
+	/// let mut head = SharedBits::from(vecbit![BigEndian, u8; 1, 1, 0, 0, 1]);
+	/// let tail = head.split_off(2);
+	/// assert_eq!(head, vecbit![1, 1]);
+	/// assert_eq!(tail, vecbit![0, 0, 1]);
+	/// ```
+	pub fn split_off(&mut self, at: usize) -> Self {
+		let tail = self.slice(at ..);
+		self.len = at;
+		tail
+	}
+
+	/// Splits the handle in two at an index, without copying.
+	///
+	/// This is [`split_off`] with the halves exchanged, and suits a consumer
+	/// that takes fields off the front of a stream.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `at`: The index at which to split. `self` keeps the bits from it to
+	///   the end.
+	///
+	/// # Returns
+	///
+	/// A handle to the bits before `at`.
+	///
+	/// # Panics
+	///
+	/// Panics if `at` is greater than the length of the region.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::shared::SharedBits;
+	///
+	/// let mut stream = SharedBits::from(vecbit![BigEndian, u8; 1, 0, 1, 1, 1]);
+	/// let tag = stream.split_to(2);
+	/// assert_eq!(tag, vecbit![1, 0]);
+	/// assert_eq!(stream, vecbit![1, 1, 1]);
+	/// ```
+	///
+	/// [`split_off`]: #method.split_off
+	pub fn split_to(&mut self, at: usize) -> Self {
+		let head = self.slice(.. at);
+		self.offset += at;
+		self.len -= at;
+		head
+	}
+
+	/// Tests if two handles see the same region of the same buffer.
+	///
+	/// # Parameters
+	///
+	/// - `this`: A handle.
+	/// - `other`: Another handle.
+	///
+	/// # Returns
+	///
+	/// Whether `this` and `other` share a buffer and see the same region of
+	/// it. Equal bits in different buffers, or in different regions of one
+	/// buffer, are not the same.
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		this.data.ptr_eq(&other.data)
+			&& this.offset == other.offset
+			&& this.len == other.len
+	}
+}
+
+impl<C, T, B> AsRef<SliceBit<C, T>> for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	fn as_ref(&self) -> &SliceBit<C, T> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, T, B> Borrow<SliceBit<C, T>> for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	fn borrow(&self) -> &SliceBit<C, T> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, T, B> Debug for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("SharedBits<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, T, B> Deref for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	type Target = SliceBit<C, T>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<C, T, B> Display for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, T, B> Eq for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {}
+
+impl<C, T> From<BitBox<C, T>> for SharedBits<C, T>
+where C: Cursor, T: BitStore {
+	fn from(bits: BitBox<C, T>) -> Self {
+		Self::new(bits)
+	}
+}
+
+impl<C, T> From<VecBit<C, T>> for SharedBits<C, T>
+where C: Cursor, T: BitStore {
+	fn from(bits: VecBit<C, T>) -> Self {
+		Self::new(bits.into_boxed_bitslice())
+	}
+}
+
+impl<C, T> From<&SliceBit<C, T>> for SharedBits<C, T>
+where C: Cursor, T: BitStore {
+	fn from(bits: &SliceBit<C, T>) -> Self {
+		Self::new(bits.into())
+	}
+}
+
+impl<C, T, B> Hash for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<C, T, B> Ord for SharedBits<C, T, B>
+where C: Cursor, T: BitStore, B: SharedBuffer<C, Store = T> {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.as_bitslice().cmp(rhs.as_bitslice())
+	}
+}
+
+impl<A, B, C, D, X, Y> PartialEq<SharedBits<C, D, Y>> for SharedBits<A, B, X>
+where
+	A: Cursor,
+	B: BitStore,
+	C: Cursor,
+	D: BitStore,
+	X: SharedBuffer<A, Store = B>,
+	Y: SharedBuffer<C, Store = D>,
+{
+	fn eq(&self, rhs: &SharedBits<C, D, Y>) -> bool {
+		self.as_bitslice() == rhs.as_bitslice()
+	}
+}
+
+impl<A, B, C, D, X> PartialEq<SliceBit<C, D>> for SharedBits<A, B, X>
+where
+	A: Cursor,
+	B: BitStore,
+	C: Cursor,
+	D: BitStore,
+	X: SharedBuffer<A, Store = B>,
+{
+	fn eq(&self, rhs: &SliceBit<C, D>) -> bool {
+		self.as_bitslice() == rhs
+	}
+}
+
+impl<A, B, C, D, X> PartialEq<&SliceBit<C, D>> for SharedBits<A, B, X>
+where
+	A: Cursor,
+	B: BitStore,
+	C: Cursor,
+	D: BitStore,
+	X: SharedBuffer<A, Store = B>,
+{
+	fn eq(&self, rhs: &&SliceBit<C, D>) -> bool {
+		self.as_bitslice() == *rhs
+	}
+}
+
+impl<A, B, C, D, X> PartialEq<VecBit<C, D>> for SharedBits<A, B, X>
+where
+	A: Cursor,
+	B: BitStore,
+	C: Cursor,
+	D: BitStore,
+	X: SharedBuffer<A, Store = B>,
+{
+	fn eq(&self, rhs: &VecBit<C, D>) -> bool {
+		self.as_bitslice() == rhs.as_bitslice()
+	}
+}
+
+impl<A, B, C, D, X, Y> PartialOrd<SharedBits<C, D, Y>> for SharedBits<A, B, X>
+where
+	A: Cursor,
+	B: BitStore,
+	C: Cursor,
+	D: BitStore,
+	X: SharedBuffer<A, Store = B>,
+	Y: SharedBuffer<C, Store = D>,
+{
+	fn partial_cmp(&self, rhs: &SharedBits<C, D, Y>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs.as_bitslice())
+	}
+}
@@ -0,0 +1,113 @@
+/*! Portable SIMD mask interoperation
+
+This module converts between bit slices and the lane masks of the unstable
+`core::simd` module, so that code which selects or compresses SIMD lanes can
+exchange masks with `vecbit` types directly.
+
+A mask of `N` lanes corresponds to a slice of `N` bits, with lane `n` held in
+bit `n`. The conversions pass through the integer bitmasks produced by
+[`SliceBit::to_bitmask`] and consumed by [`VecBit::from_bitmask`].
+
+This module requires a nightly compiler.
+
+[`SliceBit::to_bitmask`]: ../slice/struct.SliceBit.html#method.to_bitmask
+[`VecBit::from_bitmask`]: ../vec/struct.VecBit.html#method.from_bitmask
+!*/
+
+#![cfg(feature = "simd")]
+
+use crate::{
+	cursor::Cursor,
+	slice::SliceBit,
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::VecBit;
+
+use core::simd::{
+	Mask,
+	MaskElement,
+};
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Converts the slice into a SIMD lane mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A mask whose lane `n` is set if bit `n` of the slice is set.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice does not have exactly `N` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #![cfg_attr(feature = "simd", feature(portable_simd))]
+	/// # #[cfg(feature = "simd")] {
+	/// use vecbit::prelude::*;
+	/// use core::simd::Mask;
+	///
+	/// let bits = 0b1001u8.bits::<LittleEndian>();
+	/// let mask: Mask<i32, 4> = bits[.. 4].to_simd_mask();
+	/// assert_eq!(mask.to_array(), [true, false, false, true]);
+	/// # }
+	/// ```
+	pub fn to_simd_mask<M, const N: usize>(&self) -> Mask<M, N>
+	where M: MaskElement {
+		assert_eq!(
+			self.len(),
+			N,
+			"A slice of {} bits cannot fill a {}-lane mask",
+			self.len(),
+			N,
+		);
+		Mask::from_bitmask(self.to_bitmask::<u64>())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Copies a SIMD lane mask into a vector.
+	///
+	/// # Parameters
+	///
+	/// - `mask`: A SIMD lane mask.
+	///
+	/// # Returns
+	///
+	/// A vector of `N` bits, whose bit `n` is set if lane `n` of `mask` is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #![cfg_attr(feature = "simd", feature(portable_simd))]
+	/// # #[cfg(feature = "simd")] {
+	/// use vecbit::prelude::*;
+	/// use core::simd::Mask;
+	///
+	/// let mask = Mask::<i8, 8>::from_bitmask(0b1011);
+	/// let bv = VecBit::<BigEndian, u8>::from_simd_mask(mask);
+	/// assert_eq!(bv.as_slice(), &[0b1101_0000]);
+	/// # }
+	/// ```
+	pub fn from_simd_mask<M, const N: usize>(mask: Mask<M, N>) -> Self
+	where M: MaskElement {
+		Self::from_bitmask(mask.to_bitmask(), N)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T, M, const N: usize> From<Mask<M, N>> for VecBit<C, T>
+where C: Cursor, T: BitStore, M: MaskElement {
+	fn from(mask: Mask<M, N>) -> Self {
+		Self::from_simd_mask(mask)
+	}
+}
@@ -13,9 +13,13 @@ use crate::{
 	cursor::{
 		Cursor,
 		Local,
+		Stride,
 	},
 	domain::*,
-	indices::Indexable,
+	indices::{
+		BitIndex,
+		Indexable,
+	},
 	pointer::BitPtr,
 	store::{
 		BitStore,
@@ -26,7 +30,10 @@ use crate::{
 #[cfg(feature = "alloc")]
 use {
 	crate::vec::VecBit,
-	alloc::borrow::ToOwned,
+	alloc::{
+		borrow::ToOwned,
+		vec::Vec,
+	},
 };
 
 use core::{
@@ -80,6 +87,7 @@ use core::{
 		Neg,
 		Not,
 		Range,
+		RangeBounds,
 		RangeFrom,
 		RangeFull,
 		RangeInclusive,
@@ -631,6 +639,34 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Gets the bit value at a checked index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to retrieve.
+	///
+	/// # Returns
+	///
+	/// The bit at the specified index, if any. If `index` was checked against
+	/// a longer slice, and is beyond the bounds of `self`, then `None` is
+	/// produced.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::indices::BitIndex;
+	///
+	/// let bits = 8u8.bits::<BigEndian>();
+	/// let idx = BitIndex::new(6, bits).unwrap();
+	/// assert_eq!(bits.get_at(idx), Some(false));
+	/// assert!(bits[.. 4].get_at(idx).is_none());
+	/// ```
+	pub fn get_at(&self, index: BitIndex) -> Option<bool> {
+		self.get(*index)
+	}
+
 	/// Looks up a bit at an index, without doing bounds checking.
 	///
 	/// This is generally not recommended; use with caution! For a safe
@@ -690,7 +726,9 @@ where C: Cursor, T: BitStore {
 	///
 	/// # Panics
 	///
-	/// This method panics if `index` is outside the slice domain.
+	/// This method panics if `index` is outside the slice domain. With the
+	/// `no_panic` feature, it panics only in debug builds, and otherwise does
+	/// nothing.
 	///
 	/// # Examples
 	///
@@ -704,9 +742,9 @@ where C: Cursor, T: BitStore {
 	/// assert!(bits[3]);
 	/// ```
 	pub fn set(&mut self, index: usize, value: bool) {
-		let len = self.len();
-		assert!(index < len, "Index out of range: {} >= {}", index, len);
-		unsafe { self.set_unchecked(index, value) };
+		if check_index(index, self.len()) {
+			unsafe { self.set_unchecked(index, value) };
+		}
 	}
 
 	/// Sets a bit at an index, without doing bounds checking.
@@ -946,10 +984,134 @@ where C: Cursor, T: BitStore {
 	pub fn swap(&mut self, a: usize, b: usize) {
 		assert!(a < self.len(), "Index {} out of bounds: {}", a, self.len());
 		assert!(b < self.len(), "Index {} out of bounds: {}", b, self.len());
-		let bit_a = self[a];
-		let bit_b = self[b];
-		self.set(a, bit_b);
-		self.set(b, bit_a);
+		unsafe { self.swap_unchecked(a, b) };
+	}
+
+	/// Swaps two bits in the slice, without doing bounds checking.
+	///
+	/// This is generally not recommended; use with caution! For a safe
+	/// alternative, see [`swap`].
+	///
+	/// Memory is only written when the two bits differ, in which case each is
+	/// inverted in place.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `a`: The first index to be swapped. This index is *not* checked
+	///   against the length of `self`.
+	/// - `b`: The second index to be swapped. This index is *not* checked
+	///   against the length of `self`.
+	///
+	/// # Safety
+	///
+	/// This method is **not** safe. It performs raw pointer arithmetic to seek
+	/// from the start of the slice to the requested indices, and it is free to
+	/// perform out-of-bounds memory *write* access.
+	///
+	/// Use this method **only** when you have already performed the bounds
+	/// check, and can guarantee that the call occurs with safely in-bounds
+	/// indices.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut store = 0b1000_0000u8;
+	/// let bits = store.bits_mut::<BigEndian>();
+	/// unsafe { bits.swap_unchecked(0, 7); }
+	/// assert_eq!(store, 1);
+	/// ```
+	///
+	/// [`swap`]: #method.swap
+	pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+		let bitptr = self.bitptr();
+		let data_ptr = bitptr.pointer().a();
+		let (elt_a, bit_a) = bitptr.head().offset(a as isize);
+		let (elt_b, bit_b) = bitptr.head().offset(b as isize);
+		let (elt_a, elt_b) = (&*data_ptr.offset(elt_a), &*data_ptr.offset(elt_b));
+		if elt_a.get::<C>(bit_a) != elt_b.get::<C>(bit_b) {
+			elt_a.invert_bit::<C>(bit_a);
+			elt_b.invert_bit::<C>(bit_b);
+		}
+	}
+
+	/// Swaps two equal-length, non-overlapping ranges of bits in the slice.
+	///
+	/// When both ranges begin at the same bit index within their elements, all
+	/// of the elements they fully span are exchanged whole, and only the
+	/// partial edges are swapped bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `a`: The first range to be swapped.
+	/// - `b`: The second range to be swapped.
+	///
+	/// # Panics
+	///
+	/// Panics if either range is out of bounds, if the ranges differ in length,
+	/// or if the ranges overlap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut store = [0xFFu8, 0, 0x0F];
+	/// let bits = store.bits_mut::<BigEndian>();
+	/// bits.swap_ranges(0 .. 8, 8 .. 16);
+	/// assert_eq!(store, [0, 0xFF, 0x0F]);
+	///
+	/// let bits = store.bits_mut::<BigEndian>();
+	/// bits.swap_ranges(20 .., 2 ..= 5);
+	/// assert_eq!(store, [0b0011_1100, 0xFF, 0]);
+	/// ```
+	pub fn swap_ranges<R1, R2>(&mut self, a: R1, b: R2)
+	where R1: RangeBounds<usize>, R2: RangeBounds<usize> {
+		let len = self.len();
+		let a = resolve_range(a, len);
+		let b = resolve_range(b, len);
+		let count = a.end - a.start;
+		assert!(
+			count == b.end - b.start,
+			"Swapped ranges must have equal lengths: {} != {}",
+			count,
+			b.end - b.start,
+		);
+		let (front, back) = if a.start <= b.start { (a, b) } else { (b, a) };
+		assert!(
+			front.end <= back.start,
+			"Swapped ranges must not overlap: {:?} and {:?}",
+			front,
+			back,
+		);
+		let (left, right) = self.split_at_mut(back.start);
+		let lo = &mut left[front];
+		let hi = &mut right[.. count];
+
+		//  Differently-aligned ranges cannot exchange whole elements.
+		if *lo.bitptr().head() != *hi.bitptr().head() {
+			for n in 0 .. count {
+				unsafe { swap_bits(lo, hi, n) };
+			}
+			return;
+		}
+		let (lead, elts, rest) = split_elements(lo);
+		for n in lead {
+			unsafe { swap_bits(lo, hi, n) };
+		}
+		let elts_lo = &lo.as_total_slice()[elts.clone()];
+		let elts_hi = &hi.as_total_slice()[elts];
+		for (l, h) in elts_lo.iter().zip(elts_hi) {
+			let tmp = l.load();
+			l.store(h.load());
+			h.store(tmp);
+		}
+		for n in rest {
+			unsafe { swap_bits(lo, hi, n) };
+		}
 	}
 
 	/// Reverses the order of bits in the slice, in place.
@@ -1060,6 +1222,51 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Produces an iterator over the number of set bits in each sliding window
+	/// of the slice.
+	///
+	/// The first window is counted in full, a whole element at a time where it
+	/// can be. Each later count is derived from the one before it, by the bit
+	/// that leaves the window and the bit that enters it, in constant time.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `size`: The width of each window.
+	///
+	/// # Returns
+	///
+	/// An iterator which yields, for each window that [`windows`] would yield,
+	/// the number of set bits in it.
+	///
+	/// # Panics
+	///
+	/// This function panics if the `size` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b0100_1011u8.bits::<BigEndian>();
+	/// let counts = bits.windowed_counts(4).collect::<Vec<_>>();
+	/// assert_eq!(counts, [1, 2, 1, 2, 3]);
+	/// ```
+	///
+	/// [`windows`]: #method.windows
+	pub fn windowed_counts(&self, size: usize) -> WindowCounts<C, T> {
+		assert_ne!(size, 0, "Window width cannot be zero");
+		let count = if size > self.len() { 0 } else {
+			self[.. size].count_ones()
+		};
+		WindowCounts {
+			inner: self,
+			width: size,
+			pos: 0,
+			count,
+		}
+	}
+
 	/// Produces a galloping iterator over consecutive chunks in the slice. Each
 	/// chunk, except possibly the last, has the width `size`. The chunks do not
 	/// overlap. If the slice is shorter than `size`, the produced iterator
@@ -1398,6 +1605,51 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Divides the slice into some number of contiguous parts, as nearly equal
+	/// in length as possible.
+	///
+	/// The parts differ in length by at most one bit, and the longer parts come
+	/// first. This is a convenient way to hand out even shares of a slice to
+	/// worker threads, or to write it out in a fixed number of pieces.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `parts`: The number of parts to produce.
+	///
+	/// # Returns
+	///
+	/// An iterator which yields exactly `parts` consecutive, non-overlapping
+	/// sub-slices, which together cover the whole slice. If the slice has fewer
+	/// than `parts` bits, then the trailing parts are empty.
+	///
+	/// # Panics
+	///
+	/// This function panics if `parts` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0u16.bits::<BigEndian>();
+	/// let mut parts = bits.split_into(3);
+	/// assert_eq!(parts.next(), Some(&bits[0 .. 6]));
+	/// assert_eq!(parts.next(), Some(&bits[6 .. 11]));
+	/// assert_eq!(parts.next(), Some(&bits[11 .. 16]));
+	/// assert!(parts.next().is_none());
+	///
+	/// let lens = bits[.. 2].split_into(4).map(|p| p.len()).collect::<Vec<_>>();
+	/// assert_eq!(lens, [1, 1, 0, 0]);
+	/// ```
+	pub fn split_into(&self, parts: usize) -> SplitInto<C, T> {
+		assert_ne!(parts, 0, "Cannot split into zero parts");
+		SplitInto {
+			inner: self,
+			parts,
+		}
+	}
+
 	/// Divides one slice into two at an index.
 	///
 	/// The first will contain all indices from `[0, mid)` (excluding the index
@@ -1472,6 +1724,164 @@ where C: Cursor, T: BitStore {
 		(head.bitptr().into_bitslice_mut(), tail.bitptr().into_bitslice_mut())
 	}
 
+	/// Divides one slice into two at an index, without checking the index.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `mid`: The index at which to split
+	///
+	/// # Returns
+	///
+	/// - The bits up to but not including `mid`.
+	/// - The bits from mid onwards.
+	///
+	/// # Safety
+	///
+	/// `mid` must not be greater than `self.len()`. Splitting past the end
+	/// produces slices that extend beyond the original memory.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// let (l, r) = unsafe { bits.split_at_mut_unchecked(3) };
+	/// l.set(0, true);
+	/// r.set(0, true);
+	/// assert_eq!(src, 0b1001_0000);
+	/// ```
+	pub unsafe fn split_at_mut_unchecked(&mut self, mid: usize)
+	-> (&mut Self, &mut Self) {
+		let bp = self.bitptr();
+		let (data, head, len) = (bp.pointer(), bp.head(), bp.len());
+		let (skip, new_head) = head.offset(mid as isize);
+		let tail = BitPtr::new_unchecked(data.r().offset(skip), new_head, len - mid);
+		let head = BitPtr::new_unchecked(data.r(), head, mid);
+		(head.into_bitslice_mut(), tail.into_bitslice_mut())
+	}
+
+	/// Splits the slice into a bit prefix, a run of whole `U` words, and a bit
+	/// suffix.
+	///
+	/// This is the bit-slice counterpart of the standard library’s
+	/// `align_to_mut`. The middle is the longest run of `U`-aligned memory that
+	/// the slice exclusively covers, and can be processed a word at a time. The
+	/// prefix and suffix are the bits before and after it, and never share an
+	/// element with the middle.
+	///
+	/// The middle words are raw memory: their bits are in the order the
+	/// processor stores them, not in the order of the cursor `C`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// - The bits before the middle words.
+	/// - The words that the slice fully covers, viewed as `U`.
+	/// - The bits after the middle words.
+	///
+	/// # Type Parameters
+	///
+	/// - `U: BitStore`: The word type of the middle section.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = [0u8; 8];
+	/// let bits = &mut src.bits_mut::<BigEndian>()[4 .. 60];
+	/// let (prefix, words, suffix) = bits.align_to_mut::<u16>();
+	/// assert_eq!(
+	///     prefix.len() + words.len() * 16 + suffix.len(),
+	///     56,
+	/// );
+	/// for word in words.iter_mut() {
+	///     *word = !0;
+	/// }
+	/// prefix.set_all(true);
+	/// suffix.set_all(true);
+	/// assert_eq!(src, [0x0F, !0, !0, !0, !0, !0, !0, 0xF0]);
+	/// ```
+	pub fn align_to_mut<U>(&mut self) -> (&mut Self, &mut [U], &mut Self)
+	where U: BitStore {
+		let head = *self.bitptr().head() as usize;
+		let base = self.bitptr().pointer().u() as usize;
+		let body = self.as_mut_slice();
+		if body.is_empty() {
+			return (self, &mut [], Self::empty_mut());
+		}
+		let start = body.as_ptr() as usize;
+		//  The body elements are exclusively owned, so reinterpreting them as
+		//  any other fundamental integer type is sound.
+		let (pre, mid, _) = unsafe { body.align_to_mut::<U>() };
+		let front = ((start - base) / mem::size_of::<T>() + pre.len())
+			* T::BITS as usize - head;
+		let words = mid.len() * U::BITS as usize;
+		let mid = mid as *mut [U];
+		let (prefix, rest) = unsafe { self.split_at_mut_unchecked(front) };
+		let (_, suffix) = rest.split_at_mut(words);
+		(prefix, unsafe { &mut *mid }, suffix)
+	}
+
+	/// Finds where a sub-slice of this slice begins.
+	///
+	/// This compares the memory locations of the two slices, not their
+	/// contents, so it reports the position from which `child` was actually
+	/// sliced, even when the same bits appear elsewhere in `self`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `child`: A slice that may have been produced by slicing `self`.
+	///
+	/// # Returns
+	///
+	/// The index in `self` of the first bit of `child`, if `child` lies
+	/// entirely within `self`. If `child` views other memory, or extends beyond
+	/// either end of `self`, this returns `None`.
+	///
+	/// Empty slices do not always keep the address from which they were cut,
+	/// so an empty `child` may produce `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0u8; 4];
+	/// let bits = src.bits::<BigEndian>();
+	/// let field = &bits[10 .. 20][3 .. 7];
+	/// assert_eq!(bits.subslice_offset(field), Some(13));
+	/// assert_eq!(bits[4 ..].subslice_offset(field), Some(9));
+	/// assert_eq!(bits[15 ..].subslice_offset(field), None);
+	/// assert_eq!(bits[.. 16].subslice_offset(field), None);
+	///
+	/// let other = [0u8; 4];
+	/// assert_eq!(other.bits::<BigEndian>().subslice_offset(field), None);
+	/// ```
+	pub fn subslice_offset(&self, child: &Self) -> Option<usize> {
+		let (parent, sub) = (self.bitptr(), child.bitptr());
+		let (base, addr) = (parent.pointer().u(), sub.pointer().u());
+		if addr < base {
+			return None;
+		}
+		let elts = (addr - base) / mem::size_of::<T>();
+		let offset = ((elts << T::INDX) + *sub.head() as usize)
+			.checked_sub(*parent.head() as usize)?;
+		if offset + sub.len() <= parent.len() {
+			Some(offset)
+		}
+		else {
+			None
+		}
+	}
+
 	/// Tests if the slice begins with the given prefix.
 	///
 	/// # Parameters
@@ -1529,68 +1939,216 @@ where C: Cursor, T: BitStore {
 		len >= slen && suffix == self[len - slen ..]
 	}
 
-	/// Rotates the slice, in place, to the left.
-	///
-	/// After calling this method, the bits from `[.. by]` will be at the back
-	/// of the slice, and the bits from `[by ..]` will be at the front. This
-	/// operates fully in-place.
+	/// Finds the first occurrence of a pattern in the slice.
 	///
-	/// In-place rotation of bits requires this method to take `O(k × n)` time.
-	/// It is impossible to use machine intrinsics to perform galloping rotation
-	/// on bits.
+	/// Only the indices at which the pattern’s first bit occurs are tested,
+	/// and runs of elements that cannot hold that bit are skipped whole. This
+	/// suits searches for a sync word, which usually begins with a bit that
+	/// is uncommon in the data around it.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `by`: The number of bits by which to rotate left. This must be in the
-	///   range `0 ..= self.len()`. If it is `0` or `self.len()`, then this
-	///   method is a no-op.
+	/// - `&self`
+	/// - `pattern`: Any `SliceBit` to search for. This is not required to have
+	///   the same cursor or storage types as `self`.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit of the first occurrence of `pattern` in
+	/// `self`, if there is one. The empty pattern occurs at index zero.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = 0xF0u8;
-	/// let bits = src.bits_mut::<BigEndian>();
-	/// bits.rotate_left(2);
-	/// assert_eq!(bits.as_ref()[0], 0xC3);
+	/// let data = [0x00u8, 0x07, 0xE0, 0x7E];
+	/// let sync = 0x7Eu8;
+	/// let bits = data.bits::<BigEndian>();
+	/// assert_eq!(bits.find(sync.bits::<BigEndian>()), Some(12));
+	/// assert_eq!(bits[14 ..].find(sync.bits::<BigEndian>()), Some(10));
+	/// assert_eq!(bits[.. 19].find(sync.bits::<BigEndian>()), None);
 	/// ```
-	pub fn rotate_left(&mut self, by: usize) {
+	pub fn find<D, U>(&self, pattern: &SliceBit<D, U>) -> Option<usize>
+	where D: Cursor, U: BitStore {
+		let first = match pattern.first() {
+			Some(bit) => bit,
+			None => return Some(0),
+		};
 		let len = self.len();
-		assert!(by <= len, "Slices cannot be rotated by more than their length");
-		if by == 0 || by == len {
-			return;
-		}
-
-		for _ in 0 .. by {
-			let tmp = self[0];
-			for n in 1 .. len {
-				let bit = self[n];
-				self.set(n - 1, bit);
+		let mut n = self.seek(0, first);
+		while n + pattern.len() <= len {
+			if self[n ..].starts_with(pattern) {
+				return Some(n);
 			}
-			self.set(len - 1, tmp);
+			n = self.seek(n + 1, first);
 		}
+		None
 	}
 
-	/// Rotates the slice, in place, to the right.
-	///
-	/// After calling this method, the bits from `[self.len() - by ..]` will be
-	/// at the front of the slice, and the bits from `[.. self.len() - by]` will
-	/// be at the back. This operates fully in-place.
+	/// Tests whether the slice is a cyclic rotation of another.
 	///
-	/// In-place rotation of bits requires this method to take `O(k × n)` time.
-	/// It is impossible to use machine intrinsics to perform galloping rotation
-	/// on bits.
+	/// Slices with different lengths or numbers of set bits are rejected
+	/// without searching. Otherwise, `self` is sought in `other` joined to
+	/// itself, with [`find`].
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `by`: The number of bits by which to rotate right. This must be in the
-	///   range `0 ..= self.len()`. If it is `0` or `self.len`, then this method
-	///   is a no-op.
+	/// - `&self`
+	/// - `other`: Any `SliceBit`. This is not required to have the same cursor
+	///   or storage types as `self`.
 	///
-	/// # Examples
+	/// # Returns
+	///
+	/// Whether some rotation of `other` has the same bits as `self`. Every
+	/// slice, including the empty slice, is a rotation of itself.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let necklace = vecbit![1, 1, 0, 1, 0, 0];
+	/// assert!(vecbit![0, 1, 0, 0, 1, 1].is_rotation_of(&necklace));
+	/// assert!(!vecbit![1, 1, 1, 0, 0, 0].is_rotation_of(&necklace));
+	/// # }
+	/// ```
+	///
+	/// [`find`]: #method.find
+	#[cfg(feature = "alloc")]
+	pub fn is_rotation_of<D, U>(&self, other: &SliceBit<D, U>) -> bool
+	where D: Cursor, U: BitStore {
+		let len = self.len();
+		if len != other.len() || self.count_ones() != other.count_ones() {
+			return false;
+		}
+		if len == 0 {
+			return true;
+		}
+		let doubled = other.iter()
+			.chain(other[.. len - 1].iter())
+			.collect::<VecBit<D, U>>();
+		doubled.find(self).is_some()
+	}
+
+	/// Finds the rotation that makes the slice lexicographically least.
+	///
+	/// Cleared bits order before set bits. Rotating a cyclic pattern by this
+	/// amount gives it a canonical form, which is the same for every rotation
+	/// of the pattern, and so can be compared or hashed. This takes linear
+	/// time and constant space.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The least `by` such that `self.rotate_left(by)` produces the least
+	/// rotation of `self`. This is zero for the empty slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0b1101_0011u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// assert_eq!(bits.min_rotation(), 4);
+	/// bits.rotate_left(bits.min_rotation());
+	/// assert_eq!(src, 0b0011_1101);
+	/// ```
+	pub fn min_rotation(&self) -> usize {
+		let len = self.len();
+		let at = |n: usize| self[if n >= len { n - len } else { n }];
+		//  `one` and `two` are the two best candidates so far, and `same` is
+		//  the length of their common prefix. When a candidate loses a
+		//  comparison at offset `same`, neither it nor the `same` indices
+		//  after it can begin the least rotation.
+		let (mut one, mut two, mut same) = (0, 1, 0);
+		while one < len && two < len && same < len {
+			let (lhs, rhs) = (at(one + same), at(two + same));
+			if lhs == rhs {
+				same += 1;
+				continue;
+			}
+			if lhs {
+				one += same + 1;
+			}
+			else {
+				two += same + 1;
+			}
+			if one == two {
+				two += 1;
+			}
+			same = 0;
+		}
+		one.min(two)
+	}
+
+	/// Rotates the slice, in place, to the left.
+	///
+	/// After calling this method, the bits from `[.. by]` will be at the back
+	/// of the slice, and the bits from `[by ..]` will be at the front. This
+	/// operates fully in-place.
+	///
+	/// In-place rotation of bits requires this method to take `O(k × n)` time.
+	/// It is impossible to use machine intrinsics to perform galloping rotation
+	/// on bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `by`: The number of bits by which to rotate left. This must be in the
+	///   range `0 ..= self.len()`. If it is `0` or `self.len()`, then this
+	///   method is a no-op.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0xF0u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// bits.rotate_left(2);
+	/// assert_eq!(bits.as_ref()[0], 0xC3);
+	/// ```
+	pub fn rotate_left(&mut self, by: usize) {
+		let len = self.len();
+		assert!(by <= len, "Slices cannot be rotated by more than their length");
+		if by == 0 || by == len {
+			return;
+		}
+
+		for _ in 0 .. by {
+			let tmp = self[0];
+			for n in 1 .. len {
+				let bit = self[n];
+				self.set(n - 1, bit);
+			}
+			self.set(len - 1, tmp);
+		}
+	}
+
+	/// Rotates the slice, in place, to the right.
+	///
+	/// After calling this method, the bits from `[self.len() - by ..]` will be
+	/// at the front of the slice, and the bits from `[.. self.len() - by]` will
+	/// be at the back. This operates fully in-place.
+	///
+	/// In-place rotation of bits requires this method to take `O(k × n)` time.
+	/// It is impossible to use machine intrinsics to perform galloping rotation
+	/// on bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `by`: The number of bits by which to rotate right. This must be in the
+	///   range `0 ..= self.len()`. If it is `0` or `self.len`, then this method
+	///   is a no-op.
+	///
+	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
@@ -1870,722 +2428,576 @@ where C: Cursor, T: BitStore {
 		self.any() && self.not_all()
 	}
 
-	/// Counts how many bits are set high.
+	/// Tests if *all* bits in a range of the slice are set.
+	///
+	/// This is equivalent to `self[range].all()`, and similarly tests whole
+	/// elements at a time, stopping at the first element with an unset bit.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `range`: The range of indices to test.
 	///
 	/// # Returns
 	///
-	/// The number of high bits in the slice domain.
+	/// Whether all bits in `range` are set. An empty range returns `true`.
+	///
+	/// # Panics
+	///
+	/// Panics if `range` is out of bounds.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = [0xFDu8, 0x25].bits::<BigEndian>();
-	/// assert_eq!(bits.count_ones(), 10);
+	/// let bits = [0x3Fu8, 0xFF].bits::<BigEndian>();
+	/// assert!(bits.all_in(2 ..));
+	/// assert!(!bits.all_in(1 ..= 4));
 	/// ```
-	pub fn count_ones(&self) -> usize {
-		match self.bitptr().domain() {
-			BitDomain::Empty => 0,
-			BitDomain::Minor(head, elt, tail) => {
-				(*head .. *tail)
-					.map(|n| elt.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
-			},
-			BitDomain::Major(h, head, body, tail, t) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count() +
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_ones)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
-			},
-			BitDomain::PartialHead(h, head, body) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count() +
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_ones)
-					.sum::<usize>()
-			},
-			BitDomain::PartialTail(body, tail, t) => {
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_ones)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| *b)
-					.count()
-			},
-			BitDomain::Spanning(body) => {
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_ones)
-					.sum::<usize>()
-			}
-		}
+	pub fn all_in<R>(&self, range: R) -> bool
+	where R: RangeBounds<usize> {
+		self[resolve_range(range, self.len())].all()
 	}
 
-	/// Counts how many bits are set low.
+	/// Tests if *any* bit in a range of the slice is set.
+	///
+	/// This is equivalent to `self[range].any()`, and similarly tests whole
+	/// elements at a time, stopping at the first element with a set bit.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `range`: The range of indices to test.
 	///
 	/// # Returns
 	///
-	/// The number of low bits in the slice domain.
+	/// Whether any bit in `range` is set. An empty range returns `false`.
+	///
+	/// # Panics
+	///
+	/// Panics if `range` is out of bounds.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = [0xFDu8, 0x25].bits::<BigEndian>();
-	/// assert_eq!(bits.count_zeros(), 6);
+	/// let bits = [0u8, 0x01, 0].bits::<BigEndian>();
+	/// assert!(bits.any_in(4 .. 20));
+	/// assert!(!bits.any_in(.. 15));
 	/// ```
-	pub fn count_zeros(&self) -> usize {
-		match self.bitptr().domain() {
-			BitDomain::Empty => 0,
-			BitDomain::Minor(head, elt, tail) => {
-				(*head .. *tail)
-					.map(|n| !elt.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
-			},
-			BitDomain::Major(h, head, body, tail, t) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count() +
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_zeros)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
-			},
-			BitDomain::PartialHead(h, head, body) => {
-				(*h .. T::BITS)
-					.map(|n| head.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count() +
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_zeros)
-					.sum::<usize>()
-			},
-			BitDomain::PartialTail(body, tail, t) => {
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_zeros)
-					.sum::<usize>() +
-				(0 .. *t)
-					.map(|n| tail.get::<C>(n.idx()))
-					.filter(|b| !*b)
-					.count()
-			},
-			BitDomain::Spanning(body) => {
-				body.iter()
-					.map(BitAccess::<T>::load)
-					.map(T::count_zeros)
-					.sum::<usize>()
-			},
-		}
+	pub fn any_in<R>(&self, range: R) -> bool
+	where R: RangeBounds<usize> {
+		self[resolve_range(range, self.len())].any()
 	}
 
-	/// Set all bits in the slice to a value.
+	/// Tests if *all* bits in a range of the slice are unset.
+	///
+	/// This is equivalent to `self[range].not_any()`.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `value`: The bit value to which all bits in the slice will be set.
+	/// - `&self`
+	/// - `range`: The range of indices to test.
+	///
+	/// # Returns
+	///
+	/// Whether all bits in `range` are unset. An empty range returns `true`.
+	///
+	/// # Panics
+	///
+	/// Panics if `range` is out of bounds.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// let bits = src.bits_mut::<BigEndian>();
-	/// bits[2 .. 6].set_all(true);
-	/// assert_eq!(bits.as_ref(), &[0b0011_1100]);
-	/// bits[3 .. 5].set_all(false);
-	/// assert_eq!(bits.as_ref(), &[0b0010_0100]);
-	/// bits[.. 1].set_all(true);
-	/// assert_eq!(bits.as_ref(), &[0b1010_0100]);
+	/// let bits = [0u8, 0x01, 0].bits::<BigEndian>();
+	/// assert!(bits.not_any_in(16 ..));
+	/// assert!(!bits.not_any_in(8 .. 16));
 	/// ```
-	pub fn set_all(&mut self, value: bool) {
-		match self.bitptr().domain() {
-			BitDomain::Empty => {},
-			BitDomain::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					elt.set::<C>(n.idx(), value);
-				}
-			},
-			BitDomain::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					head.set::<C>(n.idx(), value);
-				}
-				for elt in body {
-					elt.store(T::bits(value));
-				}
-				for n in 0 .. *t {
-					tail.set::<C>(n.idx(), value);
-				}
-			},
-			BitDomain::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					head.set::<C>(n.idx(), value);
-				}
-				for elt in body {
-					elt.store(T::bits(value));
-				}
-			},
-			BitDomain::PartialTail(body, tail, t) => {
-				for elt in body {
-					elt.store(T::bits(value));
-				}
-				for n in 0 .. *t {
-					tail.set::<C>(n.idx(), value);
-				}
-			},
-			BitDomain::Spanning(body) => {
-				for elt in body {
-					elt.store(T::bits(value));
-				}
-			},
-		}
+	pub fn not_any_in<R>(&self, range: R) -> bool
+	where R: RangeBounds<usize> {
+		!self.any_in(range)
 	}
 
-	/// Provides mutable traversal of the collection.
+	/// Counts the places where adjacent bits in the slice differ.
 	///
-	/// It is impossible to implement `IndexMut` on `SliceBit`, because bits do
-	/// not have addresses, so there can be no `&mut u1`. This method allows the
-	/// client to receive an enumerated bit, and provide a new bit to set at
-	/// each index.
+	/// For the `BigEndian` and `LittleEndian` cursors, each fully spanned
+	/// element is counted at once, by comparing it with itself shifted by one
+	/// bit and counting the differences.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `func`: A function which receives a `(usize, bool)` pair of index and
-	///   value, and returns a bool. It receives the bit at each position, and
-	///   the return value is written back at that position.
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of indices `n` for which `self[n - 1] != self[n]`. A slice
+	/// with at most one transition is monotone: its bits are all cleared, then
+	/// all set, or the reverse.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// {
-	///  let bits = src.bits_mut::<BigEndian>();
-	///  bits.for_each(|idx, _bit| idx % 3 == 0);
-	/// }
-	/// assert_eq!(src, 0b1001_0010);
+	/// let bits = [0b0011_1000u8, 0b0000_0001].bits::<BigEndian>();
+	/// assert_eq!(bits.count_transitions(), 3);
+	/// assert_eq!(bits[.. 4].count_transitions(), 1);
+	/// assert_eq!(bits[5 .. 15].count_transitions(), 0);
 	/// ```
-	pub fn for_each<F>(&mut self, func: F)
-	where F: Fn(usize, bool) -> bool {
-		for idx in 0 .. self.len() {
-			let tmp = self[idx];
-			self.set(idx, func(idx, tmp));
+	pub fn count_transitions(&self) -> usize {
+		let (lead, elts, rest) = split_elements(self);
+		let stride = Stride::of::<C, T>();
+		let last = *C::mask::<T>(T::MASK.idx());
+
+		let mut count = 0;
+		//  The bit before the current position, if any.
+		let mut prev = None;
+		let step = |bit: bool, prev: &mut Option<bool>| {
+			prev.replace(bit).map(|p| (p != bit) as usize).unwrap_or(0)
+		};
+		for n in lead {
+			count += step(unsafe { self.get_unchecked(n) }, &mut prev);
+		}
+		for elt in &self.as_total_slice()[elts] {
+			let elt = elt.load();
+			count += step(elt.get::<C>(0u8.idx()), &mut prev);
+			match stride {
+				//  Each bit is compared with its successor in one pass. The
+				//  last bit has no successor in the element, and is dropped.
+				Some(stride) => {
+					count += ((elt ^ stride.to_front(elt, 1)) & !last).count_ones();
+					prev = Some(elt.get::<C>(T::MASK.idx()));
+				},
+				None => for n in 1 .. T::BITS {
+					count += step(elt.get::<C>(n.idx()), &mut prev);
+				},
+			}
+		}
+		for n in rest {
+			count += step(unsafe { self.get_unchecked(n) }, &mut prev);
 		}
+		count
 	}
 
-	/// Performs “reverse” addition (left to right instead of right to left).
+	/// Tests if the slice is sorted, with all cleared bits before all set
+	/// bits.
 	///
-	/// This addition interprets the slice, and the other addend, as having its
-	/// least significant bits first in the order and its most significant bits
-	/// last. This is most likely to be numerically useful under a
-	/// `LittleEndian` `Cursor` type.
+	/// This is the order produced by [`sort_bits`]. To test for either order,
+	/// use `self.count_transitions() <= 1`.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`: The addition uses `self` as one addend, and writes the
-	///   sum back into `self`.
-	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits. When this is
-	///   another `SliceBit`, iteration proceeds from left to right.
-	///
-	/// # Return
-	///
-	/// The final carry bit is returned
-	///
-	/// # Effects
+	/// - `&self`
 	///
-	/// Starting from index `0` and proceeding upwards until either `self` or
-	/// `addend` expires, the carry-propagated addition of `self[i]` and
-	/// `addend[i]` is written to `self[i]`.
+	/// # Returns
 	///
-	/// ```text
-	///   101111
-	/// + 0010__ (the two missing bits are logically zero)
-	/// --------
-	///   100000 1 (the carry-out is returned)
-	/// ```
+	/// Whether no set bit in the slice precedes a cleared bit. The empty slice
+	/// is sorted.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut a = 0b0000_1010u8;
-	/// let     b = 0b0000_1100u8;
-	/// //      s =      1 0110
-	/// let ab = &mut a.bits_mut::<LittleEndian>()[.. 4];
-	/// let bb = &    b.bits::<LittleEndian>()[.. 4];
-	/// let c = ab.add_assign_reverse(bb);
-	/// assert!(c);
-	/// assert_eq!(a, 0b0000_0110u8);
+	/// let bits = 0b0001_1110u8.bits::<LittleEndian>();
+	/// assert!(bits[.. 5].is_sorted());
+	/// assert!(!bits.is_sorted());
 	/// ```
 	///
-	/// # Performance Notes
-	///
-	/// When using `LittleEndian` `Cursor` types, this can be accelerated by
-	/// delegating the addition to the underlying types. This is a software
-	/// implementation of the [ripple-carry adder], which has `O(n)` runtime in
-	/// the number of bits. The CPU is much faster, as it has access to
-	/// element-wise or vectorized addition operations.
-	///
-	/// If your use case sincerely needs binary-integer arithmetic operations on
-	/// bit sets
-	///
-	/// [ripple-carry adder]: https://en.wikipedia.org/wiki/Ripple-carry_adder
-	pub fn add_assign_reverse<I>(&mut self, addend: I) -> bool
-	where I: IntoIterator<Item=bool> {
-		//  See AddAssign::add_assign for algorithm details
-		let mut c = false;
-		let len = self.len();
-		let zero = core::iter::repeat(false);
-		for (i, b) in addend.into_iter().chain(zero).enumerate().take(len) {
-			//  The iterator is clamped to the upper bound of `self`.
-			let a = unsafe { self.get_unchecked(i) };
-			let (y, z) = crate::rca1(a, b, c);
-			//  Write the sum into `self`
-			unsafe { self.set_unchecked(i, y); }
-			//  Propagate the carry
-			c = z;
+	/// [`sort_bits`]: #method.sort_bits
+	pub fn is_sorted(&self) -> bool {
+		match self.count_transitions() {
+			0 => true,
+			1 => !self[0],
+			_ => false,
 		}
-		c
 	}
 
-	/// Accesses the backing storage of the `SliceBit` as a slice of its
-	/// elements.
+	/// Tests if every bit set in the slice is also set in another (`A ⊆ B`).
 	///
-	/// This will not include partially-owned edge elements, as they may be
-	/// contended by other slice handles.
+	/// The two slices are treated as sets of indices, so bits beyond the end
+	/// of the shorter slice are treated as cleared. When both slices begin at
+	/// the same bit index within their elements, they are compared a whole
+	/// element at a time. The test stops at the first bit that fails.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `other`: The potential superset of `self`.
 	///
 	/// # Returns
 	///
-	/// A slice of all the elements that the `SliceBit` uses for storage.
+	/// Whether `self` is a subset of `other`. The empty slice is a subset of
+	/// every slice.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let src = [1u8, 66];
-	/// let bits = src.bits::<BigEndian>();
-	///
-	/// let accum = bits.as_slice()
-	///   .iter()
-	///   .map(|elt| elt.count_ones())
-	///   .sum::<u32>();
-	/// assert_eq!(accum, 3);
+	/// let a = 0b0100_0100u8;
+	/// let b = 0b0110_0101u8;
+	/// let (a, b) = (a.bits::<BigEndian>(), b.bits::<BigEndian>());
+	/// assert!(a.is_subset(b));
+	/// assert!(!b.is_subset(a));
+	/// assert!(a[.. 2].is_subset(&b[.. 4]));
+	/// assert!(!a[.. 6].is_subset(&b[.. 1]));
 	/// ```
-	pub fn as_slice(&self) -> &[T] {
-		&* unsafe { BitAccess::as_slice_mut(match self.bitptr().domain() {
-			| BitDomain::Empty
-			| BitDomain::Minor(_, _, _) => &[],
-			| BitDomain::PartialHead(_, _, body)
-			| BitDomain::PartialTail(body, _, _)
-			| BitDomain::Major(_, _, body, _, _)
-			| BitDomain::Spanning(body) => body,
-		}) }
+	pub fn is_subset(&self, other: &Self) -> bool {
+		use core::cmp::min;
+		let len = min(self.len(), other.len());
+		self[len ..].not_any() && all_zipped(
+			&self[.. len],
+			&other[.. len],
+			|a, b| !a || b,
+			|a, b| a & !b == T::bits(false),
+		)
 	}
 
-	/// Accesses the underlying store.
+	/// Tests if every bit set in another slice is also set in the slice
+	/// (`A ⊇ B`).
 	///
-	/// This will not include partially-owned edge elements, as they may be
-	/// contended by other slice handles.
+	/// This is [`is_subset`] with the operands exchanged.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The potential subset of `self`.
+	///
+	/// # Returns
+	///
+	/// Whether `self` is a superset of `other`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = [1u8, 64];
-	/// let bits = src.bits_mut::<BigEndian>();
-	/// for elt in bits.as_mut_slice() {
-	///   *elt |= 2;
-	/// }
-	/// assert_eq!(&[3, 66], bits.as_slice());
+	/// let a = [0xFFu8, 0x81];
+	/// let b = [0x18u8, 0x01];
+	/// let (a, b) = (a.bits::<LittleEndian>(), b.bits::<LittleEndian>());
+	/// assert!(a.is_superset(b));
+	/// assert!(!b.is_superset(a));
 	/// ```
-	pub fn as_mut_slice(&mut self) -> &mut [T] {
-		unsafe { BitAccess::as_slice_mut(match self.bitptr().domain() {
-			| BitDomain::Empty
-			| BitDomain::Minor(_, _, _) => &[],
-			| BitDomain::PartialHead(_, _, body)
-			| BitDomain::PartialTail(body, _, _)
-			| BitDomain::Major(_, _, body, _, _)
-			| BitDomain::Spanning(body) => body,
-		}) }
+	///
+	/// [`is_subset`]: #method.is_subset
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
 	}
 
-	/// Accesses the underlying store, including contended partial elements.
+	/// Tests if the slice and another have no set bits in common
+	/// (`A ∩ B = ∅`).
 	///
-	/// This produces a slice of element wrappers that permit shared mutation,
-	/// rather than a slice of the bare `T` fundamentals.
+	/// The two slices are treated as sets of indices, so bits beyond the end
+	/// of the shorter slice cannot be in common. When both slices begin at the
+	/// same bit index within their elements, they are compared a whole element
+	/// at a time. The test stops at the first common bit.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `other`: The slice to test against `self`.
 	///
 	/// # Returns
 	///
-	/// A slice of all elements under the bit span, including any
-	/// partially-owned edge elements, wrapped in safe shared-mutation types.
-	pub fn as_total_slice(&self) -> &[T::Access] {
-		self.bitptr().as_access_slice()
+	/// Whether no index is set in both `self` and `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let a = [0xF0u8, 0x0F, 0xFF];
+	/// let b = [0x0Fu8, 0xF0];
+	/// let (a, b) = (a.bits::<BigEndian>(), b.bits::<BigEndian>());
+	/// assert!(a.is_disjoint(b));
+	/// assert!(!a.is_disjoint(&b[4 ..]));
+	/// ```
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		use core::cmp::min;
+		let len = min(self.len(), other.len());
+		all_zipped(
+			&self[.. len],
+			&other[.. len],
+			|a, b| !(a && b),
+			|a, b| a & b == T::bits(false),
+		)
 	}
 
-	/// Accesses the underlying pointer structure.
+	/// Tests if the slice and another agree at every index set in a mask.
+	///
+	/// Indices cleared in the mask are “don’t care” positions, whose bits are
+	/// not compared. This is the match test of a ternary content-addressable
+	/// memory, and compares protocol fields with wildcarded subfields. When
+	/// all three slices begin at the same bit index within their elements,
+	/// they are compared a whole element at a time. The test stops at the
+	/// first mismatch.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `other`: The slice to compare against `self`.
+	/// - `mask`: The indices at which `self` and `other` must agree. Indices
+	///   beyond the end of `mask` are not compared.
 	///
 	/// # Returns
 	///
-	/// The [`BitPtr`] structure of the slice handle.
-	///
-	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
-	pub(crate) fn bitptr(&self) -> BitPtr<T> {
-		BitPtr::from_bitslice(self)
-	}
-}
-
-/// Creates an owned `VecBit<C, T>` from a borrowed `SliceBit<C, T>`.
-#[cfg(feature = "alloc")]
-impl<C, T> ToOwned for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Owned = VecBit<C, T>;
-
-	/// Clones a borrowed `SliceBit` into an owned `VecBit`.
+	/// Whether `self` and `other` have the same length, and the same bit at
+	/// every index set in `mask`.
 	///
 	/// # Examples
 	///
 	/// ```rust
-	/// # #[cfg(feature = "alloc")] {
 	/// use vecbit::prelude::*;
 	///
-	/// let store = [0u8, 2];
-	/// let src = store.bits::<LittleEndian>();
-	/// let dst = src.to_owned();
-	/// assert_eq!(src, dst);
-	/// # }
+	/// let key = 0b1010_0110u8;
+	/// let entry = 0b1011_0001u8;
+	/// let care = 0b1110_0000u8;
+	/// let key = key.bits::<BigEndian>();
+	/// let entry = entry.bits::<BigEndian>();
+	/// assert!(key.eq_ignoring(entry, care.bits()));
+	/// assert!(!key.eq_ignoring(entry, 0xF0u8.bits()));
+	/// assert!(key[4 ..].eq_ignoring(&entry[4 ..], &care.bits()[.. 1]));
+	/// assert!(!key.eq_ignoring(&entry[1 ..], care.bits()));
 	/// ```
-	fn to_owned(&self) -> Self::Owned {
-		Self::Owned::from_bitslice(self)
-	}
-}
-
-impl<C, T> Eq for SliceBit<C, T>
-where C: Cursor, T: BitStore {}
-
-impl<C, T> Ord for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	fn cmp(&self, rhs: &Self) -> Ordering {
-		self.partial_cmp(rhs)
-			.unwrap_or_else(|| unreachable!("`SliceBit` has a total ordering"))
+	pub fn eq_ignoring(&self, other: &Self, mask: &Self) -> bool {
+		use core::cmp::min;
+		let len = self.len();
+		if len != other.len() {
+			return false;
+		}
+		let care = min(len, mask.len());
+		all_zipped3(
+			&self[.. care],
+			&other[.. care],
+			&mask[.. care],
+			|a, b, m| !m || a == b,
+			|a, b, m| (a ^ b) & m == T::bits(false),
+		)
 	}
-}
-
-/** Tests if two `SliceBit`s are semantically — not bitwise — equal.
-
-It is valid to compare two slices of different cursor or element types.
 
-The equality condition requires that they have the same number of total bits and
-that each pair of bits in semantic order are identical.
-**/
-impl<A, B, C, D> PartialEq<SliceBit<C, D>> for SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	/// Performas a comparison by `==`.
+	/// Compares the slice and another as unsigned integers.
+	///
+	/// This uses the significance of the arithmetic operators: the first bit
+	/// of a slice is its most significant, and the last is its least. The
+	/// shorter slice is zero-extended at the front, so `0011` and `11` are
+	/// equal. This differs from `Ord`, which compares slices bit by bit from
+	/// the front, and so sorts `11` after `0111`.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `rhs`: Another `SliceBit` against which to compare. This slice can
-	///   have different cursor or storage types.
+	/// - `other`: The slice to compare against `self`. It may have different
+	///   cursor or storage types.
 	///
 	/// # Returns
 	///
-	/// If the two slices are equal, by comparing the lengths and bit values at
-	/// each semantic index.
+	/// The ordering of the number that `self` represents against the number
+	/// that `other` represents.
+	///
+	/// # Type Parameters
+	///
+	/// - `D: Cursor`: The cursor type of `other`.
+	/// - `U: BitStore`: The storage type of `other`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
+	/// use core::cmp::Ordering;
 	///
-	/// let lsrc = [8u8, 16, 32, 0];
-	/// let rsrc = [0x10_08_04_00u32];
-	/// let lbits = lsrc.bits::<LittleEndian>();
-	/// let rbits = rsrc.bits::<BigEndian>();
-	///
-	/// assert_eq!(lbits, rbits);
+	/// let bits = 0b0111_0011u8.bits::<BigEndian>();
+	/// let (seven, three) = (&bits[.. 4], &bits[6 ..]);
+	/// assert!(three > seven);
+	/// assert_eq!(three.cmp_numeric(seven), Ordering::Less);
+	/// assert_eq!(bits[4 ..].cmp_numeric(three), Ordering::Equal);
 	/// ```
-	fn eq(&self, rhs: &SliceBit<C, D>) -> bool {
-		if self.len() != rhs.len() {
-			return false;
+	pub fn cmp_numeric<D, U>(&self, other: &SliceBit<D, U>) -> Ordering
+	where D: Cursor, U: BitStore {
+		let (l, r) = (self.len(), other.len());
+		let (l_high, l_low) = self.split_at(l.saturating_sub(r));
+		let (r_high, r_low) = other.split_at(r.saturating_sub(l));
+		//  Only the longer slice has bits above the width of the shorter.
+		if l_high.any() {
+			return Ordering::Greater;
+		}
+		if r_high.any() {
+			return Ordering::Less;
 		}
-		self.iter().zip(rhs.iter()).all(|(l, r)| l == r)
+		l_low.partial_cmp(r_low)
+			.unwrap_or_else(|| unreachable!("`SliceBit` has a total ordering"))
 	}
-}
 
-impl<A, B, C, D> PartialEq<SliceBit<C, D>> for &SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn eq(&self, rhs: &SliceBit<C, D>) -> bool {
-		(*self).eq(rhs)
-	}
-}
-
-/// Allow comparison against the allocated form.
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialEq<VecBit<C, D>> for SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn eq(&self, rhs: &VecBit<C, D>) -> bool {
-		self.eq(rhs.as_bitslice())
-	}
-}
-
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialEq<VecBit<C, D>> for &SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn eq(&self, rhs: &VecBit<C, D>) -> bool {
-		self.eq(rhs.as_bitslice())
-	}
-}
-
-/** Compares two `SliceBit`s by semantic — not bitwise — ordering.
-
-The comparison sorts by testing each index for one slice to have a set bit where
-the other has an unset bit. If the slices are different, the slice with the set
-bit sorts greater than the slice with the unset bit.
-
-If one of the slices is exhausted before they differ, the longer slice is
-greater.
-**/
-impl<A, B, C, D> PartialOrd<SliceBit<C, D>> for SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	/// Performs a comparison by `<` or `>`.
+	/// Compares the slice and another as signed, 2’s-complement, integers.
+	///
+	/// This uses the significance of the arithmetic operators: the first bit
+	/// of a slice is its most significant, and is its sign. The shorter slice
+	/// is sign-extended at the front, so `1101` and `101` are equal, and both
+	/// are less than `0`. The empty slice is zero.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `rhs`: Another `SliceBit` against which to compare. This slice can
-	///   have different cursor or storage types.
+	/// - `other`: The slice to compare against `self`. It may have different
+	///   cursor or storage types.
 	///
 	/// # Returns
 	///
-	/// The relative ordering of `self` against `rhs`. `self` is greater if it
-	/// has a `true` bit at an index where `rhs` has a `false`; `self` is lesser
-	/// if it has a `false` bit at an index where `rhs` has a `true`; if the two
-	/// slices do not disagree then they are compared by length.
+	/// The ordering of the number that `self` represents against the number
+	/// that `other` represents.
+	///
+	/// # Type Parameters
+	///
+	/// - `D: Cursor`: The cursor type of `other`.
+	/// - `U: BitStore`: The storage type of `other`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
-	///
-	/// let src = 0x45u8;
-	/// let bits = src.bits::<BigEndian>();
-	/// let a = &bits[0 .. 3]; // 010
-	/// let b = &bits[0 .. 4]; // 0100
-	/// let c = &bits[0 .. 5]; // 01000
-	/// let d = &bits[4 .. 8]; // 0101
-	///
-	/// assert!(a < b);
-	/// assert!(b < c);
-	/// assert!(c < d);
+	/// use core::cmp::Ordering;
+	///
+	/// let bits = 0b1101_1101u8.bits::<BigEndian>();
+	/// let (minus_three, minus_one) = (&bits[.. 4], &bits[4 .. 6]);
+	/// let plus_one = &bits[6 ..];
+	/// assert_eq!(minus_three.cmp_numeric_signed(minus_one), Ordering::Less);
+	/// assert_eq!(minus_one.cmp_numeric_signed(plus_one), Ordering::Less);
+	/// assert_eq!(bits[1 .. 4].cmp_numeric_signed(&bits[.. 4]), Ordering::Equal);
+	/// assert_eq!(minus_three.cmp_numeric(minus_one), Ordering::Greater);
 	/// ```
-	fn partial_cmp(&self, rhs: &SliceBit<C, D>) -> Option<Ordering> {
-		for (l, r) in self.iter().zip(rhs.iter()) {
-			match (l, r) {
-				(true, false) => return Some(Ordering::Greater),
-				(false, true) => return Some(Ordering::Less),
-				_ => continue,
-			}
+	pub fn cmp_numeric_signed<D, U>(&self, other: &SliceBit<D, U>) -> Ordering
+	where D: Cursor, U: BitStore {
+		let sign = self.first().unwrap_or(false);
+		match (sign, other.first().unwrap_or(false)) {
+			(false, true) => return Ordering::Greater,
+			(true, false) => return Ordering::Less,
+			_ => {},
 		}
-		self.len().partial_cmp(&rhs.len())
-	}
-}
-
-impl<A, B, C, D> PartialOrd<SliceBit<C, D>> for &SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn partial_cmp(&self, rhs: &SliceBit<C, D>) -> Option<Ordering> {
-		(*self).partial_cmp(rhs)
-	}
-}
-
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialOrd<VecBit<C, D>> for SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn partial_cmp(&self, rhs: &VecBit<C, D>) -> Option<Ordering> {
-		self.partial_cmp(rhs.as_bitslice())
-	}
-}
-
-#[cfg(feature = "alloc")]
-impl<A, B, C, D> PartialOrd<VecBit<C, D>> for &SliceBit<A, B>
-where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
-	fn partial_cmp(&self, rhs: &VecBit<C, D>) -> Option<Ordering> {
-		(*self).partial_cmp(rhs.as_bitslice())
+		let (l, r) = (self.len(), other.len());
+		let (l_high, l_low) = self.split_at(l.saturating_sub(r));
+		let (r_high, r_low) = other.split_at(r.saturating_sub(l));
+		//  Only the longer slice has bits above the width of the shorter, and
+		//  they differ from its sign extension only if it has greater magnitude.
+		let (l_wide, r_wide) = if sign {
+			(l_high.not_all(), r_high.not_all())
+		}
+		else {
+			(l_high.any(), r_high.any())
+		};
+		match (l_wide, r_wide, sign) {
+			(true, _, false) | (_, true, true) => return Ordering::Greater,
+			(true, _, true) | (_, true, false) => return Ordering::Less,
+			_ => {},
+		}
+		//  Both numbers have the same sign and width, so they order as their
+		//  unsigned bits do.
+		l_low.partial_cmp(r_low)
+			.unwrap_or_else(|| unreachable!("`SliceBit` has a total ordering"))
 	}
-}
 
-/// Provides write access to all elements in the underlying storage, including
-/// the partial head and tail elements if present.
-impl<C, T> AsMut<[T]> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	/// Accesses the underlying store.
+	/// Counts how many bits are set in both the slice and another
+	/// (`|A ∩ B|`).
 	///
-	/// This will not include partially-owned edge elements, as they may be
-	/// contended by other slice handles.
+	/// This fuses the intersection and the population count, without building
+	/// the intersection. When the slices are the rows of an adjacency matrix,
+	/// it counts the neighbors that two vertices have in common, which is the
+	/// inner step of triangle counting. When both slices begin at the same bit
+	/// index within their elements, they are counted a whole element at a time.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
+	/// - `&self`
+	/// - `other`: The slice to intersect with `self`.
 	///
 	/// # Returns
 	///
-	/// A mutable slice of all uncontended storage elements.
+	/// The number of indices set in both `self` and `other`. Bits beyond the
+	/// end of the shorter slice are not counted.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = [0u8, 128];
-	/// let bits = &mut src.bits_mut::<BigEndian>();
-	///
-	/// for elt in bits.as_mut() {
-	///   *elt += 2;
-	/// }
-	///
-	/// assert_eq!(&[2, 130], bits.as_ref());
+	/// let a = [0b1101_0110u8, 0xFF];
+	/// let b = [0b0111_0011u8, 0x0F];
+	/// let (a, b) = (a.bits::<BigEndian>(), b.bits::<BigEndian>());
+	/// assert_eq!(a.count_common(b), 7);
+	/// assert_eq!(a[.. 12].count_common(&b[.. 9]), 3);
+	/// assert_eq!(a[1 ..].count_common(&b[.. 15]), 5);
 	/// ```
-	fn as_mut(&mut self) -> &mut [T] {
-		self.as_mut_slice()
+	pub fn count_common(&self, other: &Self) -> usize {
+		use core::cmp::min;
+		let len = min(self.len(), other.len());
+		sum_zipped(
+			&self[.. len],
+			&other[.. len],
+			|a, b| a && b,
+			|a, b| (a & b).count_ones(),
+		)
 	}
-}
 
-/// Provides read access to all elements in the underlying storage, including
-/// the partial head and tail elements if present.
-impl<C, T> AsRef<[T]> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	/// Accesses the underlying store.
+	/// Counts, at each alignment of a pattern against the slice, how many of
+	/// the pattern’s bits agree with the slice.
 	///
-	/// This will not include partially-owned edge elements, as they may be
-	/// contended by other slice handles.
+	/// This is the cross-correlation of the two sequences, scored by matches
+	/// (the population count of their XNOR). The highest score marks where the
+	/// slice most resembles the pattern, such as the likeliest position of a
+	/// synchronization word in a noisy stream. Each alignment is compared a
+	/// whole element at a time, against a copy of the pattern shifted to the
+	/// same bit index within its elements.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `pattern`: The sequence to slide along `self`.
 	///
 	/// # Returns
 	///
-	/// An immutable slice of all storage elements.
+	/// A vector whose `n`th entry is the number of indices `i` at which
+	/// `self[n + i] == pattern[i]`, for every `n` at which the pattern lies
+	/// entirely within the slice. This is empty if the pattern is longer than
+	/// the slice.
 	///
 	/// # Examples
 	///
 	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
 	/// use vecbit::prelude::*;
 	///
-	/// let src = [0u8, 128];
-	/// let bits = &src.bits::<BigEndian>();
-	/// assert_eq!(&[0, 128], bits.as_ref());
+	/// let stream = vecbit![0, 1, 1, 0, 1, 0, 0, 1];
+	/// let sync = vecbit![1, 0, 1];
+	/// assert_eq!(stream.correlate(&sync), [1, 1, 3, 0, 2, 2]);
+	/// # }
 	/// ```
-	fn as_ref(&self) -> &[T] {
-		self.as_slice()
-	}
-}
-
-impl<'a, C, T> From<&'a T> for &'a SliceBit<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a T) -> Self {
-		SliceBit::<C, T>::from_element(src)
-	}
-}
-
-impl<'a, C, T> From<&'a [T]> for &'a SliceBit<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a [T]) -> Self {
-		SliceBit::<C, T>::from_slice(src)
-	}
-}
-
-impl<'a, C, T> From<&'a mut T> for &'a mut SliceBit<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a mut T) -> Self {
-		SliceBit::<C, T>::from_element_mut(src)
-	}
-}
-
-impl<'a, C, T> From<&'a mut [T]> for &'a mut SliceBit<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn from(src: &'a mut [T]) -> Self {
-		SliceBit::<C, T>::from_slice_mut(src)
-	}
-}
-
-impl<'a, C, T> Default for &'a SliceBit<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn default() -> Self {
-		SliceBit::empty()
-	}
-}
-
-impl<'a, C, T> Default for &'a mut SliceBit<C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn default() -> Self {
-		SliceBit::empty_mut()
+	#[cfg(feature = "alloc")]
+	pub fn correlate(&self, pattern: &Self) -> Vec<usize> {
+		self.correlate_by(pattern, |a, b| a == b, |a, b| (!(a ^ b)).count_ones())
 	}
-}
-
-/** Prints the `SliceBit` for debugging.
-
-The output is of the form `SliceBit<C, T> [ELT, *]` where `<C, T>` is the cursor
-and element type, with square brackets on each end of the bits and all the
-elements of the array printed in binary. The printout is always in semantic
-order, and may not reflect the underlying buffer. To see the underlying buffer,
-use `.as_ref()`.
 
-The alternate character `{:#?}` prints each element on its own line, rather than
-having all elements on the same line.
-**/
-impl<C, T> Debug for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	/// Renders the `SliceBit` type header and contents for debug.
+	/// Counts, at each alignment of a pattern against the slice, how many bits
+	/// are set in both the pattern and the slice.
+	///
+	/// This is [`correlate`] scored by [`count_common`] (the population count
+	/// of their AND), which ignores agreeing cleared bits. It suits sparse
+	/// templates, where only the set bits are significant.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `pattern`: The sequence to slide along `self`.
+	///
+	/// # Returns
+	///
+	/// A vector whose `n`th entry is the number of indices `i` at which both
+	/// `self[n + i]` and `pattern[i]` are set, for every `n` at which the
+	/// pattern lies entirely within the slice. This is empty if the pattern is
+	/// longer than the slice.
 	///
 	/// # Examples
 	///
@@ -2593,1047 +3005,3558 @@ where C: Cursor, T: BitStore {
 	/// # #[cfg(feature = "alloc")] {
 	/// use vecbit::prelude::*;
 	///
-	/// let src = [0b0101_0000_1111_0101u16, 0b00000000_0000_0010];
-	/// let bits = &src.bits::<LittleEndian>()[.. 18];
-	/// assert_eq!(
-    ///     "SliceBit<LittleEndian, u16> [1010111100001010, 01]",
-	///     &format!("{:?}", bits),
-	/// );
+	/// let stream = vecbit![0, 1, 1, 0, 1, 0, 0, 1];
+	/// let sync = vecbit![1, 0, 1];
+	/// assert_eq!(stream.correlate_ones(&sync), [1, 1, 2, 0, 1, 1]);
 	/// # }
 	/// ```
-	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		f.write_str("SliceBit<")?;
-		f.write_str(C::TYPENAME)?;
-		f.write_str(", ")?;
-		f.write_str(T::TYPENAME)?;
-		f.write_str("> ")?;
-		Display::fmt(self, f)
+	///
+	/// [`correlate`]: #method.correlate
+	/// [`count_common`]: #method.count_common
+	#[cfg(feature = "alloc")]
+	pub fn correlate_ones(&self, pattern: &Self) -> Vec<usize> {
+		self.correlate_by(pattern, |a, b| a && b, |a, b| (a & b).count_ones())
+	}
+
+	/// Scores every alignment of a pattern against the slice with
+	/// [`sum_zipped`].
+	///
+	/// [`sum_zipped`]: fn.sum_zipped.html
+	#[cfg(feature = "alloc")]
+	fn correlate_by<F, G>(&self, pattern: &Self, bit: F, elt: G) -> Vec<usize>
+	where F: Fn(bool, bool) -> bool + Copy, G: Fn(T, T) -> usize + Copy {
+		let width = pattern.len();
+		let count = match (self.len() + 1).checked_sub(width) {
+			Some(n) => n,
+			None => return Vec::new(),
+		};
+		let head = *self.bitptr().head() as usize;
+		//  `shifted[h]` holds the pattern after `h` padding bits, so that its
+		//  tail begins at the same bit index as each window starting at `h`.
+		let mut shifted = (0 .. T::BITS).map(|_| None).collect::<Vec<_>>();
+		(0 .. count).map(|n| {
+			let h = (head + n) & T::MASK as usize;
+			let copy = shifted[h].get_or_insert_with(|| {
+				let mut copy = VecBit::<C, T>::with_capacity(h + width);
+				copy.resize(h, false);
+				copy.extend(pattern.iter());
+				copy
+			});
+			sum_zipped(&self[n .. n + width], &copy[h ..], bit, elt)
+		}).collect()
 	}
-}
 
-/** Prints the `SliceBit` for displaying.
-
-This prints each element in turn, formatted in binary in semantic order (so the
-first bit seen is printed first and the last bit seen is printed last). Each
-element of storage is separated by a space for ease of reading.
-
-The alternate character `{:#}` prints each element on its own line.
-
-To see the in-memory representation, use `.as_ref()` to get access to the raw
-elements and print that slice instead.
-**/
-impl<C, T> Display for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	/// Renders the `SliceBit` contents for display.
+	/// Counts how many bits are set high.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
-	/// - `f`: The formatter into which `self` is written.
 	///
 	/// # Returns
 	///
-	/// The result of the formatting operation.
+	/// The number of high bits in the slice domain.
 	///
 	/// # Examples
 	///
 	/// ```rust
-	/// # #[cfg(feature = "alloc")] {
 	/// use vecbit::prelude::*;
 	///
-	/// let src = [0b01001011u8, 0b0100_0000];
-	/// let bits = &src.bits::<BigEndian>()[.. 10];
-	/// assert_eq!("[01001011, 01]", &format!("{}", bits));
-	/// # }
+	/// let bits = [0xFDu8, 0x25].bits::<BigEndian>();
+	/// assert_eq!(bits.count_ones(), 10);
 	/// ```
-	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-		struct Part<'a>(&'a str);
-		impl<'a> Debug for Part<'a> {
-			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-				f.write_str(&self.0)
-			}
-		}
-
-		let mut dbg = f.debug_list();
-		if !self.is_empty() {
-			//  Unfortunately, `T::BITS` cannot be used as the size for the
-			//  array, due to limitations in the type system. As such, set it to
-			//  the maximum used size.
-			//
-			//  This allows the writes to target a static buffer, rather
-			//  than a dynamic string, making the formatter usable in
-			//  `#![no_std]` contexts.
-			let mut w: [u8; 64] = [b'0'; 64];
-			fn writer<C, T>(
-				l: &mut DebugList,
-				w: &mut [u8; 64],
-				e: &T,
-				from: u8,
-				to: u8,
-			)
-			where C: Cursor, T: BitStore {
-				let (from, to) = (from as usize, to as usize);
-				for (n, byte) in w.iter_mut().enumerate().take(to).skip(from) {
-					*byte = b'0' + (e.get::<C>((n as u8).idx()) as u8);
-				}
-				l.entry(&Part(unsafe {
-					str::from_utf8_unchecked(&w[from .. to])
-				}));
-			}
-			match self.bitptr().domain() {
-				BitDomain::Empty => {},
-				BitDomain::Minor(head, elt, tail) => {
-					writer::<C, T>(&mut dbg, &mut w, &elt.load(), *head, *tail)
-				},
-				BitDomain::Major(h, head, body, tail, t) => {
-					writer::<C, T>(&mut dbg, &mut w, &head.load(), *h, T::BITS);
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
-					}
-					writer::<C, T>(&mut dbg, &mut w, &tail.load(), 0, *t);
-				},
-				BitDomain::PartialHead(h, head, body) => {
-					writer::<C, T>(&mut dbg, &mut w, &head.load(), *h, T::BITS);
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
-					}
-				},
-				BitDomain::PartialTail(body, tail, t) => {
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
-					}
-					writer::<C, T>(&mut dbg, &mut w, &tail.load(), 0, *t);
-				},
-				BitDomain::Spanning(body) => {
-					for elt in body {
-						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
-					}
-				},
+	pub fn count_ones(&self) -> usize {
+		match self.bitptr().domain() {
+			BitDomain::Empty => 0,
+			BitDomain::Minor(head, elt, tail) => {
+				(*head .. *tail)
+					.map(|n| elt.get::<C>(n.idx()))
+					.filter(|b| *b)
+					.count()
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				(*h .. T::BITS)
+					.map(|n| head.get::<C>(n.idx()))
+					.filter(|b| *b)
+					.count() +
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_ones)
+					.sum::<usize>() +
+				(0 .. *t)
+					.map(|n| tail.get::<C>(n.idx()))
+					.filter(|b| *b)
+					.count()
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				(*h .. T::BITS)
+					.map(|n| head.get::<C>(n.idx()))
+					.filter(|b| *b)
+					.count() +
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_ones)
+					.sum::<usize>()
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_ones)
+					.sum::<usize>() +
+				(0 .. *t)
+					.map(|n| tail.get::<C>(n.idx()))
+					.filter(|b| *b)
+					.count()
+			},
+			BitDomain::Spanning(body) => {
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_ones)
+					.sum::<usize>()
 			}
 		}
-		dbg.finish()
+	}
+
+	/// Counts how many bits are set low.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of low bits in the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = [0xFDu8, 0x25].bits::<BigEndian>();
+	/// assert_eq!(bits.count_zeros(), 6);
+	/// ```
+	pub fn count_zeros(&self) -> usize {
+		match self.bitptr().domain() {
+			BitDomain::Empty => 0,
+			BitDomain::Minor(head, elt, tail) => {
+				(*head .. *tail)
+					.map(|n| !elt.get::<C>(n.idx()))
+					.filter(|b| !*b)
+					.count()
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				(*h .. T::BITS)
+					.map(|n| head.get::<C>(n.idx()))
+					.filter(|b| !*b)
+					.count() +
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_zeros)
+					.sum::<usize>() +
+				(0 .. *t)
+					.map(|n| tail.get::<C>(n.idx()))
+					.filter(|b| !*b)
+					.count()
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				(*h .. T::BITS)
+					.map(|n| head.get::<C>(n.idx()))
+					.filter(|b| !*b)
+					.count() +
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_zeros)
+					.sum::<usize>()
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_zeros)
+					.sum::<usize>() +
+				(0 .. *t)
+					.map(|n| tail.get::<C>(n.idx()))
+					.filter(|b| !*b)
+					.count()
+			},
+			BitDomain::Spanning(body) => {
+				body.iter()
+					.map(BitAccess::<T>::load)
+					.map(T::count_zeros)
+					.sum::<usize>()
+			},
+		}
+	}
+
+	/// Counts how many bits are set before an index.
+	///
+	/// This is the rank query of succinct data structures. Elements are
+	/// counted whole, with a population count, rather than bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `at`: The index before which to count. It may be `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The number of set bits in `self[.. at]`.
+	///
+	/// # Panics
+	///
+	/// Panics if `at` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = [0b0110_0101u8, 0x80].bits::<BigEndian>();
+	/// assert_eq!(bits.rank_ones(0), 0);
+	/// assert_eq!(bits.rank_ones(3), 2);
+	/// assert_eq!(bits.rank_ones(16), 5);
+	/// ```
+	pub fn rank_ones(&self, at: usize) -> usize {
+		self[.. at].count_ones()
+	}
+
+	/// Counts how many bits are cleared before an index.
+	///
+	/// This is the counterpart of [`rank_ones`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `at`: The index before which to count. It may be `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The number of cleared bits in `self[.. at]`.
+	///
+	/// # Panics
+	///
+	/// Panics if `at` is greater than `self.len()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = [0b0110_0101u8, 0x80].bits::<BigEndian>();
+	/// assert_eq!(bits.rank_zeros(3), 1);
+	/// assert_eq!(bits.rank_zeros(16), 11);
+	/// ```
+	///
+	/// [`rank_ones`]: #method.rank_ones
+	pub fn rank_zeros(&self, at: usize) -> usize {
+		at - self.rank_ones(at)
+	}
+
+	/// Finds the index of a set bit by its position among the set bits.
+	///
+	/// This is the select query of succinct data structures, and the inverse
+	/// of [`rank_ones`]: when bit `i` is set, `select_ones(rank_ones(i))` is
+	/// `Some(i)`. Elements are skipped whole, by their population count, until
+	/// the one holding the sought bit is reached.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The zero-based count of the set bit to find.
+	///
+	/// # Returns
+	///
+	/// The index of the set bit that has `n` set bits before it, or `None` if
+	/// the slice has no more than `n` set bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = [0b0110_0101u8, 0x80].bits::<BigEndian>();
+	/// assert_eq!(bits.select_ones(0), Some(1));
+	/// assert_eq!(bits.select_ones(4), Some(8));
+	/// assert_eq!(bits.select_ones(5), None);
+	/// ```
+	///
+	/// [`rank_ones`]: #method.rank_ones
+	pub fn select_ones(&self, n: usize) -> Option<usize> {
+		self.select(n, true)
+	}
+
+	/// Finds the index of a cleared bit by its position among the cleared
+	/// bits.
+	///
+	/// This is the counterpart of [`select_ones`], and the inverse of
+	/// [`rank_zeros`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The zero-based count of the cleared bit to find.
+	///
+	/// # Returns
+	///
+	/// The index of the cleared bit that has `n` cleared bits before it, or
+	/// `None` if the slice has no more than `n` cleared bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = [0b0110_0101u8, 0x80].bits::<BigEndian>();
+	/// assert_eq!(bits.select_zeros(0), Some(0));
+	/// assert_eq!(bits.select_zeros(3), Some(6));
+	/// assert_eq!(bits.select_zeros(11), None);
+	/// ```
+	///
+	/// [`rank_zeros`]: #method.rank_zeros
+	/// [`select_ones`]: #method.select_ones
+	pub fn select_zeros(&self, n: usize) -> Option<usize> {
+		self.select(n, false)
+	}
+
+	/// Produces an iterator over the maximal runs of set bits in the slice.
+	///
+	/// This converts a bitmap into an extent list, such as the used blocks in
+	/// an allocation bitmap. Elements in which no bit is set are skipped whole,
+	/// without examining their bits one at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the index ranges of each run of consecutive set bits,
+	/// in ascending order. No two ranges are adjacent.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b0111_0001u8, 0b1000_0000];
+	/// let bits = src.bits::<BigEndian>();
+	/// let runs = bits.iter_set_ranges().collect::<Vec<_>>();
+	/// assert_eq!(runs, [1 .. 4, 7 .. 9]);
+	/// ```
+	pub fn iter_set_ranges(&self) -> Ranges<C, T> {
+		Ranges {
+			inner: self,
+			pos: 0,
+			value: true,
+		}
+	}
+
+	/// Produces an iterator over the maximal runs of cleared bits in the
+	/// slice.
+	///
+	/// This is the counterpart of [`iter_set_ranges`], such as for listing the
+	/// free blocks in an allocation bitmap.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the index ranges of each run of consecutive cleared
+	/// bits, in ascending order. No two ranges are adjacent.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b0111_0001u8, 0b1000_0000];
+	/// let bits = src.bits::<BigEndian>();
+	/// let runs = bits.iter_clear_ranges().collect::<Vec<_>>();
+	/// assert_eq!(runs, [0 .. 1, 4 .. 7, 9 .. 16]);
+	/// ```
+	///
+	/// [`iter_set_ranges`]: #method.iter_set_ranges
+	pub fn iter_clear_ranges(&self) -> Ranges<C, T> {
+		Ranges {
+			inner: self,
+			pos: 0,
+			value: false,
+		}
+	}
+
+	/// Produces an iterator over the indices of the set bits in the slice.
+	///
+	/// Elements in which no bit is set are skipped whole, without examining
+	/// their bits one at a time, so sparse bitmaps are scanned quickly.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the index of each set bit, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b0100_0001u8, 0, 0b1000_0000];
+	/// let bits = src.bits::<BigEndian>();
+	/// let ones = bits.iter_ones().collect::<Vec<_>>();
+	/// assert_eq!(ones, [1, 7, 16]);
+	/// ```
+	pub fn iter_ones(&self) -> Indices<C, T> {
+		Indices {
+			inner: self,
+			pos: 0,
+			value: true,
+		}
+	}
+
+	/// Produces an iterator over the indices of the cleared bits in the slice.
+	///
+	/// This is the counterpart of [`iter_ones`]. Elements in which every bit
+	/// is set are skipped whole, without examining their bits one at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the index of each cleared bit, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [!0u8, 0b1101_1111];
+	/// let bits = src.bits::<BigEndian>();
+	/// let zeros = bits[4 ..].iter_zeros().collect::<Vec<_>>();
+	/// assert_eq!(zeros, [6]);
+	/// ```
+	///
+	/// [`iter_ones`]: #method.iter_ones
+	pub fn iter_zeros(&self) -> Indices<C, T> {
+		Indices {
+			inner: self,
+			pos: 0,
+			value: false,
+		}
+	}
+
+	/// Finds the longest run of one bit value in the slice.
+	///
+	/// This is computed in one pass over the slice, in which elements that do
+	/// not hold `value` at all are skipped whole.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The bit value whose runs are measured.
+	///
+	/// # Returns
+	///
+	/// - `.0`: The index at which the longest run begins. If several runs are
+	///   equally long, this is the first of them.
+	/// - `.1`: The length of the longest run. This is zero, and the start is
+	///   zero, if no bit in the slice is `value`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b0111_0001u8, 0b1100_0000];
+	/// let bits = src.bits::<BigEndian>();
+	/// assert_eq!(bits.max_run_of(true), (1, 3));
+	/// assert_eq!(bits.max_run_of(false), (10, 6));
+	/// assert_eq!(bits[1 .. 4].max_run_of(false), (0, 0));
+	/// ```
+	pub fn max_run_of(&self, value: bool) -> (usize, usize) {
+		let runs = if value {
+			self.iter_set_ranges()
+		}
+		else {
+			self.iter_clear_ranges()
+		};
+		runs.fold((0, 0), |best, run| {
+			let len = run.end - run.start;
+			if len > best.1 { (run.start, len) } else { best }
+		})
+	}
+
+	/// Finds the first run of cleared bits that is at least some length.
+	///
+	/// This is the first-fit search of a bitmap allocator, in which set bits
+	/// mark allocated blocks. Elements that are fully allocated are skipped
+	/// whole.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `len`: The minimum length of the run.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit of the first run of at least `len` cleared
+	/// bits, if there is one. An empty run fits at the front of any slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b1000_1100u8, 0b0001_1111];
+	/// let bits = src.bits::<BigEndian>();
+	/// assert_eq!(bits.find_first_fit(2), Some(1));
+	/// assert_eq!(bits.find_first_fit(4), Some(6));
+	/// assert_eq!(bits.find_first_fit(6), None);
+	/// ```
+	pub fn find_first_fit(&self, len: usize) -> Option<usize> {
+		if len == 0 {
+			return Some(0);
+		}
+		self.iter_clear_ranges()
+			.find(|run| run.end - run.start >= len)
+			.map(|run| run.start)
+	}
+
+	/// Finds and sets the first run of cleared bits that is at least some
+	/// length.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The number of bits to allocate.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit of the allocated run, if a long enough run
+	/// of cleared bits was found. The `len` bits starting there are now set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = [0b1000_1100u8, 0b0001_1111];
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// assert_eq!(bits.allocate_run(3), Some(1));
+	/// assert_eq!(bits.allocate_run(3), Some(6));
+	/// assert_eq!(bits.allocate_run(3), None);
+	/// assert_eq!(src, [0b1111_1111, 0b1001_1111]);
+	/// ```
+	pub fn allocate_run(&mut self, len: usize) -> Option<usize> {
+		let start = self.find_first_fit(len)?;
+		self[start .. start + len].set_all(true);
+		Some(start)
+	}
+
+	/// Set all bits in the slice to a value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to which all bits in the slice will be set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// bits[2 .. 6].set_all(true);
+	/// assert_eq!(bits.as_ref(), &[0b0011_1100]);
+	/// bits[3 .. 5].set_all(false);
+	/// assert_eq!(bits.as_ref(), &[0b0010_0100]);
+	/// bits[.. 1].set_all(true);
+	/// assert_eq!(bits.as_ref(), &[0b1010_0100]);
+	/// ```
+	pub fn set_all(&mut self, value: bool) {
+		match self.bitptr().domain() {
+			BitDomain::Empty => {},
+			BitDomain::Minor(head, elt, tail) => {
+				for n in *head .. *tail {
+					elt.set::<C>(n.idx(), value);
+				}
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				for n in *h .. T::BITS {
+					head.set::<C>(n.idx(), value);
+				}
+				for elt in body {
+					elt.store(T::bits(value));
+				}
+				for n in 0 .. *t {
+					tail.set::<C>(n.idx(), value);
+				}
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				for n in *h .. T::BITS {
+					head.set::<C>(n.idx(), value);
+				}
+				for elt in body {
+					elt.store(T::bits(value));
+				}
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				for elt in body {
+					elt.store(T::bits(value));
+				}
+				for n in 0 .. *t {
+					tail.set::<C>(n.idx(), value);
+				}
+			},
+			BitDomain::Spanning(body) => {
+				for elt in body {
+					elt.store(T::bits(value));
+				}
+			},
+		}
+	}
+
+	/// Overwrites the slice, from the front, with bits from an iterator.
+	///
+	/// Writing stops when either the slice is full or the iterator runs out.
+	/// Bits past the last one written keep their prior values. The iterator is
+	/// not advanced past the bits that fit, so a caller can pass `by_ref()` to
+	/// carry the remainder into the next buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: A source of bits.
+	///
+	/// # Returns
+	///
+	/// The number of bits written into the front of the slice.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator<Item=bool>`: The source of bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut buf = [0u8; 2];
+	/// let mut src = (0 .. 20).map(|n| n % 4 == 0);
+	/// let bits = buf.bits_mut::<BigEndian>();
+	/// assert_eq!(bits.fill_from_iter(src.by_ref()), 16);
+	/// assert_eq!(bits.as_ref(), &[0b1000_1000; 2]);
+	/// assert_eq!(bits[.. 8].fill_from_iter(src), 4);
+	/// assert_eq!(bits.as_ref(), &[0b1000_1000; 2]);
+	/// ```
+	pub fn fill_from_iter<I>(&mut self, src: I) -> usize
+	where I: IntoIterator<Item=bool> {
+		let mut count = 0;
+		//  The range is polled first, so that `zip` stops without pulling a
+		//  bit from `src` that would not fit.
+		for (n, bit) in (0 .. self.len()).zip(src) {
+			unsafe { self.set_unchecked(n, bit) };
+			count += 1;
+		}
+		count
+	}
+
+	/// Copies bits from another slice into the slice, at the indices set in a
+	/// mask.
+	///
+	/// This is `self = (self & !mask) | (src & mask)`, without any temporary
+	/// slices: the update-selected-bits step of register emulation and of
+	/// masked drawing. When all three slices begin at the same bit index
+	/// within their elements, each fully spanned element is read and written
+	/// once.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The slice from which to copy bits.
+	/// - `mask`: The indices to copy. Indices beyond the end of `mask` are left
+	///   unchanged.
+	///
+	/// # Panics
+	///
+	/// This panics if `src` is not the same length as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut reg = [0b1100_1100u8];
+	/// let src = [0b1010_1010u8];
+	/// let mask = [0b0000_1111u8];
+	/// let bits = reg.bits_mut::<BigEndian>();
+	/// bits.assign_masked(src.bits(), mask.bits());
+	/// assert_eq!(reg, [0b1100_1010]);
+	///
+	/// let bits = reg.bits_mut::<BigEndian>();
+	/// bits[2 ..].assign_masked(&src.bits()[2 ..], &mask.bits()[4 .. 6]);
+	/// assert_eq!(reg, [0b1110_1010]);
+	/// ```
+	pub fn assign_masked(&mut self, src: &Self, mask: &Self) {
+		use core::cmp::min;
+		let len = self.len();
+		assert!(
+			src.len() == len,
+			"Source length {} does not match destination length {}",
+			src.len(),
+			len,
+		);
+		let len = min(len, mask.len());
+		let dst = &mut self[.. len];
+		let (src, mask) = (&src[.. len], &mask[.. len]);
+		let bits = |dst: &mut Self, range: Range<usize>| for n in range {
+			unsafe {
+				if mask.get_unchecked(n) {
+					dst.set_unchecked(n, src.get_unchecked(n));
+				}
+			}
+		};
+		let head = *dst.bitptr().head();
+		//  Differently-aligned slices cannot be combined a whole element at a
+		//  time.
+		if head != *src.bitptr().head() || head != *mask.bitptr().head() {
+			bits(dst, 0 .. len);
+			return;
+		}
+		let (lead, elts, rest) = split_elements(dst);
+		bits(dst, lead);
+		let elts_dst = &dst.as_total_slice()[elts.clone()];
+		let elts_src = &src.as_total_slice()[elts.clone()];
+		let elts_mask = &mask.as_total_slice()[elts];
+		for ((d, s), m) in elts_dst.iter().zip(elts_src).zip(elts_mask) {
+			let m = m.load();
+			d.store((d.load() & !m) | (s.load() & m));
+		}
+		bits(dst, rest);
+	}
+
+	/// Clears every `step`-th bit, beginning at `start`.
+	///
+	/// This is the inner loop of the Sieve of Eratosthenes: when the slice
+	/// marks the odd numbers, say, each prime’s multiples are cleared with one
+	/// call. Steps shorter than an element are applied with a mask per
+	/// element, precomputed for each phase the step can have, so that each
+	/// fully spanned element is written once rather than once per bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `start`: The index of the first bit to clear. If this is at or past
+	///   the end of the slice, nothing is cleared.
+	/// - `step`: The distance between cleared bits.
+	///
+	/// # Panics
+	///
+	/// Panics if `step` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = [0xFFu8; 2];
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// bits.clear_multiples(3, 5);
+	/// assert_eq!(src, [0b1110_1111, 0b0111_1011]);
+	/// ```
+	pub fn clear_multiples(&mut self, start: usize, step: usize) {
+		self.fill_multiples(start, step, false);
+	}
+
+	/// Sets every `step`-th bit, beginning at `start`.
+	///
+	/// This is [`clear_multiples`], writing `1` instead of `0`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `start`: The index of the first bit to set. If this is at or past the
+	///   end of the slice, nothing is set.
+	/// - `step`: The distance between set bits.
+	///
+	/// # Panics
+	///
+	/// Panics if `step` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = [0u16; 2];
+	/// let bits = src.bits_mut::<LittleEndian>();
+	/// bits[1 ..].set_multiples(0, 3);
+	/// assert_eq!(src, [0b0010_0100_1001_0010, 0b1001_0010_0100_1001]);
+	/// ```
+	///
+	/// [`clear_multiples`]: #method.clear_multiples
+	pub fn set_multiples(&mut self, start: usize, step: usize) {
+		self.fill_multiples(start, step, true);
+	}
+
+	/// Writes a value to every `step`-th bit, beginning at `start`.
+	fn fill_multiples(&mut self, start: usize, step: usize, value: bool) {
+		use core::cmp::{
+			max,
+			min,
+		};
+		assert!(step != 0, "The step between bits must be nonzero");
+		let len = self.len();
+		let bits = T::BITS as usize;
+		let (lead, elts, rest) = split_elements(self);
+		let (lead, full, rest) = (lead.end, elts.len(), rest.start);
+		//  The first bit to write at or after `n`, which is at least `start`.
+		let next = |n: usize| n + (step - (n - start) % step) % step;
+		if start >= len || step >= bits || full == 0 {
+			for n in (start .. len).step_by(step) {
+				unsafe { self.set_unchecked(n, value); }
+			}
+			return;
+		}
+		//  `masks[o]` marks the bits of an element that are written when the
+		//  first such bit is at offset `o`.
+		let mut masks = [T::bits(false); 64];
+		for (o, mask) in masks[.. step].iter_mut().enumerate() {
+			for n in (o .. bits).step_by(step) {
+				mask.set::<C>((n as u8).idx(), true);
+			}
+		}
+		//  The first fully spanned element that `start` does not cut.
+		let begin = match start.checked_sub(lead) {
+			None | Some(0) => lead,
+			Some(n) => min(rest, lead + (n + bits - 1) / bits * bits),
+		};
+		for n in (start .. begin).step_by(step) {
+			unsafe { self.set_unchecked(n, value); }
+		}
+		if begin < rest {
+			let skip = elts.start + (begin - lead) / bits;
+			let elts = &self.as_total_slice()[skip .. skip + (rest - begin) / bits];
+			let mut phase = next(begin) - begin;
+			for elt in elts {
+				if value {
+					elt.set_bits(masks[phase]);
+				}
+				else {
+					elt.clear_bits(!masks[phase]);
+				}
+				phase = (phase + step - bits % step) % step;
+			}
+		}
+		for n in (next(max(start, rest)) .. len).step_by(step) {
+			unsafe { self.set_unchecked(n, value); }
+		}
+	}
+
+	/// Moves all set bits to the front of the slice, and all cleared bits to
+	/// the back.
+	///
+	/// Bits carry no identity beyond their value, so this counts the set bits
+	/// and fills the two halves, rather than moving any bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The partition point: the number of set bits, and the index of the first
+	/// cleared bit if there is one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0b0100_1010u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// assert_eq!(bits.partition_in_place(), 3);
+	/// assert_eq!(src, 0b1110_0000);
+	/// ```
+	pub fn partition_in_place(&mut self) -> usize {
+		let ones = self.count_ones();
+		self[.. ones].set_all(true);
+		self[ones ..].set_all(false);
+		ones
+	}
+
+	/// Sorts the slice, so that all cleared bits precede all set bits.
+	///
+	/// This is a counting sort: it counts the set bits, then fills the two
+	/// halves of the slice. It runs in `O(n)` time, and does not allocate.
+	///
+	/// To sort in the reverse order, with set bits first, use
+	/// [`partition_in_place`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0b0100_1010u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// bits[.. 6].sort_bits();
+	/// assert_eq!(src, 0b0000_1110);
+	/// ```
+	///
+	/// [`partition_in_place`]: #method.partition_in_place
+	pub fn sort_bits(&mut self) {
+		let zeros = self.count_zeros();
+		self[.. zeros].set_all(false);
+		self[zeros ..].set_all(true);
+	}
+
+	/// Sorts the slice, so that all cleared bits precede all set bits.
+	///
+	/// Bits with the same value are indistinguishable, so there is no
+	/// difference between stable and unstable sorting. This is provided for
+	/// parity with the standard library slice, and is identical to
+	/// [`sort_bits`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0b1010_0101u8;
+	/// src.bits_mut::<LittleEndian>().sort_unstable();
+	/// assert_eq!(src, 0b1111_0000);
+	/// ```
+	///
+	/// [`sort_bits`]: #method.sort_bits
+	pub fn sort_unstable(&mut self) {
+		self.sort_bits();
+	}
+
+	/// Moves the bits whose indices satisfy a predicate to the front of the
+	/// slice, and the rest to the back, preserving the order within each side.
+	///
+	/// This is a stable partition driven by bit position rather than bit value,
+	/// and is suited for compacting a slice down to a selection of its bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `pred`: A function called once with each index in the slice, in
+	///   ascending order. The bit at each index for which it returns `true` is
+	///   moved to the front.
+	///
+	/// # Returns
+	///
+	/// The partition point: the number of indices for which `pred` returned
+	/// `true`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0b1100_1010u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// let mid = bits.partition_by_index(|n| n % 2 == 1);
+	/// assert_eq!(mid, 4);
+	/// //  odd-indexed bits 1, 0, 0, 0, then even-indexed bits 1, 0, 1, 1
+	/// assert_eq!(src, 0b1000_1011);
+	/// # }
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn partition_by_index<F>(&mut self, mut pred: F) -> usize
+	where F: FnMut(usize) -> bool {
+		let len = self.len();
+		let mut rest = VecBit::<C, T>::new();
+		let mut kept = 0;
+		for n in 0 .. len {
+			//  `kept` never passes `n`, so no unread bit is overwritten.
+			let bit = unsafe { self.get_unchecked(n) };
+			if pred(n) {
+				unsafe { self.set_unchecked(kept, bit) };
+				kept += 1;
+			}
+			else {
+				rest.push(bit);
+			}
+		}
+		for (n, bit) in rest.into_iter().enumerate() {
+			unsafe { self.set_unchecked(kept + n, bit) };
+		}
+		kept
+	}
+
+	/// Provides mutable traversal of the collection.
+	///
+	/// It is impossible to implement `IndexMut` on `SliceBit`, because bits do
+	/// not have addresses, so there can be no `&mut u1`. This method allows the
+	/// client to receive an enumerated bit, and provide a new bit to set at
+	/// each index.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which receives a `(usize, bool)` pair of index and
+	///   value, and returns a bool. It receives the bit at each position, and
+	///   the return value is written back at that position.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// {
+	///  let bits = src.bits_mut::<BigEndian>();
+	///  bits.for_each(|idx, _bit| idx % 3 == 0);
+	/// }
+	/// assert_eq!(src, 0b1001_0010);
+	/// ```
+	pub fn for_each<F>(&mut self, func: F)
+	where F: Fn(usize, bool) -> bool {
+		for idx in 0 .. self.len() {
+			let tmp = self[idx];
+			self.set(idx, func(idx, tmp));
+		}
+	}
+
+	/// Performs “reverse” addition (left to right instead of right to left).
+	///
+	/// This addition interprets the slice, and the other addend, as having its
+	/// least significant bits first in the order and its most significant bits
+	/// last. This is most likely to be numerically useful under a
+	/// `LittleEndian` `Cursor` type.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`: The addition uses `self` as one addend, and writes the
+	///   sum back into `self`.
+	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits. When this is
+	///   another `SliceBit`, iteration proceeds from left to right.
+	///
+	/// # Return
+	///
+	/// The final carry bit is returned
+	///
+	/// # Effects
+	///
+	/// Starting from index `0` and proceeding upwards until either `self` or
+	/// `addend` expires, the carry-propagated addition of `self[i]` and
+	/// `addend[i]` is written to `self[i]`.
+	///
+	/// ```text
+	///   101111
+	/// + 0010__ (the two missing bits are logically zero)
+	/// --------
+	///   100000 1 (the carry-out is returned)
+	/// ```
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut a = 0b0000_1010u8;
+	/// let     b = 0b0000_1100u8;
+	/// //      s =      1 0110
+	/// let ab = &mut a.bits_mut::<LittleEndian>()[.. 4];
+	/// let bb = &    b.bits::<LittleEndian>()[.. 4];
+	/// let c = ab.add_assign_reverse(bb);
+	/// assert!(c);
+	/// assert_eq!(a, 0b0000_0110u8);
+	/// ```
+	///
+	/// # Performance Notes
+	///
+	/// When using `LittleEndian` `Cursor` types, this can be accelerated by
+	/// delegating the addition to the underlying types. This is a software
+	/// implementation of the [ripple-carry adder], which has `O(n)` runtime in
+	/// the number of bits. The CPU is much faster, as it has access to
+	/// element-wise or vectorized addition operations.
+	///
+	/// If your use case sincerely needs binary-integer arithmetic operations on
+	/// bit sets
+	///
+	/// [ripple-carry adder]: https://en.wikipedia.org/wiki/Ripple-carry_adder
+	pub fn add_assign_reverse<I>(&mut self, addend: I) -> bool
+	where I: IntoIterator<Item=bool> {
+		//  See AddAssign::add_assign for algorithm details
+		let mut c = false;
+		let len = self.len();
+		let zero = core::iter::repeat(false);
+		for (i, b) in addend.into_iter().chain(zero).enumerate().take(len) {
+			//  The iterator is clamped to the upper bound of `self`.
+			let a = unsafe { self.get_unchecked(i) };
+			let (y, z) = crate::rca1(a, b, c);
+			//  Write the sum into `self`
+			unsafe { self.set_unchecked(i, y); }
+			//  Propagate the carry
+			c = z;
+		}
+		c
+	}
+
+	/// Accesses the backing storage of the `SliceBit` as a slice of its
+	/// elements.
+	///
+	/// This will not include partially-owned edge elements, as they may be
+	/// contended by other slice handles.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A slice of all the elements that the `SliceBit` uses for storage.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [1u8, 66];
+	/// let bits = src.bits::<BigEndian>();
+	///
+	/// let accum = bits.as_slice()
+	///   .iter()
+	///   .map(|elt| elt.count_ones())
+	///   .sum::<u32>();
+	/// assert_eq!(accum, 3);
+	/// ```
+	pub fn as_slice(&self) -> &[T] {
+		&* unsafe { BitAccess::as_slice_mut(match self.bitptr().domain() {
+			| BitDomain::Empty
+			| BitDomain::Minor(_, _, _) => &[],
+			| BitDomain::PartialHead(_, _, body)
+			| BitDomain::PartialTail(body, _, _)
+			| BitDomain::Major(_, _, body, _, _)
+			| BitDomain::Spanning(body) => body,
+		}) }
+	}
+
+	/// Accesses the underlying store.
+	///
+	/// This will not include partially-owned edge elements, as they may be
+	/// contended by other slice handles.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = [1u8, 64];
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// for elt in bits.as_mut_slice() {
+	///   *elt |= 2;
+	/// }
+	/// assert_eq!(&[3, 66], bits.as_slice());
+	/// ```
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe { BitAccess::as_slice_mut(match self.bitptr().domain() {
+			| BitDomain::Empty
+			| BitDomain::Minor(_, _, _) => &[],
+			| BitDomain::PartialHead(_, _, body)
+			| BitDomain::PartialTail(body, _, _)
+			| BitDomain::Major(_, _, body, _, _)
+			| BitDomain::Spanning(body) => body,
+		}) }
+	}
+
+	/// Accesses the underlying store, including contended partial elements.
+	///
+	/// This produces a slice of element wrappers that permit shared mutation,
+	/// rather than a slice of the bare `T` fundamentals.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A slice of all elements under the bit span, including any
+	/// partially-owned edge elements, wrapped in safe shared-mutation types.
+	pub fn as_total_slice(&self) -> &[T::Access] {
+		self.bitptr().as_access_slice()
+	}
+
+	/// Describes the memory under the slice, for handing to foreign code.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `*const T`: A pointer to the first element, partial or not, in the
+	///   underlying store. This is null if `self` is empty, as in [`as_ptr`].
+	/// - `usize`: The number of elements, partial or not, that the slice
+	///   touches.
+	/// - `usize`: The number of live bits in the slice.
+	/// - `u8`: The number of dead bits, counted by the cursor `C`, that precede
+	///   the first live bit in the first element.
+	///
+	/// Any bits after the last live bit in the last element are dead, and may
+	/// hold any value.
+	///
+	/// # Safety
+	///
+	/// The pointer is subject to the same rules as that of [`as_ptr`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0u16; 3];
+	/// let bits = &src.bits::<LittleEndian>()[5 .. 20];
+	/// let (ptr, elts, len, head) = bits.as_ffi_parts();
+	/// assert_eq!(ptr, src.as_ptr());
+	/// assert_eq!((elts, len, head), (2, 15, 5));
+	/// ```
+	///
+	/// [`as_ptr`]: #method.as_ptr
+	pub fn as_ffi_parts(&self) -> (*const T, usize, usize, u8) {
+		let bitptr = self.bitptr();
+		(self.as_ptr(), bitptr.elements(), bitptr.len(), *bitptr.head())
+	}
+
+	/// Copies the slice into a byte buffer, most significant bit first.
+	///
+	/// This produces the packed, MSbit-first layout that C interfaces such as
+	/// X11 bitmaps and V4L2 masks expect, whatever the cursor, storage type,
+	/// and alignment of the slice. The first bit of the slice is written into
+	/// the most significant bit of `buf[0]`, and any bits after the last live
+	/// bit in the last byte written are cleared.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `buf`: The buffer to fill. Bytes after those needed to hold the slice
+	///   are left untouched.
+	///
+	/// # Returns
+	///
+	/// The number of bytes written, which is the slice length divided by eight,
+	/// rounded up.
+	///
+	/// # Panics
+	///
+	/// Panics if `buf` is too short to hold the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b1011u16];
+	/// let bits = &src.bits::<LittleEndian>()[.. 10];
+	/// let mut buf = [0xFFu8; 4];
+	/// assert_eq!(bits.write_msb_first(&mut buf), 2);
+	/// assert_eq!(buf, [0b1101_0000, 0b0000_0000, 0xFF, 0xFF]);
+	/// ```
+	pub fn write_msb_first(&self, buf: &mut [u8]) -> usize {
+		let len = self.len();
+		let bytes = (len >> 3) + (len & 7 != 0) as usize;
+		assert!(
+			bytes <= buf.len(),
+			"A buffer of {} bytes cannot hold {} bits",
+			buf.len(),
+			len,
+		);
+		for (byte, chunk) in buf.iter_mut().zip(self.chunks(8)) {
+			let packed = chunk.iter().fold(0u8, |acc, bit| acc << 1 | bit as u8);
+			*byte = packed << (8 - chunk.len());
+		}
+		bytes
+	}
+
+	/// Expands the slice into a buffer of `bool`s.
+	///
+	/// Each element under the slice is read once, and all of its live bits are
+	/// decoded from that one read, rather than fetching each bit through the
+	/// full index path as the iterator does.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `out`: The buffer to fill. `out[n]` receives `self[n]`.
+	///
+	/// # Panics
+	///
+	/// Panics if `out` is not the same length as the slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b1100_1010u8, 0b1000_0000];
+	/// let bits = &src.bits::<BigEndian>()[2 .. 9];
+	/// let mut out = [false; 7];
+	/// bits.write_bools_into(&mut out);
+	/// assert_eq!(out, [false, false, true, false, true, false, true]);
+	/// ```
+	pub fn write_bools_into(&self, out: &mut [bool]) {
+		use core::cmp::min;
+		let len = self.len();
+		assert_eq!(
+			out.len(),
+			len,
+			"A buffer of {} bools cannot receive {} bits",
+			out.len(),
+			len,
+		);
+		let mut head = *self.bitptr().head();
+		let mut slots = out.iter_mut();
+		for elt in self.as_total_slice() {
+			let elt = elt.load();
+			let end = min(T::BITS as usize, head as usize + slots.len()) as u8;
+			for (n, slot) in (head .. end).zip(&mut slots) {
+				*slot = elt.get::<C>(n.idx());
+			}
+			head = 0;
+		}
+	}
+
+	/// Expands the slice into a vector of `bool`s.
+	///
+	/// This is the allocating form of [`write_bools_into`], for code that needs
+	/// the unpacked form of a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A vector holding each bit of the slice, in order, as a `bool`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b0110u8.bits::<LittleEndian>();
+	/// assert_eq!(&bits[.. 4].to_bool_vec(), &[false, true, true, false]);
+	/// # }
+	/// ```
+	///
+	/// [`write_bools_into`]: #method.write_bools_into
+	#[cfg(feature = "alloc")]
+	pub fn to_bool_vec(&self) -> Vec<bool> {
+		let mut out = Vec::with_capacity(self.len());
+		out.resize(self.len(), false);
+		self.write_bools_into(&mut out);
+		out
+	}
+
+	/// Packs a short slice into an integer bitmask.
+	///
+	/// Bit `n` of the slice becomes the bit of value `1 << n` in the mask, which
+	/// is the layout that SIMD instruction sets use for lane masks: lane `n` of
+	/// a vector is selected by bit `n` of its mask. This is independent of the
+	/// cursor and storage types of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A mask whose `self.len()` least significant bits hold the slice. Its
+	/// remaining bits are cleared.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is longer than `U::BITS`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U: BitStore`: The integer type of the mask.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b0110_0001u8.bits::<BigEndian>();
+	/// assert_eq!(bits.to_bitmask::<u8>(), 0b1000_0110);
+	/// assert_eq!(bits[.. 3].to_bitmask::<u64>(), 0b110);
+	/// ```
+	pub fn to_bitmask<U>(&self) -> U
+	where U: BitStore {
+		assert!(
+			self.len() <= U::BITS as usize,
+			"A slice of {} bits does not fit in a {}-bit mask",
+			self.len(),
+			U::BITS,
+		);
+		self.iter().rev().fold(U::bits(false), |mask, bit| {
+			mask << 1u8 | U::from(bit as u8)
+		})
+	}
+
+	/// Finds the first bit at or after an index that has a given value.
+	///
+	/// Whole elements that hold none of the sought value are skipped without
+	/// examining their bits individually.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `from`: The index at which to begin the search.
+	/// - `value`: The bit value to seek.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit at or after `from` that equals `value`, or
+	/// `self.len()` if there is none.
+	pub(crate) fn seek(&self, from: usize, value: bool) -> usize {
+		use core::cmp::min;
+		let len = self.len();
+		let head = *self.bitptr().head() as usize;
+		let elts = self.as_total_slice();
+		let skip = T::bits(!value);
+		let mut n = from;
+		while n < len {
+			let (elt, bit) = ((head + n) >> T::INDX, (head + n) & T::MASK as usize);
+			let elt = elts[elt].load();
+			let end = min(T::BITS as usize, bit + len - n);
+			if bit == 0 && end == T::BITS as usize && elt == skip {
+				n += T::BITS as usize;
+				continue;
+			}
+			for idx in bit .. end {
+				if elt.get::<C>((idx as u8).idx()) == value {
+					return n + idx - bit;
+				}
+			}
+			n += end - bit;
+		}
+		len
+	}
+
+	/// Finds the index of the bit that has a number of bits of the same value
+	/// before it.
+	///
+	/// When the slice has whole elements, they are skipped by their population
+	/// counts; only the partial edges, and the element that holds the sought
+	/// bit, are examined bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `n`: The number of bits equal to `value` that precede the sought bit.
+	/// - `value`: The bit value to count.
+	///
+	/// # Returns
+	///
+	/// The index of the sought bit, if the slice has more than `n` bits equal
+	/// to `value`.
+	fn select(&self, mut n: usize, value: bool) -> Option<usize> {
+		let (lead, elts, rest) = split_elements(self);
+		let scan = |range: Range<usize>, n: &mut usize| {
+			for idx in range {
+				if unsafe { self.get_unchecked(idx) } == value {
+					if *n == 0 {
+						return Some(idx);
+					}
+					*n -= 1;
+				}
+			}
+			None
+		};
+		let base = lead.end;
+		if let Some(idx) = scan(lead, &mut n) {
+			return Some(idx);
+		}
+		let elts = &self.as_total_slice()[elts];
+		for (k, elt) in elts.iter().enumerate() {
+			let elt = elt.load();
+			let count = if value { elt.count_ones() } else { elt.count_zeros() };
+			if n < count {
+				let start = base + (k << T::INDX);
+				return scan(start .. start + T::BITS as usize, &mut n);
+			}
+			n -= count;
+		}
+		scan(rest, &mut n)
+	}
+
+	/// Accesses the underlying pointer structure.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The [`BitPtr`] structure of the slice handle.
+	///
+	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
+	pub(crate) fn bitptr(&self) -> BitPtr<T> {
+		BitPtr::from_bitslice(self)
+	}
+}
+
+/** Resolves a range of any bounds against a slice length.
+
+# Parameters
+
+- `range`: The range to resolve.
+- `len`: The length of the slice into which `range` indexes.
+
+# Returns
+
+The half-open range of indices described by `range`.
+
+# Panics
+
+Panics if the range start is above its end, or if its end is above `len`.
+**/
+pub(crate) fn resolve_range<R>(range: R, len: usize) -> Range<usize>
+where R: RangeBounds<usize> {
+	use core::ops::Bound::*;
+	let from = match range.start_bound() {
+		Included(&n) => n,
+		Excluded(&n) => n + 1,
+		Unbounded   => 0,
+	};
+	let upto = match range.end_bound() {
+		Included(&n) => n + 1,
+		Excluded(&n) => n,
+		Unbounded    => len,
+	};
+	assert!(from <= upto, "The range start {} is above its end {}", from, upto);
+	assert!(upto <= len, "The range end {} is out of bounds: {}", upto, len);
+	from .. upto
+}
+
+/** Checks that an index is within a length, for the operations that must
+otherwise panic.
+
+With the `no_panic` feature, the check panics only in debug builds, and release
+builds leave the caller to handle an out-of-bounds index without panicking.
+
+# Parameters
+
+- `index`: The index to check.
+- `len`: The length of the slice being indexed.
+
+# Returns
+
+Whether `index` is in bounds.
+
+# Panics
+
+Panics if `index` is not less than `len`, unless the `no_panic` feature is
+enabled in a release build.
+**/
+#[inline]
+pub(crate) fn check_index(index: usize, len: usize) -> bool {
+	let ok = index < len;
+	if cfg!(feature = "no_panic") {
+		debug_assert!(ok, "Index out of range: {} >= {}", index, len);
+	}
+	else {
+		assert!(ok, "Index out of range: {} >= {}", index, len);
+	}
+	ok
+}
+
+/** Splits a slice at the edges of the elements that it fully spans.
+
+Slices that begin at the same bit index within their elements, and have the
+same length, split at the same places, so one split serves all of them.
+
+# Parameters
+
+- `bits`: The slice to split.
+
+# Returns
+
+- The bit indices before the first fully spanned element.
+- The indices, into `bits.as_total_slice()`, of the fully spanned elements.
+- The bit indices after the last fully spanned element.
+**/
+pub(crate) fn split_elements<C, T>(
+	bits: &SliceBit<C, T>,
+) -> (Range<usize>, Range<usize>, Range<usize>)
+where C: Cursor, T: BitStore {
+	use core::cmp::min;
+	let len = bits.len();
+	let lead = match *bits.bitptr().head() {
+		0 => 0,
+		h => min(len, (T::BITS - h) as usize),
+	};
+	let full = (len - lead) >> T::INDX;
+	let skip = (lead != 0) as usize;
+	(0 .. lead, skip .. skip + full, lead + (full << T::INDX) .. len)
+}
+
+/** Tests a relation over each pair of bits at the same index in two slices.
+
+When the slices begin at the same bit index within their elements, the fully
+spanned elements are tested whole, and only the partial edges are tested bit by
+bit.
+
+# Parameters
+
+- `a`, `b`: Two slices of equal length.
+- `bit`: The relation, applied to a pair of bits.
+- `elt`: The same relation, applied to every pair of bits in a pair of fully
+  spanned elements at once.
+
+# Returns
+
+Whether the relation holds for every pair. This returns at the first failure.
+**/
+fn all_zipped<C, T, F, G>(
+	a: &SliceBit<C, T>,
+	b: &SliceBit<C, T>,
+	bit: F,
+	elt: G,
+) -> bool
+where C: Cursor, T: BitStore, F: Fn(bool, bool) -> bool, G: Fn(T, T) -> bool {
+	let test = |n| unsafe { bit(a.get_unchecked(n), b.get_unchecked(n)) };
+	if *a.bitptr().head() != *b.bitptr().head() {
+		return (0 .. a.len()).all(test);
+	}
+	let (mut lead, elts, mut rest) = split_elements(a);
+	let elts_a = &a.as_total_slice()[elts.clone()];
+	let elts_b = &b.as_total_slice()[elts];
+	lead.all(test)
+		&& elts_a.iter().zip(elts_b).all(|(x, y)| elt(x.load(), y.load()))
+		&& rest.all(test)
+}
+
+/** Tests a relation over each triple of bits at the same index in three slices.
+
+This splits the slices as [`all_zipped`] does, when all three begin at the same
+bit index within their elements.
+
+# Parameters
+
+- `a`, `b`, `c`: Three slices of equal length.
+- `bit`: The relation, applied to a triple of bits.
+- `elt`: The same relation, applied to every triple of bits in a triple of fully
+  spanned elements at once.
+
+# Returns
+
+Whether the relation holds for every triple. This returns at the first failure.
+
+[`all_zipped`]: fn.all_zipped.html
+**/
+fn all_zipped3<C, T, F, G>(
+	a: &SliceBit<C, T>,
+	b: &SliceBit<C, T>,
+	c: &SliceBit<C, T>,
+	bit: F,
+	elt: G,
+) -> bool
+where
+	C: Cursor,
+	T: BitStore,
+	F: Fn(bool, bool, bool) -> bool,
+	G: Fn(T, T, T) -> bool,
+{
+	let test = |n| unsafe {
+		bit(a.get_unchecked(n), b.get_unchecked(n), c.get_unchecked(n))
+	};
+	let head = *a.bitptr().head();
+	if head != *b.bitptr().head() || head != *c.bitptr().head() {
+		return (0 .. a.len()).all(test);
+	}
+	let (mut lead, elts, mut rest) = split_elements(a);
+	let elts_a = &a.as_total_slice()[elts.clone()];
+	let elts_b = &b.as_total_slice()[elts.clone()];
+	let elts_c = &c.as_total_slice()[elts];
+	lead.all(test)
+		&& elts_a.iter()
+			.zip(elts_b)
+			.zip(elts_c)
+			.all(|((x, y), z)| elt(x.load(), y.load(), z.load()))
+		&& rest.all(test)
+}
+
+/** Counts the pairs of bits at the same index in two slices that satisfy a
+relation.
+
+This splits the slices as [`all_zipped`] does.
+
+# Parameters
+
+- `a`, `b`: Two slices of equal length.
+- `bit`: The relation, applied to a pair of bits.
+- `elt`: The count of pairs satisfying the relation in a pair of fully spanned
+  elements.
+
+# Returns
+
+The number of indices at which the relation holds.
+
+[`all_zipped`]: fn.all_zipped.html
+**/
+fn sum_zipped<C, T, F, G>(
+	a: &SliceBit<C, T>,
+	b: &SliceBit<C, T>,
+	bit: F,
+	elt: G,
+) -> usize
+where C: Cursor, T: BitStore, F: Fn(bool, bool) -> bool, G: Fn(T, T) -> usize {
+	let test = |&n: &usize| unsafe {
+		bit(a.get_unchecked(n), b.get_unchecked(n))
+	};
+	if *a.bitptr().head() != *b.bitptr().head() {
+		return (0 .. a.len()).filter(test).count();
+	}
+	let (lead, elts, rest) = split_elements(a);
+	let elts_a = &a.as_total_slice()[elts.clone()];
+	let elts_b = &b.as_total_slice()[elts];
+	lead.filter(test).count()
+		+ elts_a.iter()
+			.zip(elts_b)
+			.map(|(x, y)| elt(x.load(), y.load()))
+			.sum::<usize>()
+		+ rest.filter(test).count()
+}
+
+/** Swaps the bits at the same index in two slices.
+
+Memory is only written when the two bits differ.
+
+# Safety
+
+`n` must be in bounds for both `a` and `b`.
+**/
+unsafe fn swap_bits<C, T>(a: &mut SliceBit<C, T>, b: &mut SliceBit<C, T>, n: usize)
+where C: Cursor, T: BitStore {
+	let (bit_a, bit_b) = (a.get_unchecked(n), b.get_unchecked(n));
+	if bit_a != bit_b {
+		a.set_unchecked(n, bit_b);
+		b.set_unchecked(n, bit_a);
+	}
+}
+
+/// Creates an owned `VecBit<C, T>` from a borrowed `SliceBit<C, T>`.
+#[cfg(feature = "alloc")]
+impl<C, T> ToOwned for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Owned = VecBit<C, T>;
+
+	/// Clones a borrowed `SliceBit` into an owned `VecBit`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let store = [0u8, 2];
+	/// let src = store.bits::<LittleEndian>();
+	/// let dst = src.to_owned();
+	/// assert_eq!(src, dst);
+	/// # }
+	/// ```
+	fn to_owned(&self) -> Self::Owned {
+		Self::Owned::from_bitslice(self)
+	}
+}
+
+impl<C, T> Eq for SliceBit<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> Ord for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.partial_cmp(rhs)
+			.unwrap_or_else(|| unreachable!("`SliceBit` has a total ordering"))
+	}
+}
+
+/** Tests if two `SliceBit`s are semantically — not bitwise — equal.
+
+It is valid to compare two slices of different cursor or element types.
+
+The equality condition requires that they have the same number of total bits and
+that each pair of bits in semantic order are identical.
+**/
+impl<A, B, C, D> PartialEq<SliceBit<C, D>> for SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	/// Performas a comparison by `==`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rhs`: Another `SliceBit` against which to compare. This slice can
+	///   have different cursor or storage types.
+	///
+	/// # Returns
+	///
+	/// If the two slices are equal, by comparing the lengths and bit values at
+	/// each semantic index.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let lsrc = [8u8, 16, 32, 0];
+	/// let rsrc = [0x10_08_04_00u32];
+	/// let lbits = lsrc.bits::<LittleEndian>();
+	/// let rbits = rsrc.bits::<BigEndian>();
+	///
+	/// assert_eq!(lbits, rbits);
+	/// ```
+	fn eq(&self, rhs: &SliceBit<C, D>) -> bool {
+		if self.len() != rhs.len() {
+			return false;
+		}
+		if !same_layout::<A, B, C, D>() {
+			return self.iter().zip(rhs.iter()).all(|(l, r)| l == r);
+		}
+		//  The two slices store each bit in the same place, so `rhs` can be
+		//  viewed with the types of `self`, and compared a whole element at a
+		//  time.
+		let rptr = rhs.bitptr();
+		let rhs = unsafe {
+			BitPtr::<B>::new_unchecked(
+				rptr.pointer().r() as *const B,
+				(*rptr.head()).idx(),
+				rptr.len(),
+			)
+		}.into_bitslice::<A>();
+		all_zipped(self, rhs, |l, r| l == r, |l, r| l == r)
+	}
+}
+
+/// Tests if two cursor and storage pairs place every bit in the same position.
+///
+/// Storage types of the same width are the same fundamental type, so slices
+/// with the same layout can be compared element by element, rather than bit by
+/// bit.
+fn same_layout<A, B, C, D>() -> bool
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	B::BITS == D::BITS
+		&& (0 .. B::BITS).all(|n| *A::at::<B>(n.idx()) == *C::at::<D>(n.idx()))
+}
+
+impl<A, B, C, D> PartialEq<SliceBit<C, D>> for &SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &SliceBit<C, D>) -> bool {
+		(*self).eq(rhs)
+	}
+}
+
+/// Allow comparison against the allocated form.
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialEq<VecBit<C, D>> for SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &VecBit<C, D>) -> bool {
+		self.eq(rhs.as_bitslice())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialEq<VecBit<C, D>> for &SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn eq(&self, rhs: &VecBit<C, D>) -> bool {
+		self.eq(rhs.as_bitslice())
+	}
+}
+
+/** Compares two `SliceBit`s by semantic — not bitwise — ordering.
+
+The comparison sorts by testing each index for one slice to have a set bit where
+the other has an unset bit. If the slices are different, the slice with the set
+bit sorts greater than the slice with the unset bit.
+
+If one of the slices is exhausted before they differ, the longer slice is
+greater.
+
+This is not a numeric ordering: `11` sorts after `0111`. Use
+[`SliceBit::cmp_numeric`] or [`SliceBit::cmp_numeric_signed`] to order slices
+by the integers that the arithmetic operators treat them as.
+
+[`SliceBit::cmp_numeric`]: #method.cmp_numeric
+[`SliceBit::cmp_numeric_signed`]: #method.cmp_numeric_signed
+**/
+impl<A, B, C, D> PartialOrd<SliceBit<C, D>> for SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	/// Performs a comparison by `<` or `>`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `rhs`: Another `SliceBit` against which to compare. This slice can
+	///   have different cursor or storage types.
+	///
+	/// # Returns
+	///
+	/// The relative ordering of `self` against `rhs`. `self` is greater if it
+	/// has a `true` bit at an index where `rhs` has a `false`; `self` is lesser
+	/// if it has a `false` bit at an index where `rhs` has a `true`; if the two
+	/// slices do not disagree then they are compared by length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = 0x45u8;
+	/// let bits = src.bits::<BigEndian>();
+	/// let a = &bits[0 .. 3]; // 010
+	/// let b = &bits[0 .. 4]; // 0100
+	/// let c = &bits[0 .. 5]; // 01000
+	/// let d = &bits[4 .. 8]; // 0101
+	///
+	/// assert!(a < b);
+	/// assert!(b < c);
+	/// assert!(c < d);
+	/// ```
+	fn partial_cmp(&self, rhs: &SliceBit<C, D>) -> Option<Ordering> {
+		for (l, r) in self.iter().zip(rhs.iter()) {
+			match (l, r) {
+				(true, false) => return Some(Ordering::Greater),
+				(false, true) => return Some(Ordering::Less),
+				_ => continue,
+			}
+		}
+		self.len().partial_cmp(&rhs.len())
+	}
+}
+
+impl<A, B, C, D> PartialOrd<SliceBit<C, D>> for &SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &SliceBit<C, D>) -> Option<Ordering> {
+		(*self).partial_cmp(rhs)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialOrd<VecBit<C, D>> for SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &VecBit<C, D>) -> Option<Ordering> {
+		self.partial_cmp(rhs.as_bitslice())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, C, D> PartialOrd<VecBit<C, D>> for &SliceBit<A, B>
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	fn partial_cmp(&self, rhs: &VecBit<C, D>) -> Option<Ordering> {
+		(*self).partial_cmp(rhs.as_bitslice())
+	}
+}
+
+/// Provides write access to all elements in the underlying storage, including
+/// the partial head and tail elements if present.
+impl<C, T> AsMut<[T]> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Accesses the underlying store.
+	///
+	/// This will not include partially-owned edge elements, as they may be
+	/// contended by other slice handles.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable slice of all uncontended storage elements.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = [0u8, 128];
+	/// let bits = &mut src.bits_mut::<BigEndian>();
+	///
+	/// for elt in bits.as_mut() {
+	///   *elt += 2;
+	/// }
+	///
+	/// assert_eq!(&[2, 130], bits.as_ref());
+	/// ```
+	fn as_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+/// Provides read access to all elements in the underlying storage, including
+/// the partial head and tail elements if present.
+impl<C, T> AsRef<[T]> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Accesses the underlying store.
+	///
+	/// This will not include partially-owned edge elements, as they may be
+	/// contended by other slice handles.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An immutable slice of all storage elements.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0u8, 128];
+	/// let bits = &src.bits::<BigEndian>();
+	/// assert_eq!(&[0, 128], bits.as_ref());
+	/// ```
+	fn as_ref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<'a, C, T> From<&'a T> for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a T) -> Self {
+		SliceBit::<C, T>::from_element(src)
+	}
+}
+
+impl<'a, C, T> From<&'a [T]> for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a [T]) -> Self {
+		SliceBit::<C, T>::from_slice(src)
+	}
+}
+
+impl<'a, C, T> From<&'a mut T> for &'a mut SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a mut T) -> Self {
+		SliceBit::<C, T>::from_element_mut(src)
+	}
+}
+
+impl<'a, C, T> From<&'a mut [T]> for &'a mut SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn from(src: &'a mut [T]) -> Self {
+		SliceBit::<C, T>::from_slice_mut(src)
+	}
+}
+
+impl<'a, C, T> Default for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn default() -> Self {
+		SliceBit::empty()
+	}
+}
+
+impl<'a, C, T> Default for &'a mut SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn default() -> Self {
+		SliceBit::empty_mut()
+	}
+}
+
+/** Prints the `SliceBit` for debugging.
+
+The output is of the form `SliceBit<C, T> [ELT, *]` where `<C, T>` is the cursor
+and element type, with square brackets on each end of the bits and all the
+elements of the array printed in binary. The printout is always in semantic
+order, and may not reflect the underlying buffer. To see the underlying buffer,
+use `.as_ref()`.
+
+The alternate character `{:#?}` prints each element on its own line, rather than
+having all elements on the same line.
+**/
+impl<C, T> Debug for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `SliceBit` type header and contents for debug.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b0101_0000_1111_0101u16, 0b00000000_0000_0010];
+	/// let bits = &src.bits::<LittleEndian>()[.. 18];
+	/// assert_eq!(
+    ///     "SliceBit<LittleEndian, u16> [1010111100001010, 01]",
+	///     &format!("{:?}", bits),
+	/// );
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("SliceBit<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(T::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(self, f)
+	}
+}
+
+/** Prints the `SliceBit` for displaying.
+
+This prints each element in turn, formatted in binary in semantic order (so the
+first bit seen is printed first and the last bit seen is printed last). Each
+element of storage is separated by a space for ease of reading.
+
+The alternate character `{:#}` prints each element on its own line.
+
+To see the in-memory representation, use `.as_ref()` to get access to the raw
+elements and print that slice instead.
+**/
+impl<C, T> Display for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Renders the `SliceBit` contents for display.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `f`: The formatter into which `self` is written.
+	///
+	/// # Returns
+	///
+	/// The result of the formatting operation.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0b01001011u8, 0b0100_0000];
+	/// let bits = &src.bits::<BigEndian>()[.. 10];
+	/// assert_eq!("[01001011, 01]", &format!("{}", bits));
+	/// # }
+	/// ```
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		struct Part<'a>(&'a str);
+		impl<'a> Debug for Part<'a> {
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+				f.write_str(&self.0)
+			}
+		}
+
+		let mut dbg = f.debug_list();
+		if !self.is_empty() {
+			//  Unfortunately, `T::BITS` cannot be used as the size for the
+			//  array, due to limitations in the type system. As such, set it to
+			//  the maximum used size.
+			//
+			//  This allows the writes to target a static buffer, rather
+			//  than a dynamic string, making the formatter usable in
+			//  `#![no_std]` contexts.
+			let mut w: [u8; 64] = [b'0'; 64];
+			fn writer<C, T>(
+				l: &mut DebugList,
+				w: &mut [u8; 64],
+				e: &T,
+				from: u8,
+				to: u8,
+			)
+			where C: Cursor, T: BitStore {
+				let (from, to) = (from as usize, to as usize);
+				for (n, byte) in w.iter_mut().enumerate().take(to).skip(from) {
+					*byte = b'0' + (e.get::<C>((n as u8).idx()) as u8);
+				}
+				l.entry(&Part(unsafe {
+					str::from_utf8_unchecked(&w[from .. to])
+				}));
+			}
+			match self.bitptr().domain() {
+				BitDomain::Empty => {},
+				BitDomain::Minor(head, elt, tail) => {
+					writer::<C, T>(&mut dbg, &mut w, &elt.load(), *head, *tail)
+				},
+				BitDomain::Major(h, head, body, tail, t) => {
+					writer::<C, T>(&mut dbg, &mut w, &head.load(), *h, T::BITS);
+					for elt in body {
+						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
+					}
+					writer::<C, T>(&mut dbg, &mut w, &tail.load(), 0, *t);
+				},
+				BitDomain::PartialHead(h, head, body) => {
+					writer::<C, T>(&mut dbg, &mut w, &head.load(), *h, T::BITS);
+					for elt in body {
+						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
+					}
+				},
+				BitDomain::PartialTail(body, tail, t) => {
+					for elt in body {
+						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
+					}
+					writer::<C, T>(&mut dbg, &mut w, &tail.load(), 0, *t);
+				},
+				BitDomain::Spanning(body) => {
+					for elt in body {
+						writer::<C, T>(&mut dbg, &mut w, &elt.load(), 0, T::BITS);
+					}
+				},
+			}
+		}
+		dbg.finish()
+	}
+}
+
+/// Writes the contents of the `SliceBit`, in semantic bit order, into a hasher.
+impl<C, T> Hash for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Writes each bit of the `SliceBit`, as a full `bool`, into the hasher.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `hasher`: The hashing state into which the slice will be written.
+	///
+	/// # Type Parameters
+	///
+	/// - `H: Hasher`: The type of the hashing algorithm which receives the bits
+	///   of `self`.
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		for bit in self {
+			hasher.write_u8(bit as u8);
+		}
+	}
+}
+
+/** Produces a read-only iterator over all the bits in the `SliceBit`.
+
+This iterator follows the ordering in the `SliceBit` type, and implements
+`ExactSizeIterator` as `SliceBit` has a known, fixed, length, and
+`DoubleEndedIterator` as it has known ends.
+**/
+impl<'a, C, T> IntoIterator for &'a SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+	type IntoIter = Iter<'a, C, T>;
+
+	/// Iterates over the slice.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the slice domain.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b1010_1100u8.bits::<BigEndian>();
+	/// let mut count = 0;
+	/// for bit in bits {
+	///   if bit { count += 1; }
+	/// }
+	/// assert_eq!(count, 4);
+	/// ```
+	fn into_iter(self) -> Self::IntoIter {
+		Iter {
+			inner: self,
+			taken: 0,
+		}
+	}
+}
+
+/** `SliceBit` is safe to move across thread boundaries, when atomic operations
+are enabled.
+
+Consider this (contrived) example:
+
+```rust
+# #[cfg(feature = "std")] {
+use vecbit::prelude::*;
+use std::thread;
+
+static mut SRC: u8 = 0;
+# {
+let bits = unsafe { SRC.bits_mut::<BigEndian>() };
+let (l, r) = bits.split_at_mut(4);
+
+let a = thread::spawn(move || l.set(2, true));
+let b = thread::spawn(move || r.set(2, true));
+a.join();
+b.join();
+# }
+
+println!("{:02X}", unsafe { SRC });
+# }
+```
+
+Without atomic operations, this is logically a data race. It *so happens* that,
+on x86, the read/modify/write cycles used in the crate are *basically* atomic by
+default, even when not specified as such. This is not necessarily true on other
+architectures, however.
+**/
+#[cfg(feature = "atomic")]
+unsafe impl<C, T> Send for SliceBit<C, T>
+where C: Cursor, T: BitStore {}
+
+/** Reading across threads still has synchronization concerns if one thread can
+mutate, so read access across threads requires atomicity in order to ensure that
+write operations from one thread to an element conclude before another thread
+can read from the element, even if the two `SliceBit`s do not collide.
+**/
+#[cfg(feature = "atomic")]
+unsafe impl<C, T> Sync for SliceBit<C, T>
+where C: Cursor, T: BitStore {}
+
+/** Performs unsigned addition in place on a `SliceBit`.
+
+If the addend bitstream is shorter than `self`, the addend is zero-extended at
+the left (so that its final bit matches with `self`’s final bit). If the addend
+is longer, the excess front length is unused.
+
+Addition proceeds from the right ends of each slice towards the left. Because
+this trait is forbidden from returning anything, the final carry-out bit is
+discarded.
+
+Note that, unlike `VecBit`, there is no subtraction implementation until I find
+a subtraction algorithm that does not require modifying the subtrahend.
+
+Subtraction can be implemented by negating the intended subtrahend yourself and
+then using addition, or by using `VecBit`s instead of `SliceBit`s.
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool, IntoIter: DoubleEndedIterator>`: The bitstream to
+  add into `self`. It must be finite and double-ended, since addition operates
+  in reverse.
+**/
+impl<C, T, I> AddAssign<I> for SliceBit<C, T>
+where C: Cursor, T: BitStore,
+	I: IntoIterator<Item=bool>, I::IntoIter: DoubleEndedIterator {
+	/// Performs unsigned wrapping addition in place.
+	///
+	/// # Examples
+	///
+	/// This example shows addition of a slice wrapping from max to zero.
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = [0b1110_1111u8, 0b0000_0001];
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// let (nums, one) = bits.split_at_mut(12);
+	/// let (accum, steps) = nums.split_at_mut(4);
+	/// *accum += &*one;
+	/// assert_eq!(accum, &steps[.. 4]);
+	/// *accum += &*one;
+	/// assert_eq!(accum, &steps[4 ..]);
+	/// ```
+	//  Clippy doesn’t like single-letter names (which is accurate) but this is
+	//  pretty standard mathematical notation in EE.
+	#[allow(clippy::many_single_char_names)]
+	fn add_assign(&mut self, addend: I) {
+		use core::iter::repeat;
+
+		//  I don't, at this time, want to implement a carry-lookahead adder in
+		//  software, so this is going to be a plain ripple-carry adder with
+		//  O(n) runtime. Furthermore, until I think of an optimization
+		//  strategy, it is going to build up another vecbit to use as a stack.
+		//
+		//  Computers are fast. Whatever.
+		let mut c = false;
+		//  Reverse self, reverse addend and zero-extend, and zip both together.
+		//  This walks both slices from rightmost to leftmost, and considers an
+		//  early expiration of addend to continue with 0 bits.
+		//
+		//  100111
+		// +  0010
+		//  ^^---- semantically zero
+		let addend_iter = addend.into_iter().rev().chain(repeat(false));
+		for (i, b) in (0 .. self.len()).rev().zip(addend_iter) {
+			//  Bounds checks are performed in the loop header.
+			let a = unsafe { self.get_unchecked(i) };
+			let (y, z) = crate::rca1(a, b, c);
+			unsafe { self.set_unchecked(i, y); }
+			c = z;
+		}
+	}
+}
+
+/** Performs the Boolean `AND` operation against another bitstream and writes
+the result into `self`. If the other bitstream ends before `self,`, the
+remaining bits of `self` are cleared.
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `SliceBit`
+  or some other bit producer as desired.
+**/
+impl<C, T, I> BitAndAssign<I> for SliceBit<C, T>
+where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
+	/// `AND`s a bitstream into a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The bitstream to `AND` into `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut store = [0b0101_0100u8];
+	/// let     other = [0b0011_0000u8];
+	/// let lhs = store.bits_mut::<BigEndian>();
+	/// let rhs = other.bits::<BigEndian>();
+	/// lhs[.. 6] &= &rhs[.. 4];
+	/// assert_eq!(store[0], 0b0001_0000);
+	/// ```
+	fn bitand_assign(&mut self, rhs: I) {
+		use core::iter;
+		rhs.into_iter()
+			.chain(iter::repeat(false))
+			.enumerate()
+			.take(self.len())
+			.for_each(|(idx, bit)| {
+				let val = self[idx] & bit;
+				self.set(idx, val);
+			});
+	}
+}
+
+/** Performs the Boolean `OR` operation against another bitstream and writes the
+result into `self`. If the other bitstream ends before `self`, the remaining
+bits of `self` are not affected.
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `SliceBit`
+  or some other bit producer as desired.
+**/
+impl<C, T, I> BitOrAssign<I> for SliceBit<C, T>
+where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
+	/// `OR`s a bitstream into a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The bitstream to `OR` into `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut store = [0b0101_0100u8];
+	/// let     other = [0b0011_0000u8];
+	/// let lhs = store.bits_mut::<BigEndian>();
+	/// let rhs = other.bits::<BigEndian>();
+	/// lhs[.. 6] |= &rhs[.. 4];
+	/// assert_eq!(store[0], 0b0111_0100);
+	/// ```
+	fn bitor_assign(&mut self, rhs: I) {
+		rhs.into_iter()
+			.enumerate()
+			.take(self.len())
+			.for_each(|(idx, bit)| {
+				let val = self[idx] | bit;
+				self.set(idx, val);
+			});
+	}
+}
+
+/** Performs the Boolean `XOR` operation against another bitstream and writes
+the result into `self`. If the other bitstream ends before `self`, the remaining
+bits of `self` are not affected.
+
+# Type Parameters
+
+- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `SliceBit`
+  or some other bit producer as desired.
+**/
+impl<C, T, I> BitXorAssign<I> for SliceBit<C, T>
+where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
+	/// `XOR`s a bitstream into a slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `rhs`: The bitstream to `XOR` into `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut store = [0b0101_0100u8];
+	/// let     other = [0b0011_0000u8];
+	/// let lhs = store.bits_mut::<BigEndian>();
+	/// let rhs = other.bits::<BigEndian>();
+	/// lhs[.. 6] ^= &rhs[.. 4];
+	/// assert_eq!(store[0], 0b0110_0100);
+	/// ```
+	fn bitxor_assign(&mut self, rhs: I) {
+		rhs.into_iter()
+			.enumerate()
+			.take(self.len())
+			.for_each(|(idx, bit)| {
+				let val = self[idx] ^ bit;
+				self.set(idx, val);
+			})
+	}
+}
+
+/// Indexes a single bit by semantic count. The index must be less than the
+/// length of the `SliceBit`.
+impl<C, T> Index<usize> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = bool;
+
+	/// Looks up a single bit by semantic index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The semantic index of the bit to look up.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at the requested index.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is outside the slice domain. With the `no_panic`
+	/// feature, it panics only in debug builds, and otherwise produces `false`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = 0b0010_0000u8;
+	/// let bits = src.bits::<BigEndian>();
+	/// assert!(bits[2]);
+	/// assert!(!bits[3]);
+	/// ```
+	fn index(&self, index: usize) -> &Self::Output {
+		let hit = check_index(index, self.len())
+			&& unsafe { self.get_unchecked(index) };
+		if hit { &true } else { &false }
+	}
+}
+
+/// Indexes a single bit by an index that was checked against a slice when it
+/// was made.
+impl<C, T> Index<BitIndex> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = bool;
+
+	/// Looks up a single bit by checked index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index of the bit to look up.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at the requested index.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` was checked against a longer slice, and is beyond the
+	/// bounds of `self`.
+	fn index(&self, index: BitIndex) -> &Self::Output {
+		&self[*index]
+	}
+}
+
+impl<C, T> Index<Range<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, Range { start, end }: Range<usize>) -> &Self::Output {
+		let (data, head, len) = self.bitptr().raw_parts();
+		assert!(
+			start <= len,
+			"Index {} out of range: {}",
+			start,
+			len,
+		);
+		assert!(end <= len, "Index {} out of range: {}", end, len);
+		assert!(start <= end, "Ranges can only run from low to high");
+		//  Find the number of elements to drop from the front, and the index of
+		//  the new head
+		let (skip, new_head) = head.offset(start as isize);
+		let new_len = end - start;
+		unsafe { BitPtr::new_unchecked(
+			data.r().offset(skip),
+			new_head,
+			new_len,
+		) }.into_bitslice::<C>()
+	}
+}
+
+impl<C, T> IndexMut<Range<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(
+		&mut self,
+		Range { start, end }: Range<usize>,
+	) -> &mut Self::Output {
+		//  Get an immutable slice, and then type-hack mutability back in.
+		(&self[start .. end]).bitptr().into_bitslice_mut()
+	}
+}
+
+impl<C, T> Index<RangeInclusive<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
+		let start = *index.start();
+		//  This check can never fail, due to implementation details of
+		//  `BitPtr<T>`.
+		if let Some(end) = index.end().checked_add(1) {
+			&self[start .. end]
+		}
+		else {
+			&self[start ..]
+		}
+	}
+}
+
+impl<C, T> IndexMut<RangeInclusive<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut Self::Output {
+		let start = *index.start();
+		//  This check can never fail, due to implementation details of
+		//  `BitPtr<T>`.
+		if let Some(end) = index.end().checked_add(1) {
+			&mut self[start .. end]
+		}
+		else {
+			&mut self[start ..]
+		}
+	}
+}
+
+impl<C, T> Index<RangeFrom<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, RangeFrom { start }: RangeFrom<usize>) -> &Self::Output {
+		&self[start .. self.len()]
+	}
+}
+
+impl<C, T> IndexMut<RangeFrom<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(
+		&mut self,
+		RangeFrom { start }: RangeFrom<usize>,
+	) -> &mut Self::Output {
+		let len = self.len();
+		&mut self[start .. len]
+	}
+}
+
+impl<C, T> Index<RangeFull> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, _: RangeFull) -> &Self::Output {
+		self
+	}
+}
+
+impl<C, T> IndexMut<RangeFull> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(&mut self, _: RangeFull) -> &mut Self::Output {
+		self
+	}
+}
+
+impl<C, T> Index<RangeTo<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
+
+	fn index(&self, RangeTo { end }: RangeTo<usize>) -> &Self::Output {
+		&self[0 .. end]
 	}
 }
 
-/// Writes the contents of the `SliceBit`, in semantic bit order, into a hasher.
-impl<C, T> Hash for SliceBit<C, T>
+impl<C, T> IndexMut<RangeTo<usize>> for SliceBit<C, T>
 where C: Cursor, T: BitStore {
-	/// Writes each bit of the `SliceBit`, as a full `bool`, into the hasher.
-	///
-	/// # Parameters
-	///
-	/// - `&self`
-	/// - `hasher`: The hashing state into which the slice will be written.
-	///
-	/// # Type Parameters
-	///
-	/// - `H: Hasher`: The type of the hashing algorithm which receives the bits
-	///   of `self`.
-	fn hash<H>(&self, hasher: &mut H)
-	where H: Hasher {
-		for bit in self {
-			hasher.write_u8(bit as u8);
-		}
+	fn index_mut(
+		&mut self,
+		RangeTo { end }: RangeTo<usize>,
+	) -> &mut Self::Output {
+		&mut self[0 .. end]
 	}
 }
 
-/** Produces a read-only iterator over all the bits in the `SliceBit`.
+impl<C, T> Index<RangeToInclusive<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	type Output = Self;
 
-This iterator follows the ordering in the `SliceBit` type, and implements
-`ExactSizeIterator` as `SliceBit` has a known, fixed, length, and
-`DoubleEndedIterator` as it has known ends.
+	fn index(
+		&self,
+		RangeToInclusive { end }: RangeToInclusive<usize>,
+	) -> &Self::Output {
+		&self[0 ..= end]
+	}
+}
+
+impl<C, T> IndexMut<RangeToInclusive<usize>> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	fn index_mut(
+		&mut self,
+		RangeToInclusive { end }: RangeToInclusive<usize>,
+	) -> &mut Self::Output {
+		&mut self[0 ..= end]
+	}
+}
+
+/** Performs fixed-width 2’s-complement negation of a `SliceBit`.
+
+Unlike the `!` operator (`Not` trait), the unary `-` operator treats the
+`SliceBit` as if it represents a signed 2’s-complement integer of fixed
+width. The negation of a number in 2’s complement is defined as its
+inversion (using `!`) plus one, and on fixed-width numbers has the following
+discontinuities:
+
+- A slice whose bits are all zero is considered to represent the number zero
+  which negates as itself.
+- A slice whose bits are all one is considered to represent the most negative
+  number, which has no correpsonding positive number, and thus negates as zero.
+
+This behavior was chosen so that all possible values would have *some*
+output, and so that repeated application converges at idempotence. The most
+negative input can never be reached by negation, but `--MOST_NEG` converges
+at the least unreasonable fallback value, 0.
+
+Because `SliceBit` cannot move, the negation is performed in place.
 **/
-impl<'a, C, T> IntoIterator for &'a SliceBit<C, T>
+impl<'a, C, T> Neg for &'a mut SliceBit<C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Item = bool;
-	type IntoIter = Iter<'a, C, T>;
+	type Output = Self;
 
-	/// Iterates over the slice.
+	/// Perform 2’s-complement fixed-width negation.
+	///
+	/// Negation is accomplished by inverting the bits and adding one. This has
+	/// one edge case: `1000…`, the most negative number for its width, will
+	/// negate to zero instead of itself. It thas no corresponding positive
+	/// number to which it can negate.
 	///
 	/// # Parameters
 	///
 	/// - `self`
 	///
-	/// # Returns
+	/// # Examples
 	///
-	/// An iterator over the slice domain.
+	/// The contortions shown here are a result of this operator applying to a
+	/// mutable reference, and this example balancing access to the original
+	/// `VecBit` for comparison with aquiring a mutable borrow *as a slice* to
+	/// ensure that the `SliceBit` implementation is used, not the `VecBit`.
 	///
-	/// # Examples
+	/// Negate an arbitrary positive number (first bit unset).
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 0b1010_1100u8.bits::<BigEndian>();
-	/// let mut count = 0;
-	/// for bit in bits {
-	///   if bit { count += 1; }
-	/// }
-	/// assert_eq!(count, 4);
+	/// let mut src = 0b0110_1010u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// eprintln!("{:?}", bits.split_at(4));
+	/// let num = &mut bits[.. 4];
+	/// -num;
+	/// eprintln!("{:?}", bits.split_at(4));
+	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
 	/// ```
-	fn into_iter(self) -> Self::IntoIter {
-		Iter {
-			inner: self
-		}
-	}
-}
-
-/** `SliceBit` is safe to move across thread boundaries, when atomic operations
-are enabled.
-
-Consider this (contrived) example:
-
-```rust
-# #[cfg(feature = "std")] {
-use vecbit::prelude::*;
-use std::thread;
-
-static mut SRC: u8 = 0;
-# {
-let bits = unsafe { SRC.bits_mut::<BigEndian>() };
-let (l, r) = bits.split_at_mut(4);
-
-let a = thread::spawn(move || l.set(2, true));
-let b = thread::spawn(move || r.set(2, true));
-a.join();
-b.join();
-# }
-
-println!("{:02X}", unsafe { SRC });
-# }
-```
-
-Without atomic operations, this is logically a data race. It *so happens* that,
-on x86, the read/modify/write cycles used in the crate are *basically* atomic by
-default, even when not specified as such. This is not necessarily true on other
-architectures, however.
-**/
-#[cfg(feature = "atomic")]
-unsafe impl<C, T> Send for SliceBit<C, T>
-where C: Cursor, T: BitStore {}
-
-/** Reading across threads still has synchronization concerns if one thread can
-mutate, so read access across threads requires atomicity in order to ensure that
-write operations from one thread to an element conclude before another thread
-can read from the element, even if the two `SliceBit`s do not collide.
-**/
-#[cfg(feature = "atomic")]
-unsafe impl<C, T> Sync for SliceBit<C, T>
-where C: Cursor, T: BitStore {}
-
-/** Performs unsigned addition in place on a `SliceBit`.
-
-If the addend bitstream is shorter than `self`, the addend is zero-extended at
-the left (so that its final bit matches with `self`’s final bit). If the addend
-is longer, the excess front length is unused.
-
-Addition proceeds from the right ends of each slice towards the left. Because
-this trait is forbidden from returning anything, the final carry-out bit is
-discarded.
-
-Note that, unlike `VecBit`, there is no subtraction implementation until I find
-a subtraction algorithm that does not require modifying the subtrahend.
-
-Subtraction can be implemented by negating the intended subtrahend yourself and
-then using addition, or by using `VecBit`s instead of `SliceBit`s.
-
-# Type Parameters
-
-- `I: IntoIterator<Item=bool, IntoIter: DoubleEndedIterator>`: The bitstream to
-  add into `self`. It must be finite and double-ended, since addition operates
-  in reverse.
-**/
-impl<C, T, I> AddAssign<I> for SliceBit<C, T>
-where C: Cursor, T: BitStore,
-	I: IntoIterator<Item=bool>, I::IntoIter: DoubleEndedIterator {
-	/// Performs unsigned wrapping addition in place.
 	///
-	/// # Examples
+	/// Negate an arbitrary negative number. This example will use the above
+	/// result to demonstrate round-trip correctness.
 	///
-	/// This example shows addition of a slice wrapping from max to zero.
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut src = 0b1010_0110u8;
+	/// let bits = src.bits_mut::<BigEndian>();
+	/// let num = &mut bits[.. 4];
+	/// -num;
+	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
+	/// ```
+	///
+	/// Negate the most negative number, which will become zero, and show
+	/// convergence at zero.
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = [0b1110_1111u8, 0b0000_0001];
+	/// let mut src = 128u8;
 	/// let bits = src.bits_mut::<BigEndian>();
-	/// let (nums, one) = bits.split_at_mut(12);
-	/// let (accum, steps) = nums.split_at_mut(4);
-	/// *accum += &*one;
-	/// assert_eq!(accum, &steps[.. 4]);
-	/// *accum += &*one;
-	/// assert_eq!(accum, &steps[4 ..]);
+	/// let num = &mut bits[..];
+	/// -num;
+	/// assert!(bits.not_any());
+	/// let num = &mut bits[..];
+	/// -num;
+	/// assert!(bits.not_any());
 	/// ```
-	//  Clippy doesn’t like single-letter names (which is accurate) but this is
-	//  pretty standard mathematical notation in EE.
-	#[allow(clippy::many_single_char_names)]
-	fn add_assign(&mut self, addend: I) {
-		use core::iter::repeat;
-
-		//  I don't, at this time, want to implement a carry-lookahead adder in
-		//  software, so this is going to be a plain ripple-carry adder with
-		//  O(n) runtime. Furthermore, until I think of an optimization
-		//  strategy, it is going to build up another vecbit to use as a stack.
-		//
-		//  Computers are fast. Whatever.
-		let mut c = false;
-		//  Reverse self, reverse addend and zero-extend, and zip both together.
-		//  This walks both slices from rightmost to leftmost, and considers an
-		//  early expiration of addend to continue with 0 bits.
-		//
-		//  100111
-		// +  0010
-		//  ^^---- semantically zero
-		let addend_iter = addend.into_iter().rev().chain(repeat(false));
-		for (i, b) in (0 .. self.len()).rev().zip(addend_iter) {
-			//  Bounds checks are performed in the loop header.
-			let a = unsafe { self.get_unchecked(i) };
-			let (y, z) = crate::rca1(a, b, c);
-			unsafe { self.set_unchecked(i, y); }
-			c = z;
+	fn neg(self) -> Self::Output {
+		//  negative zero is zero. The invert-and-add will result in zero, but
+		//  this case can be detected quickly.
+		if self.is_empty() || self.not_any() {
+			return self;
+		}
+		//  The most negative number (leading one, all zeroes else) negates to
+		//  zero.
+		if self[0] {
+			//  Testing the whole range, rather than [1 ..], is more likely to
+			//  hit the fast path.
+			self.set(0, false);
+			if self.not_any() {
+				return self;
+			}
+			self.set(0, true);
 		}
+		let _ = Not::not(&mut *self);
+		let one: &[T] = &[T::bits(true)];
+		let one_bs: &SliceBit<C, T> = one.into();
+		AddAssign::add_assign(&mut *self, &one_bs[.. 1]);
+		self
 	}
 }
 
-/** Performs the Boolean `AND` operation against another bitstream and writes
-the result into `self`. If the other bitstream ends before `self,`, the
-remaining bits of `self` are cleared.
-
-# Type Parameters
+/// Flips all bits in the slice, in place.
+impl<'a, C, T> Not for &'a mut SliceBit<C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Output = Self;
 
-- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `SliceBit`
-  or some other bit producer as desired.
-**/
-impl<C, T, I> BitAndAssign<I> for SliceBit<C, T>
-where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
-	/// `AND`s a bitstream into a slice.
+	/// Inverts all bits in the slice.
+	///
+	/// This will not affect bits outside the slice in slice storage elements.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `rhs`: The bitstream to `AND` into `self`.
+	/// - `self`
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut store = [0b0101_0100u8];
-	/// let     other = [0b0011_0000u8];
-	/// let lhs = store.bits_mut::<BigEndian>();
-	/// let rhs = other.bits::<BigEndian>();
-	/// lhs[.. 6] &= &rhs[.. 4];
-	/// assert_eq!(store[0], 0b0001_0000);
+	/// let mut src = [0u8; 2];
+	/// let bits = &mut src.bits_mut::<BigEndian>()[2 .. 14];
+	/// let _ = !bits;
+	/// //  The `bits` binding is consumed by the `!` operator, and a new
+	/// //  reference is returned.
+	/// // assert_eq!(bits.as_ref(), &[!0, !0]);
+	/// assert_eq!(src, [0x3F, 0xFC]);
 	/// ```
-	fn bitand_assign(&mut self, rhs: I) {
-		use core::iter;
-		rhs.into_iter()
-			.chain(iter::repeat(false))
-			.enumerate()
-			.take(self.len())
-			.for_each(|(idx, bit)| {
-				let val = self[idx] & bit;
-				self.set(idx, val);
-			});
+	fn not(self) -> Self::Output {
+		match self.bitptr().domain() {
+			BitDomain::Empty => {},
+			BitDomain::Minor(head, elt, tail) => {
+				for n in *head .. *tail {
+					elt.invert_bit::<C>(n.idx());
+				}
+			},
+			BitDomain::Major(h, head, body, tail, t) => {
+				for n in *h .. T::BITS {
+					head.invert_bit::<C>(n.idx());
+				}
+				for elt in body {
+					elt.store(!elt.load());
+				}
+				for n in 0 .. *t {
+					tail.invert_bit::<C>(n.idx());
+				}
+			},
+			BitDomain::PartialHead(h, head, body) => {
+				for n in *h .. T::BITS {
+					head.invert_bit::<C>(n.idx());
+				}
+				for elt in body {
+					elt.store(!elt.load());
+				}
+			},
+			BitDomain::PartialTail(body, tail, t) => {
+				for elt in body {
+					elt.store(!elt.load());
+				}
+				for n in 0 .. *t {
+					tail.invert_bit::<C>(n.idx());
+				}
+			},
+			BitDomain::Spanning(body) => {
+				for elt in body {
+					elt.store(!elt.load());
+				}
+			},
+		}
+		self
 	}
 }
 
-/** Performs the Boolean `OR` operation against another bitstream and writes the
-result into `self`. If the other bitstream ends before `self`, the remaining
-bits of `self` are not affected.
+__bitslice_shift!(u8, u16, u32, u64, i8, i16, i32, i64);
 
-# Type Parameters
+/** Shifts all bits in the array to the left — **DOWN AND TOWARDS THE FRONT**.
 
-- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `SliceBit`
-  or some other bit producer as desired.
-**/
-impl<C, T, I> BitOrAssign<I> for SliceBit<C, T>
-where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
-	/// `OR`s a bitstream into a slice.
-	///
-	/// # Parameters
-	///
-	/// - `&mut self`
-	/// - `rhs`: The bitstream to `OR` into `self`.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let mut store = [0b0101_0100u8];
-	/// let     other = [0b0011_0000u8];
-	/// let lhs = store.bits_mut::<BigEndian>();
-	/// let rhs = other.bits::<BigEndian>();
-	/// lhs[.. 6] |= &rhs[.. 4];
-	/// assert_eq!(store[0], 0b0111_0100);
-	/// ```
-	fn bitor_assign(&mut self, rhs: I) {
-		rhs.into_iter()
-			.enumerate()
-			.take(self.len())
-			.for_each(|(idx, bit)| {
-				let val = self[idx] | bit;
-				self.set(idx, val);
-			});
-	}
-}
+On fundamentals, the left-shift operator `<<` moves bits away from the origin
+and  towards the ceiling. This is because we label the bits in a primitive with
+the  minimum on the right and the maximum on the left, which is big-endian bit
+order.  This increases the value of the primitive being shifted.
 
-/** Performs the Boolean `XOR` operation against another bitstream and writes
-the result into `self`. If the other bitstream ends before `self`, the remaining
-bits of `self` are not affected.
+**THAT IS NOT HOW `SliceBit` WORKS!**
 
-# Type Parameters
+`SliceBit` defines its layout with the minimum on the left and the maximum on
+the right! Thus, left-shifting moves bits towards the **minimum**.
 
-- `I: IntoIterator<Item=bool>`: A stream of bits, which may be a `SliceBit`
-  or some other bit producer as desired.
+In BigEndian order, the effect in memory will be what you expect the `<<`
+operator to do.
+
+**In LittleEndian order, the effect will be equivalent to using `>>` on the**
+**fundamentals in memory!**
+
+# Notes
+
+In order to preserve the effecs in memory that this operator traditionally
+expects, the bits that are emptied by this operation are zeroed rather than
+left to their old value.
+
+The shift amount is modulated against the array length, so it is not an
+error to pass a shift amount greater than the array length.
+
+A shift amount of zero is a no-op, and returns immediately.
 **/
-impl<C, T, I> BitXorAssign<I> for SliceBit<C, T>
-where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
-	/// `XOR`s a bitstream into a slice.
+impl<C, T> ShlAssign<usize> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Shifts a slice left, in place.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `rhs`: The bitstream to `XOR` into `self`.
+	/// - `shamt`: The shift amount. If this is greater than the length, then
+	///   the slice is zeroed immediately.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut store = [0b0101_0100u8];
-	/// let     other = [0b0011_0000u8];
-	/// let lhs = store.bits_mut::<BigEndian>();
-	/// let rhs = other.bits::<BigEndian>();
-	/// lhs[.. 6] ^= &rhs[.. 4];
-	/// assert_eq!(store[0], 0b0110_0100);
+	/// let mut src = [0x4Bu8, 0xA5];
+	/// let bits = &mut src.bits_mut::<BigEndian>()[2 .. 14];
+	/// *bits <<= 3;
+	/// assert_eq!(src, [0b01_011_101, 0b001_000_01]);
 	/// ```
-	fn bitxor_assign(&mut self, rhs: I) {
-		rhs.into_iter()
-			.enumerate()
-			.take(self.len())
-			.for_each(|(idx, bit)| {
-				let val = self[idx] ^ bit;
-				self.set(idx, val);
-			})
+	fn shl_assign(&mut self, shamt: usize) {
+		use core::ops::Shr;
+		if shamt == 0 {
+			return;
+		}
+		let len = self.len();
+		if shamt >= len {
+			self.set_all(false);
+			return;
+		}
+		//  If the shift amount is an even multiple of the element width, use
+		//  `ptr::copy` instead of a bitwise crawl.
+		if shamt & T::MASK as usize == 0 {
+			//  Compute the shift distance measured in elements.
+			let offset = shamt.shr(T::INDX);
+			//  Compute the number of elements that will remain.
+			let rem = self.as_ref().len().saturating_sub(offset);
+			//  Clear the bits after the tail cursor before the move.
+			for n in *self.bitptr().tail() .. T::BITS {
+				self.as_mut()[len.saturating_sub(1)].set::<C>(n.idx(), false);
+			}
+			//  Memory model: suppose we have this slice of sixteen elements,
+			//  that is shifted five elements to the left. We have three
+			//  pointers and two lengths to manage.
+			//  - rem is 11
+			//  - offset is 5
+			//  - head is [0]
+			//  - body is [5; 11]
+			//  - tail is [11]
+			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
+			//              ^-------before------^
+			//    ^-------after-------^ 0 0 0 0 0
+			//  Pointer to the front of the slice
+			let head: *mut T = self.as_mut_ptr();
+			//  Pointer to the front of the section that will move and be
+			//  retained
+			let body: *const T = &self.as_ref()[offset];
+			//  Pointer to the back of the slice that will be zero-filled.
+			let tail: *mut T = &mut self.as_mut()[rem];
+			unsafe {
+				ptr::copy(body, head, rem);
+				ptr::write_bytes(tail, 0, offset);
+			}
+			return;
+		}
+		//  Otherwise, crawl.
+		for (to, from) in (shamt .. len).enumerate() {
+			let val = self[from];
+			self.set(to, val);
+		}
+		for bit in (len.saturating_sub(shamt)) .. len {
+			self.set(bit, false);
+		}
 	}
 }
 
-/// Indexes a single bit by semantic count. The index must be less than the
-/// length of the `SliceBit`.
-impl<C, T> Index<usize> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Output = bool;
+/** Shifts all bits in the array to the right — **UP AND TOWARDS THE BACK**.
+
+On fundamentals, the right-shift operator `>>` moves bits towards the origin and
+away from the ceiling. This is because we label the bits in a primitive with the
+minimum on the right and the maximum on the left, which is big-endian bit order.
+This decreases the value of the primitive being shifted.
+
+**THAT IS NOT HOW `SliceBit` WORKS!**
+
+`SliceBit` defines its layout with the minimum on the left and the maximum on
+the right! Thus, right-shifting moves bits towards the **maximum**.
+
+In Big-Endian order, the effect in memory will be what you expect the `>>`
+operator to do.
+
+**In LittleEndian order, the effect will be equivalent to using `<<` on the**
+**fundamentals in memory!**
 
-	/// Looks up a single bit by semantic index.
+# Notes
+
+In order to preserve the effects in memory that this operator traditionally
+expects, the bits that are emptied by this operation are zeroed rather than left
+to their old value.
+
+The shift amount is modulated against the array length, so it is not an error to
+pass a shift amount greater than the array length.
+
+A shift amount of zero is a no-op, and returns immediately.
+**/
+impl<C, T> ShrAssign<usize> for SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Shifts a slice right, in place.
 	///
 	/// # Parameters
 	///
-	/// - `&self`
-	/// - `index`: The semantic index of the bit to look up.
-	///
-	/// # Returns
-	///
-	/// The value of the bit at the requested index.
+	/// - `&mut self`
+	/// - `shamt`: The shift amount. If this is greater than the length, then
+	///   the slice is zeroed immediately.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let src = 0b0010_0000u8;
-	/// let bits = src.bits::<BigEndian>();
-	/// assert!(bits[2]);
-	/// assert!(!bits[3]);
+	/// let mut src = [0x4Bu8, 0xA5];
+	/// let bits = &mut src.bits_mut::<BigEndian>()[2 .. 14];
+	/// *bits >>= 3;
+	/// assert_eq!(src, [0b01_000_00_1, 0b011_101_01])
 	/// ```
-	fn index(&self, index: usize) -> &Self::Output {
+	fn shr_assign(&mut self, shamt: usize) {
+		if shamt == 0 {
+			return;
+		}
 		let len = self.len();
-		assert!(index < len, "Index out of range: {} >= {}", index, len);
-		if unsafe { self.get_unchecked(index) } { &true } else { &false }
-	}
-}
-
-impl<C, T> Index<Range<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
-
-	fn index(&self, Range { start, end }: Range<usize>) -> &Self::Output {
-		let (data, head, len) = self.bitptr().raw_parts();
-		assert!(
-			start <= len,
-			"Index {} out of range: {}",
-			start,
-			len,
-		);
-		assert!(end <= len, "Index {} out of range: {}", end, len);
-		assert!(start <= end, "Ranges can only run from low to high");
-		//  Find the number of elements to drop from the front, and the index of
-		//  the new head
-		let (skip, new_head) = head.offset(start as isize);
-		let new_len = end - start;
-		unsafe { BitPtr::new_unchecked(
-			data.r().offset(skip),
-			new_head,
-			new_len,
-		) }.into_bitslice::<C>()
-	}
-}
-
-impl<C, T> IndexMut<Range<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		Range { start, end }: Range<usize>,
-	) -> &mut Self::Output {
-		//  Get an immutable slice, and then type-hack mutability back in.
-		(&self[start .. end]).bitptr().into_bitslice_mut()
-	}
-}
-
-impl<C, T> Index<RangeInclusive<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
-
-	fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
-		let start = *index.start();
-		//  This check can never fail, due to implementation details of
-		//  `BitPtr<T>`.
-		if let Some(end) = index.end().checked_add(1) {
-			&self[start .. end]
+		if shamt >= len {
+			self.set_all(false);
+			return;
 		}
-		else {
-			&self[start ..]
+		//  IF the shift amount is an even multiple of the element width, use
+		//  `ptr::copy` instead of a bitwise crawl.
+		if shamt & T::MASK as usize == 0 {
+			//  Compute the shift amount measured in elements.
+			let offset = shamt >> T::INDX;
+			// Compute the number of elements that will remain.
+			let rem = self.as_ref().len().saturating_sub(offset);
+			//  Clear the bits ahead of the head cursor before the move.
+			for n in 0 .. *self.bitptr().head() {
+				self.as_mut()[0].set::<C>(n.idx(), false);
+			}
+			//  Memory model: suppose we have this slice of sixteen elements,
+			//  that is shifted five elements to the right. We have two pointers
+			//  and two lengths to manage.
+			//  - rem is 11
+			//  - offset is 5
+			//  - head is [0; 11]
+			//  - body is [5]
+			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
+			//    ^-------before------^
+			//    0 0 0 0 0 ^-------after-------^
+			let head: *mut T = self.as_mut_ptr();
+			let body: *mut T = &mut self.as_mut()[offset];
+			unsafe {
+				ptr::copy(head, body, rem);
+				ptr::write_bytes(head, 0, offset);
+			}
+			return;
 		}
-	}
-}
-
-impl<C, T> IndexMut<RangeInclusive<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut Self::Output {
-		let start = *index.start();
-		//  This check can never fail, due to implementation details of
-		//  `BitPtr<T>`.
-		if let Some(end) = index.end().checked_add(1) {
-			&mut self[start .. end]
+		//  Otherwise, crawl.
+		for (from, to) in (shamt .. len).enumerate().rev() {
+			let val = self[from];
+			self.set(to, val);
 		}
-		else {
-			&mut self[start ..]
+		for bit in 0 .. shamt {
+			self.set(bit, false);
 		}
 	}
 }
 
-impl<C, T> Index<RangeFrom<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
-
-	fn index(&self, RangeFrom { start }: RangeFrom<usize>) -> &Self::Output {
-		&self[start .. self.len()]
-	}
-}
-
-impl<C, T> IndexMut<RangeFrom<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		RangeFrom { start }: RangeFrom<usize>,
-	) -> &mut Self::Output {
-		let len = self.len();
-		&mut self[start .. len]
-	}
-}
-
-impl<C, T> Index<RangeFull> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+/** Write reference to a single bit.
 
-	fn index(&self, _: RangeFull) -> &Self::Output {
-		self
-	}
-}
+Rust requires that `DerefMut` produce the plain address of a value which can be
+written with a `memcpy`, so, there is no way to make plain write assignments
+work nicely in Rust. This reference structure is the second best option.
 
-impl<C, T> IndexMut<RangeFull> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(&mut self, _: RangeFull) -> &mut Self::Output {
-		self
-	}
+It contains a write reference to a single-bit slice, and a local cache `bool`.
+This structure `Deref`s to the local cache, and commits the cache to the slice
+on drop. This allows writing to the guard with `=` assignment.
+**/
+#[derive(Debug)]
+pub struct BitGuard<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	slot: &'a mut SliceBit<C, T>,
+	bit: bool,
+	_m: PhantomData<*mut T>,
 }
 
-impl<C, T> Index<RangeTo<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
+/// Read from the local cache.
+impl<'a, C, T> Deref for BitGuard<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Target = bool;
 
-	fn index(&self, RangeTo { end }: RangeTo<usize>) -> &Self::Output {
-		&self[0 .. end]
+	fn deref(&self) -> &Self::Target {
+		&self.bit
 	}
 }
 
-impl<C, T> IndexMut<RangeTo<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		RangeTo { end }: RangeTo<usize>,
-	) -> &mut Self::Output {
-		&mut self[0 .. end]
+/// Write to the local cache.
+impl<'a, C, T> DerefMut for BitGuard<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.bit
 	}
 }
 
-impl<C, T> Index<RangeToInclusive<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	type Output = Self;
-
-	fn index(
-		&self,
-		RangeToInclusive { end }: RangeToInclusive<usize>,
-	) -> &Self::Output {
-		&self[0 ..= end]
+/// Commit the local cache to the backing slice.
+impl<'a, C, T> Drop for BitGuard<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn drop(&mut self) {
+		self.slot.set(0, self.bit);
 	}
 }
 
-impl<C, T> IndexMut<RangeToInclusive<usize>> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	fn index_mut(
-		&mut self,
-		RangeToInclusive { end }: RangeToInclusive<usize>,
-	) -> &mut Self::Output {
-		&mut self[0 ..= end]
-	}
-}
+/// This type is a mutable reference with extra steps, so, it should be moveable
+/// but not shareable.
+unsafe impl<'a, C, T> Send for BitGuard<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
 
-/** Performs fixed-width 2’s-complement negation of a `SliceBit`.
+/** State keeper for chunked iteration over a `SliceBit`.
 
-Unlike the `!` operator (`Not` trait), the unary `-` operator treats the
-`SliceBit` as if it represents a signed 2’s-complement integer of fixed
-width. The negation of a number in 2’s complement is defined as its
-inversion (using `!`) plus one, and on fixed-width numbers has the following
-discontinuities:
+# Type Parameters
 
-- A slice whose bits are all zero is considered to represent the number zero
-  which negates as itself.
-- A slice whose bits are all one is considered to represent the most negative
-  number, which has no correpsonding positive number, and thus negates as zero.
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
 
-This behavior was chosen so that all possible values would have *some*
-output, and so that repeated application converges at idempotence. The most
-negative input can never be reached by negation, but `--MOST_NEG` converges
-at the least unreasonable fallback value, 0.
+# Lifetimes
 
-Because `SliceBit` cannot move, the negation is performed in place.
+- `'a`: The lifetime of the underlying `SliceBit`.
 **/
-impl<'a, C, T> Neg for &'a mut SliceBit<C, T>
+#[derive(Clone, Debug)]
+pub struct Chunks<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Output = Self;
+	/// The `SliceBit` being iterated.
+	inner: &'a SliceBit<C, T>,
+	/// The width of the chunks.
+	width: usize,
+}
 
-	/// Perform 2’s-complement fixed-width negation.
-	///
-	/// Negation is accomplished by inverting the bits and adding one. This has
-	/// one edge case: `1000…`, the most negative number for its width, will
-	/// negate to zero instead of itself. It thas no corresponding positive
-	/// number to which it can negate.
+impl<'a, C, T> DoubleEndedIterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the next chunk from the back of the slice.
 	///
 	/// # Parameters
 	///
-	/// - `self`
+	/// - `&mut self`
 	///
-	/// # Examples
+	/// # Returns
 	///
-	/// The contortions shown here are a result of this operator applying to a
-	/// mutable reference, and this example balancing access to the original
-	/// `VecBit` for comparison with aquiring a mutable borrow *as a slice* to
-	/// ensure that the `SliceBit` implementation is used, not the `VecBit`.
+	/// The last chunk in the slice, if any.
 	///
-	/// Negate an arbitrary positive number (first bit unset).
+	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = 0b0110_1010u8;
-	/// let bits = src.bits_mut::<BigEndian>();
-	/// eprintln!("{:?}", bits.split_at(4));
-	/// let num = &mut bits[.. 4];
-	/// -num;
-	/// eprintln!("{:?}", bits.split_at(4));
-	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
+	/// let bits = 1u8.bits::<BigEndian>();
+	/// let mut chunks = bits.chunks(5);
+	/// assert_eq!(chunks.next_back(), Some(&bits[5 ..]));
+	/// assert_eq!(chunks.next_back(), Some(&bits[.. 5]));
+	/// assert!(chunks.next_back().is_none());
 	/// ```
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.inner.is_empty() {
+			return None;
+		}
+		let len = self.inner.len();
+		let rem = len % self.width;
+		let size = if rem == 0 { self.width } else { rem };
+		let (head, tail) = self.inner.split_at(len - size);
+		self.inner = head;
+		Some(tail)
+	}
+}
+
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Chunks<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = &'a SliceBit<C, T>;
+
+	/// Advances the iterator by one, returning the first chunk in it (if any).
 	///
-	/// Negate an arbitrary negative number. This example will use the above
-	/// result to demonstrate round-trip correctness.
+	/// # Parameters
 	///
-	/// ```rust
-	/// use vecbit::prelude::*;
+	/// - `&mut self`
 	///
-	/// let mut src = 0b1010_0110u8;
-	/// let bits = src.bits_mut::<BigEndian>();
-	/// let num = &mut bits[.. 4];
-	/// -num;
-	/// assert_eq!(&bits[.. 4], &bits[4 ..]);
-	/// ```
+	/// # Returns
 	///
-	/// Negate the most negative number, which will become zero, and show
-	/// convergence at zero.
+	/// The leading chunk in the iterator, if any.
+	///
+	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = 128u8;
-	/// let bits = src.bits_mut::<BigEndian>();
-	/// let num = &mut bits[..];
-	/// -num;
-	/// assert!(bits.not_any());
-	/// let num = &mut bits[..];
-	/// -num;
-	/// assert!(bits.not_any());
+	/// let bits = 1u8.bits::<LittleEndian>();
+	/// let mut chunks = bits.chunks(5);
+	/// assert_eq!(chunks.next(), Some(&bits[.. 5]));
+	/// assert_eq!(chunks.next(), Some(&bits[5 ..]));
+	/// assert!(chunks.next().is_none());
 	/// ```
-	fn neg(self) -> Self::Output {
-		//  negative zero is zero. The invert-and-add will result in zero, but
-		//  this case can be detected quickly.
-		if self.is_empty() || self.not_any() {
-			return self;
-		}
-		//  The most negative number (leading one, all zeroes else) negates to
-		//  zero.
-		if self[0] {
-			//  Testing the whole range, rather than [1 ..], is more likely to
-			//  hit the fast path.
-			self.set(0, false);
-			if self.not_any() {
-				return self;
-			}
-			self.set(0, true);
+	fn next(&mut self) -> Option<Self::Item> {
+		use core::cmp::min;
+		if self.inner.is_empty() {
+			return None;
 		}
-		let _ = Not::not(&mut *self);
-		let one: &[T] = &[T::bits(true)];
-		let one_bs: &SliceBit<C, T> = one.into();
-		AddAssign::add_assign(&mut *self, &one_bs[.. 1]);
-		self
+		let size = min(self.inner.len(), self.width);
+		let (head, tail) = self.inner.split_at(size);
+		self.inner = tail;
+		Some(head)
 	}
-}
 
-/// Flips all bits in the slice, in place.
-impl<'a, C, T> Not for &'a mut SliceBit<C, T>
-where C: Cursor, T: 'a + BitStore {
-	type Output = Self;
-
-	/// Inverts all bits in the slice.
+	/// Hints at the number of chunks remaining in the iterator.
 	///
-	/// This will not affect bits outside the slice in slice storage elements.
+	/// Because the exact size is always known, this always produces
+	/// `(len, Some(len))`.
 	///
 	/// # Parameters
 	///
-	/// - `self`
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `usize`: The minimum chunks remaining.
+	/// - `Option<usize>`: The maximum chunks remaining.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = [0u8; 2];
-	/// let bits = &mut src.bits_mut::<BigEndian>()[2 .. 14];
-	/// let _ = !bits;
-	/// //  The `bits` binding is consumed by the `!` operator, and a new
-	/// //  reference is returned.
-	/// // assert_eq!(bits.as_ref(), &[!0, !0]);
-	/// assert_eq!(src, [0x3F, 0xFC]);
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// let mut chunks = bits.chunks(5);
+	/// assert_eq!(chunks.size_hint(), (2, Some(2)));
+	/// chunks.next();
+	/// assert_eq!(chunks.size_hint(), (1, Some(1)));
+	/// chunks.next();
+	/// assert_eq!(chunks.size_hint(), (0, Some(0)));
 	/// ```
-	fn not(self) -> Self::Output {
-		match self.bitptr().domain() {
-			BitDomain::Empty => {},
-			BitDomain::Minor(head, elt, tail) => {
-				for n in *head .. *tail {
-					elt.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomain::Major(h, head, body, tail, t) => {
-				for n in *h .. T::BITS {
-					head.invert_bit::<C>(n.idx());
-				}
-				for elt in body {
-					elt.store(!elt.load());
-				}
-				for n in 0 .. *t {
-					tail.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomain::PartialHead(h, head, body) => {
-				for n in *h .. T::BITS {
-					head.invert_bit::<C>(n.idx());
-				}
-				for elt in body {
-					elt.store(!elt.load());
-				}
-			},
-			BitDomain::PartialTail(body, tail, t) => {
-				for elt in body {
-					elt.store(!elt.load());
-				}
-				for n in 0 .. *t {
-					tail.invert_bit::<C>(n.idx());
-				}
-			},
-			BitDomain::Spanning(body) => {
-				for elt in body {
-					elt.store(!elt.load());
-				}
-			},
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.inner.is_empty() {
+			return (0, Some(0));
 		}
-		self
+		let len = self.inner.len();
+		let (n, r) = (len / self.width, len % self.width);
+		let len = n + (r > 0) as usize;
+		(len, Some(len))
 	}
-}
-
-__bitslice_shift!(u8, u16, u32, u64, i8, i16, i32, i64);
-
-/** Shifts all bits in the array to the left — **DOWN AND TOWARDS THE FRONT**.
-
-On fundamentals, the left-shift operator `<<` moves bits away from the origin
-and  towards the ceiling. This is because we label the bits in a primitive with
-the  minimum on the right and the maximum on the left, which is big-endian bit
-order.  This increases the value of the primitive being shifted.
-
-**THAT IS NOT HOW `SliceBit` WORKS!**
-
-`SliceBit` defines its layout with the minimum on the left and the maximum on
-the right! Thus, left-shifting moves bits towards the **minimum**.
-
-In BigEndian order, the effect in memory will be what you expect the `<<`
-operator to do.
-
-**In LittleEndian order, the effect will be equivalent to using `>>` on the**
-**fundamentals in memory!**
-
-# Notes
-
-In order to preserve the effecs in memory that this operator traditionally
-expects, the bits that are emptied by this operation are zeroed rather than
-left to their old value.
 
-The shift amount is modulated against the array length, so it is not an
-error to pass a shift amount greater than the array length.
+	/// Counts how many chunks are live in the iterator, consuming it.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The number of chunks remaining in the iterator.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// assert_eq!(bits.chunks(3).count(), 3);
+	/// ```
+	fn count(self) -> usize {
+		self.len()
+	}
 
-A shift amount of zero is a no-op, and returns immediately.
-**/
-impl<C, T> ShlAssign<usize> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	/// Shifts a slice left, in place.
+	/// Advances the iterator by `n` chunks, starting from zero.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `shamt`: The shift amount. If this is greater than the length, then
-	///   the slice is zeroed immediately.
+	/// - `n`: The number of chunks to skip, before producing the next bit after
+	///   skips. If this overshoots the iterator’s remaining length, then the
+	///   iterator is marked empty before returning `None`.
+	///
+	/// # Returns
+	///
+	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
+	/// bit after advancing the iterator to it, discarding the intermediate
+	/// chunks.
+	///
+	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
+	/// and returns `None`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut src = [0x4Bu8, 0xA5];
-	/// let bits = &mut src.bits_mut::<BigEndian>()[2 .. 14];
-	/// *bits <<= 3;
-	/// assert_eq!(src, [0b01_011_101, 0b001_000_01]);
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// let mut chunks = bits.chunks(3);
+	/// assert_eq!(chunks.nth(1), Some(&bits[3 .. 6]));
+	/// assert_eq!(chunks.nth(0), Some(&bits[6 ..]));
+	/// assert!(chunks.nth(0).is_none());
 	/// ```
-	fn shl_assign(&mut self, shamt: usize) {
-		use core::ops::Shr;
-		if shamt == 0 {
-			return;
-		}
-		let len = self.len();
-		if shamt >= len {
-			self.set_all(false);
-			return;
-		}
-		//  If the shift amount is an even multiple of the element width, use
-		//  `ptr::copy` instead of a bitwise crawl.
-		if shamt & T::MASK as usize == 0 {
-			//  Compute the shift distance measured in elements.
-			let offset = shamt.shr(T::INDX);
-			//  Compute the number of elements that will remain.
-			let rem = self.as_ref().len().saturating_sub(offset);
-			//  Clear the bits after the tail cursor before the move.
-			for n in *self.bitptr().tail() .. T::BITS {
-				self.as_mut()[len.saturating_sub(1)].set::<C>(n.idx(), false);
-			}
-			//  Memory model: suppose we have this slice of sixteen elements,
-			//  that is shifted five elements to the left. We have three
-			//  pointers and two lengths to manage.
-			//  - rem is 11
-			//  - offset is 5
-			//  - head is [0]
-			//  - body is [5; 11]
-			//  - tail is [11]
-			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
-			//              ^-------before------^
-			//    ^-------after-------^ 0 0 0 0 0
-			//  Pointer to the front of the slice
-			let head: *mut T = self.as_mut_ptr();
-			//  Pointer to the front of the section that will move and be
-			//  retained
-			let body: *const T = &self.as_ref()[offset];
-			//  Pointer to the back of the slice that will be zero-filled.
-			let tail: *mut T = &mut self.as_mut()[rem];
-			unsafe {
-				ptr::copy(body, head, rem);
-				ptr::write_bytes(tail, 0, offset);
-			}
-			return;
-		}
-		//  Otherwise, crawl.
-		for (to, from) in (shamt .. len).enumerate() {
-			let val = self[from];
-			self.set(to, val);
-		}
-		for bit in (len.saturating_sub(shamt)) .. len {
-			self.set(bit, false);
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		use core::cmp::min;
+		let (start, ovf) = n.overflowing_mul(self.width);
+		let len = self.inner.len();
+		if start >= len || ovf {
+			self.inner = SliceBit::empty();
+			return None;
 		}
+		let end = start.checked_add(self.width)
+			.map(|s| min(s, len))
+			.unwrap_or(len);
+		let out = &self.inner[start .. end];
+		self.inner = &self.inner[end ..];
+		Some(out)
+	}
+
+	/// Consumes the iterator, returning only the final chunk.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The last chunk in the iterator slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// assert_eq!(bits.chunks(3).last(), Some(&bits[6 ..]));
+	/// ```
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
 	}
 }
 
-/** Shifts all bits in the array to the right — **UP AND TOWARDS THE BACK**.
+/** State keeper for mutable chunked iteration over a `SliceBit`.
 
-On fundamentals, the right-shift operator `>>` moves bits towards the origin and
-away from the ceiling. This is because we label the bits in a primitive with the
-minimum on the right and the maximum on the left, which is big-endian bit order.
-This decreases the value of the primitive being shifted.
+# Type Parameters
 
-**THAT IS NOT HOW `SliceBit` WORKS!**
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
 
-`SliceBit` defines its layout with the minimum on the left and the maximum on
-the right! Thus, right-shifting moves bits towards the **maximum**.
+# Lifetimes
 
-In Big-Endian order, the effect in memory will be what you expect the `>>`
-operator to do.
+- `'a`: The lifetime of the underlying `SliceBit`.
+**/
+#[derive(Debug)]
+pub struct ChunksMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The `SliceBit` being iterated.
+	inner: &'a mut SliceBit<C, T>,
+	/// The width of the chunks.
+	width: usize,
+}
 
-**In LittleEndian order, the effect will be equivalent to using `<<` on the**
-**fundamentals in memory!**
+impl<'a, C, T> DoubleEndedIterator for ChunksMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the next chunk from the back of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The last chunk in the slice, if any.
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.inner.is_empty() {
+			return None;
+		}
+		let len = self.inner.len();
+		let rem = len % self.width;
+		let size = if rem == 0 { self.width } else { rem };
+		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
+		let (head, tail) = tmp.split_at_mut(len - size);
+		self.inner = head;
+		Some(tail)
+	}
+}
 
-# Notes
+impl<'a, C, T> ExactSizeIterator for ChunksMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
 
-In order to preserve the effects in memory that this operator traditionally
-expects, the bits that are emptied by this operation are zeroed rather than left
-to their old value.
+impl<'a, C, T> FusedIterator for ChunksMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
 
-The shift amount is modulated against the array length, so it is not an error to
-pass a shift amount greater than the array length.
+impl<'a, C, T> Iterator for ChunksMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = &'a mut SliceBit<C, T>;
 
-A shift amount of zero is a no-op, and returns immediately.
-**/
-impl<C, T> ShrAssign<usize> for SliceBit<C, T>
-where C: Cursor, T: BitStore {
-	/// Shifts a slice right, in place.
+	/// Advances the iterator by one, returning the first chunk in it (if any).
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `shamt`: The shift amount. If this is greater than the length, then
-	///   the slice is zeroed immediately.
-	///
-	/// # Examples
 	///
-	/// ```rust
-	/// use vecbit::prelude::*;
+	/// # Returns
 	///
-	/// let mut src = [0x4Bu8, 0xA5];
-	/// let bits = &mut src.bits_mut::<BigEndian>()[2 .. 14];
-	/// *bits >>= 3;
-	/// assert_eq!(src, [0b01_000_00_1, 0b011_101_01])
-	/// ```
-	fn shr_assign(&mut self, shamt: usize) {
-		if shamt == 0 {
-			return;
-		}
-		let len = self.len();
-		if shamt >= len {
-			self.set_all(false);
-			return;
-		}
-		//  IF the shift amount is an even multiple of the element width, use
-		//  `ptr::copy` instead of a bitwise crawl.
-		if shamt & T::MASK as usize == 0 {
-			//  Compute the shift amount measured in elements.
-			let offset = shamt >> T::INDX;
-			// Compute the number of elements that will remain.
-			let rem = self.as_ref().len().saturating_sub(offset);
-			//  Clear the bits ahead of the head cursor before the move.
-			for n in 0 .. *self.bitptr().head() {
-				self.as_mut()[0].set::<C>(n.idx(), false);
-			}
-			//  Memory model: suppose we have this slice of sixteen elements,
-			//  that is shifted five elements to the right. We have two pointers
-			//  and two lengths to manage.
-			//  - rem is 11
-			//  - offset is 5
-			//  - head is [0; 11]
-			//  - body is [5]
-			//  [ 0 1 2 3 4 5 6 7 8 9 a b c d e f ]
-			//    ^-------before------^
-			//    0 0 0 0 0 ^-------after-------^
-			let head: *mut T = self.as_mut_ptr();
-			let body: *mut T = &mut self.as_mut()[offset];
-			unsafe {
-				ptr::copy(head, body, rem);
-				ptr::write_bytes(head, 0, offset);
-			}
-			return;
-		}
-		//  Otherwise, crawl.
-		for (from, to) in (shamt .. len).enumerate().rev() {
-			let val = self[from];
-			self.set(to, val);
-		}
-		for bit in 0 .. shamt {
-			self.set(bit, false);
+	/// The leading chunk in the iterator, if any.
+	fn next(&mut self) -> Option<Self::Item> {
+		use core::cmp::min;
+		if self.inner.is_empty() {
+			return None;
 		}
+		let size = min(self.inner.len(), self.width);
+		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
+		let (head, tail) = tmp.split_at_mut(size);
+		self.inner = tail;
+		Some(head)
 	}
-}
-
-/** Write reference to a single bit.
-
-Rust requires that `DerefMut` produce the plain address of a value which can be
-written with a `memcpy`, so, there is no way to make plain write assignments
-work nicely in Rust. This reference structure is the second best option.
-
-It contains a write reference to a single-bit slice, and a local cache `bool`.
-This structure `Deref`s to the local cache, and commits the cache to the slice
-on drop. This allows writing to the guard with `=` assignment.
-**/
-#[derive(Debug)]
-pub struct BitGuard<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	slot: &'a mut SliceBit<C, T>,
-	bit: bool,
-	_m: PhantomData<*mut T>,
-}
 
-/// Read from the local cache.
-impl<'a, C, T> Deref for BitGuard<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	type Target = bool;
+	/// Hints at the number of chunks remaining in the iterator.
+	///
+	/// Because the exact size is always known, this always produces
+	/// `(len, Some(len))`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `usize`: The minimum chunks remaining.
+	/// - `Option<usize>`: The maximum chunks remaining.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.inner.is_empty() {
+			return (0, Some(0));
+		}
+		let len = self.inner.len();
+		let (n, r) = (len / self.width, len % self.width);
+		let len = n + (r > 0) as usize;
+		(len, Some(len))
+	}
 
-	fn deref(&self) -> &Self::Target {
-		&self.bit
+	/// Counts how many chunks are live in the iterator, consuming it.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The number of chunks remaining in the iterator.
+	fn count(self) -> usize {
+		self.len()
 	}
-}
 
-/// Write to the local cache.
-impl<'a, C, T> DerefMut for BitGuard<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn deref_mut(&mut self) -> &mut Self::Target {
-		&mut self.bit
+	/// Advances the iterator by `n` chunks, starting from zero.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The number of chunks to skip, before producing the next bit after
+	///   skips. If this overshoots the iterator’s remaining length, then the
+	///   iterator is marked empty before returning `None`.
+	///
+	/// # Returns
+	///
+	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
+	/// bit after advancing the iterator to it, discarding the intermediate
+	/// chunks.
+	///
+	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
+	/// and returns `None`.
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		use core::cmp::min;
+		let (start, ovf) = n.overflowing_mul(self.width);
+		let len = self.inner.len();
+		if start >= len || ovf {
+			self.inner = SliceBit::empty_mut();
+			return None;
+		}
+		let end = start.checked_add(self.width)
+			.map(|s| min(s, len))
+			.unwrap_or(len);
+		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
+		let (head, tail) = tmp.split_at_mut(start);
+		let (_, nth) = head.split_at_mut(end - start);
+		self.inner = tail;
+		Some(nth)
 	}
-}
 
-/// Commit the local cache to the backing slice.
-impl<'a, C, T> Drop for BitGuard<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	fn drop(&mut self) {
-		self.slot.set(0, self.bit);
+	/// Consumes the iterator, returning only the final chunk.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The last chunk in the iterator slice, if any.
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
 	}
 }
 
-/// This type is a mutable reference with extra steps, so, it should be moveable
-/// but not shareable.
-unsafe impl<'a, C, T> Send for BitGuard<'a, C, T>
-where C: Cursor, T: 'a + BitStore {}
-
-/** State keeper for chunked iteration over a `SliceBit`.
+/** State keeper for exact chunked iteration over a `SliceBit`.
 
 # Type Parameters
 
@@ -3645,15 +6568,44 @@ where C: Cursor, T: 'a + BitStore {}
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
 #[derive(Clone, Debug)]
-pub struct Chunks<'a, C, T>
+pub struct ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
 	inner: &'a SliceBit<C, T>,
+	/// The excess of the original `SliceBit`, which is not iterated.
+	extra: &'a SliceBit<C, T>,
 	/// The width of the chunks.
 	width: usize,
 }
 
-impl<'a, C, T> DoubleEndedIterator for Chunks<'a, C, T>
+impl<'a, C, T> ChunksExact<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the remainder of the original slice, which will not be included
+	/// in the iteration.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The remaining slice that iteration will not include.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// let chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.remainder(), &bits[6 ..]);
+	/// ```
+	pub fn remainder(&self) -> &'a SliceBit<C, T> {
+		self.extra
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// Produces the next chunk from the back of the slice.
 	///
@@ -3671,33 +6623,31 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 1u8.bits::<BigEndian>();
-	/// let mut chunks = bits.chunks(5);
-	/// assert_eq!(chunks.next_back(), Some(&bits[5 ..]));
-	/// assert_eq!(chunks.next_back(), Some(&bits[.. 5]));
-	/// assert!(chunks.next_back().is_none());
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.next_back(), Some(&bits[3 .. 6]));
+	/// assert_eq!(chunks_exact.next_back(), Some(&bits[0 .. 3]));
+	/// assert!(chunks_exact.next_back().is_none());
 	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = SliceBit::empty();
 			return None;
 		}
-		let len = self.inner.len();
-		let rem = len % self.width;
-		let size = if rem == 0 { self.width } else { rem };
-		let (head, tail) = self.inner.split_at(len - size);
+		let (head, tail) = self.inner.split_at(self.inner.len() - self.width);
 		self.inner = head;
 		Some(tail)
 	}
 }
 
 /// Mark that the iterator has an exact size.
-impl<'a, C, T> ExactSizeIterator for Chunks<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
 /// Mark that the iterator will not resume after halting.
-impl<'a, C, T> FusedIterator for Chunks<'a, C, T>
+impl<'a, C, T> FusedIterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for Chunks<'a, C, T>
+impl<'a, C, T> Iterator for ChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	type Item = &'a SliceBit<C, T>;
 
@@ -3717,18 +6667,17 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 1u8.bits::<LittleEndian>();
-	/// let mut chunks = bits.chunks(5);
-	/// assert_eq!(chunks.next(), Some(&bits[.. 5]));
-	/// assert_eq!(chunks.next(), Some(&bits[5 ..]));
-	/// assert!(chunks.next().is_none());
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.next(), Some(&bits[0 .. 3]));
+	/// assert_eq!(chunks_exact.next(), Some(&bits[3 .. 6]));
+	/// assert!(chunks_exact.next().is_none());
 	/// ```
 	fn next(&mut self) -> Option<Self::Item> {
-		use core::cmp::min;
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = SliceBit::empty();
 			return None;
 		}
-		let size = min(self.inner.len(), self.width);
-		let (head, tail) = self.inner.split_at(size);
+		let (head, tail) = self.inner.split_at(self.width);
 		self.inner = tail;
 		Some(head)
 	}
@@ -3753,20 +6702,15 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let mut chunks = bits.chunks(5);
-	/// assert_eq!(chunks.size_hint(), (2, Some(2)));
-	/// chunks.next();
-	/// assert_eq!(chunks.size_hint(), (1, Some(1)));
-	/// chunks.next();
-	/// assert_eq!(chunks.size_hint(), (0, Some(0)));
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.size_hint(), (2, Some(2)));
+	/// chunks_exact.next();
+	/// assert_eq!(chunks_exact.size_hint(), (1, Some(1)));
+	/// chunks_exact.next();
+	/// assert_eq!(chunks_exact.size_hint(), (0, Some(0)));
 	/// ```
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		if self.inner.is_empty() {
-			return (0, Some(0));
-		}
-		let len = self.inner.len();
-		let (n, r) = (len / self.width, len % self.width);
-		let len = n + (r > 0) as usize;
+		let len = self.inner.len() / self.width;
 		(len, Some(len))
 	}
 
@@ -3786,7 +6730,7 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.chunks(3).count(), 3);
+	/// assert_eq!(bits.chunks_exact(3).count(), 2);
 	/// ```
 	fn count(self) -> usize {
 		self.len()
@@ -3815,26 +6759,20 @@ where C: Cursor, T: 'a + BitStore {
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let mut chunks = bits.chunks(3);
-	/// assert_eq!(chunks.nth(1), Some(&bits[3 .. 6]));
-	/// assert_eq!(chunks.nth(0), Some(&bits[6 ..]));
-	/// assert!(chunks.nth(0).is_none());
+	/// let bits = 2u8.bits::<BigEndian>();
+	/// let mut chunks_exact = bits.chunks_exact(3);
+	/// assert_eq!(chunks_exact.nth(1), Some(&bits[3 .. 6]));
+	/// assert!(chunks_exact.nth(0).is_none());
 	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		use core::cmp::min;
 		let (start, ovf) = n.overflowing_mul(self.width);
-		let len = self.inner.len();
-		if start >= len || ovf {
+		if start >= self.inner.len() || ovf {
 			self.inner = SliceBit::empty();
 			return None;
 		}
-		let end = start.checked_add(self.width)
-			.map(|s| min(s, len))
-			.unwrap_or(len);
-		let out = &self.inner[start .. end];
-		self.inner = &self.inner[end ..];
-		Some(out)
+		let (_, tail) = self.inner.split_at(start);
+		self.inner = tail;
+		self.next()
 	}
 
 	/// Consumes the iterator, returning only the final chunk.
@@ -3853,14 +6791,14 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.chunks(3).last(), Some(&bits[6 ..]));
+	/// assert_eq!(bits.chunks_exact(3).last(), Some(&bits[3 .. 6]));
 	/// ```
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for mutable chunked iteration over a `SliceBit`.
+/** State keeper for mutable exact chunked iteration over a `SliceBit`.
 
 # Type Parameters
 
@@ -3872,17 +6810,36 @@ where C: Cursor, T: 'a + BitStore {
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
 #[derive(Debug)]
-pub struct ChunksMut<'a, C, T>
+pub struct ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
 	inner: &'a mut SliceBit<C, T>,
+	/// The excess of the original `SliceBit`, which is not iterated.
+	extra: &'a mut SliceBit<C, T>,
 	/// The width of the chunks.
 	width: usize,
 }
 
-impl<'a, C, T> DoubleEndedIterator for ChunksMut<'a, C, T>
+impl<'a, C, T> ChunksExactMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the remainder of the original slice, which will not be included
+	/// in the iteration.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The remaining slice that iteration will not include.
+	pub fn into_remainder(self) -> &'a mut SliceBit<C, T> {
+		self.extra
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the back of the slice.
+	/// Produces the next chunk from the back of th eslice.
 	///
 	/// # Parameters
 	///
@@ -3892,26 +6849,17 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// The last chunk in the slice, if any.
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.is_empty() {
-			return None;
-		}
-		let len = self.inner.len();
-		let rem = len % self.width;
-		let size = if rem == 0 { self.width } else { rem };
-		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (head, tail) = tmp.split_at_mut(len - size);
-		self.inner = head;
-		Some(tail)
+		unimplemented!()
 	}
 }
 
-impl<'a, C, T> ExactSizeIterator for ChunksMut<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> FusedIterator for ChunksMut<'a, C, T>
+impl<'a, C, T> FusedIterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for ChunksMut<'a, C, T>
+impl<'a, C, T> Iterator for ChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	type Item = &'a mut SliceBit<C, T>;
 
@@ -3925,13 +6873,12 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// The leading chunk in the iterator, if any.
 	fn next(&mut self) -> Option<Self::Item> {
-		use core::cmp::min;
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = SliceBit::empty_mut();
 			return None;
 		}
-		let size = min(self.inner.len(), self.width);
 		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (head, tail) = tmp.split_at_mut(size);
+		let (head, tail) = tmp.split_at_mut(self.width);
 		self.inner = tail;
 		Some(head)
 	}
@@ -3950,12 +6897,7 @@ where C: Cursor, T: 'a + BitStore {
 	/// - `usize`: The minimum chunks remaining.
 	/// - `Option<usize>`: The maximum chunks remaining.
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		if self.inner.is_empty() {
-			return (0, Some(0));
-		}
-		let len = self.inner.len();
-		let (n, r) = (len / self.width, len % self.width);
-		let len = n + (r > 0) as usize;
+		let len = self.inner.len() / self.width;
 		(len, Some(len))
 	}
 
@@ -3990,21 +6932,15 @@ where C: Cursor, T: 'a + BitStore {
 	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
 	/// and returns `None`.
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		use core::cmp::min;
 		let (start, ovf) = n.overflowing_mul(self.width);
-		let len = self.inner.len();
-		if start >= len || ovf {
+		if start >= self.inner.len() || ovf {
 			self.inner = SliceBit::empty_mut();
 			return None;
 		}
-		let end = start.checked_add(self.width)
-			.map(|s| min(s, len))
-			.unwrap_or(len);
 		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (head, tail) = tmp.split_at_mut(start);
-		let (_, nth) = head.split_at_mut(end - start);
+		let (_, tail) = tmp.split_at_mut(start);
 		self.inner = tail;
-		Some(nth)
+		self.next()
 	}
 
 	/// Consumes the iterator, returning only the final chunk.
@@ -4021,7 +6957,12 @@ where C: Cursor, T: 'a + BitStore {
 	}
 }
 
-/** State keeper for exact chunked iteration over a `SliceBit`.
+/** State keeper for iteration over a `SliceBit`.
+
+The iterator is a slice handle and a counter, so cloning it is as cheap as
+copying a slice reference, and never copies the bits it views. This makes it
+suitable for backtracking: save a clone before attempting to parse, and restore
+it on failure.
 
 # Type Parameters
 
@@ -4032,29 +6973,280 @@ where C: Cursor, T: 'a + BitStore {
 
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
-#[derive(Clone, Debug)]
-pub struct ChunksExact<'a, C, T>
+#[derive(Debug)]
+pub struct Iter<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
 	inner: &'a SliceBit<C, T>,
-	/// The excess of the original `SliceBit`, which is not iterated.
-	extra: &'a SliceBit<C, T>,
-	/// The width of the chunks.
-	width: usize,
+	/// The number of bits taken from the front of the original slice.
+	taken: usize,
+}
+
+impl<'a, C, T> Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Views the bits that the iterator has not yet produced.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The remainder of the original slice, borrowed for the original lifetime.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b1100_1010u8.bits::<BigEndian>();
+	/// let mut iter = bits.iter();
+	/// iter.next();
+	/// iter.next_back();
+	/// assert_eq!(iter.as_bitslice(), &bits[1 .. 7]);
+	/// ```
+	pub fn as_bitslice(&self) -> &'a SliceBit<C, T> {
+		self.inner
+	}
+
+	/// Counts the bits that the iterator has produced from the front.
+	///
+	/// Bits taken from the back, with `next_back`, are not counted, so this is
+	/// always the index in the original slice of the next bit from the front.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of bits consumed from the front of the original slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0u16.bits::<LittleEndian>();
+	/// let mut iter = bits.iter();
+	/// iter.next();
+	/// iter.nth(4);
+	/// iter.next_back();
+	/// assert_eq!(iter.offset(), 6);
+	///
+	/// let saved = iter.clone();
+	/// iter.nth(3);
+	/// assert_eq!(iter.offset(), 10);
+	/// iter = saved;
+	/// assert_eq!(iter.offset(), 6);
+	/// ```
+	pub fn offset(&self) -> usize {
+		self.taken
+	}
+
+	/// Looks at the next bit from the front, without consuming it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The bit that `next` would produce, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b0100_0000u8.bits::<BigEndian>();
+	/// let mut iter = bits.iter();
+	/// iter.next();
+	/// assert_eq!(iter.peek(), Some(true));
+	/// assert_eq!(iter.next(), Some(true));
+	/// ```
+	pub fn peek(&self) -> Option<bool> {
+		self.inner.first()
+	}
+
+	/// Accesses the `BitPtr` representation of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The `BitPtr` representation of the remaining slice.
+	//  The linter is incorrect; this method is absolutely used.
+	#[allow(dead_code)]
+	pub(crate) fn bitptr(&self) -> BitPtr<T> {
+		self.inner.bitptr()
+	}
+
+	/// Advances the iterator past the first bit that satisfies a predicate,
+	/// reading each memory element once.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `pred`: The test applied to each bit.
+	///
+	/// # Returns
+	///
+	/// The index, counted from the current front of the iterator, of the first
+	/// bit for which `pred` holds, if any. The iterator resumes after that bit,
+	/// or is empty if there is none.
+	fn seek_by<F>(&mut self, mut pred: F) -> Option<usize>
+	where F: FnMut(bool) -> bool {
+		let bitptr = self.inner.bitptr();
+		let (head, tail) = (*bitptr.head(), *bitptr.tail());
+		let elts = bitptr.as_access_slice();
+		let last = elts.len().wrapping_sub(1);
+		let mut pos = 0;
+		for (n, elt) in elts.iter().enumerate() {
+			let word = BitAccess::load(elt);
+			let from = if n == 0 { head } else { 0 };
+			let upto = if n == last { tail } else { T::BITS };
+			for place in from .. upto {
+				if pred(word.get::<C>(place.idx())) {
+					self.inner = &self.inner[pos + 1 ..];
+					self.taken += pos + 1;
+					return Some(pos);
+				}
+				pos += 1;
+			}
+		}
+		self.inner = &self.inner[pos ..];
+		self.taken += pos;
+		None
+	}
+}
+
+/// Cloning is available for every cursor, and copies only the slice handle.
+impl<'a, C, T> Clone for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner,
+			taken: self.taken,
+		}
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the next bit from the back of the slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The last bit in the slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = &1u8.bits::<BigEndian>()[6 ..];
+	/// let mut iter = bits.iter();
+	/// assert!(iter.next_back().unwrap());
+	/// assert!(!iter.next_back().unwrap());
+	/// assert!(iter.next_back().is_none());
+	/// ```
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.split_last().map(|(b, r)| {
+			self.inner = r;
+			b
+		})
+	}
 }
 
-impl<'a, C, T> ChunksExact<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	/// Produces the remainder of the original slice, which will not be included
-	/// in the iteration.
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Iter<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = bool;
+
+	/// Advances the iterator by one, returning the first bit in it (if any).
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The leading bit in the iterator, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = &1u8.bits::<LittleEndian>()[.. 2];
+	/// let mut iter = bits.iter();
+	/// assert!(iter.next().unwrap());
+	/// assert!(!iter.next().unwrap());
+	/// assert!(iter.next().is_none());
+	/// ```
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.split_first().map(|(b, r)| {
+			self.inner = r;
+			self.taken += 1;
+			b
+		})
+	}
+
+	/// Hints at the number of bits remaining in the iterator.
+	///
+	/// Because the exact size is always known, this always produces
+	/// `(len, Some(len))`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `usize`: The minimum bits remaining.
+	/// - `Option<usize>`: The maximum bits remaining.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = &0x4Bu8.bits::<BigEndian>()[.. 2];
+	/// let mut iter = bits.iter();
+	/// assert_eq!(iter.size_hint(), (2, Some(2)));
+	/// iter.next();
+	/// assert_eq!(iter.size_hint(), (1, Some(1)));
+	/// iter.next();
+	/// assert_eq!(iter.size_hint(), (0, Some(0)));
+	/// ```
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		(len, Some(len))
+	}
+
+	/// Counts how many bits are live in the iterator, consuming it.
 	///
 	/// # Parameters
 	///
-	/// - `&self`
+	/// - `self`
 	///
 	/// # Returns
 	///
-	/// The remaining slice that iteration will not include.
+	/// The number of bits remaining in the iterator.
 	///
 	/// # Examples
 	///
@@ -4062,208 +7254,223 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.remainder(), &bits[6 ..]);
+	/// assert_eq!(bits.iter().count(), 8);
 	/// ```
-	pub fn remainder(&self) -> &'a SliceBit<C, T> {
-		self.extra
+	fn count(self) -> usize {
+		self.len()
 	}
-}
 
-impl<'a, C, T> DoubleEndedIterator for ChunksExact<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the back of the slice.
+	/// Advances the iterator by `n` bits, starting from zero.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
+	/// - `n`: The number of bits to skip, before producing the next bit after
+	///   skips. If this overshoots the iterator’s remaining length, then the
+	///   iterator is marked empty before returning `None`.
 	///
 	/// # Returns
 	///
-	/// The last chunk in the slice, if any.
+	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
+	/// bit after advancing the iterator to it, discarding the intermediate
+	/// bits.
+	///
+	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
+	/// and returns `None`.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 1u8.bits::<BigEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.next_back(), Some(&bits[3 .. 6]));
-	/// assert_eq!(chunks_exact.next_back(), Some(&bits[0 .. 3]));
-	/// assert!(chunks_exact.next_back().is_none());
+	/// let bits = 2u8.bits::<BigEndian>();
+	/// let mut iter = bits.iter();
+	/// assert!(iter.nth(6).unwrap());
+	/// assert!(!iter.nth(0).unwrap());
+	/// assert!(iter.nth(0).is_none());
 	/// ```
-	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.taken += self.len();
 			self.inner = SliceBit::empty();
 			return None;
 		}
-		let (head, tail) = self.inner.split_at(self.inner.len() - self.width);
-		self.inner = head;
-		Some(tail)
+		self.inner = &self.inner[n ..];
+		self.taken += n;
+		self.next()
 	}
-}
-
-/// Mark that the iterator has an exact size.
-impl<'a, C, T> ExactSizeIterator for ChunksExact<'a, C, T>
-where C: Cursor, T: 'a + BitStore {}
-
-/// Mark that the iterator will not resume after halting.
-impl<'a, C, T> FusedIterator for ChunksExact<'a, C, T>
-where C: Cursor, T: 'a + BitStore {}
-
-impl<'a, C, T> Iterator for ChunksExact<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	type Item = &'a SliceBit<C, T>;
 
-	/// Advances the iterator by one, returning the first chunk in it (if any).
+	/// Consumes the iterator, returning only the final bit.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
+	/// - `self`
 	///
 	/// # Returns
 	///
-	/// The leading chunk in the iterator, if any.
+	/// The last bit in the iterator slice, if any.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 1u8.bits::<LittleEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.next(), Some(&bits[0 .. 3]));
-	/// assert_eq!(chunks_exact.next(), Some(&bits[3 .. 6]));
-	/// assert!(chunks_exact.next().is_none());
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// assert!(bits.iter().last().unwrap());
 	/// ```
-	fn next(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = SliceBit::empty();
-			return None;
-		}
-		let (head, tail) = self.inner.split_at(self.width);
-		self.inner = tail;
-		Some(head)
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
 	}
 
-	/// Hints at the number of chunks remaining in the iterator.
+	/// Folds every remaining bit into an accumulator, consuming the iterator.
 	///
-	/// Because the exact size is always known, this always produces
-	/// `(len, Some(len))`.
+	/// This reads each memory element once and walks its bits, rather than
+	/// seeking each bit from the front of the slice as [`next`] does, so
+	/// iterator chains ending in `fold`, `sum`, `for_each`, and similar
+	/// consumers stay on the fast path. (`try_fold` cannot be specialized on
+	/// the stable compiler; chains that stop early should use `all`, `any`,
+	/// or `position`, which are.)
 	///
 	/// # Parameters
 	///
-	/// - `&self`
+	/// - `self`
+	/// - `init`: The initial value of the accumulator.
+	/// - `func`: Combines the accumulator with the next bit.
 	///
 	/// # Returns
 	///
-	/// - `usize`: The minimum chunks remaining.
-	/// - `Option<usize>`: The maximum chunks remaining.
+	/// The accumulator after every bit has been folded into it.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.size_hint(), (2, Some(2)));
-	/// chunks_exact.next();
-	/// assert_eq!(chunks_exact.size_hint(), (1, Some(1)));
-	/// chunks_exact.next();
-	/// assert_eq!(chunks_exact.size_hint(), (0, Some(0)));
+	/// let bits = &[0x4Bu8, 0x20].bits::<BigEndian>()[1 .. 11];
+	/// let value = bits.iter().fold(0u16, |acc, bit| acc << 1 | bit as u16);
+	/// assert_eq!(value, 0b10_0101_1001);
 	/// ```
-	fn size_hint(&self) -> (usize, Option<usize>) {
-		let len = self.inner.len() / self.width;
-		(len, Some(len))
+	///
+	/// [`next`]: #method.next
+	fn fold<B, F>(self, init: B, mut func: F) -> B
+	where F: FnMut(B, bool) -> B {
+		let bitptr = self.inner.bitptr();
+		let (head, tail) = (*bitptr.head(), *bitptr.tail());
+		let elts = bitptr.as_access_slice();
+		let last = elts.len().wrapping_sub(1);
+		let mut acc = init;
+		for (n, elt) in elts.iter().enumerate() {
+			let word = BitAccess::load(elt);
+			let from = if n == 0 { head } else { 0 };
+			let upto = if n == last { tail } else { T::BITS };
+			for place in from .. upto {
+				acc = func(acc, word.get::<C>(place.idx()));
+			}
+		}
+		acc
 	}
 
-	/// Counts how many chunks are live in the iterator, consuming it.
+	/// Tests if every remaining bit satisfies a predicate.
+	///
+	/// Like [`fold`], this reads each memory element once. It stops at the
+	/// first bit that fails the predicate, and leaves the iterator after it.
 	///
 	/// # Parameters
 	///
-	/// - `self`
+	/// - `&mut self`
+	/// - `pred`: The test applied to each bit.
 	///
 	/// # Returns
 	///
-	/// The number of chunks remaining in the iterator.
+	/// Whether `pred` holds for every bit. This is `true` for an empty
+	/// iterator.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.chunks_exact(3).count(), 2);
+	/// let bits = 0b1110_1111u8.bits::<BigEndian>();
+	/// let mut iter = bits.iter();
+	/// assert!(!iter.all(|bit| bit));
+	/// assert_eq!(iter.len(), 4);
+	/// assert!(iter.all(|bit| bit));
 	/// ```
-	fn count(self) -> usize {
-		self.len()
+	///
+	/// [`fold`]: #method.fold
+	fn all<F>(&mut self, mut pred: F) -> bool
+	where F: FnMut(bool) -> bool {
+		self.seek_by(|bit| !pred(bit)).is_none()
 	}
 
-	/// Advances the iterator by `n` chunks, starting from zero.
+	/// Tests if any remaining bit satisfies a predicate.
+	///
+	/// Like [`fold`], this reads each memory element once. It stops at the
+	/// first bit that passes the predicate, and leaves the iterator after it.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `n`: The number of chunks to skip, before producing the next bit after
-	///   skips. If this overshoots the iterator’s remaining length, then the
-	///   iterator is marked empty before returning `None`.
+	/// - `pred`: The test applied to each bit.
 	///
 	/// # Returns
 	///
-	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
-	/// bit after advancing the iterator to it, discarding the intermediate
-	/// chunks.
-	///
-	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
-	/// and returns `None`.
+	/// Whether `pred` holds for any bit. This is `false` for an empty
+	/// iterator.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 2u8.bits::<BigEndian>();
-	/// let mut chunks_exact = bits.chunks_exact(3);
-	/// assert_eq!(chunks_exact.nth(1), Some(&bits[3 .. 6]));
-	/// assert!(chunks_exact.nth(0).is_none());
+	/// let bits = [0u8, 0, 0x10].bits::<LittleEndian>();
+	/// let mut iter = bits.iter();
+	/// assert!(iter.any(|bit| bit));
+	/// assert_eq!(iter.len(), 3);
+	/// assert!(!iter.any(|bit| bit));
 	/// ```
-	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		let (start, ovf) = n.overflowing_mul(self.width);
-		if start >= self.inner.len() || ovf {
-			self.inner = SliceBit::empty();
-			return None;
-		}
-		let (_, tail) = self.inner.split_at(start);
-		self.inner = tail;
-		self.next()
+	///
+	/// [`fold`]: #method.fold
+	fn any<F>(&mut self, pred: F) -> bool
+	where F: FnMut(bool) -> bool {
+		self.seek_by(pred).is_some()
 	}
 
-	/// Consumes the iterator, returning only the final chunk.
+	/// Finds the index of the first remaining bit that satisfies a predicate.
+	///
+	/// Like [`fold`], this reads each memory element once. It stops at the
+	/// first bit that passes the predicate, and leaves the iterator after it.
 	///
 	/// # Parameters
 	///
-	/// - `self`
+	/// - `&mut self`
+	/// - `pred`: The test applied to each bit.
 	///
 	/// # Returns
 	///
-	/// The last chunk in the iterator slice, if any.
+	/// The index, counted from the current front of the iterator, of the first
+	/// bit for which `pred` holds, if any.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.chunks_exact(3).last(), Some(&bits[3 .. 6]));
+	/// let bits = [0u8, 0x21].bits::<BigEndian>();
+	/// let mut iter = bits.iter();
+	/// assert_eq!(iter.position(|bit| bit), Some(10));
+	/// assert_eq!(iter.position(|bit| bit), Some(4));
+	/// assert_eq!(iter.position(|bit| bit), None);
 	/// ```
-	fn last(mut self) -> Option<Self::Item> {
-		self.next_back()
+	///
+	/// [`fold`]: #method.fold
+	fn position<F>(&mut self, pred: F) -> Option<usize>
+	where F: FnMut(bool) -> bool {
+		self.seek_by(pred)
 	}
 }
 
-/** State keeper for mutable exact chunked iteration over a `SliceBit`.
+/** State keeper for reverse chunked iteration over a `SliceBit`.
 
 # Type Parameters
 
@@ -4274,59 +7481,62 @@ where C: Cursor, T: 'a + BitStore {
 
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
-#[derive(Debug)]
-pub struct ChunksExactMut<'a, C, T>
+#[derive(Clone, Debug)]
+pub struct RChunks<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
-	inner: &'a mut SliceBit<C, T>,
-	/// The excess of the original `SliceBit`, which is not iterated.
-	extra: &'a mut SliceBit<C, T>,
+	inner: &'a SliceBit<C, T>,
 	/// The width of the chunks.
 	width: usize,
 }
 
-impl<'a, C, T> ChunksExactMut<'a, C, T>
+impl<'a, C, T> DoubleEndedIterator for RChunks<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the remainder of the original slice, which will not be included
-	/// in the iteration.
+	/// Produces the next chunk from the front of the slice.
 	///
 	/// # Parameters
 	///
-	/// - `&self`
+	/// - `&mut self`
 	///
 	/// # Returns
 	///
-	/// The remaining slice that iteration will not include.
-	pub fn into_remainder(self) -> &'a mut SliceBit<C, T> {
-		self.extra
-	}
-}
-
-impl<'a, C, T> DoubleEndedIterator for ChunksExactMut<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the back of th eslice.
-	///
-	/// # Parameters
+	/// The last chunk in the slice, if any.
 	///
-	/// - `&mut self`
+	/// # Examples
 	///
-	/// # Returns
+	/// ```rust
+	/// use vecbit::prelude::*;
 	///
-	/// The last chunk in the slice, if any.
+	/// let bits = 1u8.bits::<BigEndian>();
+	/// let mut rchunks = bits.rchunks(5);
+	/// assert_eq!(rchunks.next_back(), Some(&bits[.. 3]));
+	/// assert_eq!(rchunks.next_back(), Some(&bits[3 ..]));
+	/// assert!(rchunks.next_back().is_none());
+	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
-		unimplemented!()
+		if self.inner.is_empty() {
+			return None;
+		}
+		let len = self.inner.len();
+		let rem = len % self.width;
+		let size = if rem == 0 { self.width } else { rem };
+		let (head, tail) = self.inner.split_at(size);
+		self.inner = tail;
+		Some(head)
 	}
 }
 
-impl<'a, C, T> ExactSizeIterator for ChunksExactMut<'a, C, T>
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for RChunks<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> FusedIterator for ChunksExactMut<'a, C, T>
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for RChunks<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for ChunksExactMut<'a, C, T>
+impl<'a, C, T> Iterator for RChunks<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Item = &'a mut SliceBit<C, T>;
+	type Item = &'a SliceBit<C, T>;
 
 	/// Advances the iterator by one, returning the first chunk in it (if any).
 	///
@@ -4337,15 +7547,28 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The leading chunk in the iterator, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 1u8.bits::<LittleEndian>();
+	/// let mut rchunks = bits.rchunks(5);
+	/// assert_eq!(rchunks.next(), Some(&bits[3 ..]));
+	/// assert_eq!(rchunks.next(), Some(&bits[.. 3]));
+	/// assert!(rchunks.next().is_none());
+	/// ```
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = SliceBit::empty_mut();
+		use core::cmp::min;
+		if self.inner.is_empty() {
 			return None;
 		}
-		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (head, tail) = tmp.split_at_mut(self.width);
-		self.inner = tail;
-		Some(head)
+		let len = self.inner.len();
+		let size = min(len, self.width);
+		let (head, tail) = self.inner.split_at(len - size);
+		self.inner = head;
+		Some(tail)
 	}
 
 	/// Hints at the number of chunks remaining in the iterator.
@@ -4361,8 +7584,27 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// - `usize`: The minimum chunks remaining.
 	/// - `Option<usize>`: The maximum chunks remaining.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// let mut rchunks = bits.rchunks(5);
+	/// assert_eq!(rchunks.size_hint(), (2, Some(2)));
+	/// rchunks.next();
+	/// assert_eq!(rchunks.size_hint(), (1, Some(1)));
+	/// rchunks.next();
+	/// assert_eq!(rchunks.size_hint(), (0, Some(0)));
+	/// ```
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let len = self.inner.len() / self.width;
+		if self.inner.is_empty() {
+			return (0, Some(0));
+		}
+		let len = self.inner.len();
+		let (len, rem) = (len / self.width, len % self.width);
+		let len = len + (rem > 0) as usize;
 		(len, Some(len))
 	}
 
@@ -4375,6 +7617,15 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The number of chunks remaining in the iterator.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// assert_eq!(bits.rchunks(3).count(), 3);
+	/// ```
 	fn count(self) -> usize {
 		self.len()
 	}
@@ -4396,16 +7647,29 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
 	/// and returns `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 2u8.bits::<BigEndian>();
+	/// let mut rchunks = bits.rchunks(3);
+	/// assert_eq!(rchunks.nth(2), Some(&bits[0 .. 2]));
+	/// assert!(rchunks.nth(0).is_none());
+	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		let (start, ovf) = n.overflowing_mul(self.width);
-		if start >= self.inner.len() || ovf {
-			self.inner = SliceBit::empty_mut();
+		let (end, ovf) = n.overflowing_mul(self.width);
+		if end >= self.inner.len() || ovf {
+			self.inner = SliceBit::empty();
 			return None;
 		}
-		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (_, tail) = tmp.split_at_mut(start);
-		self.inner = tail;
-		self.next()
+		// Can't underflow because of the check above
+		let end = self.inner.len() - end;
+		let start = end.checked_sub(self.width).unwrap_or(0);
+		let nth = &self.inner[start .. end];
+		self.inner = &self.inner[.. start];
+		Some(nth)
 	}
 
 	/// Consumes the iterator, returning only the final chunk.
@@ -4417,12 +7681,21 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// The last chunk in the iterator slice, if any.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// assert_eq!(bits.rchunks(3).last(), Some(&bits[.. 2]));
+	/// ```
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for iteration over a `SliceBit`.
+/** State keeper for mutable reverse chunked iteration over a `SliceBit`.
 
 # Type Parameters
 
@@ -4433,34 +7706,18 @@ where C: Cursor, T: 'a + BitStore {
 
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
-#[derive(Clone, Debug)]
-pub struct Iter<'a, C, T>
+#[derive(Debug)]
+pub struct RChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
-	inner: &'a SliceBit<C, T>,
-}
-
-impl<'a, C, T> Iter<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	/// Accesses the `BitPtr` representation of the slice.
-	///
-	/// # Parameters
-	///
-	/// - `&self`
-	///
-	/// # Returns
-	///
-	/// The `BitPtr` representation of the remaining slice.
-	//  The linter is incorrect; this method is absolutely used.
-	#[allow(dead_code)]
-	pub(crate) fn bitptr(&self) -> BitPtr<T> {
-		self.inner.bitptr()
-	}
+	inner: &'a mut SliceBit<C, T>,
+	/// The width of the chunks.
+	width: usize,
 }
 
-impl<'a, C, T> DoubleEndedIterator for Iter<'a, C, T>
+impl<'a, C, T> DoubleEndedIterator for RChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the next bit from the back of the slice.
+	/// Produces the next chunk from the front of the slice.
 	///
 	/// # Parameters
 	///
@@ -4468,40 +7725,31 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last bit in the slice, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = &1u8.bits::<BigEndian>()[6 ..];
-	/// let mut iter = bits.iter();
-	/// assert!(iter.next_back().unwrap());
-	/// assert!(!iter.next_back().unwrap());
-	/// assert!(iter.next_back().is_none());
-	/// ```
+	/// The last chunk in the slice, if any.
 	fn next_back(&mut self) -> Option<Self::Item> {
-		self.inner.split_last().map(|(b, r)| {
-			self.inner = r;
-			b
-		})
+		if self.inner.is_empty() {
+			return None;
+		}
+		let rem = self.inner.len() % self.width;
+		let size = if rem == 0 { self.width } else { rem };
+		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
+		let (head, tail) = tmp.split_at_mut(size);
+		self.inner = tail;
+		Some(head)
 	}
 }
 
-/// Mark that the iterator has an exact size.
-impl<'a, C, T> ExactSizeIterator for Iter<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for RChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-/// Mark that the iterator will not resume after halting.
-impl<'a, C, T> FusedIterator for Iter<'a, C, T>
+impl<'a, C, T> FusedIterator for RChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for Iter<'a, C, T>
+impl<'a, C, T> Iterator for RChunksMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Item = bool;
+	type Item = &'a mut SliceBit<C, T>;
 
-	/// Advances the iterator by one, returning the first bit in it (if any).
+	/// Advances the iterator by one, returning the first chunk in it (if any).
 	///
 	/// # Parameters
 	///
@@ -4509,27 +7757,21 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The leading bit in the iterator, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = &1u8.bits::<LittleEndian>()[.. 2];
-	/// let mut iter = bits.iter();
-	/// assert!(iter.next().unwrap());
-	/// assert!(!iter.next().unwrap());
-	/// assert!(iter.next().is_none());
-	/// ```
+	/// The leading chunk in the iterator, if any.
 	fn next(&mut self) -> Option<Self::Item> {
-		self.inner.split_first().map(|(b, r)| {
-			self.inner = r;
-			b
-		})
+		use core::cmp::min;
+		if self.inner.is_empty() {
+			return None;
+		}
+		let size = min(self.inner.len(), self.width);
+		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
+		let tlen = tmp.len();
+		let (head, tail) = tmp.split_at_mut(tlen - size);
+		self.inner = head;
+		Some(tail)
 	}
 
-	/// Hints at the number of bits remaining in the iterator.
+	/// Hints at the number of chunks remaining in the iterator.
 	///
 	/// Because the exact size is always known, this always produces
 	/// `(len, Some(len))`.
@@ -4540,28 +7782,19 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// - `usize`: The minimum bits remaining.
-	/// - `Option<usize>`: The maximum bits remaining.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = &0x4Bu8.bits::<BigEndian>()[.. 2];
-	/// let mut iter = bits.iter();
-	/// assert_eq!(iter.size_hint(), (2, Some(2)));
-	/// iter.next();
-	/// assert_eq!(iter.size_hint(), (1, Some(1)));
-	/// iter.next();
-	/// assert_eq!(iter.size_hint(), (0, Some(0)));
-	/// ```
+	/// - `usize`: The minimum chunks remaining.
+	/// - `Option<usize>`: The maximum chunks remaining.
 	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.inner.is_empty() {
+			return (0, Some(0));
+		}
 		let len = self.inner.len();
+		let (len, rem) = (len / self.width, len % self.width);
+		let len = len + (rem > 0) as usize;
 		(len, Some(len))
 	}
 
-	/// Counts how many bits are live in the iterator, consuming it.
+	/// Counts how many chunks are live in the iterator, consuming it.
 	///
 	/// # Parameters
 	///
@@ -4569,26 +7802,17 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The number of bits remaining in the iterator.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.iter().count(), 8);
-	/// ```
+	/// The number of chunks remaining in the iterator.
 	fn count(self) -> usize {
 		self.len()
 	}
 
-	/// Advances the iterator by `n` bits, starting from zero.
+	/// Advances the iterator by `n` chunks, starting from zero.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `n`: The number of bits to skip, before producing the next bit after
+	/// - `n`: The number of chunks to skip, before producing the next bit after
 	///   skips. If this overshoots the iterator’s remaining length, then the
 	///   iterator is marked empty before returning `None`.
 	///
@@ -4596,32 +7820,27 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
 	/// bit after advancing the iterator to it, discarding the intermediate
-	/// bits.
+	/// chunks.
 	///
 	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
 	/// and returns `None`.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = 2u8.bits::<BigEndian>();
-	/// let mut iter = bits.iter();
-	/// assert!(iter.nth(6).unwrap());
-	/// assert!(!iter.nth(0).unwrap());
-	/// assert!(iter.nth(0).is_none());
-	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		if n >= self.len() {
-			self.inner = SliceBit::empty();
+		let (end, ovf) = n.overflowing_mul(self.width);
+		if end >= self.inner.len() || ovf {
+			self.inner = SliceBit::empty_mut();
 			return None;
 		}
-		self.inner = &self.inner[n ..];
-		self.next()
+		// Can't underflow because of the check above
+		let end = self.inner.len() - end;
+		let start = end.checked_sub(self.width).unwrap_or(0);
+		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
+		let (head, tail) = tmp.split_at_mut(start);
+		let (nth, _) = tail.split_at_mut(end - start);
+		self.inner = head;
+		Some(nth)
 	}
 
-	/// Consumes the iterator, returning only the final bit.
+	/// Consumes the iterator, returning only the final chunk.
 	///
 	/// # Parameters
 	///
@@ -4629,22 +7848,13 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last bit in the iterator slice, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert!(bits.iter().last().unwrap());
-	/// ```
+	/// The last chunk in the iterator slice, if any.
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for reverse chunked iteration over a `SliceBit`.
+/** State keeper for reverse exact iteration over a `SliceBit`.
 
 # Type Parameters
 
@@ -4656,15 +7866,44 @@ where C: Cursor, T: 'a + BitStore {
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
 #[derive(Clone, Debug)]
-pub struct RChunks<'a, C, T>
+pub struct RChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
 	inner: &'a SliceBit<C, T>,
+	/// The excess of the original `SliceBit`, which is not iterated.
+	extra: &'a SliceBit<C, T>,
 	/// The width of the chunks.
 	width: usize,
 }
 
-impl<'a, C, T> DoubleEndedIterator for RChunks<'a, C, T>
+impl<'a, C, T> RChunksExact<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the remainder of the original slice, which will not be included
+	/// in the iteration.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The remaining slice that the iteration will not include.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// let rchunks_exact = bits.rchunks_exact(3);
+	/// assert_eq!(rchunks_exact.remainder(), &bits[.. 2]);
+	/// ```
+	pub fn remainder(&self) -> &'a SliceBit<C, T> {
+		self.extra
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for RChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// Produces the next chunk from the front of the slice.
 	///
@@ -4682,33 +7921,31 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 1u8.bits::<BigEndian>();
-	/// let mut rchunks = bits.rchunks(5);
-	/// assert_eq!(rchunks.next_back(), Some(&bits[.. 3]));
-	/// assert_eq!(rchunks.next_back(), Some(&bits[3 ..]));
-	/// assert!(rchunks.next_back().is_none());
+	/// let mut rchunks_exact = bits.rchunks_exact(3);
+	/// assert_eq!(rchunks_exact.next_back(), Some(&bits[2 .. 5]));
+	/// assert_eq!(rchunks_exact.next_back(), Some(&bits[5 .. 8]));
+	/// assert!(rchunks_exact.next_back().is_none());
 	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = SliceBit::empty();
 			return None;
 		}
-		let len = self.inner.len();
-		let rem = len % self.width;
-		let size = if rem == 0 { self.width } else { rem };
-		let (head, tail) = self.inner.split_at(size);
+		let (head, tail) = self.inner.split_at(self.width);
 		self.inner = tail;
 		Some(head)
 	}
 }
 
 /// Mark that the iterator has an exact size.
-impl<'a, C, T> ExactSizeIterator for RChunks<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for RChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
 /// Mark that the iterator will not resume after halting.
-impl<'a, C, T> FusedIterator for RChunks<'a, C, T>
+impl<'a, C, T> FusedIterator for RChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for RChunks<'a, C, T>
+impl<'a, C, T> Iterator for RChunksExact<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	type Item = &'a SliceBit<C, T>;
 
@@ -4728,19 +7965,17 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 1u8.bits::<LittleEndian>();
-	/// let mut rchunks = bits.rchunks(5);
-	/// assert_eq!(rchunks.next(), Some(&bits[3 ..]));
-	/// assert_eq!(rchunks.next(), Some(&bits[.. 3]));
-	/// assert!(rchunks.next().is_none());
+	/// let mut rchunks_exact = bits.rchunks_exact(3);
+	/// assert_eq!(rchunks_exact.next(), Some(&bits[5 .. 8]));
+	/// assert_eq!(rchunks_exact.next(), Some(&bits[2 .. 5]));
+	/// assert!(rchunks_exact.next().is_none());
 	/// ```
 	fn next(&mut self) -> Option<Self::Item> {
-		use core::cmp::min;
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = SliceBit::empty();
 			return None;
 		}
-		let len = self.inner.len();
-		let size = min(len, self.width);
-		let (head, tail) = self.inner.split_at(len - size);
+		let (head, tail) = self.inner.split_at(self.inner.len() - self.width);
 		self.inner = head;
 		Some(tail)
 	}
@@ -4765,21 +8000,16 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let mut rchunks = bits.rchunks(5);
-	/// assert_eq!(rchunks.size_hint(), (2, Some(2)));
-	/// rchunks.next();
-	/// assert_eq!(rchunks.size_hint(), (1, Some(1)));
-	/// rchunks.next();
-	/// assert_eq!(rchunks.size_hint(), (0, Some(0)));
+	/// let mut rchunks_exact = bits.rchunks_exact(3);
+	/// assert_eq!(rchunks_exact.size_hint(), (2, Some(2)));
+	/// rchunks_exact.next();
+	/// assert_eq!(rchunks_exact.size_hint(), (1, Some(1)));
+	/// rchunks_exact.next();
+	/// assert_eq!(rchunks_exact.size_hint(), (0, Some(0)));
 	/// ```
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		if self.inner.is_empty() {
-			return (0, Some(0));
-		}
-		let len = self.inner.len();
-		let (len, rem) = (len / self.width, len % self.width);
-		let len = len + (rem > 0) as usize;
-		(len, Some(len))
+		let n = self.inner.len() / self.width;
+		(n, Some(n))
 	}
 
 	/// Counts how many chunks are live in the iterator, consuming it.
@@ -4798,7 +8028,7 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.rchunks(3).count(), 3);
+	/// assert_eq!(bits.rchunks_exact(3).count(), 2);
 	/// ```
 	fn count(self) -> usize {
 		self.len()
@@ -4827,10 +8057,10 @@ where C: Cursor, T: 'a + BitStore {
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 2u8.bits::<BigEndian>();
-	/// let mut rchunks = bits.rchunks(3);
-	/// assert_eq!(rchunks.nth(2), Some(&bits[0 .. 2]));
-	/// assert!(rchunks.nth(0).is_none());
+	/// let bits = 0x4Bu8.bits::<BigEndian>();
+	/// let mut rchunks_exact = bits.rchunks_exact(3);
+	/// assert_eq!(rchunks_exact.nth(1), Some(&bits[2 .. 5]));
+	/// assert!(rchunks_exact.nth(0).is_none());
 	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
 		let (end, ovf) = n.overflowing_mul(self.width);
@@ -4838,15 +8068,12 @@ where C: Cursor, T: 'a + BitStore {
 			self.inner = SliceBit::empty();
 			return None;
 		}
-		// Can't underflow because of the check above
-		let end = self.inner.len() - end;
-		let start = end.checked_sub(self.width).unwrap_or(0);
-		let nth = &self.inner[start .. end];
-		self.inner = &self.inner[.. start];
-		Some(nth)
+		let (head, _) = self.inner.split_at(self.inner.len() - end);
+		self.inner = head;
+		self.next()
 	}
 
-	/// Consumes the iterator, returning only the final chunk.
+	/// Consumes the iterator, returning only the final bit.
 	///
 	/// # Parameters
 	///
@@ -4854,7 +8081,7 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last chunk in the iterator slice, if any.
+	/// The last bit in the iterator slice, if any.
 	///
 	/// # Examples
 	///
@@ -4862,14 +8089,14 @@ where C: Cursor, T: 'a + BitStore {
 	/// use vecbit::prelude::*;
 	///
 	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.rchunks(3).last(), Some(&bits[.. 2]));
+	/// assert!(bits.iter().last().unwrap());
 	/// ```
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for mutable reverse chunked iteration over a `SliceBit`.
+/** State keeper for mutable reverse exact chunked iteration over a `SliceBit`.
 
 # Type Parameters
 
@@ -4881,15 +8108,34 @@ where C: Cursor, T: 'a + BitStore {
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
 #[derive(Debug)]
-pub struct RChunksMut<'a, C, T>
+pub struct RChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
 	inner: &'a mut SliceBit<C, T>,
+	/// The excess of the original `SliceBit`, which is not iterated.
+	extra: &'a mut SliceBit<C, T>,
 	/// The width of the chunks.
 	width: usize,
 }
 
-impl<'a, C, T> DoubleEndedIterator for RChunksMut<'a, C, T>
+impl<'a, C, T> RChunksExactMut<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// Produces the remainder of the original slice, which will not be included
+	/// in the iteration.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The remaining slice that iteration will not include.
+	pub fn into_remainder(self) -> &'a mut SliceBit<C, T> {
+		self.extra
+	}
+}
+
+impl<'a, C, T> DoubleEndedIterator for RChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// Produces the next chunk from the front of the slice.
 	///
@@ -4901,25 +8147,24 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// The last chunk in the slice, if any.
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = SliceBit::empty_mut();
 			return None;
 		}
-		let rem = self.inner.len() % self.width;
-		let size = if rem == 0 { self.width } else { rem };
 		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (head, tail) = tmp.split_at_mut(size);
+		let (head, tail) = tmp.split_at_mut(self.width);
 		self.inner = tail;
 		Some(head)
 	}
 }
 
-impl<'a, C, T> ExactSizeIterator for RChunksMut<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for RChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> FusedIterator for RChunksMut<'a, C, T>
+impl<'a, C, T> FusedIterator for RChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for RChunksMut<'a, C, T>
+impl<'a, C, T> Iterator for RChunksExactMut<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	type Item = &'a mut SliceBit<C, T>;
 
@@ -4933,14 +8178,13 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// The leading chunk in the iterator, if any.
 	fn next(&mut self) -> Option<Self::Item> {
-		use core::cmp::min;
-		if self.inner.is_empty() {
+		if self.inner.len() < self.width {
+			self.inner = SliceBit::empty_mut();
 			return None;
 		}
-		let size = min(self.inner.len(), self.width);
 		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
 		let tlen = tmp.len();
-		let (head, tail) = tmp.split_at_mut(tlen - size);
+		let (head, tail) = tmp.split_at_mut(tlen - self.width);
 		self.inner = head;
 		Some(tail)
 	}
@@ -4959,13 +8203,8 @@ where C: Cursor, T: 'a + BitStore {
 	/// - `usize`: The minimum chunks remaining.
 	/// - `Option<usize>`: The maximum chunks remaining.
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		if self.inner.is_empty() {
-			return (0, Some(0));
-		}
-		let len = self.inner.len();
-		let (len, rem) = (len / self.width, len % self.width);
-		let len = len + (rem > 0) as usize;
-		(len, Some(len))
+		let n = self.inner.len() / self.width;
+		(n, Some(n))
 	}
 
 	/// Counts how many chunks are live in the iterator, consuming it.
@@ -5004,17 +8243,14 @@ where C: Cursor, T: 'a + BitStore {
 			self.inner = SliceBit::empty_mut();
 			return None;
 		}
-		// Can't underflow because of the check above
-		let end = self.inner.len() - end;
-		let start = end.checked_sub(self.width).unwrap_or(0);
 		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (head, tail) = tmp.split_at_mut(start);
-		let (nth, _) = tail.split_at_mut(end - start);
+		let tlen = tmp.len();
+		let (head, _) = tmp.split_at_mut(tlen - end);
 		self.inner = head;
-		Some(nth)
+		self.next()
 	}
 
-	/// Consumes the iterator, returning only the final chunk.
+	/// Consumes the iterator, returning only the final bit.
 	///
 	/// # Parameters
 	///
@@ -5022,13 +8258,13 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last chunk in the iterator slice, if any.
+	/// The last bit in the iterator slice, if any.
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for reverse exact iteration over a `SliceBit`.
+/** State keeper for iteration over the runs of one bit value in a `SliceBit`.
 
 # Type Parameters
 
@@ -5040,20 +8276,49 @@ where C: Cursor, T: 'a + BitStore {
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
 #[derive(Clone, Debug)]
-pub struct RChunksExact<'a, C, T>
+pub struct Ranges<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
 	inner: &'a SliceBit<C, T>,
-	/// The excess of the original `SliceBit`, which is not iterated.
-	extra: &'a SliceBit<C, T>,
-	/// The width of the chunks.
-	width: usize,
+	/// The index from which to search for the next run.
+	pos: usize,
+	/// The bit value of the runs being produced.
+	value: bool,
 }
 
-impl<'a, C, T> RChunksExact<'a, C, T>
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for Ranges<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Ranges<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the remainder of the original slice, which will not be included
-	/// in the iteration.
+	type Item = Range<usize>;
+
+	/// Advances the iterator by one, returning the next run in it (if any).
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The index range of the next run, if any.
+	fn next(&mut self) -> Option<Self::Item> {
+		let len = self.inner.len();
+		let start = self.inner.seek(self.pos, self.value);
+		if start == len {
+			self.pos = len;
+			return None;
+		}
+		let end = self.inner.seek(start, !self.value);
+		self.pos = end;
+		Some(start .. end)
+	}
+
+	/// Hints at the number of runs remaining in the iterator.
+	///
+	/// Runs are separated by at least one bit, so there can be no more than
+	/// half as many as there are bits remaining, rounded up.
 	///
 	/// # Parameters
 	///
@@ -5061,25 +8326,103 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The remaining slice that the iteration will not include.
+	/// - `usize`: The minimum runs remaining.
+	/// - `Option<usize>`: The maximum runs remaining.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let rem = self.inner.len() - self.pos;
+		(0, Some((rem + 1) / 2))
+	}
+}
+
+/** State keeper for iteration over the indices of one bit value in a
+`SliceBit`.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+**/
+#[derive(Clone, Debug)]
+pub struct Indices<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The `SliceBit` being iterated.
+	inner: &'a SliceBit<C, T>,
+	/// The index from which to search for the next bit.
+	pos: usize,
+	/// The bit value whose indices are being produced.
+	value: bool,
+}
+
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for Indices<'a, C, T>
+where C: Cursor, T: 'a + BitStore {}
+
+impl<'a, C, T> Iterator for Indices<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	type Item = usize;
+
+	/// Advances the iterator by one, returning the next index in it (if any).
 	///
-	/// # Examples
+	/// # Parameters
 	///
-	/// ```rust
-	/// use vecbit::prelude::*;
+	/// - `&mut self`
 	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let rchunks_exact = bits.rchunks_exact(3);
-	/// assert_eq!(rchunks_exact.remainder(), &bits[.. 2]);
-	/// ```
-	pub fn remainder(&self) -> &'a SliceBit<C, T> {
-		self.extra
+	/// # Returns
+	///
+	/// The index of the next bit with the sought value, if any.
+	fn next(&mut self) -> Option<Self::Item> {
+		let len = self.inner.len();
+		let idx = self.inner.seek(self.pos, self.value);
+		if idx == len {
+			self.pos = len;
+			return None;
+		}
+		self.pos = idx + 1;
+		Some(idx)
+	}
+
+	/// Hints at the number of indices remaining in the iterator.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `usize`: The minimum indices remaining.
+	/// - `Option<usize>`: The maximum indices remaining.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.inner.len() - self.pos))
 	}
 }
 
-impl<'a, C, T> DoubleEndedIterator for RChunksExact<'a, C, T>
+/** State keeper for even partitioning of a `SliceBit`.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the underlying `SliceBit`.
+- `T: 'a + BitStore`: The storage type of the underlying `SliceBit`.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying `SliceBit`.
+**/
+#[derive(Clone, Debug)]
+pub struct SplitInto<'a, C, T>
+where C: Cursor, T: 'a + BitStore {
+	/// The `SliceBit` being iterated.
+	inner: &'a SliceBit<C, T>,
+	/// The number of parts not yet produced.
+	parts: usize,
+}
+
+impl<'a, C, T> DoubleEndedIterator for SplitInto<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the front of the slice.
+	/// Produces the next part from the back of the slice.
 	///
 	/// # Parameters
 	///
@@ -5087,43 +8430,47 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last chunk in the slice, if any.
+	/// The last part in the slice, if any.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 1u8.bits::<BigEndian>();
-	/// let mut rchunks_exact = bits.rchunks_exact(3);
-	/// assert_eq!(rchunks_exact.next_back(), Some(&bits[2 .. 5]));
-	/// assert_eq!(rchunks_exact.next_back(), Some(&bits[5 .. 8]));
-	/// assert!(rchunks_exact.next_back().is_none());
+	/// let bits = 0u8.bits::<LittleEndian>();
+	/// let mut parts = bits.split_into(3);
+	/// assert_eq!(parts.next_back(), Some(&bits[6 ..]));
+	/// assert_eq!(parts.next(), Some(&bits[.. 3]));
+	/// assert_eq!(parts.next_back(), Some(&bits[3 .. 6]));
+	/// assert!(parts.next_back().is_none());
 	/// ```
 	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = SliceBit::empty();
+		if self.parts == 0 {
 			return None;
 		}
-		let (head, tail) = self.inner.split_at(self.width);
-		self.inner = tail;
-		Some(head)
+		let len = self.inner.len();
+		//  The shorter parts, if any, are at the back.
+		let size = len / self.parts;
+		let (head, tail) = self.inner.split_at(len - size);
+		self.inner = head;
+		self.parts -= 1;
+		Some(tail)
 	}
 }
 
 /// Mark that the iterator has an exact size.
-impl<'a, C, T> ExactSizeIterator for RChunksExact<'a, C, T>
+impl<'a, C, T> ExactSizeIterator for SplitInto<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
 /// Mark that the iterator will not resume after halting.
-impl<'a, C, T> FusedIterator for RChunksExact<'a, C, T>
+impl<'a, C, T> FusedIterator for SplitInto<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for RChunksExact<'a, C, T>
+impl<'a, C, T> Iterator for SplitInto<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	type Item = &'a SliceBit<C, T>;
 
-	/// Advances the iterator by one, returning the first chunk in it (if any).
+	/// Advances the iterator by one, returning the first part in it (if any).
 	///
 	/// # Parameters
 	///
@@ -5131,30 +8478,21 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The leading chunk in the iterator, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = 1u8.bits::<LittleEndian>();
-	/// let mut rchunks_exact = bits.rchunks_exact(3);
-	/// assert_eq!(rchunks_exact.next(), Some(&bits[5 .. 8]));
-	/// assert_eq!(rchunks_exact.next(), Some(&bits[2 .. 5]));
-	/// assert!(rchunks_exact.next().is_none());
-	/// ```
+	/// The leading part in the iterator, if any.
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = SliceBit::empty();
+		if self.parts == 0 {
 			return None;
 		}
-		let (head, tail) = self.inner.split_at(self.inner.len() - self.width);
-		self.inner = head;
-		Some(tail)
+		let len = self.inner.len();
+		//  The longer parts, if any, are at the front.
+		let size = len / self.parts + (len % self.parts != 0) as usize;
+		let (head, tail) = self.inner.split_at(size);
+		self.inner = tail;
+		self.parts -= 1;
+		Some(head)
 	}
 
-	/// Hints at the number of chunks remaining in the iterator.
+	/// Hints at the number of parts remaining in the iterator.
 	///
 	/// Because the exact size is always known, this always produces
 	/// `(len, Some(len))`.
@@ -5165,28 +8503,13 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// - `usize`: The minimum chunks remaining.
-	/// - `Option<usize>`: The maximum chunks remaining.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let mut rchunks_exact = bits.rchunks_exact(3);
-	/// assert_eq!(rchunks_exact.size_hint(), (2, Some(2)));
-	/// rchunks_exact.next();
-	/// assert_eq!(rchunks_exact.size_hint(), (1, Some(1)));
-	/// rchunks_exact.next();
-	/// assert_eq!(rchunks_exact.size_hint(), (0, Some(0)));
-	/// ```
+	/// - `usize`: The minimum parts remaining.
+	/// - `Option<usize>`: The maximum parts remaining.
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let n = self.inner.len() / self.width;
-		(n, Some(n))
+		(self.parts, Some(self.parts))
 	}
 
-	/// Counts how many chunks are live in the iterator, consuming it.
+	/// Counts how many parts are live in the iterator, consuming it.
 	///
 	/// # Parameters
 	///
@@ -5194,34 +8517,28 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The number of chunks remaining in the iterator.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert_eq!(bits.rchunks_exact(3).count(), 2);
-	/// ```
+	/// The number of parts remaining in the iterator.
 	fn count(self) -> usize {
 		self.len()
 	}
 
-	/// Advances the iterator by `n` chunks, starting from zero.
+	/// Advances the iterator by `n` parts, starting from zero.
+	///
+	/// The lengths of the skipped parts are computed directly, so this runs in
+	/// constant time.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `n`: The number of chunks to skip, before producing the next bit after
-	///   skips. If this overshoots the iterator’s remaining length, then the
-	///   iterator is marked empty before returning `None`.
+	/// - `n`: The number of parts to skip, before producing the next part after
+	///   the skips. If this overshoots the iterator’s remaining length, then
+	///   the iterator is marked empty before returning `None`.
 	///
 	/// # Returns
 	///
 	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
-	/// bit after advancing the iterator to it, discarding the intermediate
-	/// chunks.
+	/// part after advancing the iterator to it, discarding the intermediate
+	/// parts.
 	///
 	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
 	/// and returns `None`.
@@ -5231,23 +8548,27 @@ where C: Cursor, T: 'a + BitStore {
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// let mut rchunks_exact = bits.rchunks_exact(3);
-	/// assert_eq!(rchunks_exact.nth(1), Some(&bits[2 .. 5]));
-	/// assert!(rchunks_exact.nth(0).is_none());
+	/// let bits = [0u8; 2].bits::<LittleEndian>();
+	/// let mut parts = bits.split_into(5);
+	/// assert_eq!(parts.nth(2), Some(&bits[7 .. 10]));
+	/// assert_eq!(parts.nth(0), Some(&bits[10 .. 13]));
+	/// assert!(parts.nth(1).is_none());
 	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		let (end, ovf) = n.overflowing_mul(self.width);
-		if end >= self.inner.len() || ovf {
+		if n >= self.parts {
 			self.inner = SliceBit::empty();
+			self.parts = 0;
 			return None;
 		}
-		let (head, _) = self.inner.split_at(self.inner.len() - end);
-		self.inner = head;
+		let len = self.inner.len();
+		//  The first `len % parts` parts are one bit longer than the rest.
+		let (size, long) = (len / self.parts, len % self.parts);
+		self.inner = &self.inner[n * size + n.min(long) ..];
+		self.parts -= n;
 		self.next()
 	}
 
-	/// Consumes the iterator, returning only the final bit.
+	/// Consumes the iterator, returning only the final part.
 	///
 	/// # Parameters
 	///
@@ -5255,22 +8576,13 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The last bit in the iterator slice, if any.
-	///
-	/// # Examples
-	///
-	/// ```rust
-	/// use vecbit::prelude::*;
-	///
-	/// let bits = 0x4Bu8.bits::<BigEndian>();
-	/// assert!(bits.iter().last().unwrap());
-	/// ```
+	/// The last part in the iterator slice, if any.
 	fn last(mut self) -> Option<Self::Item> {
 		self.next_back()
 	}
 }
 
-/** State keeper for mutable reverse exact chunked iteration over a `SliceBit`.
+/** State keeper for sliding-window population counts over a `SliceBit`.
 
 # Type Parameters
 
@@ -5281,68 +8593,41 @@ where C: Cursor, T: 'a + BitStore {
 
 - `'a`: The lifetime of the underlying `SliceBit`.
 **/
-#[derive(Debug)]
-pub struct RChunksExactMut<'a, C, T>
+#[derive(Clone, Debug)]
+pub struct WindowCounts<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// The `SliceBit` being iterated.
-	inner: &'a mut SliceBit<C, T>,
-	/// The excess of the original `SliceBit`, which is not iterated.
-	extra: &'a mut SliceBit<C, T>,
-	/// The width of the chunks.
+	inner: &'a SliceBit<C, T>,
+	/// The width of the windows.
 	width: usize,
+	/// The index at which the next window begins.
+	pos: usize,
+	/// The number of set bits in the next window.
+	count: usize,
 }
 
-impl<'a, C, T> RChunksExactMut<'a, C, T>
-where C: Cursor, T: 'a + BitStore {
-	/// Produces the remainder of the original slice, which will not be included
-	/// in the iteration.
-	///
-	/// # Parameters
-	///
-	/// - `self`
-	///
-	/// # Returns
-	///
-	/// The remaining slice that iteration will not include.
-	pub fn into_remainder(self) -> &'a mut SliceBit<C, T> {
-		self.extra
-	}
-}
-
-impl<'a, C, T> DoubleEndedIterator for RChunksExactMut<'a, C, T>
+impl<'a, C, T> WindowCounts<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	/// Produces the next chunk from the front of the slice.
-	///
-	/// # Parameters
-	///
-	/// - `&mut self`
-	///
-	/// # Returns
-	///
-	/// The last chunk in the slice, if any.
-	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = SliceBit::empty_mut();
-			return None;
-		}
-		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let (head, tail) = tmp.split_at_mut(self.width);
-		self.inner = tail;
-		Some(head)
+	/// Counts the windows not yet produced.
+	fn remaining(&self) -> usize {
+		(self.inner.len() + 1).saturating_sub(self.pos + self.width)
 	}
 }
 
-impl<'a, C, T> ExactSizeIterator for RChunksExactMut<'a, C, T>
+/// Mark that the iterator has an exact size.
+impl<'a, C, T> ExactSizeIterator for WindowCounts<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> FusedIterator for RChunksExactMut<'a, C, T>
+/// Mark that the iterator will not resume after halting.
+impl<'a, C, T> FusedIterator for WindowCounts<'a, C, T>
 where C: Cursor, T: 'a + BitStore {}
 
-impl<'a, C, T> Iterator for RChunksExactMut<'a, C, T>
+impl<'a, C, T> Iterator for WindowCounts<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
-	type Item = &'a mut SliceBit<C, T>;
+	type Item = usize;
 
-	/// Advances the iterator by one, returning the first chunk in it (if any).
+	/// Advances the iterator by one, returning the count of the next window
+	/// (if any).
 	///
 	/// # Parameters
 	///
@@ -5350,20 +8635,23 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// The leading chunk in the iterator, if any.
+	/// The number of set bits in the next window, if there is one.
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.inner.len() < self.width {
-			self.inner = SliceBit::empty_mut();
+		let end = self.pos + self.width;
+		let len = self.inner.len();
+		if end > len {
 			return None;
 		}
-		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let tlen = tmp.len();
-		let (head, tail) = tmp.split_at_mut(tlen - self.width);
-		self.inner = head;
-		Some(tail)
+		let out = self.count;
+		if end < len {
+			self.count -= self.inner[self.pos] as usize;
+			self.count += self.inner[end] as usize;
+		}
+		self.pos += 1;
+		Some(out)
 	}
 
-	/// Hints at the number of chunks remaining in the iterator.
+	/// Hints at the number of windows remaining in the iterator.
 	///
 	/// Because the exact size is always known, this always produces
 	/// `(len, Some(len))`.
@@ -5374,68 +8662,58 @@ where C: Cursor, T: 'a + BitStore {
 	///
 	/// # Returns
 	///
-	/// - `usize`: The minimum chunks remaining.
-	/// - `Option<usize>`: The maximum chunks remaining.
+	/// - `usize`: The minimum windows remaining.
+	/// - `Option<usize>`: The maximum windows remaining.
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let n = self.inner.len() / self.width;
-		(n, Some(n))
+		let rem = self.remaining();
+		(rem, Some(rem))
 	}
 
-	/// Counts how many chunks are live in the iterator, consuming it.
-	///
-	/// # Parameters
-	///
-	/// - `self`
-	///
-	/// # Returns
+	/// Advances the iterator by `n` windows, starting from zero.
 	///
-	/// The number of chunks remaining in the iterator.
-	fn count(self) -> usize {
-		self.len()
-	}
-
-	/// Advances the iterator by `n` chunks, starting from zero.
+	/// Rather than stepping the running count through every skipped window,
+	/// this counts the destination window afresh, a whole element at a time
+	/// where it can be.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `n`: The number of chunks to skip, before producing the next bit after
-	///   skips. If this overshoots the iterator’s remaining length, then the
-	///   iterator is marked empty before returning `None`.
+	/// - `n`: The number of windows to skip, before producing the count of the
+	///   next window after the skips. If this overshoots the iterator’s
+	///   remaining length, then the iterator is marked empty before returning
+	///   `None`.
 	///
 	/// # Returns
 	///
-	/// If `n` does not overshoot the iterator’s bounds, this produces the `n`th
-	/// bit after advancing the iterator to it, discarding the intermediate
-	/// chunks.
+	/// If `n` does not overshoot the iterator’s bounds, this produces the count
+	/// of the `n`th window after advancing the iterator to it.
 	///
 	/// If `n` does overshoot the iterator’s bounds, this empties the iterator
 	/// and returns `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = 0b0110_1110u8.bits::<BigEndian>();
+	/// let mut counts = bits.windowed_counts(3);
+	/// assert_eq!(counts.nth(3), Some(2));
+	/// assert_eq!(counts.nth(0), Some(3));
+	/// assert!(counts.nth(1).is_none());
+	/// ```
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		let (end, ovf) = n.overflowing_mul(self.width);
-		if end >= self.inner.len() || ovf {
-			self.inner = SliceBit::empty_mut();
+		if n >= self.remaining() {
+			self.pos = self.inner.len();
 			return None;
 		}
-		let tmp = mem::replace(&mut self.inner, SliceBit::empty_mut());
-		let tlen = tmp.len();
-		let (head, _) = tmp.split_at_mut(tlen - end);
-		self.inner = head;
+		if n != 0 {
+			self.pos += n;
+			let end = self.pos + self.width;
+			self.count = self.inner[self.pos .. end].count_ones();
+		}
 		self.next()
 	}
-
-	/// Consumes the iterator, returning only the final bit.
-	///
-	/// # Parameters
-	///
-	/// - `self`
-	///
-	/// # Returns
-	///
-	/// The last bit in the iterator slice, if any.
-	fn last(mut self) -> Option<Self::Item> {
-		self.next_back()
-	}
 }
 
 /** State keeper for sliding-window iteration over a `SliceBit`.
@@ -5661,3 +8939,189 @@ where C: Cursor, T: 'a + BitStore {
 		self.next_back()
 	}
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::*;
+	use crate::{
+		fixtures::{
+			check_nth,
+			each_subslice,
+			each_subslice_mut,
+		},
+		prelude::*,
+	};
+
+	#[test]
+	fn rank_select() {
+		fn check<C, T>(bits: &SliceBit<C, T>)
+		where C: Cursor, T: BitStore {
+			let (mut ones, mut zeros) = (0, 0);
+			for n in 0 .. bits.len() {
+				assert_eq!(bits.rank_ones(n), ones);
+				assert_eq!(bits.rank_zeros(n), zeros);
+				if bits[n] {
+					assert_eq!(bits.select_ones(ones), Some(n));
+					ones += 1;
+				}
+				else {
+					assert_eq!(bits.select_zeros(zeros), Some(n));
+					zeros += 1;
+				}
+			}
+			assert_eq!(bits.rank_ones(bits.len()), ones);
+			assert_eq!(bits.rank_zeros(bits.len()), zeros);
+			assert_eq!(bits.select_ones(ones), None);
+			assert_eq!(bits.select_zeros(zeros), None);
+		}
+
+		let src = [0x5AC3u16, 0x0000, 0xFFFF, 0x8001];
+		each_subslice(src.bits::<BigEndian>(), 20, 1, |_, bits| check(bits));
+		each_subslice(src.bits::<LittleEndian>(), 20, 1, |_, bits| check(bits));
+	}
+
+	#[test]
+	#[should_panic]
+	fn rank_out_of_bounds() {
+		0u8.bits::<BigEndian>().rank_ones(9);
+	}
+
+	#[test]
+	fn iter_indices() {
+		fn check<C, T>(bits: &SliceBit<C, T>)
+		where C: Cursor, T: BitStore {
+			let ones = (0 .. bits.len()).filter(|&n| bits[n]);
+			let zeros = (0 .. bits.len()).filter(|&n| !bits[n]);
+			assert!(bits.iter_ones().eq(ones));
+			assert!(bits.iter_zeros().eq(zeros));
+		}
+
+		let src = [0x5AC3u16, 0x0000, 0xFFFF, 0x8001];
+		each_subslice(src.bits::<BigEndian>(), 20, 1, |_, bits| check(bits));
+		each_subslice(src.bits::<LittleEndian>(), 20, 1, |_, bits| check(bits));
+	}
+
+	#[test]
+	fn iter_indices_sparse() {
+		let mut bv = vecbit![LittleEndian, u64; 0; 10_000];
+		for &n in &[3, 64, 65, 4_000, 9_999] {
+			bv.set(n, true);
+		}
+		let mut ones = bv.iter_ones();
+		assert_eq!(ones.size_hint(), (0, Some(10_000)));
+		assert_eq!(ones.next(), Some(3));
+		assert_eq!(ones.size_hint(), (0, Some(9_996)));
+		assert!(ones.eq([64, 65, 4_000, 9_999].iter().cloned()));
+		assert_eq!(bv.iter_zeros().count(), 9_995);
+		bv.set_all(true);
+		assert!(bv.iter_zeros().next().is_none());
+	}
+
+	#[test]
+	fn multiples() {
+		fn check<C, T>(orig: &[T])
+		where C: Cursor, T: BitStore {
+			let steps = [(0, 1), (3, 2), (5, 3), (1, 7), (40, 11), (2, 64)];
+			each_subslice_mut::<C, T, _>(orig, 7, |range, bits| {
+				for &(start, step) in &steps {
+					for &value in &[false, true] {
+						let mut model = bits.to_owned();
+						for n in (start .. model.len()).step_by(step) {
+							model.set(n, value);
+						}
+						if value {
+							bits.set_multiples(start, step);
+						}
+						else {
+							bits.clear_multiples(start, step);
+						}
+						let msg = (&range, start, step);
+						assert_eq!(*bits, model, "{:?}", msg);
+					}
+				}
+			});
+		}
+
+		check::<BigEndian, u8>(&[0x5A, 0xC3, 0x0F, 0xF0, 0x99, 0x66, 0x12]);
+		check::<LittleEndian, u32>(&[0x1234_5678, 0x9ABC_DEF0, 0x0F0F_F0F0]);
+		check::<BigEndian, u64>(&[0x1234_5678_9ABC_DEF0, 0x0F0F_F0F0_3C3C_C3C3]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn multiples_zero_step() {
+		let mut src = [0u8; 2];
+		src.bits_mut::<BigEndian>().clear_multiples(0, 0);
+	}
+
+	#[test]
+	fn correlate() {
+		fn check<C, T>(all: &SliceBit<C, T>)
+		where C: Cursor, T: BitStore {
+			let patterns = [(0, 1), (3, 7), (5, 20), (1, 40), (2, 70)];
+			for start in 0 .. 9 {
+				let hay = &all[start ..];
+				for &(from, width) in &patterns {
+					let pattern = &all[from .. from + width];
+					let agree = hay.correlate(pattern);
+					let both = hay.correlate_ones(pattern);
+					let count = (hay.len() + 1).saturating_sub(width);
+					assert_eq!(agree.len(), count);
+					assert_eq!(both.len(), count);
+					for n in 0 .. count {
+						let window = &hay[n .. n + width];
+						let (mut same, mut ones) = (0, 0);
+						for (a, b) in window.iter().zip(pattern.iter()) {
+							same += (a == b) as usize;
+							ones += (a && b) as usize;
+						}
+						assert_eq!((agree[n], both[n]), (same, ones));
+					}
+				}
+			}
+		}
+
+		let bytes = (0 .. 24u8)
+			.map(|n| n.wrapping_mul(0x9D) ^ 0x35)
+			.collect::<Vec<_>>();
+		check(bytes.bits::<BigEndian>());
+		check(bytes.bits::<LittleEndian>());
+		let words = [0x0123_4567_89AB_CDEFu64, 0xF0F0_0F0F_AAAA_5555, !0];
+		check(words.bits::<LittleEndian>());
+	}
+
+	#[test]
+	fn correlate_sync_word() {
+		let sync = vecbit![BigEndian, u16; 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, 0];
+		let mut stream = VecBit::<BigEndian, u16>::new();
+		for n in 0 .. 57 {
+			stream.push(n % 3 == 0);
+		}
+		let at = stream.len();
+		stream.extend(sync.iter());
+		stream.extend((0 .. 30).map(|n| n % 5 == 1));
+		let at_flipped = at + 2;
+		let flipped = !stream[at_flipped];
+		stream.set(at_flipped, flipped);
+		let scores = stream.correlate(&sync);
+		let best = (0 .. scores.len()).max_by_key(|&n| scores[n]).unwrap();
+		assert_eq!(best, at);
+		assert_eq!(scores[at], sync.len() - 1);
+		assert_eq!(stream.correlate(&stream[1 ..].to_owned()).len(), 2);
+		assert!(sync.correlate(&stream).is_empty());
+	}
+
+	#[test]
+	fn nth() {
+		let data = [0x5AC3u16, 0x0FF0, 0x9669];
+		each_subslice(data.bits::<BigEndian>(), 5, 1, |_, bits| {
+			check_nth(bits.iter());
+			for k in 1 .. 7 {
+				check_nth(bits.split_into(k));
+				check_nth(bits.windowed_counts(k));
+				check_nth(bits.windows(k));
+				check_nth(bits.chunks(k));
+			}
+		});
+	}
+}
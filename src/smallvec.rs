@@ -0,0 +1,601 @@
+/*! Bit vectors with inline storage
+
+`VecBit` always keeps its bits on the heap, so even a vector of a handful of
+bits costs an allocation. Programs that hold very many short vectors pay that
+cost for every one of them.
+
+A [`SmallVecBit`] stores its bits inline, in a [`BitArray`], while they fit,
+and moves them into a `VecBit` on the heap once they grow past it. After moving
+to the heap, it behaves as an ordinary `VecBit` until it is shrunk back down.
+
+As with `BitArray`, the inline capacity is set by the storage type rather than
+by a number, since the supported compilers have no const generics.
+
+[`BitArray`]: ../array/struct.BitArray.html
+[`SmallVecBit`]: struct.SmallVecBit.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	array::BitArray,
+	bits::BitsMut,
+	cursor::{
+		Cursor,
+		Local,
+	},
+	slice::SliceBit,
+	store::{
+		BitStore,
+		Word,
+	},
+	vec::VecBit,
+};
+
+use core::{
+	cmp::{
+		self,
+		Ordering,
+	},
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+	mem,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+/** A bit vector that keeps short contents inline, and spills to the heap.
+
+A `SmallVecBit` dereferences to a `SliceBit` over its live bits, wherever they
+are stored, and grows like a `VecBit`. It holds up to [`inline_capacity`] bits
+without allocating.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the vector.
+- `A: BitsMut`: The inline storage, such as `[u8; 4]` or `u64`. The vector holds
+  as many bits inline as the storage does, and uses its element type on the
+  heap.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+
+let mut bv = SmallVecBit::<BigEndian, u16>::new();
+bv.extend([true, false, true].iter().cloned());
+assert!(!bv.spilled());
+bv.resize(20, true);
+assert!(bv.spilled());
+assert_eq!(bv.count_ones(), 19);
+```
+
+[`inline_capacity`]: #method.inline_capacity
+**/
+pub struct SmallVecBit<C = Local, A = [Word; 1]>
+where C: Cursor, A: BitsMut {
+	/// The bits, in whichever location they currently occupy.
+	inner: Inner<C, A>,
+}
+
+/// The two locations of a `SmallVecBit`'s bits.
+enum Inner<C, A>
+where C: Cursor, A: BitsMut {
+	/// The bits are held inline. Only the first `len` bits are live.
+	Inline {
+		data: BitArray<C, A>,
+		len: usize,
+	},
+	/// The bits are held on the heap.
+	Heap(VecBit<C, A::Store>),
+}
+
+impl<C, A> SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	/// Constructs a new, empty, `SmallVecBit`, which does not allocate.
+	///
+	/// # Returns
+	///
+	/// An empty vector, with its bits held inline.
+	pub fn new() -> Self {
+		Self {
+			inner: Inner::Inline {
+				data: BitArray::default(),
+				len: 0,
+			},
+		}
+	}
+
+	/// Constructs a new, empty, `SmallVecBit` with at least the given capacity.
+	///
+	/// # Parameters
+	///
+	/// - `capacity`: The number of bits that the vector must be able to hold
+	///   without reallocating.
+	///
+	/// # Returns
+	///
+	/// An empty vector. It allocates only if `capacity` exceeds the inline
+	/// capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// assert!(!SmallVecBit::<LittleEndian, u32>::with_capacity(32).spilled());
+	/// assert!(SmallVecBit::<LittleEndian, u32>::with_capacity(33).spilled());
+	/// ```
+	pub fn with_capacity(capacity: usize) -> Self {
+		if capacity <= Self::inline_capacity() {
+			Self::new()
+		}
+		else {
+			Self { inner: Inner::Heap(VecBit::with_capacity(capacity)) }
+		}
+	}
+
+	/// Constructs a `SmallVecBit` from a copy of a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `slice`: The source bits.
+	///
+	/// # Returns
+	///
+	/// A vector holding the bits of `slice`, inline if they fit.
+	pub fn from_bitslice(slice: &SliceBit<C, A::Store>) -> Self {
+		let mut out = Self::with_capacity(slice.len());
+		out.extend_from_bitslice(slice);
+		out
+	}
+
+	/// The number of bits that a `SmallVecBit` can hold inline.
+	///
+	/// # Returns
+	///
+	/// The number of bits in the inline storage type.
+	pub fn inline_capacity() -> usize {
+		mem::size_of::<A>() << 3
+	}
+
+	/// Tests whether the bits have moved to the heap.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// Whether the vector holds its bits in a heap allocation.
+	pub fn spilled(&self) -> bool {
+		match self.inner {
+			Inner::Inline { .. } => false,
+			Inner::Heap(_) => true,
+		}
+	}
+
+	/// Gets the number of bits the vector can hold without reallocating.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The inline capacity while the bits are inline, or the capacity of the
+	/// heap allocation once they have spilled.
+	pub fn capacity(&self) -> usize {
+		match &self.inner {
+			Inner::Inline { .. } => Self::inline_capacity(),
+			Inner::Heap(bv) => bv.capacity(),
+		}
+	}
+
+	/// Reserves capacity for at least `additional` more bits.
+	///
+	/// This moves the bits to the heap if they would no longer fit inline.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of bits to be added to the vector.
+	pub fn reserve(&mut self, additional: usize) {
+		match &mut self.inner {
+			Inner::Inline { len, .. } => {
+				if *len + additional > Self::inline_capacity() {
+					self.spill(additional);
+				}
+			},
+			Inner::Heap(bv) => bv.reserve(additional),
+		}
+	}
+
+	/// Releases unused capacity.
+	///
+	/// If the bits have spilled, but now fit inline, they are moved back inline
+	/// and the heap allocation is freed.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = SmallVecBit::<BigEndian, u8>::new();
+	/// bv.resize(12, true);
+	/// bv.truncate(8);
+	/// assert!(bv.spilled());
+	/// bv.shrink_to_fit();
+	/// assert!(!bv.spilled());
+	/// assert!(bv.all());
+	/// ```
+	pub fn shrink_to_fit(&mut self) {
+		let inline = match &mut self.inner {
+			Inner::Inline { .. } => return,
+			Inner::Heap(bv) if bv.len() > Self::inline_capacity() => {
+				bv.shrink_to_fit();
+				return;
+			},
+			Inner::Heap(bv) => {
+				let mut data = BitArray::<C, A>::default();
+				for (n, bit) in bv.iter().enumerate() {
+					data.set(n, bit);
+				}
+				Inner::Inline { data, len: bv.len() }
+			},
+		};
+		self.inner = inline;
+	}
+
+	/// Appends a bit to the back of the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit to append.
+	///
+	/// # Panics
+	///
+	/// This panics under the same conditions as [`VecBit::push`].
+	///
+	/// [`VecBit::push`]: ../vec/struct.VecBit.html#method.push
+	pub fn push(&mut self, value: bool) {
+		self.reserve(1);
+		match &mut self.inner {
+			Inner::Inline { data, len } => {
+				data.set(*len, value);
+				*len += 1;
+			},
+			Inner::Heap(bv) => bv.push(value),
+		}
+	}
+
+	/// Removes the last bit from the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The last bit, or `None` if the vector is empty.
+	pub fn pop(&mut self) -> Option<bool> {
+		match &mut self.inner {
+			Inner::Inline { len: 0, .. } => None,
+			Inner::Inline { data, len } => {
+				*len -= 1;
+				data.get(*len)
+			},
+			Inner::Heap(bv) => bv.pop(),
+		}
+	}
+
+	/// Appends a copy of a bit slice to the back of the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The bits to append.
+	pub fn extend_from_bitslice(&mut self, src: &SliceBit<C, A::Store>) {
+		self.reserve(src.len());
+		match &mut self.inner {
+			Inner::Inline { data, len } => {
+				for bit in src.iter() {
+					data.set(*len, bit);
+					*len += 1;
+				}
+			},
+			Inner::Heap(bv) => bv.extend_from_bitslice(src),
+		}
+	}
+
+	/// Shortens the vector, keeping the first `len` bits.
+	///
+	/// This has no effect if `len` is not less than the current length. It
+	/// does not move spilled bits back inline; use [`shrink_to_fit`] for that.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The new length of the vector.
+	///
+	/// [`shrink_to_fit`]: #method.shrink_to_fit
+	pub fn truncate(&mut self, len: usize) {
+		match &mut self.inner {
+			Inner::Inline { len: live, .. } => *live = cmp::min(*live, len),
+			Inner::Heap(bv) => bv.truncate(len),
+		}
+	}
+
+	/// Removes all bits from the vector, keeping its capacity.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Resizes the vector in place, filling any new bits with `value`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `new_len`: The new length of the vector.
+	/// - `value`: The value of any bits added to the vector.
+	pub fn resize(&mut self, new_len: usize, value: bool) {
+		let len = self.len();
+		if new_len <= len {
+			self.truncate(new_len);
+			return;
+		}
+		self.reserve(new_len - len);
+		match &mut self.inner {
+			Inner::Inline { data, len: live } => {
+				data[len .. new_len].set_all(value);
+				*live = new_len;
+			},
+			Inner::Heap(bv) => bv.resize(new_len, value),
+		}
+	}
+
+	/// Views the live bits of the vector as a bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `SliceBit` over the live bits of the vector.
+	pub fn as_bitslice(&self) -> &SliceBit<C, A::Store> {
+		match &self.inner {
+			Inner::Inline { data, len } => &data[.. *len],
+			Inner::Heap(bv) => bv,
+		}
+	}
+
+	/// Views the live bits of the vector as a mutable bit slice.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A mutable `SliceBit` over the live bits of the vector.
+	pub fn as_mut_bitslice(&mut self) -> &mut SliceBit<C, A::Store> {
+		match &mut self.inner {
+			Inner::Inline { data, len } => &mut data[.. *len],
+			Inner::Heap(bv) => bv,
+		}
+	}
+
+	/// Converts the vector into a `VecBit`.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A `VecBit` of the same bits. This allocates only if the bits are still
+	/// inline.
+	pub fn into_vecbit(self) -> VecBit<C, A::Store> {
+		match self.inner {
+			Inner::Inline { data, len } => VecBit::from_bitslice(&data[.. len]),
+			Inner::Heap(bv) => bv,
+		}
+	}
+
+	/// Moves the bits to the heap, with room for `additional` more.
+	///
+	/// The allocation holds at least twice the inline capacity, so that a
+	/// vector which has just spilled does not reallocate on every push.
+	fn spill(&mut self, additional: usize) {
+		let capacity = cmp::max(
+			self.len() + additional,
+			Self::inline_capacity() << 1,
+		);
+		let mut bv = VecBit::with_capacity(capacity);
+		bv.extend_from_bitslice(self);
+		self.inner = Inner::Heap(bv);
+	}
+}
+
+impl<C, A> Clone for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Clone {
+	fn clone(&self) -> Self {
+		let inner = match &self.inner {
+			Inner::Inline { data, len } => Inner::Inline {
+				data: data.clone(),
+				len: *len,
+			},
+			Inner::Heap(bv) => Inner::Heap(bv.clone()),
+		};
+		Self { inner }
+	}
+}
+
+impl<C, A> Eq for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {}
+
+impl<C, A> Ord for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn cmp(&self, rhs: &Self) -> Ordering {
+		self.as_bitslice().cmp(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, B> PartialEq<SmallVecBit<D, B>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default, D: Cursor, B: BitsMut + Default {
+	fn eq(&self, rhs: &SmallVecBit<D, B>) -> bool {
+		self.as_bitslice().eq(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, U> PartialEq<SliceBit<D, U>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default, D: Cursor, U: BitStore {
+	fn eq(&self, rhs: &SliceBit<D, U>) -> bool {
+		self.as_bitslice().eq(rhs)
+	}
+}
+
+impl<C, A, D, U> PartialEq<&SliceBit<D, U>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default, D: Cursor, U: BitStore {
+	fn eq(&self, rhs: &&SliceBit<D, U>) -> bool {
+		self.as_bitslice().eq(*rhs)
+	}
+}
+
+impl<C, A, D, U> PartialEq<VecBit<D, U>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default, D: Cursor, U: BitStore {
+	fn eq(&self, rhs: &VecBit<D, U>) -> bool {
+		self.as_bitslice().eq(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, B> PartialOrd<SmallVecBit<D, B>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default, D: Cursor, B: BitsMut + Default {
+	fn partial_cmp(&self, rhs: &SmallVecBit<D, B>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs.as_bitslice())
+	}
+}
+
+impl<C, A, D, U> PartialOrd<SliceBit<D, U>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default, D: Cursor, U: BitStore {
+	fn partial_cmp(&self, rhs: &SliceBit<D, U>) -> Option<Ordering> {
+		self.as_bitslice().partial_cmp(rhs)
+	}
+}
+
+impl<C, A> AsMut<SliceBit<C, A::Store>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn as_mut(&mut self) -> &mut SliceBit<C, A::Store> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<C, A> AsRef<SliceBit<C, A::Store>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn as_ref(&self) -> &SliceBit<C, A::Store> {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> From<&SliceBit<C, A::Store>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn from(src: &SliceBit<C, A::Store>) -> Self {
+		Self::from_bitslice(src)
+	}
+}
+
+/// Wraps a `VecBit` without moving its bits inline.
+impl<C, A> From<VecBit<C, A::Store>> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn from(src: VecBit<C, A::Store>) -> Self {
+		Self { inner: Inner::Heap(src) }
+	}
+}
+
+impl<C, A> Default for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C, A> Debug for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.write_str("SmallVecBit<")?;
+		f.write_str(C::TYPENAME)?;
+		f.write_str(", ")?;
+		f.write_str(A::Store::TYPENAME)?;
+		f.write_str("> ")?;
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Display for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), f)
+	}
+}
+
+impl<C, A> Hash for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn hash<H: Hasher>(&self, hasher: &mut H) {
+		self.as_bitslice().hash(hasher)
+	}
+}
+
+impl<C, A> Extend<bool> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn extend<I: IntoIterator<Item=bool>>(&mut self, src: I) {
+		let iter = src.into_iter();
+		self.reserve(iter.size_hint().0);
+		iter.for_each(|b| self.push(b));
+	}
+}
+
+impl<C, A> FromIterator<bool> for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn from_iter<I: IntoIterator<Item=bool>>(src: I) -> Self {
+		let mut out = Self::new();
+		out.extend(src);
+		out
+	}
+}
+
+impl<C, A> Deref for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	type Target = SliceBit<C, A::Store>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<C, A> DerefMut for SmallVecBit<C, A>
+where C: Cursor, A: BitsMut + Default {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
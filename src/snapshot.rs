@@ -0,0 +1,303 @@
+/*! Binary snapshots
+
+This module defines a small, versioned binary format for persisting bit
+sequences, independent of `serde`. A snapshot describes its own layout, so that
+it can be checked on load and read back on any platform, by any later version
+of this crate.
+
+# Format
+
+All integers are little-endian.
+
+| Bytes | Contents                                                 |
+|:------|:---------------------------------------------------------|
+| 4     | The magic number `b"VBIT"`.                              |
+| 1     | The format version, currently [`VERSION`].               |
+| 1     | The length, `n`, of the cursor tag.                      |
+| `n`   | The cursor tag: `BigEndian` or `LittleEndian`.           |
+| 1     | The width, in bits, of the storage elements.             |
+| 8     | The number of live bits.                                 |
+| …     | The storage elements, each little-endian.                |
+
+The payload holds exactly as many elements as are needed for the live bits.
+The bits in each element are placed by the cursor named in the header, and any
+dead bits after the last live bit are cleared.
+
+The tag names the layout of the cursor, not its type: a cursor that places the
+bits of the storage type as `BigEndian` or `LittleEndian` does is recorded, and
+accepted, as that cursor. Sequences under any other cursor are written in the
+`LittleEndian` layout, and re-packed into their own cursor when read.
+
+The header records the types that `Local` and `Word` name on the writing
+target, not the aliases, so a snapshot written through them is rejected, rather
+than misread, on a target where they name other types. Use `Portable` and
+`PortableWord` for snapshots that must load on every target.
+
+[`VERSION`]: constant.VERSION.html
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	access::BitAccess,
+	capacity,
+	cursor::{
+		Cursor,
+		LittleEndian,
+		Stride,
+	},
+	indices::Indexable,
+	pointer::BitPtr,
+	slice::SliceBit,
+	store::BitStore,
+	vec::VecBit,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	mem,
+};
+
+/// The leading bytes of every snapshot.
+const MAGIC: &[u8; 4] = b"VBIT";
+
+/// The snapshot format version that this crate writes.
+pub const VERSION: u8 = 1;
+
+/// The reasons a snapshot can be rejected on load.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotError {
+	/// The input does not begin with the snapshot magic number.
+	Magic,
+	/// The snapshot was written in an unknown format version.
+	Version(u8),
+	/// The snapshot was written with a different cursor.
+	Cursor,
+	/// The snapshot was written with a different storage element width.
+	Width {
+		/// The width, in bits, recorded in the snapshot.
+		found: u8,
+		/// The width, in bits, of the requested storage type.
+		expected: u8,
+	},
+	/// The recorded bit length is too long for a vector to hold.
+	Length(u64),
+	/// The input ends before the snapshot does.
+	Truncated,
+	/// The input continues after the snapshot ends.
+	Trailing,
+	/// The dead bits after the last live bit are not cleared.
+	DeadBits,
+}
+
+impl Display for SnapshotError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			SnapshotError::Magic => f.write_str("not a bit snapshot"),
+			SnapshotError::Version(v) => {
+				write!(f, "unknown snapshot version {}", v)
+			},
+			SnapshotError::Cursor => f.write_str("snapshot cursor does not match"),
+			SnapshotError::Width { found, expected } => write!(
+				f,
+				"snapshot element width {} does not match {}",
+				found,
+				expected,
+			),
+			SnapshotError::Length(len) => {
+				write!(f, "snapshot length {} is too long", len)
+			},
+			SnapshotError::Truncated => f.write_str("snapshot is truncated"),
+			SnapshotError::Trailing => f.write_str("snapshot has trailing bytes"),
+			SnapshotError::DeadBits => {
+				f.write_str("snapshot has set bits after its end")
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+impl<C, T> SliceBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Writes the slice as a binary snapshot.
+	///
+	/// The snapshot records the cursor and element width, and can be read back
+	/// with [`VecBit::from_snapshot`] using the same cursor and storage types.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The snapshot bytes, in the format described in the [module docs].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[cfg(feature = "alloc")] {
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = &0b1011_0000u8.bits::<BigEndian>()[.. 4];
+	/// let snap = bits.to_snapshot();
+	/// assert_eq!(&snap[.. 5], b"VBIT\x01");
+	/// assert_eq!(&snap[snap.len() - 1 ..], &[0b1011_0000]);
+	///
+	/// let bv = VecBit::<BigEndian, u8>::from_snapshot(&snap).unwrap();
+	/// assert_eq!(bv, bits);
+	/// # }
+	/// ```
+	///
+	/// [`VecBit::from_snapshot`]: ../vec/struct.VecBit.html#method.from_snapshot
+	/// [module docs]: ../snapshot/index.html
+	pub fn to_snapshot(&self) -> Vec<u8> {
+		if *self.bitptr().head() != 0 {
+			return VecBit::from_bitslice(self).to_snapshot();
+		}
+		let tag = match Stride::tag::<C, T>() {
+			Some(tag) => tag.as_bytes(),
+			None => return self.iter()
+				.collect::<VecBit<LittleEndian, T>>()
+				.to_snapshot(),
+		};
+		let len = self.len();
+		let width = mem::size_of::<T>();
+		let elts = self.as_total_slice();
+		let mut out = Vec::with_capacity(15 + tag.len() + elts.len() * width);
+		out.extend_from_slice(MAGIC);
+		out.push(VERSION);
+		out.push(tag.len() as u8);
+		out.extend_from_slice(tag);
+		out.push(T::BITS);
+		out.extend_from_slice(&(len as u64).to_le_bytes());
+		let tail = *self.bitptr().tail();
+		for (n, elt) in elts.iter().enumerate() {
+			let mut elt = elt.load();
+			if n + 1 == elts.len() {
+				for idx in tail .. T::BITS {
+					elt.set::<C>(idx.idx(), false);
+				}
+			}
+			let elt: u64 = elt.into();
+			out.extend_from_slice(&elt.to_le_bytes()[.. width]);
+		}
+		out
+	}
+}
+
+impl<C, T> VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Reads a vector back from a binary snapshot.
+	///
+	/// The snapshot is checked strictly: its header must name the layout of the
+	/// cursor `C` and the width of `T`, and its payload must be exactly as long as its
+	/// recorded length needs, with every dead bit cleared.
+	///
+	/// # Parameters
+	///
+	/// - `snap`: The bytes of a snapshot, as produced by
+	///   [`SliceBit::to_snapshot`].
+	///
+	/// # Returns
+	///
+	/// The vector that the snapshot describes, or the reason that it could not
+	/// be read.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::snapshot::SnapshotError;
+	///
+	/// let bv = vecbit![LittleEndian, u16; 1, 0, 1, 1];
+	/// let snap = bv.to_snapshot();
+	/// assert_eq!(VecBit::<LittleEndian, u16>::from_snapshot(&snap), Ok(bv));
+	/// assert_eq!(
+	///   VecBit::<BigEndian, u16>::from_snapshot(&snap),
+	///   Err(SnapshotError::Cursor),
+	/// );
+	/// assert_eq!(
+	///   VecBit::<LittleEndian, u8>::from_snapshot(&snap),
+	///   Err(SnapshotError::Width { found: 16, expected: 8 }),
+	/// );
+	/// assert_eq!(
+	///   VecBit::<LittleEndian, u16>::from_snapshot(&snap[.. snap.len() - 1]),
+	///   Err(SnapshotError::Truncated),
+	/// );
+	/// ```
+	///
+	/// [`SliceBit::to_snapshot`]: ../slice/struct.SliceBit.html#method.to_snapshot
+	pub fn from_snapshot(snap: &[u8]) -> Result<Self, SnapshotError> {
+		let mut rest = snap;
+		if take(&mut rest, MAGIC.len())? != MAGIC {
+			return Err(SnapshotError::Magic);
+		}
+		match take(&mut rest, 1)?[0] {
+			VERSION => {},
+			v => return Err(SnapshotError::Version(v)),
+		}
+		let tag = match Stride::tag::<C, T>() {
+			Some(tag) => tag,
+			None => return VecBit::<LittleEndian, T>::from_snapshot(snap)
+				.map(|bv| bv.iter().collect()),
+		};
+		let tag_len = take(&mut rest, 1)?[0] as usize;
+		if take(&mut rest, tag_len)? != tag.as_bytes() {
+			return Err(SnapshotError::Cursor);
+		}
+		match take(&mut rest, 1)?[0] {
+			w if w == T::BITS => {},
+			w => return Err(SnapshotError::Width {
+				found: w,
+				expected: T::BITS,
+			}),
+		}
+		let mut len = [0u8; 8];
+		len.copy_from_slice(take(&mut rest, 8)?);
+		let len = u64::from_le_bytes(len);
+		if len > BitPtr::<T>::MAX_BITS as u64 {
+			return Err(SnapshotError::Length(len));
+		}
+		let len = len as usize;
+
+		let width = mem::size_of::<T>();
+		let elts = capacity::elts_for_bits::<T>(len);
+		let payload = take(&mut rest, elts * width)?;
+		if !rest.is_empty() {
+			return Err(SnapshotError::Trailing);
+		}
+		let data = payload.chunks(width)
+			.map(|bytes| bytes.iter().rev().fold(T::bits(false), |elt, &byte| {
+				//  Shifting by the full width of `u8` is an overflow.
+				if width == 1 { T::from(byte) } else { elt << 8 | T::from(byte) }
+			}))
+			.collect::<Vec<T>>();
+		if let Some(last) = data.last() {
+			let tail = len - ((elts - 1) << T::INDX);
+			if (tail as u8 .. T::BITS).any(|n| last.get::<C>(n.idx())) {
+				return Err(SnapshotError::DeadBits);
+			}
+		}
+		let mut out = Self::from_vec(data);
+		unsafe { out.set_len(len); }
+		Ok(out)
+	}
+}
+
+/// Splits some bytes off the front of the input.
+fn take<'a>(rest: &mut &'a [u8], count: usize) -> Result<&'a [u8], SnapshotError> {
+	if rest.len() < count {
+		return Err(SnapshotError::Truncated);
+	}
+	let (head, tail) = rest.split_at(count);
+	*rest = tail;
+	Ok(head)
+}
@@ -35,6 +35,8 @@ use core::{
 		BitAndAssign,
 		BitOr,
 		BitOrAssign,
+		BitXor,
+		BitXorAssign,
 		Not,
 		Shl,
 		ShlAssign,
@@ -59,6 +61,13 @@ on 32-bit systems.
 
 The `Sealed` supertrait ensures that this can only be implemented locally, and
 will never be implemented by downstream crates on new types.
+
+`BitStore` describes element *values*, and so is not implemented for the
+shared-mutability wrappers `Cell<uN>` and `AtomicUN`: they are neither `Copy`
+nor closed under the bitwise operators that the rest of the crate uses to build
+masks. Those wrappers appear instead as `BitStore::Access`, through which the
+slice types perform every write to memory that may be shared. Existing buffers
+of `Cell`s or atomics cannot currently be viewed as bit slices.
 **/
 pub trait BitStore:
 	//  Forbid external implementation
@@ -69,6 +78,8 @@ pub trait BitStore:
 	+ BitAndAssign<Self>
 	+ BitOr<Self, Output = Self>
 	+ BitOrAssign<Self>
+	+ BitXor<Self, Output = Self>
+	+ BitXorAssign<Self>
 	//  Permit indexing into a generic array
 	+ Copy
 	+ Debug
@@ -327,6 +338,15 @@ to `u8` as an uninformed guess.
 #[cfg(not(any(target_pointer_width = "32", target_pointer_width = "64")))]
 pub type Word = u8;
 
+/** A storage type for data that leaves the program.
+
+`Word` has a different width on targets of different pointer widths, and any
+element wider than a byte is laid out in memory in the target’s byte order.
+Elements of `PortableWord` have the same size and layout on every target, so a
+buffer of them can be written to a file or socket as it is.
+**/
+pub type PortableWord = u8;
+
 /** Marker trait to seal `BitStore` against downstream implementation.
 
 This trait is public in the module, so that other modules in the crate can use
@@ -10,14 +10,24 @@ slice and vector types.
 #![cfg(any(feature = "alloc", feature = "std"))]
 
 use crate::{
+	access::BitAccess,
 	boxed::BitBox,
+	bits::Bits,
+	capacity,
 	cursor::{
+		BigEndian,
 		Cursor,
+		LittleEndian,
 		Local,
+		Stride,
 	},
 	indices::Indexable,
+	planes::reverse,
 	pointer::BitPtr,
-	slice::SliceBit,
+	slice::{
+		SliceBit,
+		resolve_range,
+	},
 	store::{
 		BitStore,
 		Word,
@@ -286,6 +296,8 @@ its handle representation, for two reasons:
 - It would penalize the general, heap-allocated, case by incurring a branch on
   every access.
 
+Use [`SmallVecBit`] when most vectors are short enough to avoid the heap.
+
 `VecBit` will never automatically shrink itself, even if it is emptied. This
 ensures that no unnecessary allocations or deallocations occur. Emptying a
 `VecBit` and then refilling it to the same length will incur no calls to the
@@ -331,6 +343,7 @@ is ***extremely binary incompatible*** with them. Attempting to treat
 [`clear_on_drop`]: https://docs.rs/clear_on_drop
 [`len`]: #method.len
 [`shrink_to_fit`]: #method.shrink_to_fit
+[`SmallVecBit`]: ../smallvec/struct.SmallVecBit.html
 [`&str`]: https://doc.rust-lang.org/stable/std/primitive.str.html
 [`&[]`]: https://doc.rust-lang.org/stable/std/primitive.slice.html
 **/
@@ -351,6 +364,11 @@ where C: Cursor, T: BitStore {
 	///
 	/// The vector does not allocate until bits are written into it.
 	///
+	/// This is not a `const fn`, as the stable compiler does not yet permit
+	/// trait bounds on the type parameters of `const fn` items, and `VecBit`
+	/// requires `C: Cursor` and `T: BitStore`. Global vectors must still be
+	/// initialized lazily.
+	///
 	/// # Returns
 	///
 	/// An empty, unallocated, `VecBit` handle.
@@ -398,7 +416,7 @@ where C: Cursor, T: BitStore {
 	pub fn with_capacity(capacity: usize) -> Self {
 		//  Find the number of elements needed to store the requested capacity
 		//  of bits.
-		let (cap, _) = 0u8.idx::<T>().span(capacity);
+		let cap = capacity::elts_for_bits::<T>(capacity);
 		//  Acquire a region of memory large enough for that element number.
 		let (ptr, cap) = {
 			let v = Vec::with_capacity(cap);
@@ -414,6 +432,127 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Constructs a new, empty, `VecBit<C, T>` with the specified capacity,
+	/// measured in elements rather than bits.
+	///
+	/// Allocation always happens in whole `T` elements, so this constructor
+	/// is useful when the caller budgets memory directly rather than through a
+	/// bit count that the vector must round up.
+	///
+	/// # Parameters
+	///
+	/// - `elements`: The minimum number of `T` elements that the new vector
+	///   will need to be able to hold.
+	///
+	/// # Returns
+	///
+	/// An empty vector with at least the given element capacity.
+	///
+	/// # Panics
+	///
+	/// Panics if `elements` exceeds the maximum number of elements that a
+	/// `VecBit` can address.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bv = VecBit::<BigEndian, u8>::with_capacity_in_elements(3);
+	/// assert!(bv.is_empty());
+	/// assert!(bv.capacity_elements() >= 3);
+	/// assert!(bv.capacity() >= 24);
+	/// ```
+	pub fn with_capacity_in_elements(elements: usize) -> Self {
+		assert!(
+			elements <= BitPtr::<T>::MAX_ELTS,
+			"Capacity overflow: {} exceeds {}",
+			elements,
+			BitPtr::<T>::MAX_ELTS,
+		);
+		let (ptr, cap) = {
+			let v = Vec::<T>::with_capacity(elements);
+			let (ptr, cap) = (v.as_ptr(), v.capacity());
+			mem::forget(v);
+			(ptr, cap)
+		};
+		Self {
+			_cursor: PhantomData,
+			pointer: BitPtr::uninhabited(ptr),
+			capacity: cap,
+		}
+	}
+
+	/// Constructs a new, empty, `VecBit<C, T>` with the specified capacity,
+	/// reporting failure rather than panicking or aborting.
+	///
+	/// # Parameters
+	///
+	/// - `capacity`: The minimum number of bits that the new vector will need
+	///   to be able to hold.
+	///
+	/// # Returns
+	///
+	/// An empty vector with at least the given capacity.
+	///
+	/// # Errors
+	///
+	/// - `CapacityError::Overflow` if `capacity` exceeds the number of bits that
+	///   a `VecBit` can address.
+	/// - `CapacityError::Alloc` if the allocator cannot provide the memory.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::vec::CapacityError;
+	///
+	/// let bv = VecBit::<BigEndian, u8>::try_with_capacity(100).unwrap();
+	/// assert!(bv.capacity() >= 100);
+	/// assert_eq!(
+	///   VecBit::<BigEndian, u8>::try_with_capacity(!0).err(),
+	///   Some(CapacityError::Overflow),
+	/// );
+	/// ```
+	pub fn try_with_capacity(capacity: usize) -> Result<Self, CapacityError> {
+		if capacity > BitPtr::<T>::MAX_BITS {
+			return Err(CapacityError::Overflow);
+		}
+		Self::try_with_capacity_in_elements(
+			capacity::elts_for_bits::<T>(capacity),
+		)
+	}
+
+	/// Constructs a new, empty, `VecBit<C, T>` with the specified capacity,
+	/// measured in elements, reporting failure rather than panicking or
+	/// aborting.
+	///
+	/// # Parameters
+	///
+	/// - `elements`: The minimum number of `T` elements that the new vector
+	///   will need to be able to hold.
+	///
+	/// # Returns
+	///
+	/// An empty vector with at least the given element capacity.
+	///
+	/// # Errors
+	///
+	/// - `CapacityError::Overflow` if `elements` exceeds the number of elements
+	///   that a `VecBit` can address.
+	/// - `CapacityError::Alloc` if the allocator cannot provide the memory.
+	pub fn try_with_capacity_in_elements(elements: usize)
+	-> Result<Self, CapacityError> {
+		let v = capacity::try_allocate::<T>(elements)?;
+		let (ptr, cap) = (v.as_ptr(), v.capacity());
+		mem::forget(v);
+		Ok(Self {
+			_cursor: PhantomData,
+			pointer: BitPtr::uninhabited(ptr),
+			capacity: cap,
+		})
+	}
+
 	/// Constructs a `VecBit` from a single element.
 	///
 	/// The produced `VecBit` will span the element, and include all bits in it.
@@ -515,6 +654,70 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Wraps a `Vec` of elements in a `VecBit`, reporting overflow rather than
+	/// panicking.
+	///
+	/// # Parameters
+	///
+	/// - `vec`: The elements to wrap.
+	///
+	/// # Returns
+	///
+	/// A `VecBit` over all the bits of `vec`, reusing its allocation.
+	///
+	/// # Errors
+	///
+	/// `CapacityError::Overflow` if `vec` has more elements than a `VecBit` can
+	/// address. The elements are dropped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bv = VecBit::<BigEndian, u8>::try_from_vec(vec![0xF0, 0x0F]);
+	/// assert_eq!(bv.map(|bv| bv.count_ones()), Ok(8));
+	/// ```
+	pub fn try_from_vec(vec: Vec<T>) -> Result<Self, CapacityError> {
+		if vec.len() > BitPtr::<T>::MAX_ELTS {
+			return Err(CapacityError::Overflow);
+		}
+		Ok(Self::from_vec(vec))
+	}
+
+	/// Wraps a boxed slice of elements in a `VecBit`.
+	///
+	/// This takes ownership of the allocation, and does not copy or
+	/// reallocate.
+	///
+	/// # Parameters
+	///
+	/// - `boxed`: The source boxed slice whose memory will be used.
+	///
+	/// # Returns
+	///
+	/// A `VecBit` over all the bits in `boxed`, with no spare capacity.
+	///
+	/// # Panics
+	///
+	/// Panics if the source slice would cause the `VecBit` to overflow
+	/// capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let boxed: Box<[u8]> = vec![0x0F, 0xF0].into_boxed_slice();
+	/// let addr = boxed.as_ptr();
+	/// let bv = VecBit::<BigEndian, u8>::from_boxed_slice(boxed);
+	/// assert_eq!(bv.as_slice().as_ptr(), addr);
+	/// assert_eq!(bv.len(), 16);
+	/// ```
+	pub fn from_boxed_slice(boxed: Box<[T]>) -> Self {
+		Self::from_vec(boxed.into_vec())
+	}
+
 	/// Clones a `&SliceBit` into a `VecBit`.
 	///
 	/// # Parameters
@@ -536,7 +739,299 @@ where C: Cursor, T: BitStore {
 	/// assert!(bv.some());
 	/// ```
 	pub fn from_bitslice(slice: &SliceBit<C, T>) -> Self {
-		Self::from_iter(slice.iter())
+		let mut out = Self::with_capacity(slice.len());
+		out.extend_from_bitslice(slice);
+		out
+	}
+
+	/// Packs a slice of `bool`s into a new `VecBit`.
+	///
+	/// The bits are packed one whole element at a time, which is far faster
+	/// than pushing them individually.
+	///
+	/// # Parameters
+	///
+	/// - `src`: The bits to pack.
+	///
+	/// # Returns
+	///
+	/// A `VecBit` whose `n`th bit is `src[n]`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bv = VecBit::<BigEndian, u8>::from_bools(&[true, false, true]);
+	/// assert_eq!(bv.as_slice(), &[0b1010_0000]);
+	/// ```
+	pub fn from_bools(src: &[bool]) -> Self {
+		let mut out = Self::with_capacity(src.len());
+		out.extend_from_bools(src);
+		out
+	}
+
+	/// Builds a vector of cleared bits, with some ranges of them set.
+	///
+	/// This is the inverse of `SliceBit::iter_set_ranges`, and converts an
+	/// extent list into a bitmap. Each range is set with one fill, rather than
+	/// bit by bit.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The length of the new vector.
+	/// - `ranges`: The ranges of bits to set. They may overlap, and may come
+	///   in any order.
+	///
+	/// # Returns
+	///
+	/// A vector of `len` bits, in which exactly the bits covered by `ranges`
+	/// are set.
+	///
+	/// # Panics
+	///
+	/// Panics if any range runs from high to low, or extends beyond `len`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bv = VecBit::<BigEndian, u8>::from_ranges(16, vec![1 .. 4, 7 .. 9]);
+	/// assert_eq!(bv.as_slice(), &[0b0111_0001, 0b1000_0000]);
+	/// assert_eq!(bv.iter_set_ranges().collect::<Vec<_>>(), [1 .. 4, 7 .. 9]);
+	/// ```
+	pub fn from_ranges<I>(len: usize, ranges: I) -> Self
+	where I: IntoIterator<Item = Range<usize>> {
+		let mut out = Self::with_capacity(len);
+		out.set_elements(T::bits(false));
+		unsafe { out.set_len(len); }
+		for range in ranges {
+			out[range].set_all(true);
+		}
+		out
+	}
+
+	/// Builds a vector by testing a predicate against each item of an
+	/// iterator.
+	///
+	/// This is the entry point for building a bitmap index over a column of
+	/// values: bit `n` of the vector records whether the `n`th row satisfies
+	/// the predicate. The results are packed into a local element, and memory
+	/// is written only once per filled element, as by [`VecBitBuilder`].
+	///
+	/// # Parameters
+	///
+	/// - `iter`: The values to test.
+	/// - `pred`: The predicate to test against each value.
+	///
+	/// # Returns
+	///
+	/// A vector with one bit per item of `iter`, set where `pred` is true.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator`: A source of values.
+	/// - `F: FnMut(I::Item) -> bool`: The predicate.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let ages = [17u8, 42, 35, 12, 68, 20];
+	/// let adults = VecBit::<BigEndian, u8>::from_predicate(
+	///   &ages,
+	///   |&age| age >= 18,
+	/// );
+	/// assert_eq!(adults, vecbit![0, 1, 1, 0, 1, 1]);
+	/// ```
+	///
+	/// [`VecBitBuilder`]: struct.VecBitBuilder.html
+	pub fn from_predicate<I, F>(iter: I, mut pred: F) -> Self
+	where I: IntoIterator, F: FnMut(I::Item) -> bool {
+		let iter = iter.into_iter();
+		let mut builder = VecBitBuilder::with_capacity(iter.size_hint().0);
+		iter.for_each(|item| builder.push_bit(pred(item)));
+		builder.build()
+	}
+
+	/// Builds a vector marking the items of an iterator that equal a value.
+	///
+	/// This is [`from_predicate`] with an equality test, and builds one
+	/// bitmap of an equality-encoded bitmap index.
+	///
+	/// # Parameters
+	///
+	/// - `iter`: The values to test.
+	/// - `value`: The value to find.
+	///
+	/// # Returns
+	///
+	/// A vector with one bit per item of `iter`, set where the item equals
+	/// `value`.
+	///
+	/// # Type Parameters
+	///
+	/// - `I: IntoIterator`: A source of values.
+	/// - `V`: The type of the value to find, which the items can be compared
+	///   against.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let colors = ["red", "blue", "red", "green", "red"];
+	/// let red = VecBit::<LittleEndian, u8>::from_eq(colors.iter(), &"red");
+	/// assert_eq!(red, vecbit![1, 0, 1, 0, 1]);
+	/// assert_eq!(red.as_slice(), &[0b1_0101]);
+	/// ```
+	///
+	/// [`from_predicate`]: #method.from_predicate
+	pub fn from_eq<I, V>(iter: I, value: V) -> Self
+	where I: IntoIterator, I::Item: PartialEq<V> {
+		Self::from_predicate(iter, |item| item == value)
+	}
+
+	/// Appends a slice of `bool`s to the vector.
+	///
+	/// Bits are pushed individually only until the vector ends on an element
+	/// edge. After that, they are packed one whole element at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The bits to append.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![BigEndian, u8; 1, 1, 1];
+	/// bv.extend_from_bools(&[false; 6]);
+	/// bv.extend_from_bools(&[true]);
+	/// assert_eq!(bv.as_slice(), &[0b1110_0000, 0b0100_0000]);
+	/// ```
+	pub fn extend_from_bools(&mut self, src: &[bool]) {
+		use core::cmp::min;
+		self.reserve(src.len());
+		let free = capacity::bits_free_in_tail(self.pointer.tail());
+		let (lead, rest) = src.split_at(min(free, src.len()));
+		lead.iter().for_each(|&bit| self.push(bit));
+		let len = self.len();
+		self.do_unto_vec(|v| v.extend(rest.chunks(T::BITS as usize).map(|chunk| {
+			let mut elt = T::bits(false);
+			for (n, &bit) in chunk.iter().enumerate() {
+				elt.set::<C>((n as u8).idx(), bit);
+			}
+			elt
+		})));
+		unsafe { self.set_len(len + rest.len()); }
+	}
+
+	/// Appends a copy of a bit slice to the vector.
+	///
+	/// Bits are pushed individually only until the vector ends on an element
+	/// edge. After that, for the `BigEndian` and `LittleEndian` cursors, each
+	/// new element is assembled from at most two source elements with a pair
+	/// of shifts, however the source is aligned. Other cursors pack the bits
+	/// one whole element at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The bits to append.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let src = [0x5Au8, 0xC3];
+	/// let mut bv = vecbit![BigEndian, u8; 1, 1];
+	/// bv.extend_from_bitslice(&src.bits()[3 .. 13]);
+	/// assert_eq!(bv.as_slice()[0], 0b1111_0101);
+	/// assert_eq!(bv, vecbit![1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 0, 0]);
+	/// ```
+	pub fn extend_from_bitslice(&mut self, src: &SliceBit<C, T>) {
+		use core::cmp::min;
+		self.reserve(src.len());
+		let free = capacity::bits_free_in_tail(self.pointer.tail());
+		let (lead, rest) = src.split_at(min(free, src.len()));
+		lead.iter().for_each(|bit| self.push(bit));
+		let len = self.len();
+		let elts = rest.bitptr().as_access_slice();
+		let shift = *rest.bitptr().head();
+		let count = capacity::elts_for_bits::<T>(rest.len());
+		self.do_unto_vec(|v| match Stride::of::<C, T>() {
+			Some(stride) => v.extend((0 .. count).map(|n| {
+				let lo = elts[n].load();
+				match (shift, elts.get(n + 1)) {
+					(0, _) => lo,
+					//  The source element after `lo` holds the rest of the bits.
+					(sh, Some(hi)) => stride.to_front(lo, sh)
+						| stride.to_back(hi.load(), T::BITS - sh),
+					(sh, None) => stride.to_front(lo, sh),
+				}
+			})),
+			None => v.extend(rest.chunks(T::BITS as usize).map(|chunk| {
+				let mut elt = T::bits(false);
+				for (n, bit) in chunk.iter().enumerate() {
+					elt.set::<C>((n as u8).idx(), bit);
+				}
+				elt
+			})),
+		});
+		unsafe { self.set_len(len + rest.len()); }
+		//  The last element may have taken bits from beyond the end of `src`.
+		self.mask_dead_bits();
+	}
+
+	/// Unpacks the low bits of an integer bitmask into a vector.
+	///
+	/// This is the inverse of [`SliceBit::to_bitmask`]: bit `n` of the vector
+	/// is set if the bit of value `1 << n` in the mask is set.
+	///
+	/// # Parameters
+	///
+	/// - `mask`: An integer bitmask, such as a SIMD lane mask.
+	/// - `len`: The number of bits, from the least significant end of `mask`,
+	///   to take.
+	///
+	/// # Returns
+	///
+	/// A vector of `len` bits, copied out of `mask`.
+	///
+	/// # Panics
+	///
+	/// Panics if `len` is greater than `U::BITS`.
+	///
+	/// # Type Parameters
+	///
+	/// - `U: BitStore`: The integer type of the mask.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bv = VecBit::<BigEndian, u8>::from_bitmask(0b1101u16, 5);
+	/// assert_eq!(bv, vecbit![1, 0, 1, 1, 0]);
+	/// assert_eq!(bv.to_bitmask::<u16>(), 0b1101);
+	/// ```
+	///
+	/// [`SliceBit::to_bitmask`]: ../slice/struct.SliceBit.html#method.to_bitmask
+	pub fn from_bitmask<U>(mask: U, len: usize) -> Self
+	where U: BitStore {
+		assert!(
+			len <= U::BITS as usize,
+			"Cannot take {} bits from a {}-bit mask",
+			len,
+			U::BITS,
+		);
+		(0 .. len as u8).map(|n| mask.get::<LittleEndian>(n.idx())).collect()
 	}
 
 	/// Converts a frozen `BitBox` allocation into a growable `VecBit`.
@@ -634,9 +1129,38 @@ where C: Cursor, T: BitStore {
 			.expect("Vector capacity overflow")
 	}
 
-	/// Reserves capacity for at least `additional` more bits to be inserted.
+	/// Returns the number of elements the vector can hold without
+	/// reallocating.
 	///
-	/// The collection may reserve more space to avoid frequent reallocations.
+	/// This is the allocation size underlying [`capacity`], without the
+	/// multiplication into bits.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The number of `T` elements that the vector has allocated.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bv = VecBit::<BigEndian, u16>::with_capacity(20);
+	/// assert!(bv.capacity_elements() >= 2);
+	/// assert_eq!(bv.capacity(), bv.capacity_elements() * 16);
+	/// ```
+	///
+	/// [`capacity`]: #method.capacity
+	pub fn capacity_elements(&self) -> usize {
+		self.capacity
+	}
+
+	/// Reserves capacity for at least `additional` more bits to be inserted.
+	///
+	/// The collection may reserve more space to avoid frequent reallocations.
 	/// After calling `reserve`, capacity will be greater than or equal to
 	/// `self.len() + additional`. Does nothing if the capacity is already
 	/// sufficient.
@@ -661,6 +1185,45 @@ where C: Cursor, T: BitStore {
 	/// assert!(bv.capacity() >= 15);
 	/// ```
 	pub fn reserve(&mut self, additional: usize) {
+		self.reserve_with(additional, Growth::Doubling);
+	}
+
+	/// Reserves capacity for at least `additional` more bits to be inserted,
+	/// choosing the new capacity by a growth policy.
+	///
+	/// After calling `reserve_with`, capacity will be greater than or equal to
+	/// `self.len() + additional`. Does nothing if the capacity is already
+	/// sufficient. Applications that need predictable allocation sizes can
+	/// reserve ahead of their writes with this, so that the writes themselves
+	/// never reallocate.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of extra bits to be granted space.
+	/// - `growth`: The policy that chooses the new capacity, if the vector
+	///   must grow.
+	///
+	/// # Panics
+	///
+	/// Panics if the new capacity would overflow the vector’s limits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::vec::Growth;
+	///
+	/// let mut bv = VecBit::<BigEndian, u8>::new();
+	/// bv.reserve_with(20, Growth::Exact);
+	/// assert_eq!(bv.capacity_elements(), 3);
+	///
+	/// //  Grow in steps of 64 elements.
+	/// let step = |_: usize, need: usize| (need + 63) / 64 * 64;
+	/// bv.reserve_with(100, Growth::Custom(step));
+	/// assert_eq!(bv.capacity_elements(), 64);
+	/// ```
+	pub fn reserve_with(&mut self, additional: usize, growth: Growth) {
 		let newlen = self.len() + additional;
 		assert!(
 			newlen <= BitPtr::<T>::MAX_BITS,
@@ -670,8 +1233,48 @@ where C: Cursor, T: BitStore {
 		);
 		//  Compute the number of additional elements needed to store the
 		//  requested number of additional bits.
-		let (e, _) = self.pointer.tail().span(additional);
-		self.do_unto_vec(|v| v.reserve(e));
+		let e = capacity::elts_to_grow(self.pointer.tail(), additional);
+		self.grow_elements(e, growth);
+	}
+
+	/// Reserves capacity for at least `additional` more elements beyond those
+	/// currently in use.
+	///
+	/// After calling `reserve_elements`, [`capacity_elements`] will be greater
+	/// than or equal to the number of elements the vector currently touches,
+	/// plus `additional`. Does nothing if the capacity is already sufficient.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of extra `T` elements to be granted space.
+	///
+	/// # Panics
+	///
+	/// Panics if the new capacity would overflow the vector’s limits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![BigEndian, u8; 1; 12];
+	/// bv.reserve_elements(4);
+	/// assert!(bv.capacity_elements() >= 6);
+	/// ```
+	///
+	/// [`capacity_elements`]: #method.capacity_elements
+	pub fn reserve_elements(&mut self, additional: usize) {
+		let newelts = self.pointer.elements()
+			.checked_add(additional)
+			.expect("Capacity overflow");
+		assert!(
+			newelts <= BitPtr::<T>::MAX_ELTS,
+			"Capacity overflow: {} exceeds {}",
+			newelts,
+			BitPtr::<T>::MAX_ELTS,
+		);
+		self.do_unto_vec(|v| v.reserve(additional));
 	}
 
 	/// Reserves the minimum capacity for at least `additional` more bits.
@@ -713,7 +1316,7 @@ where C: Cursor, T: BitStore {
 		);
 		//  Compute the number of additional elements needed to store the
 		//  requested number of additional bits.
-		let (e, _) = self.pointer.tail().span(additional);
+		let e = capacity::elts_to_grow(self.pointer.tail(), additional);
 		self.do_unto_vec(|v| v.reserve_exact(e));
 	}
 
@@ -745,6 +1348,87 @@ where C: Cursor, T: BitStore {
 		self.do_unto_vec(Vec::shrink_to_fit);
 	}
 
+	/// Puts the vector’s memory into canonical form.
+	///
+	/// A vector always begins at the front edge of its first element, but the
+	/// dead bits after its last live bit may hold any value. This clears them,
+	/// so that two vectors with the same cursor, storage type, and contents
+	/// also have identical element slices. The canonical element slice can be
+	/// hashed, compared with `memcmp`, or written out as a snapshot directly.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `shrink`: Whether to also release any spare capacity, as
+	///   `shrink_to_fit` does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut a = vecbit![BigEndian, u8; 1; 12];
+	/// a.truncate(3);
+	/// let b = vecbit![BigEndian, u8; 1, 1, 1];
+	/// assert_eq!(a, b);
+	///
+	/// a.canonicalize(true);
+	/// assert_eq!(a.as_slice(), b.as_slice());
+	/// assert_eq!(a.as_slice(), &[0b1110_0000]);
+	/// ```
+	pub fn canonicalize(&mut self, shrink: bool) {
+		self.mask_dead_bits();
+		if shrink {
+			self.shrink_to_fit();
+		}
+	}
+
+	/// Clears the dead bits after the end of the vector.
+	///
+	/// The last element of a vector whose length is not a whole number of
+	/// elements holds dead bits after its last live bit. These are visible
+	/// through the element views (`as_slice`, `into_vec`, and the like), and
+	/// may hold any value.
+	///
+	/// # Dead Bits
+	///
+	/// Appending bits never dirties the dead bits, but removing bits leaves the
+	/// removed values behind in them. The operations that may do so are:
+	///
+	/// - `truncate`, `pop`, `remove`, `swap_remove`, `retain`, `resize`,
+	///   `split_off`, and `take_range`;
+	/// - `drain` and `splice`;
+	/// - `set_elements`, which writes whole elements;
+	/// - writes through `as_mut_slice`, and `set_len`.
+	///
+	/// With the `deadbits` feature enabled, every one of these except the last
+	/// group clears the dead bits itself, so that they are always zero. Debug
+	/// builds with the feature also check that the dead bits are zero whenever
+	/// the elements are viewed, which catches writes through `as_mut_slice`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![BigEndian, u8; 1; 8];
+	/// bv.truncate(3);
+	/// bv.mask_dead_bits();
+	/// assert_eq!(bv.as_slice(), &[0b1110_0000]);
+	/// ```
+	pub fn mask_dead_bits(&mut self) {
+		let tail = *self.pointer.tail();
+		if let Some(last) = self.as_mut_slice().last_mut() {
+			for n in tail .. T::BITS {
+				last.set::<C>(n.idx(), false);
+			}
+		}
+	}
+
 	/// Shortens the vector, keeping the first `len` bits and dropping the rest.
 	///
 	/// If `len` is greater than the vector’s current length, this has no
@@ -770,6 +1454,7 @@ where C: Cursor, T: BitStore {
 	pub fn truncate(&mut self, len: usize) {
 		if len < self.len() {
 			unsafe { self.bitptr_mut().set_len(len); }
+			self.tidy_dead_bits();
 		}
 	}
 
@@ -835,6 +1520,7 @@ where C: Cursor, T: BitStore {
 	/// The slice of all live elements in the backing storage, including the
 	/// partial edges if present.
 	pub fn as_slice(&self) -> &[T] {
+		self.check_dead_bits();
 		self.bitptr().as_slice()
 	}
 
@@ -940,9 +1626,54 @@ where C: Cursor, T: BitStore {
 			.expect("VecBit::swap_remove cannot fail after index validation")
 	}
 
+	/// Removes a range of bits from the vector, filling the gap with the bits
+	/// at the end of the vector.
+	///
+	/// This does not preserve ordering, but only moves as many bits as the
+	/// range is long, rather than every bit after it. The bits moved into the
+	/// gap keep their order.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: The range of bits to remove.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is ill-formed, or if it is beyond the vector bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![0, 0, 1, 1, 1, 0, 0, 1, 0];
+	/// bv.swap_remove_range(2 .. 5);
+	/// assert_eq!(bv, vecbit![0, 0, 0, 1, 0, 0]);
+	/// bv.swap_remove_range(4 ..);
+	/// assert_eq!(bv, vecbit![0, 0, 0, 1]);
+	/// ```
+	pub fn swap_remove_range<R>(&mut self, range: R)
+	where R: RangeBounds<usize> {
+		use core::cmp::max;
+		let len = self.len();
+		let Range { start, end } = resolve_range(range, len);
+		let count = end - start;
+		//  Bits at the end of the vector that lie within the range need not
+		//  move.
+		let from = max(end, len - count);
+		let data = self.as_mut_slice().as_mut_ptr();
+		unsafe {
+			move_bits::<C, T>(data, from, data, start, len - from);
+		}
+		self.truncate(len - count);
+	}
+
 	/// Inserts a bit at a position, shifting all bits after it to the right.
 	///
-	/// Note that this is `O(n)` runtime.
+	/// Note that this is `O(n)` runtime. Only the bits after `index` are
+	/// moved, and for the provided cursors they are moved a whole element at a
+	/// time.
 	///
 	/// # Parameters
 	///
@@ -966,17 +1697,51 @@ where C: Cursor, T: BitStore {
 	/// assert_eq!(bv, vecbit![0, 0, 1, 0, 0]);
 	/// bv.insert(5, true);
 	/// assert_eq!(bv, vecbit![0, 0, 1, 0, 0, 1]);
+	///
+	/// let mut bv = vecbit![BigEndian, u8; 0; 20];
+	/// bv.set(9, true);
+	/// bv.insert(3, true);
+	/// assert_eq!(bv.as_slice(), &[0b0001_0000, 0b0010_0000, 0]);
 	/// ```
 	pub fn insert(&mut self, index: usize, value: bool) {
 		let len = self.len();
 		assert!(index <= len, "Index {} is out of bounds: {}", index, len);
-		self.push(value);
-		self[index ..].rotate_right(1);
+		//  Grow by one bit, then move only the bits in `index .. len` up into
+		//  the new slot.
+		self.push(false);
+		match Stride::of::<C, T>() {
+			Some(stride) => {
+				let (e, i) = (index >> T::INDX, (index & T::MASK as usize) as u8);
+				let elts = self.as_mut_slice();
+				//  Every element after the one holding `index` moves whole, and
+				//  receives the last bit of its predecessor in its first slot.
+				for n in (e + 1 .. elts.len()).rev() {
+					let carry = elts[n - 1].get::<C>(T::MASK.idx());
+					elts[n] = stride.to_back(elts[n], 1);
+					elts[n].set::<C>(0u8.idx(), carry);
+				}
+				//  The element holding `index` moves only its bits after `i`.
+				let elt = &mut elts[e];
+				for n in (i .. T::MASK).rev() {
+					let bit = elt.get::<C>(n.idx());
+					elt.set::<C>((n + 1).idx(), bit);
+				}
+			},
+			None => for n in (index .. len).rev() {
+				let bit = self[n];
+				self.set(n + 1, bit);
+			},
+		}
+		self.set(index, value);
 	}
 
 	/// Removes and returns the bit at position `index`, shifting all bits after
 	/// it to the left.
 	///
+	/// Note that this is `O(n)` runtime. Only the bits after `index` are
+	/// moved, and for the provided cursors they are moved a whole element at a
+	/// time.
+	///
 	/// # Parameters
 	///
 	/// - `&mut self`
@@ -999,13 +1764,57 @@ where C: Cursor, T: BitStore {
 	/// let mut bv = vecbit![0, 0, 1, 0, 0];
 	/// assert!(bv.remove(2));
 	/// assert_eq!(bv, vecbit![0, 0, 0, 0]);
+	///
+	/// let mut bv = vecbit![LittleEndian, u8; 0; 20];
+	/// bv.set(3, true);
+	/// bv.set(10, true);
+	/// assert!(bv.remove(3));
+	/// assert_eq!(bv.as_slice(), &[0, 0b0000_0010, 0]);
 	/// ```
 	pub fn remove(&mut self, index: usize) -> bool {
 		let len = self.len();
 		assert!(index < len, "Index {} is out of bounds: {}", index, len);
-		self[index ..].rotate_left(1);
-		self.pop()
-			.expect("VecBit::remove cannot fail after index validation")
+		let out = self[index];
+		//  Move only the bits in `index + 1 .. len` down by one, then drop the
+		//  last bit.
+		match Stride::of::<C, T>() {
+			Some(stride) => {
+				let (e, i) = (index >> T::INDX, (index & T::MASK as usize) as u8);
+				let elts = self.as_mut_slice();
+				let last = elts.len() - 1;
+				//  The element holding `index` moves only its bits after `i`,
+				//  and receives the first bit of its successor in its last slot.
+				let next = if e < last {
+					elts[e + 1].get::<C>(0u8.idx())
+				}
+				else {
+					false
+				};
+				let elt = &mut elts[e];
+				for n in i .. T::MASK {
+					let bit = elt.get::<C>((n + 1).idx());
+					elt.set::<C>(n.idx(), bit);
+				}
+				elt.set::<C>(T::MASK.idx(), next);
+				//  Every later element moves whole.
+				for n in e + 1 ..= last {
+					let carry = if n < last {
+						elts[n + 1].get::<C>(0u8.idx())
+					}
+					else {
+						false
+					};
+					elts[n] = stride.to_front(elts[n], 1);
+					elts[n].set::<C>(T::MASK.idx(), carry);
+				}
+			},
+			None => for n in index .. len - 1 {
+				let bit = self[n + 1];
+				self.set(n, bit);
+			},
+		}
+		self.truncate(len - 1);
+		out
 	}
 
 	/// Retains only the bits that pass the predicate.
@@ -1058,7 +1867,9 @@ where C: Cursor, T: BitStore {
 	/// # Panics
 	///
 	/// This will panic if the push will cause the vector to allocate above
-	/// `BitPtr<T>` or machine capacity.
+	/// `BitPtr<T>` or machine capacity. With the `no_panic` feature, it panics
+	/// on `BitPtr<T>` overflow only in debug builds, and otherwise discards the
+	/// bit.
 	///
 	/// # Examples
 	///
@@ -1073,15 +1884,29 @@ where C: Cursor, T: BitStore {
 	/// ```
 	pub fn push(&mut self, value: bool) {
 		let len = self.len();
-		assert!(
-			len <= BitPtr::<T>::MAX_BITS,
-			"Capacity overflow: {} >= {}",
-			len,
-			BitPtr::<T>::MAX_BITS,
-		);
-		//  If self is empty *or* tail is at the back edge of an element, push
-		//  an element onto the vector.
-		if self.is_empty() || *self.pointer.tail() == T::BITS {
+		let room = len <= BitPtr::<T>::MAX_BITS;
+		if cfg!(feature = "no_panic") {
+			debug_assert!(
+				room,
+				"Capacity overflow: {} >= {}",
+				len,
+				BitPtr::<T>::MAX_BITS,
+			);
+			if !room {
+				return;
+			}
+		}
+		else {
+			assert!(
+				room,
+				"Capacity overflow: {} >= {}",
+				len,
+				BitPtr::<T>::MAX_BITS,
+			);
+		}
+		//  If the last element has no dead bits left (including when there is
+		//  no last element), push an element onto the vector.
+		if capacity::bits_free_in_tail(self.pointer.tail()) == 0 {
 			self.do_unto_vec(|v| v.push(0.into()));
 		}
 		//  At this point, it is always safe to increment the tail, and then
@@ -1090,6 +1915,51 @@ where C: Cursor, T: BitStore {
 		self.set(len, value);
 	}
 
+	/// Appends a bit to the back of the vector, without checking or growing
+	/// the capacity.
+	///
+	/// This never panics or reallocates, so it suits decode loops that must be
+	/// shown to be free of both, after a single [`reserve`] or
+	/// [`try_with_capacity`] up front. For a safe alternative, see [`push`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to append.
+	///
+	/// # Safety
+	///
+	/// The vector must have spare capacity: `self.len()` must be less than
+	/// `self.capacity()`. Pushing past the capacity writes out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = VecBit::<BigEndian, u8>::with_capacity(12);
+	/// for n in 0 .. 12 {
+	///   unsafe { bv.push_unchecked(n % 3 == 0); }
+	/// }
+	/// assert_eq!(bv.as_slice(), &[0b1001_0010, 0b0100_0000]);
+	/// ```
+	///
+	/// [`push`]: #method.push
+	/// [`reserve`]: #method.reserve
+	/// [`try_with_capacity`]: #method.try_with_capacity
+	pub unsafe fn push_unchecked(&mut self, value: bool) {
+		let len = self.len();
+		if capacity::bits_free_in_tail(self.pointer.tail()) == 0 {
+			self.do_unto_vec(|v| {
+				let end = v.len();
+				ptr::write(v.as_mut_ptr().add(end), T::bits(false));
+				v.set_len(end + 1);
+			});
+		}
+		self.bitptr_mut().incr_tail();
+		self.set_unchecked(len, value);
+	}
+
 	/// Removes the last bit from the collection, if present.
 	///
 	/// # Parameters
@@ -1122,6 +1992,7 @@ where C: Cursor, T: BitStore {
 		}
 		let out = self[self.len() - 1];
 		unsafe { self.bitptr_mut().decr_tail() };
+		self.tidy_dead_bits();
 		Some(out)
 	}
 
@@ -1162,8 +2033,10 @@ where C: Cursor, T: BitStore {
 	///
 	/// 1. The element range is removed, regardless of whether the iterator is
 	///    consumed.
-	/// 2. The amount of items removed from the vector if the draining iterator
-	///    is leaked, is left unspecified.
+	/// 2. If the draining iterator is leaked (for example, with
+	///    `mem::forget`), the vector is truncated to the start of the range.
+	///    The drained range and every bit after it are lost, but the vector
+	///    remains valid.
 	///
 	/// # Parameters
 	///
@@ -1309,6 +2182,58 @@ where C: Cursor, T: BitStore {
 		}
 	}
 
+	/// Removes a range of bits from the vector, and returns them in a new
+	/// vector.
+	///
+	/// This is equivalent to `self.drain(range).collect()`, but copies the
+	/// removed bits out, and the bits after them down, a whole element at a
+	/// time rather than bit by bit.
+	///
+	/// Note that the capacity of `self` does not change.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: The range of bits to remove.
+	///
+	/// # Returns
+	///
+	/// A new `VecBit` containing the bits that were in `range`.
+	///
+	/// # Panics
+	///
+	/// Panics if `range` is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![0, 0, 1, 1, 1, 0, 0];
+	/// let taken = bv.take_range(2 .. 5);
+	/// assert_eq!(taken, vecbit![1, 1, 1]);
+	/// assert_eq!(bv, vecbit![0, 0, 0, 0]);
+	/// ```
+	pub fn take_range<R>(&mut self, range: R) -> Self
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let Range { start, end } = resolve_range(range, len);
+		let count = end - start;
+		let mut out = Self::with_capacity(count);
+		out.set_elements(T::bits(false));
+		unsafe {
+			out.set_len(count);
+			let data = self.as_mut_slice().as_mut_ptr();
+			let dest = out.as_mut_slice().as_mut_ptr();
+			move_bits::<C, T>(data, start, dest, 0, count);
+			move_bits::<C, T>(data, end, data, start, len - end);
+			self.set_len(len - count);
+		}
+		self.tidy_dead_bits();
+		out.tidy_dead_bits();
+		out
+	}
+
 	/// Resizes the `VecBit` in place so that `len` is equal to `new_len`.
 	///
 	/// If `new_len` is greater than `len`, then  the vector is extended by the
@@ -1416,7 +2341,9 @@ where C: Cursor, T: BitStore {
 	/// ignore the `VecBit`’s cursors.
 	///
 	/// This has the unobservable effect of setting the allocated, but dead,
-	/// bits beyond the end of the vector’s *length*, up to its *capacity*.
+	/// bits beyond the end of the vector’s *length*, up to its *capacity*. When
+	/// the `deadbits` feature is enabled, the dead bits in the last live
+	/// element are cleared again afterwards.
 	///
 	/// # Parameters
 	///
@@ -1429,7 +2356,7 @@ where C: Cursor, T: BitStore {
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut bv = vecbit![Local, u8; 0; 10];
+	/// let mut bv = vecbit![Local, u8; 0; 16];
 	/// assert_eq!(bv.as_slice(), &[0, 0]);
 	/// bv.set_elements(0xA5);
 	/// assert_eq!(bv.as_slice(), &[0xA5, 0xA5]);
@@ -1440,84 +2367,200 @@ where C: Cursor, T: BitStore {
 			for elt in unsafe { slice::from_raw_parts_mut(ptr, cap) } {
 				*elt = element;
 			}
-		})
+		});
+		self.tidy_dead_bits();
 	}
 
-	/// Performs “reverse” addition (left to right instead of right to left).
-	///
-	/// This addition traverses the addends from left to right, performing
-	/// the addition at each index and writing the sum into `self`.
-	///
-	/// If `addend` expires before `self` does, `addend` is zero-extended and
-	/// the carry propagates through the rest of `self`. If `self` expires
-	/// before `addend`, then `self` is zero-extended and the carry propagates
-	/// through the rest of `addend`, growing `self` until `addend` expires.
+	/// Sets each bit in the vector that is set in another slice (`A ∪ B`).
 	///
-	/// An infinite `addend` will cause unbounded memory growth until the vector
-	/// overflows and panics.
+	/// The two sequences are treated as sets of indices. Unlike the `|=`
+	/// operator, which truncates to the shorter sequence, this grows `self` to
+	/// the longer of the two lengths, filling the new bits from `other`.
 	///
 	/// # Parameters
 	///
-	/// - `self`
-	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits to add into
-	///   `self`, from left to right.
-	///
-	/// # Returns
-	///
-	/// The sum vector of `self` and `addend`.
+	/// - `&mut self`
+	/// - `other`: A `SliceBit` of any order and storage type.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let a = vecbit![0, 1, 0, 1];
-	/// let b = vecbit![0, 0, 1, 1];
-	/// let c = a.add_reverse(b);
-	/// assert_eq!(c, vecbit![0, 1, 1, 0, 1]);
+	/// let mut bv = vecbit![0, 1, 0, 1];
+	/// bv.union_in_place(&vecbit![0, 0, 1, 1, 0, 1]);
+	/// assert_eq!(bv, vecbit![0, 1, 1, 1, 0, 1]);
 	/// ```
-	pub fn add_reverse<I>(mut self, addend: I) -> Self
-	where I: IntoIterator<Item=bool> {
-		self.add_assign_reverse(addend);
-		self
+	pub fn union_in_place<D, U>(&mut self, other: &SliceBit<D, U>)
+	where D: Cursor, U: BitStore {
+		let len = other.len();
+		if len > self.len() {
+			self.resize(len, false);
+		}
+		self[.. len] |= other.iter();
 	}
 
-	/// Performs “reverse” addition (left to right instead of right to left).
-	///
-	/// This addition traverses the addends from left to right, performing
-	/// the addition at each index and writing the sum into `self`.
-	///
-	/// If `addend` expires before `self` does, `addend` is zero-extended and
-	/// the carry propagates through the rest of `self`. If `self` expires
-	/// before `addend`, then `self` is zero-extended and the carry propagates
-	/// through the rest of `addend`, growing `self` until `addend` expires.
+	/// Clears each bit in the vector that is not set in another slice
+	/// (`A ∩ B`).
 	///
-	/// An infinite `addend` will cause unbounded memory growth until the vector
-	/// overflows and panics.
+	/// The two sequences are treated as sets of indices. Unlike the `&=`
+	/// operator, which truncates to the shorter sequence, this keeps the length
+	/// of `self`. Bits beyond the end of `other` are not in it, and are
+	/// cleared.
 	///
 	/// # Parameters
 	///
 	/// - `&mut self`
-	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits to add into
-	///   `self`, from left to right.
-	///
-	/// # Effects
-	///
-	/// `self` may grow as a result of the final carry-out bit being `1` and
-	/// pushed onto the right end.
+	/// - `other`: A `SliceBit` of any order and storage type.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use vecbit::prelude::*;
 	///
-	/// let mut a = vecbit![0, 1, 0, 1];
-	/// let     b = vecbit![0, 0, 1, 1];
-	/// a.add_assign_reverse(&b);
-	/// assert_eq!(a, vecbit![0, 1, 1, 0, 1]);
+	/// let mut bv = vecbit![0, 1, 1, 1, 0, 1];
+	/// bv.intersect_in_place(&vecbit![0, 0, 1, 1]);
+	/// assert_eq!(bv, vecbit![0, 0, 1, 1, 0, 0]);
 	/// ```
-	pub fn add_assign_reverse<I>(&mut self, addend: I)
-	where I: IntoIterator<Item=bool> {
+	pub fn intersect_in_place<D, U>(&mut self, other: &SliceBit<D, U>)
+	where D: Cursor, U: BitStore {
+		*self.as_mut_bitslice() &= other.iter();
+	}
+
+	/// Clears each bit in the vector that is set in another slice (`A \ B`).
+	///
+	/// The two sequences are treated as sets of indices. This keeps the length
+	/// of `self`. Bits beyond the end of `other` are not in it, and are left
+	/// unchanged.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: A `SliceBit` of any order and storage type.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![0, 1, 1, 1, 0, 1];
+	/// bv.difference_in_place(&vecbit![0, 0, 1, 1]);
+	/// assert_eq!(bv, vecbit![0, 1, 0, 0, 0, 1]);
+	/// ```
+	pub fn difference_in_place<D, U>(&mut self, other: &SliceBit<D, U>)
+	where D: Cursor, U: BitStore {
+		use core::cmp::min;
+		let len = min(self.len(), other.len());
+		self[.. len] &= other[.. len].iter().map(|bit| !bit);
+	}
+
+	/// Inverts each bit in the vector that is set in another slice (`A ∆ B`).
+	///
+	/// The two sequences are treated as sets of indices. Unlike the `^=`
+	/// operator, which truncates to the shorter sequence, this grows `self` to
+	/// the longer of the two lengths, filling the new bits from `other`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: A `SliceBit` of any order and storage type.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![0, 1, 0, 1];
+	/// bv.symmetric_difference_in_place(&vecbit![0, 0, 1, 1, 0, 1]);
+	/// assert_eq!(bv, vecbit![0, 1, 1, 0, 0, 1]);
+	/// ```
+	pub fn symmetric_difference_in_place<D, U>(
+		&mut self,
+		other: &SliceBit<D, U>,
+	)
+	where D: Cursor, U: BitStore {
+		let len = other.len();
+		if len > self.len() {
+			self.resize(len, false);
+		}
+		self[.. len] ^= other.iter();
+	}
+
+	/// Performs “reverse” addition (left to right instead of right to left).
+	///
+	/// This addition traverses the addends from left to right, performing
+	/// the addition at each index and writing the sum into `self`.
+	///
+	/// If `addend` expires before `self` does, `addend` is zero-extended and
+	/// the carry propagates through the rest of `self`. If `self` expires
+	/// before `addend`, then `self` is zero-extended and the carry propagates
+	/// through the rest of `addend`, growing `self` until `addend` expires.
+	///
+	/// An infinite `addend` will cause unbounded memory growth until the vector
+	/// overflows and panics.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits to add into
+	///   `self`, from left to right.
+	///
+	/// # Returns
+	///
+	/// The sum vector of `self` and `addend`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let a = vecbit![0, 1, 0, 1];
+	/// let b = vecbit![0, 0, 1, 1];
+	/// let c = a.add_reverse(b);
+	/// assert_eq!(c, vecbit![0, 1, 1, 0, 1]);
+	/// ```
+	pub fn add_reverse<I>(mut self, addend: I) -> Self
+	where I: IntoIterator<Item=bool> {
+		self.add_assign_reverse(addend);
+		self
+	}
+
+	/// Performs “reverse” addition (left to right instead of right to left).
+	///
+	/// This addition traverses the addends from left to right, performing
+	/// the addition at each index and writing the sum into `self`.
+	///
+	/// If `addend` expires before `self` does, `addend` is zero-extended and
+	/// the carry propagates through the rest of `self`. If `self` expires
+	/// before `addend`, then `self` is zero-extended and the carry propagates
+	/// through the rest of `addend`, growing `self` until `addend` expires.
+	///
+	/// An infinite `addend` will cause unbounded memory growth until the vector
+	/// overflows and panics.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `addend: impl IntoIterator<Item=bool>`: A stream of bits to add into
+	///   `self`, from left to right.
+	///
+	/// # Effects
+	///
+	/// `self` may grow as a result of the final carry-out bit being `1` and
+	/// pushed onto the right end.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut a = vecbit![0, 1, 0, 1];
+	/// let     b = vecbit![0, 0, 1, 1];
+	/// a.add_assign_reverse(&b);
+	/// assert_eq!(a, vecbit![0, 1, 1, 0, 1]);
+	/// ```
+	pub fn add_assign_reverse<I>(&mut self, addend: I)
+	where I: IntoIterator<Item=bool> {
 		//  Set up iteration over the addend
 		let mut addend = addend.into_iter().fuse();
 		//  Delegate to the `SliceBit` implementation for the initial addition.
@@ -1559,6 +2602,10 @@ where C: Cursor, T: BitStore {
 
 	/// Degrades a `VecBit` to a `BitBox`, freezing its size.
 	///
+	/// This only reallocates if the vector has spare capacity. A vector made
+	/// from a `BitBox` has none, and so the round trip from `BitBox` to
+	/// `VecBit` and back never reallocates.
+	///
 	/// # Parameters
 	///
 	/// - `self`
@@ -1566,10 +2613,27 @@ where C: Cursor, T: BitStore {
 	/// # Returns
 	///
 	/// Itself, with its size frozen and ungrowable.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bb = bitbox![1, 0, 1];
+	/// let addr = bb.as_slice().as_ptr();
+	/// let bb = VecBit::from(bb).into_boxed_bitslice();
+	/// assert_eq!(bb.as_slice().as_ptr(), addr);
+	/// assert_eq!(bb, bitbox![1, 0, 1]);
+	/// ```
 	pub fn into_boxed_bitslice(self) -> BitBox<C, T> {
-		let pointer = self.pointer;
-		//  Convert the Vec allocation into a Box<[T]> allocation
-		mem::forget(self.into_boxed_slice());
+		let len = self.len();
+		//  Convert the Vec allocation into a Box<[T]> allocation. This may
+		//  move the allocation, so the pointer must be taken from the box.
+		let boxed = self.into_boxed_slice();
+		let pointer = unsafe {
+			BitPtr::new_unchecked(boxed.as_ptr(), 0u8.idx(), len)
+		};
+		mem::forget(boxed);
 		unsafe { BitBox::from_raw(pointer) }
 	}
 
@@ -1596,6 +2660,7 @@ where C: Cursor, T: BitStore {
 	///
 	/// The plain vector underlying the `VecBit`.
 	pub fn into_vec(self) -> Vec<T> {
+		self.check_dead_bits();
 		let slice = self.pointer.as_mut_slice();
 		let out = unsafe {
 			Vec::from_raw_parts(slice.as_mut_ptr(), slice.len(), self.capacity)
@@ -1604,6 +2669,41 @@ where C: Cursor, T: BitStore {
 		out
 	}
 
+	/// Consumes the vector, yielding its storage elements by value.
+	///
+	/// The dead bits after the end of the vector are cleared first, so the
+	/// elements are in the canonical form that [`canonicalize`] produces. The
+	/// allocation is taken over as-is, without copying, so this suits
+	/// serializers that stream the storage out.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the elements the `VecBit` had owned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![BigEndian, u8; 1; 12];
+	/// bv.truncate(10);
+	/// let elts = bv.into_elements().collect::<Vec<_>>();
+	/// assert_eq!(elts, [0xFF, 0b1100_0000]);
+	/// ```
+	///
+	/// [`canonicalize`]: #method.canonicalize
+	pub fn into_elements(mut self) -> IntoElements<C, T> {
+		self.mask_dead_bits();
+		IntoElements {
+			inner: self.into_vec().into_iter(),
+			_cursor: PhantomData,
+		}
+	}
+
 	/// Gets the raw `BitPtr` powering the vector.
 	///
 	/// # Parameters
@@ -1665,6 +2765,51 @@ where C: Cursor, T: BitStore {
 		out
 	}
 
+	/// Ensures space for some more elements, choosing the new capacity by a
+	/// growth policy.
+	fn grow_elements(&mut self, additional: usize, growth: Growth) {
+		use core::cmp::max;
+		let needed = self.pointer.elements() + additional;
+		if needed <= self.capacity {
+			return;
+		}
+		self.do_unto_vec(|v| match growth {
+			Growth::Doubling => v.reserve(additional),
+			Growth::Exact => v.reserve_exact(additional),
+			Growth::Custom(func) => {
+				let cap = max(func(v.capacity(), needed), needed);
+				v.reserve_exact(cap - v.len());
+			},
+		});
+	}
+
+	/// Clears the dead bits after an operation that removed bits, if the
+	/// `deadbits` feature asks for them to be kept clear.
+	#[inline]
+	fn tidy_dead_bits(&mut self) {
+		if cfg!(feature = "deadbits") {
+			self.mask_dead_bits();
+		}
+	}
+
+	/// Checks that the dead bits are clear, in debug builds with the
+	/// `deadbits` feature.
+	#[inline]
+	fn check_dead_bits(&self) {
+		if cfg!(all(debug_assertions, feature = "deadbits")) {
+			let tail = *self.pointer.tail();
+			if let Some(last) = self.bitptr().as_slice().last() {
+				for n in tail .. T::BITS {
+					assert!(
+						!last.get::<C>(n.idx()),
+						"Dead bit {} of the last element is set",
+						n,
+					);
+				}
+			}
+		}
+	}
+
 	/// Permits a function to view the `Vec<T>` underneath a `VecBit<_, T>`.
 	///
 	/// This produces a `Vec<T>` structure referring to the same data region as
@@ -1701,6 +2846,77 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/** Copies a run of bits from one element buffer into another.
+
+For the `BigEndian` and `LittleEndian` cursors, once the destination reaches an
+element boundary, each destination element is assembled from at most two source
+elements with a pair of shifts, rather than bit by bit.
+
+# Parameters
+
+- `src`: The base of the source buffer.
+- `from`: The bit index in `src` of the first bit to copy.
+- `dst`: The base of the destination buffer.
+- `to`: The bit index in `dst` at which to write the first bit.
+- `count`: The number of bits to copy.
+
+# Safety
+
+Both bit ranges must lie within allocated, uniquely owned elements. The two
+buffers must either be disjoint, or have the same base address; in the latter
+case, the copy runs back to front when `to` is after `from`, so the ranges may
+overlap in either direction.
+**/
+unsafe fn move_bits<C, T>(
+	src: *const T,
+	from: usize,
+	dst: *mut T,
+	to: usize,
+	count: usize,
+)
+where C: Cursor, T: BitStore {
+	use core::cmp::min;
+	let backward = src == dst as *const T && to > from;
+	let copy = |n: usize| {
+		let (s, d) = (from + n, to + n);
+		let bit = (*src.add(s >> T::INDX))
+			.get::<C>(((s & T::MASK as usize) as u8).idx());
+		(*dst.add(d >> T::INDX))
+			.set::<C>(((d & T::MASK as usize) as u8).idx(), bit);
+	};
+	let stride = match Stride::of::<C, T>() {
+		Some(stride) => stride,
+		None if backward => return (0 .. count).rev().for_each(copy),
+		None => return (0 .. count).for_each(copy),
+	};
+	//  Copy bitwise until the destination is aligned to an element.
+	let mask = T::MASK as usize;
+	let lead = min(count, (T::BITS as usize - (to & mask)) & mask);
+	let full = (count - lead) >> T::INDX;
+	let (s, d) = (from + lead, (to + lead) >> T::INDX);
+	let (elt, shift) = (s >> T::INDX, (s & mask) as u8);
+	let whole = |n: usize| {
+		let lo = *src.add(elt + n);
+		*dst.add(d + n) = match shift {
+			0 => lo,
+			//  The source element after `lo` holds the rest of the bits.
+			sh => stride.to_front(lo, sh)
+				| stride.to_back(*src.add(elt + n + 1), T::BITS - sh),
+		};
+	};
+	let rest = lead + (full << T::INDX) .. count;
+	if backward {
+		rest.rev().for_each(copy);
+		(0 .. full).rev().for_each(whole);
+		(0 .. lead).rev().for_each(copy);
+	}
+	else {
+		(0 .. lead).for_each(copy);
+		(0 .. full).for_each(whole);
+		rest.for_each(copy);
+	}
+}
+
 /// Signifies that `SliceBit` is the borrowed form of `VecBit`.
 impl<C, T> Borrow<SliceBit<C, T>> for VecBit<C, T>
 where C: Cursor, T: BitStore {
@@ -1967,13 +3183,14 @@ where C: Cursor, T: BitStore {
 
 /** Builds a `VecBit` out of a slice of `bool`.
 
-This is primarily for the `vecbit!` macro; it is not recommended for general
-use.
+This packs the bits one whole element at a time, as [`VecBit::from_bools`] does.
+
+[`VecBit::from_bools`]: struct.VecBit.html#method.from_bools
 **/
 impl<C, T> From<&[bool]> for VecBit<C, T>
 where C: Cursor, T: BitStore {
 	fn from(src: &[bool]) -> Self {
-		src.iter().cloned().collect()
+		Self::from_bools(src)
 	}
 }
 
@@ -1994,7 +3211,7 @@ where C: Cursor, T: BitStore {
 impl<C, T> From<Box<[T]>> for VecBit<C, T>
 where C: Cursor, T: BitStore {
 	fn from(src: Box<[T]>) -> Self {
-		Self::from_boxed_bitslice(BitBox::from_boxed_slice(src))
+		Self::from_boxed_slice(src)
 	}
 }
 
@@ -2129,7 +3346,10 @@ where C: Cursor, T: BitStore {
 
 At present, this just calls `.push()` in a loop. When specialization becomes
 available, it will be able to more intelligently perform bulk moves from the
-source into `self` when the source is `SliceBit`-compatible.
+source into `self` when the source is `SliceBit`-compatible. Until then,
+[`VecBit::extend_from_bitslice`] performs those moves explicitly.
+
+[`VecBit::extend_from_bitslice`]: struct.VecBit.html#method.extend_from_bitslice
 **/
 impl<C, T> Extend<bool> for VecBit<C, T>
 where C: Cursor, T: BitStore {
@@ -2156,10 +3376,10 @@ where C: Cursor, T: BitStore {
 	/// ```
 	fn extend<I: IntoIterator<Item=bool>>(&mut self, src: I) {
 		let iter = src.into_iter();
-		match iter.size_hint() {
-			(_, Some(hi)) => self.reserve(hi),
-			(lo, None) => self.reserve(lo),
-		}
+		//  Only the lower bound is certain. The upper bound of an adapter such
+		//  as `filter` may be far larger than what it yields, and `push` grows
+		//  the allocation as needed.
+		self.reserve(iter.size_hint().0);
 		iter.for_each(|b| self.push(b));
 	}
 }
@@ -2181,21 +3401,43 @@ where C: Cursor, T: BitStore {
 	///   .chain(repeat(false).take(4))
 	///   .collect();
 	/// assert_eq!(bv.as_slice()[0], 0xF0);
+	///
+	/// let none: VecBit = repeat(true).take(1000).filter(|_| false).collect();
+	/// assert_eq!(none.capacity(), 0);
 	/// ```
 	fn from_iter<I: IntoIterator<Item=bool>>(src: I) -> Self {
-		let iter = src.into_iter();
-		let mut bv = match iter.size_hint() {
-			| (_, Some(len))
-			| (len, _)
-			=> Self::with_capacity(len),
-		};
-		for bit in iter {
-			bv.push(bit);
-		}
+		let mut bv = Self::new();
+		bv.extend(src);
 		bv
 	}
 }
 
+/// Permits the construction of a `VecBit` by using `.collect()` on an iterator
+/// of storage elements.
+impl<C, T> FromIterator<T> for VecBit<C, T>
+where C: Cursor, T: BitStore {
+	/// Collects an iterator of elements into a vector.
+	///
+	/// Each element supplies `T::BITS` bits. The elements are collected into an
+	/// ordinary `Vec<T>`, whose buffer the `VecBit` then takes over without
+	/// copying.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bv: VecBit<BigEndian, u8> = (0 .. 4u8)
+	///   .map(|n| 1 << n)
+	///   .collect();
+	/// assert_eq!(bv.len(), 32);
+	/// assert_eq!(bv.as_slice(), &[1, 2, 4, 8]);
+	/// ```
+	fn from_iter<I: IntoIterator<Item=T>>(src: I) -> Self {
+		Self::from_vec(src.into_iter().collect())
+	}
+}
+
 /** Produces an iterator over all the bits in the vector.
 
 This iterator follows the ordering in the vector type, and implements
@@ -2381,6 +3623,10 @@ where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
 /** Performs the Boolean `AND` operation in place on a `VecBit`, using a stream
 of `bool` values as the other bit for each operation. If the other stream is
 shorter than `self`, `self` will be truncated when the other stream expires.
+To treat the vectors as sets of indices, without truncation, use
+[`VecBit::intersect_in_place`].
+
+[`VecBit::intersect_in_place`]: struct.VecBit.html#method.intersect_in_place
 **/
 impl<C, T, I> BitAndAssign<I> for VecBit<C, T>
 where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
@@ -2442,6 +3688,10 @@ where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
 /** Performs the Boolean `OR` operation in place on a `VecBit`, using a stream
 of `bool` values as the other bit for each operation. If the other stream is
 shorter than `self`, `self` will be truncated when the other stream expires.
+To treat the vectors as sets of indices, without truncation, use
+[`VecBit::union_in_place`].
+
+[`VecBit::union_in_place`]: struct.VecBit.html#method.union_in_place
 **/
 impl<C, T, I> BitOrAssign<I> for VecBit<C, T>
 where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
@@ -2503,6 +3753,10 @@ where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
 /** Performs the Boolean `XOR` operation in place on a `VecBit`, using a stream
 of `bool` values as the other bit for each operation. If the other stream is
 shorter than `self`, `self` will be truncated when the other stream expires.
+To treat the vectors as sets of indices, without truncation, use
+[`VecBit::symmetric_difference_in_place`].
+
+[`VecBit::symmetric_difference_in_place`]: struct.VecBit.html#method.symmetric_difference_in_place
 **/
 impl<C, T, I> BitXorAssign<I> for VecBit<C, T>
 where C: Cursor, T: BitStore, I: IntoIterator<Item=bool> {
@@ -3164,6 +4418,17 @@ where C: Cursor, T: BitStore {
 
 /** State keeper for draining iteration.
 
+The vector is truncated to the start of the drained range as soon as the
+`Drain` is created. When the `Drain` is dropped, the bits after the drained
+range are copied down to close the gap, whole elements at a time where the
+cursor permits.
+
+# Leaking
+
+If a `Drain` is leaked, its destructor never runs, and the vector is left
+truncated to the start of the drained range. This matches `Vec::drain`, and
+never exposes a vector holding bits in an inconsistent state.
+
 # Type Parameters
 
 - `C: Cursor`: The cursor type of the underlying vector.
@@ -3189,9 +4454,9 @@ impl<'a, C, T> Drain<'a, C, T>
 where C: Cursor, T: 'a + BitStore {
 	/// Fills the drain span with another iterator.
 	///
-	/// If the stream exhausts before the drain is filled, then the tail
-	/// elements move downwards; otherwise, the tail stays put and the drain is
-	/// filled.
+	/// If the stream exhausts before the drain is filled, then the vector ends
+	/// at the last bit written, and `Drop` moves the tail down to meet it;
+	/// otherwise, the tail stays put and the drain is filled.
 	///
 	/// # Parameters
 	///
@@ -3201,7 +4466,8 @@ where C: Cursor, T: 'a + BitStore {
 	/// # Returns
 	///
 	/// - `true` if the drain was filled before the `stream` exhausted.
-	/// - `false` if the `stream` exhausted early, and the tail was moved down.
+	/// - `false` if the `stream` exhausted early, and the tail must be moved
+	///   down.
 	///
 	/// # Type Parameters
 	///
@@ -3211,15 +4477,15 @@ where C: Cursor, T: 'a + BitStore {
 		let drain_from = bv.len();
 		let drain_upto = self.tail_start;
 
+		//  The drained span is already allocated, and may share elements with
+		//  the tail, so it is written in place rather than `push`ed.
 		for n in drain_from .. drain_upto {
 			if let Some(bit) = stream.next() {
-				bv.push(bit);
+				bv.set_len(n + 1);
+				bv.set(n, bit);
 			}
 			else {
-				for (to, from) in (n .. n + self.tail_len).zip(drain_upto ..) {
-					bv.swap(from, to);
-				}
-				self.tail_start = n;
+				//  `Drain::drop` closes the gap between `n` and the tail.
 				return false;
 			}
 		}
@@ -3234,15 +4500,17 @@ where C: Cursor, T: 'a + BitStore {
 	/// - `by`: The amount by which to move the tail span.
 	unsafe fn move_tail(&mut self, by: usize) {
 		let bv = self.vecbit.as_mut();
-		bv.reserve(by);
 		let new_tail = self.tail_start + by;
 		let old_len = bv.len();
 		let new_len = self.tail_start + self.tail_len + by;
 
+		//  The tail lies beyond the vector’s length, so the length must cover
+		//  it while reserving, or a reallocation would not carry it along.
+		bv.set_len(self.tail_start + self.tail_len);
+		bv.reserve(by);
 		bv.set_len(new_len);
-		for n in (0 .. self.tail_len).rev() {
-			bv.swap(self.tail_start + n, new_tail + n);
-		}
+		let data = bv.as_mut_slice().as_mut_ptr();
+		move_bits::<C, T>(data, self.tail_start, data, new_tail, self.tail_len);
 		bv.set_len(old_len);
 
 		self.tail_start = new_tail;
@@ -3296,18 +4564,14 @@ where C: Cursor, T: 'a + BitStore {
 		//  Get the start of the remnant span.
 		let tail = self.tail_start;
 		let tail_len = self.tail_len;
-		//  Get the full length of the vector,
-		let full_len = tail + tail_len;
-		//  And the length of the vector after the drain.
-		let end_len = start + tail_len;
 		//  Inflate the vector to include the remnant span,
-		bv.set_len(full_len);
-		//  Swap the remnant span down into the drained span,
-		for (from, to) in (tail .. full_len).zip(start .. end_len) {
-			bv.swap(from, to);
-		}
+		bv.set_len(tail + tail_len);
+		//  Copy the remnant span down into the drained span,
+		let data = bv.as_mut_slice().as_mut_ptr();
+		move_bits::<C, T>(data, tail, data, start, tail_len);
 		//  And deflate the vector to fit.
-		bv.set_len(end_len);
+		bv.set_len(start + tail_len);
+		bv.tidy_dead_bits();
 	} }
 }
 
@@ -3425,12 +4689,16 @@ where C: Cursor, T: BitStore {
 	/// ```rust
 	/// use vecbit::prelude::*;
 	/// let bv = vecbit![BigEndian, u8; 0, 1, 0, 1, 0];
-	/// assert_eq!(bv.into_iter().count(), 5);
+	/// assert_eq!(bv.clone().into_iter().count(), 5);
+	///
+	/// let mut iter = bv.into_iter();
+	/// iter.next();
+	/// assert_eq!(iter.count(), 4);
 	/// ```
 	///
 	/// [`SliceBit`]: ../struct.SliceBit.html#method.iter
 	fn count(self) -> usize {
-		self.vecbit.len()
+		self.len()
 	}
 
 	/// Advances the iterator by `n` bits, starting from zero.
@@ -3489,18 +4757,118 @@ where C: Cursor, T: BitStore {
 	}
 }
 
+/** A consuming iterator over the storage elements of a `VecBit`.
+
+This is produced by [`VecBit::into_elements`]. The dead bits of the last element
+are cleared before it is yielded.
+
+# Type Parameters
+
+- `C: Cursor`: The bit-order type of the consumed `VecBit`.
+- `T: BitStore`: The storage type of the consumed `VecBit`.
+
+[`VecBit::into_elements`]: struct.VecBit.html#method.into_elements
+**/
+pub struct IntoElements<C, T>
+where C: Cursor, T: BitStore {
+	/// The elements not yet yielded.
+	inner: alloc::vec::IntoIter<T>,
+	_cursor: PhantomData<C>,
+}
+
+impl<C, T> DoubleEndedIterator for IntoElements<C, T>
+where C: Cursor, T: BitStore {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back()
+	}
+}
+
+/// Mark that the iterator has an exact size.
+impl<C, T> ExactSizeIterator for IntoElements<C, T>
+where C: Cursor, T: BitStore {}
+
+/// Mark that the iterator will not resume after halting.
+impl<C, T> FusedIterator for IntoElements<C, T>
+where C: Cursor, T: BitStore {}
+
+impl<C, T> Iterator for IntoElements<C, T>
+where C: Cursor, T: BitStore {
+	type Item = T;
+
+	/// Advances the iterator by one, returning the next element (if any).
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// The leading element in the iterator, if any.
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next()
+	}
+
+	/// Hints at the number of elements remaining in the iterator.
+	///
+	/// Because the exact size is always known, this always produces
+	/// `(len, Some(len))`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// - `usize`: The minimum elements remaining.
+	/// - `Option<usize>`: The maximum elements remaining.
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+
+	/// Advances the iterator by `n` elements, starting from zero.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The number of elements to skip, before producing the next
+	///   element after the skips.
+	///
+	/// # Returns
+	///
+	/// The `n`th element from the front of the iterator, if any.
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.inner.nth(n)
+	}
+}
+
 /** A splicing iterator for `VecBit`.
 
 This removes a segment from the vector and inserts another bitstream into its
 spot. Any bits from the original `VecBit` after the removed segment are kept,
 after the inserted bitstream.
 
-Only the removed segment is available for iteration.
+Only the removed segment is available for iteration: the `size_hint` and `len`
+of a `Splice` count the removed bits not yet yielded, and never include the
+replacement bitstream.
+
+Each bit pulled from the front of the splice allows one replacement bit to be
+written into the vector immediately. The rest of the replacement is inserted
+when the splice is dropped, or can be recovered without insertion through
+[`into_remaining`].
+
+# Leaking
+
+If a `Splice` is leaked (for example, with `mem::forget`), the vector is left
+holding only the bits before the removed segment, followed by any replacement
+bits already inserted. The removed segment, the rest of the replacement, and
+the bits after the removed segment are all lost, but the vector remains valid.
 
 # Type Parameters
 
 - `I: Iterator<Item=bool>`: Any bitstream. This will be used to fill the
   removed span.
+
+[`into_remaining`]: #method.into_remaining
 **/
 pub struct Splice<'a, C, T, I>
 where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
@@ -3508,6 +4876,46 @@ where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
 	splice: I,
 }
 
+impl<'a, C, T, I> Splice<'a, C, T, I>
+where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
+	/// Ends the splice without inserting the rest of the replacement.
+	///
+	/// Any replacement bits already written by iterating the splice are kept,
+	/// and the bits after the removed segment are moved down to follow them.
+	/// The replacement bitstream is returned as-is, so none of its remaining
+	/// bits are lost.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// The replacement bitstream, holding every bit that was not inserted.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let mut bv = vecbit![0; 6];
+	/// let mut splice = bv.splice(2 .. 4, vecbit![1; 4]);
+	/// assert_eq!(splice.next(), Some(false));
+	/// let rest = splice.into_remaining();
+	/// assert_eq!(rest.count(), 3);
+	/// assert_eq!(bv, vecbit![0, 0, 1, 0, 0]);
+	/// ```
+	pub fn into_remaining(self) -> I {
+		let this = mem::ManuallyDrop::new(self);
+		let (drain, splice) = unsafe {
+			(ptr::read(&this.drain), ptr::read(&this.splice))
+		};
+		//  Dropping the drain closes the gap after the inserted bits.
+		mem::drop(drain);
+		splice
+	}
+}
+
 impl<'a, C, T, I> DoubleEndedIterator for Splice<'a, C, T, I>
 where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
 	fn next_back(&mut self) -> Option<Self::Item> {
@@ -3528,13 +4936,18 @@ where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
 
 	fn next(&mut self) -> Option<Self::Item> {
 		//  If the drain produced a bit, then try to pull a bit from the
-		//  replacement. If the replacement produced a bit, push it into the
-		//  `VecBit` that the drain is managing. This works because the `Drain`
-		//  type truncates the `VecBit` to the front of the region being
-		//  drained, then tracks the remainder of the memory.
+		//  replacement. If the replacement produced a bit, write it just past
+		//  the end of the `VecBit` that the drain is managing. This works
+		//  because the `Drain` type truncates the `VecBit` to the front of the
+		//  region being drained, then tracks the remainder of the memory. The
+		//  bit is not `push`ed, as that would clear the rest of a fresh
+		//  element, which may still hold undrained bits.
 		self.drain.next().map(|bit| {
 			if let Some(new_bit) = self.splice.next() {
-				unsafe { self.drain.vecbit.as_mut() }.push(new_bit);
+				let bv = unsafe { self.drain.vecbit.as_mut() };
+				let len = bv.len();
+				unsafe { bv.set_len(len + 1); }
+				bv.set(len, new_bit);
 			}
 			bit
 		})
@@ -3567,7 +4980,7 @@ where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
 
 		//  Fill the drained span from the splice. If this exhausts the splice,
 		//  exit. Note that `Drain::fill` runs from the current `VecBit.len`
-		//  value, so the fact that `Splice::next` attempts to push onto the
+		//  value, so the fact that `Splice::next` writes onto the end of the
 		//  vector is not a problem here.
 		if !self.drain.fill(&mut self.splice) {
 			return;
@@ -3592,3 +5005,468 @@ where C: Cursor, T: 'a + BitStore, I: Iterator<Item=bool> {
 		//  Drain::drop does the rest
 	} }
 }
+
+/** Incremental constructor for `VecBit`, for many small writes.
+
+Serializers often emit a stream of single bits and short fields, interleaved
+with runs of whole bytes and padding. Pushing each of these onto a `VecBit`
+writes to its memory once per bit. The builder instead collects bits into a
+staging element, and only writes memory when that element is full, so the
+buffer receives exactly one write per element.
+
+The bits are placed in the finished vector in the order that they are pushed,
+under the cursor `C`.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the produced vector.
+- `T: BitStore`: The storage type of the produced vector.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::vec::VecBitBuilder;
+
+let mut builder = VecBitBuilder::<BigEndian, u8>::new();
+builder.push_bit(true);
+builder.push_bits(0b101, 3);
+builder.align_to(8, false);
+builder.push_bytes(&[0xA5]);
+let bv = builder.build();
+assert_eq!(bv.as_slice(), &[0b1101_0000, 0xA5]);
+```
+**/
+#[derive(Clone, Debug)]
+pub struct VecBitBuilder<C = Local, T = Word>
+where C: Cursor, T: BitStore {
+	/// Every element that has been filled.
+	done: VecBit<C, T>,
+	/// The element currently being filled.
+	part: T,
+	/// The number of bits written into `part`.
+	fill: u8,
+}
+
+impl<C, T> VecBitBuilder<C, T>
+where C: Cursor, T: BitStore {
+	/// Constructs an empty builder.
+	///
+	/// # Returns
+	///
+	/// A builder with no bits, which has not allocated.
+	pub fn new() -> Self {
+		Self::with_capacity(0)
+	}
+
+	/// Constructs an empty builder with space for some bits.
+	///
+	/// # Parameters
+	///
+	/// - `capacity`: The number of bits that the builder can hold before it
+	///   reallocates.
+	///
+	/// # Returns
+	///
+	/// A builder with no bits.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			done: VecBit::with_capacity(capacity),
+			part: T::bits(false),
+			fill: 0,
+		}
+	}
+
+	/// Counts the bits pushed into the builder.
+	///
+	/// # Returns
+	///
+	/// The length of the vector that the builder would produce.
+	pub fn len(&self) -> usize {
+		self.done.len() + self.fill as usize
+	}
+
+	/// Tests if the builder holds no bits.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Appends a single bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `bit`: The bit to append.
+	pub fn push_bit(&mut self, bit: bool) {
+		self.part.set::<C>(self.fill.idx(), bit);
+		self.fill += 1;
+		if self.fill == T::BITS {
+			self.done.extend_from_bitslice(SliceBit::from_element(&self.part));
+			self.part = T::bits(false);
+			self.fill = 0;
+		}
+	}
+
+	/// Appends the low bits of an integer, most significant first.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer holding the bits to append.
+	/// - `count`: The number of bits, counted from the least significant bit
+	///   of `value`, to append. The bit at `count - 1` is appended first, and
+	///   the least significant bit is appended last.
+	///
+	/// # Panics
+	///
+	/// Panics if `count` is greater than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::vec::VecBitBuilder;
+	///
+	/// let mut builder = VecBitBuilder::<LittleEndian, u16>::new();
+	/// builder.push_bits(0xFF_01, 12);
+	/// let bv = builder.build();
+	/// assert_eq!(bv, vecbit![1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1]);
+	/// ```
+	pub fn push_bits(&mut self, value: u64, count: u8) {
+		assert!(count <= 64, "Cannot push {} bits from a u64", count);
+		self.push_msb_first(&value.to_be_bytes(), 64 - count as usize);
+	}
+
+	/// Appends bytes, each with its most significant bit first.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `bytes`: The bytes to append.
+	pub fn push_bytes(&mut self, bytes: &[u8]) {
+		self.push_msb_first(bytes, 0);
+	}
+
+	/// Pads the builder until its length is a multiple of some number of bits.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `align`: The alignment, in bits, to reach.
+	/// - `fill`: The value of the padding bits.
+	///
+	/// # Panics
+	///
+	/// Panics if `align` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::vec::VecBitBuilder;
+	///
+	/// let mut builder = VecBitBuilder::<BigEndian, u8>::new();
+	/// builder.push_bits(0, 5);
+	/// builder.align_to(3, true);
+	/// assert_eq!(builder.len(), 6);
+	/// builder.align_to(3, true);
+	/// assert_eq!(builder.len(), 6);
+	/// ```
+	pub fn align_to(&mut self, align: usize, fill: bool) {
+		assert!(align != 0, "Cannot align to zero bits");
+		let over = self.len() % align;
+		if over != 0 {
+			for _ in over .. align {
+				self.push_bit(fill);
+			}
+		}
+	}
+
+	/// Appends the bits of some bytes, each with its most significant bit
+	/// first.
+	///
+	/// When `C` walks `T` contiguously, the bytes are packed into elements in
+	/// blocks, and each block is appended a whole element at a time. Other
+	/// cursors take the bits one at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `bytes`: The bytes holding the bits to append.
+	/// - `skip`: The number of bits at the front of `bytes` to leave out. This
+	///   must be less than the number of bits in a block.
+	fn push_msb_first(&mut self, bytes: &[u8], skip: usize) {
+		const BLOCK: usize = 64;
+		let stride = match Stride::of::<C, T>() {
+			Some(stride) => stride,
+			None => {
+				let bits = &bytes.bits::<BigEndian>()[skip ..];
+				bits.iter().for_each(|bit| self.push_bit(bit));
+				return;
+			},
+		};
+		self.flush();
+		let width = T::BITS as usize >> 3;
+		let mut skip = skip;
+		for block in bytes.chunks(BLOCK) {
+			let mut elts = [T::bits(false); BLOCK];
+			for (elt, chunk) in elts.iter_mut().zip(block.chunks(width)) {
+				for (n, &byte) in chunk.iter().enumerate() {
+					let n = n as u8 * 8;
+					*elt |= match stride {
+						Stride::Descending => T::from(byte) << (T::BITS - 8 - n),
+						Stride::Ascending => T::from(reverse(byte)) << n,
+					};
+				}
+			}
+			let bits = SliceBit::<C, T>::from_slice(&elts);
+			self.done.extend_from_bitslice(&bits[skip .. block.len() << 3]);
+			skip = 0;
+		}
+		//  Stage the bits of a partly filled last element again.
+		let len = self.done.len();
+		self.fill = (len & T::MASK as usize) as u8;
+		if self.fill != 0 {
+			self.part = self.done.as_slice()[len >> T::INDX];
+			self.done.truncate(len - self.fill as usize);
+		}
+	}
+
+	/// Moves the bits staged in the partly filled element into the vector of
+	/// filled elements.
+	fn flush(&mut self) {
+		if self.fill != 0 {
+			let part = SliceBit::<C, T>::from_element(&self.part);
+			self.done.extend_from_bitslice(&part[.. self.fill as usize]);
+			self.part = T::bits(false);
+			self.fill = 0;
+		}
+	}
+
+	/// Produces the vector of all bits pushed into the builder.
+	///
+	/// # Returns
+	///
+	/// A `VecBit` holding the builder’s bits, in the order they were pushed.
+	pub fn build(mut self) -> VecBit<C, T> {
+		self.flush();
+		self.done
+	}
+
+	/// Produces the boxed slice of all bits pushed into the builder.
+	///
+	/// # Returns
+	///
+	/// A `BitBox` holding the builder’s bits, in the order they were pushed.
+	pub fn build_boxed(self) -> BitBox<C, T> {
+		self.build().into_boxed_bitslice()
+	}
+}
+
+impl<C, T> Default for VecBitBuilder<C, T>
+where C: Cursor, T: BitStore {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/** The policy by which a `VecBit` chooses its new capacity when it must grow.
+
+A policy is given to [`VecBit::reserve_with`]. Every other operation that adds
+bits past the capacity grows by `Doubling`, except [`VecBit::reserve_exact`],
+which always reserves exactly.
+
+All capacities are measured in `T` elements. As with `Vec`, the allocator may
+still provide more than is requested.
+
+[`VecBit::reserve_exact`]: struct.VecBit.html#method.reserve_exact
+[`VecBit::reserve_with`]: struct.VecBit.html#method.reserve_with
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Growth {
+	/// Grow as `Vec` does, at least doubling the capacity, so that a sequence
+	/// of pushes takes amortized constant time.
+	Doubling,
+	/// Grow to exactly the capacity needed, so that the allocation size is
+	/// always predictable.
+	Exact,
+	/// Grow to the capacity computed by a function of the current capacity and
+	/// the needed capacity. A result below the needed capacity is raised to it.
+	Custom(fn(usize, usize) -> usize),
+}
+
+impl Default for Growth {
+	fn default() -> Self {
+		Growth::Doubling
+	}
+}
+
+/** The reasons a fallible `VecBit` constructor can fail.
+
+This is produced by [`VecBit::try_with_capacity`],
+[`VecBit::try_with_capacity_in_elements`], and [`VecBit::try_from_vec`].
+
+[`VecBit::try_from_vec`]: struct.VecBit.html#method.try_from_vec
+[`VecBit::try_with_capacity`]: struct.VecBit.html#method.try_with_capacity
+[`VecBit::try_with_capacity_in_elements`]: struct.VecBit.html#method.try_with_capacity_in_elements
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CapacityError {
+	/// The requested size exceeds what a `VecBit` can address.
+	Overflow,
+	/// The allocator could not provide the memory.
+	Alloc {
+		/// The number of elements requested from the allocator.
+		elements: usize,
+	},
+}
+
+impl Display for CapacityError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			CapacityError::Overflow => {
+				f.write_str("Capacity overflow: the size exceeds `BitPtr` limits")
+			},
+			CapacityError::Alloc { elements } => write!(
+				f,
+				"The allocator could not provide {} elements",
+				elements,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		cursor::{
+			BigEndian,
+			LittleEndian,
+			Permuted,
+		},
+		fixtures::{
+			each_subslice,
+			PairSwap,
+		},
+	};
+
+	#[test]
+	fn extend_from_bitslice() {
+		fn check<C, T>(src: &SliceBit<C, T>)
+		where C: Cursor, T: BitStore {
+			for init in 0 .. 2 * T::BITS as usize {
+				let dst = (0 .. init)
+					.map(|n| n % 3 == 0)
+					.collect::<VecBit<C, T>>();
+				each_subslice(src, 2 * T::BITS as usize, 1, |_, bits| {
+					let mut fast = dst.clone();
+					let mut slow = dst.clone();
+					fast.extend_from_bitslice(bits);
+					slow.extend(bits.iter());
+					assert_eq!(fast, slow);
+				});
+			}
+		}
+
+		let src = [0x5AC3u16, 0x0FF0, 0x9669, 0x1234, 0xFEDC];
+		check(src.bits::<BigEndian>());
+		check(src.bits::<LittleEndian>());
+		let bytes = [0x5Au8, 0xC3, 0x0F, 0xF0, 0x96, 0x69];
+		check(bytes.bits::<BigEndian>());
+		check(bytes.bits::<LittleEndian>());
+		check(bytes.bits::<Permuted<PairSwap>>());
+	}
+
+	#[test]
+	fn from_bitslice() {
+		let src = [0x5AC3u16, 0x0FF0, 0x9669];
+		let bits = &src.bits::<BigEndian>()[5 .. 40];
+		let bv = VecBit::from_bitslice(bits);
+		assert_eq!(bv, bits);
+		assert_eq!(bv.len(), 35);
+		assert_eq!(bv.as_slice()[2], 0b1100_0000_0000_0000);
+	}
+
+	#[test]
+	fn swap_remove_range() {
+		let data = [0x5AC3u16, 0x0FF0, 0x9669];
+		let bits = data.bits::<LittleEndian>();
+		let len = bits.len();
+		each_subslice(bits, len + 1, 1, |range, _| {
+			let (start, end) = (range.start, range.end);
+			let mut bv = VecBit::from_bitslice(bits);
+			bv.swap_remove_range(range);
+			let mut expected = VecBit::from_bitslice(bits);
+			let from = end.max(len - (end - start));
+			for (n, m) in (from .. len).enumerate() {
+				expected.set(start + n, bits[m]);
+			}
+			expected.truncate(len - (end - start));
+			assert_eq!(bv, expected);
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "Capacity overflow")]
+	fn reserve_elements_overflow() {
+		let mut bv = VecBit::<BigEndian, u8>::from_vec(vec![0; 2]);
+		bv.reserve_elements(!0 - 1);
+	}
+
+	#[test]
+	fn builder() {
+		fn check<C, T>()
+		where C: Cursor, T: BitStore {
+			let bytes = (0 .. 150u8)
+				.map(|n| n.wrapping_mul(37))
+				.collect::<Vec<_>>();
+			let value = 0xA5C3_0FF0_9669_1234u64;
+			for lead in 0 .. 2 * T::BITS as usize {
+				for count in 0 ..= 64 {
+					//  Sizes from none to more than is pushed, so that the
+					//  pushes write both into and past the first allocation.
+					let cap = lead * count as usize;
+					let mut builder = VecBitBuilder::<C, T>::with_capacity(cap);
+					let mut model = VecBit::<C, T>::new();
+					for n in 0 .. lead {
+						builder.push_bit(n % 3 == 0);
+						model.push(n % 3 == 0);
+					}
+					builder.push_bits(value, count);
+					for n in (0 .. count).rev() {
+						model.push(value >> n & 1 == 1);
+					}
+					let bytes = &bytes[.. count as usize * 2];
+					builder.push_bytes(bytes);
+					model.extend(bytes.bits::<BigEndian>());
+					builder.push_bit(true);
+					model.push(true);
+					assert_eq!(builder.len(), model.len());
+					assert_eq!(builder.build(), model);
+				}
+			}
+		}
+
+		check::<BigEndian, u8>();
+		check::<LittleEndian, u8>();
+		check::<Permuted<PairSwap>, u8>();
+		check::<BigEndian, u16>();
+		check::<LittleEndian, u32>();
+		check::<BigEndian, u64>();
+	}
+
+	#[test]
+	#[cfg(feature = "deadbits")]
+	fn pop_clears_dead_bits() {
+		let mut bv = (0 .. 7).map(|_| true).collect::<VecBit<BigEndian, u8>>();
+		assert_eq!(bv.pop(), Some(true));
+		assert_eq!(bv.as_slice(), &[0b1111_1100]);
+		bv.set(1, false);
+		assert!(!bv.swap_remove(1));
+		assert_eq!(bv.as_slice(), &[0b1111_1000]);
+	}
+}
@@ -0,0 +1,210 @@
+/*! Copy-on-write bitmap versions
+
+This module holds `SnapshotBits`, a bitmap that stages writes against a
+committed base, for transactional bitmap indices. Readers see the staged
+writes immediately; the base changes only when they are committed, and
+discarding them restores the base exactly.
+
+The base is a `BitBox`, which is never written while changes are pending. Each
+write copies the storage element that it touches into an overlay the first time
+that element changes, so the cost of a transaction is proportional to the number
+of elements it touches, rather than to the length of the bitmap.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	boxed::BitBox,
+	cursor::{
+		Cursor,
+		Local,
+	},
+	indices::Indexable,
+	slice::SliceBit,
+	store::{
+		BitStore,
+		Word,
+	},
+	vec::VecBit,
+};
+
+use alloc::collections::BTreeMap;
+
+/** A bitmap with a committed base and a set of pending changes.
+
+Every commit advances the version number, so that readers can tell whether the
+committed state has changed since they last looked.
+
+# Type Parameters
+
+- `C: Cursor`: The cursor type of the bitmap.
+- `T: BitStore`: The storage type of the bitmap, and the granularity at which
+  changes are copied.
+
+# Examples
+
+```rust
+use vecbit::prelude::*;
+use vecbit::versioned::SnapshotBits;
+
+let mut bits = SnapshotBits::new(bitbox![BigEndian, u8; 0; 20]);
+bits.set(3, true);
+bits.set(17, true);
+assert!(bits.get(3).unwrap());
+assert!(bits.base().not_any());
+
+bits.rollback();
+assert!(!bits.get(3).unwrap());
+
+bits.set(5, true);
+bits.commit();
+assert_eq!(bits.version(), 1);
+assert_eq!(bits.base().count_ones(), 1);
+```
+**/
+#[derive(Clone, Debug)]
+pub struct SnapshotBits<C = Local, T = Word>
+where C: Cursor, T: BitStore {
+	/// The committed bits.
+	base: BitBox<C, T>,
+	/// The pending values of changed elements, keyed by element index.
+	overlay: BTreeMap<usize, T>,
+	/// The number of commits so far.
+	version: u64,
+}
+
+impl<C, T> SnapshotBits<C, T>
+where C: Cursor, T: BitStore {
+	/// Begins tracking changes to a bitmap.
+	///
+	/// # Parameters
+	///
+	/// - `base`: The committed state of the bitmap, at version zero.
+	pub fn new(base: BitBox<C, T>) -> Self {
+		Self { base, overlay: BTreeMap::new(), version: 0 }
+	}
+
+	/// Counts the bits in the bitmap.
+	pub fn len(&self) -> usize {
+		self.base.len()
+	}
+
+	/// Tests whether the bitmap has no bits.
+	pub fn is_empty(&self) -> bool {
+		self.base.is_empty()
+	}
+
+	/// Gets the number of commits made so far.
+	pub fn version(&self) -> u64 {
+		self.version
+	}
+
+	/// Tests whether any changes are pending.
+	///
+	/// A write that restores a bit to its committed value still counts as a
+	/// change.
+	pub fn is_dirty(&self) -> bool {
+		!self.overlay.is_empty()
+	}
+
+	/// Counts the storage elements that pending changes have copied.
+	pub fn changed_elements(&self) -> usize {
+		self.overlay.len()
+	}
+
+	/// Borrows the committed bits, without any pending changes.
+	pub fn base(&self) -> &SliceBit<C, T> {
+		self.base.as_bitslice()
+	}
+
+	/// Reads a bit, including any pending change to it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The index of the bit to read.
+	///
+	/// # Returns
+	///
+	/// The current value of the bit, or `None` if `index` is out of bounds.
+	pub fn get(&self, index: usize) -> Option<bool> {
+		if index >= self.len() {
+			return None;
+		}
+		let (elt, bit) = Self::split(index);
+		Some(match self.overlay.get(&elt) {
+			Some(elt) => elt.get::<C>(bit.idx()),
+			None => self.base[index],
+		})
+	}
+
+	/// Writes a bit as a pending change.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `index`: The index of the bit to write.
+	/// - `value`: The new value of the bit.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds.
+	pub fn set(&mut self, index: usize, value: bool) {
+		let len = self.len();
+		assert!(index < len, "Index out of range: {} >= {}", index, len);
+		let (elt, bit) = Self::split(index);
+		let base = &self.base;
+		self.overlay.entry(elt)
+			.or_insert_with(|| base.as_slice()[elt])
+			.set::<C>(bit.idx(), value);
+	}
+
+	/// Writes every pending change into the base, and advances the version.
+	///
+	/// This does nothing, and does not advance the version, if no changes are
+	/// pending.
+	pub fn commit(&mut self) {
+		if self.overlay.is_empty() {
+			return;
+		}
+		let elts = self.base.as_mut_slice();
+		for (elt, value) in &self.overlay {
+			elts[*elt] = *value;
+		}
+		self.overlay.clear();
+		self.version += 1;
+	}
+
+	/// Discards every pending change.
+	pub fn rollback(&mut self) {
+		self.overlay.clear();
+	}
+
+	/// Copies the current bits, including pending changes, into a vector.
+	pub fn to_vecbit(&self) -> VecBit<C, T> {
+		let mut elts = self.base.as_slice().to_vec();
+		for (elt, value) in &self.overlay {
+			elts[*elt] = *value;
+		}
+		let mut out = VecBit::from_vec(elts);
+		out.truncate(self.len());
+		out
+	}
+
+	/// Discards any pending changes, and returns the committed bits.
+	pub fn into_base(self) -> BitBox<C, T> {
+		self.base
+	}
+
+	/// Splits an index into an element index and a bit index within it.
+	fn split(index: usize) -> (usize, u8) {
+		(index >> T::INDX, (index & T::MASK as usize) as u8)
+	}
+}
+
+impl<C, T> From<BitBox<C, T>> for SnapshotBits<C, T>
+where C: Cursor, T: BitStore {
+	fn from(base: BitBox<C, T>) -> Self {
+		Self::new(base)
+	}
+}
@@ -0,0 +1,271 @@
+/*! Web interoperation
+
+This module converts byte-stored bit vectors to forms that web applications
+can render or persist:
+
+- a compact text form, which is safe to embed in JSON, URLs, and storage APIs
+  that accept only strings;
+- under the `wasm` feature, the `Uint8Array` type of the `js-sys` crate, which
+  JavaScript code running alongside a WebAssembly module can read directly.
+
+Both forms carry the bytes of a `VecBit<C, u8>`, in which the cursor `C` places
+each bit. The dead bits after the last live bit are always cleared.
+
+# Text Form
+
+The text form is the bit length, in decimal, then a colon, then the bytes in
+the URL-safe Base64 alphabet of RFC 4648, without padding. For example,
+`vecbit![LittleEndian, u8; 1, 0, 1, 1]` is written as `4:DQ`.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	cursor::Cursor,
+	pointer::BitPtr,
+	slice::SliceBit,
+	vec::VecBit,
+};
+
+use alloc::{
+	string::{
+		String,
+		ToString,
+	},
+	vec::Vec,
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+#[cfg(feature = "wasm")]
+use js_sys::Uint8Array;
+
+/// The URL-safe Base64 alphabet.
+const ALPHABET: &[u8; 64] =
+	b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The reasons a text form can be rejected on load.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Base64Error {
+	/// The text does not begin with a decimal length and a colon, its length
+	/// is longer than any vector can be, or its Base64 part has an impossible
+	/// length.
+	Format,
+	/// The character at this byte index is not in the Base64 alphabet.
+	Symbol(usize),
+	/// The number of bytes does not match the recorded bit length.
+	Length {
+		/// The number of bytes in the text.
+		found: usize,
+		/// The number of bytes that the recorded bit length needs.
+		expected: usize,
+	},
+	/// The dead bits after the last live bit are not cleared.
+	DeadBits,
+}
+
+impl Display for Base64Error {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Base64Error::Format => f.write_str("not a bit vector text form"),
+			Base64Error::Symbol(at) => {
+				write!(f, "invalid Base64 character at index {}", at)
+			},
+			Base64Error::Length { found, expected } => write!(
+				f,
+				"text form has {} bytes, but its length needs {}",
+				found,
+				expected,
+			),
+			Base64Error::DeadBits => {
+				f.write_str("text form has set bits after its end")
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base64Error {}
+
+impl<C> SliceBit<C, u8>
+where C: Cursor {
+	/// Writes the slice in the text form described in the [module docs].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The bit length, a colon, and the bytes of the slice in URL-safe Base64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	///
+	/// let bits = &0b1011_0110u8.bits::<BigEndian>()[2 ..];
+	/// assert_eq!(bits.to_base64(), "6:2A");
+	/// ```
+	///
+	/// [module docs]: ../web/index.html
+	pub fn to_base64(&self) -> String {
+		let bytes = packed(self);
+		let mut out = self.len().to_string();
+		out.push(':');
+		out.reserve((bytes.len() * 4 + 2) / 3);
+		for chunk in bytes.chunks(3) {
+			let mut word = 0u32;
+			for (n, &byte) in chunk.iter().enumerate() {
+				word |= u32::from(byte) << (16 - n * 8);
+			}
+			for n in 0 ..= chunk.len() {
+				let sym = (word >> (18 - n * 6)) & 0x3F;
+				out.push(ALPHABET[sym as usize] as char);
+			}
+		}
+		out
+	}
+
+	/// Copies the slice into a JavaScript byte array.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A `Uint8Array` of the bytes of the slice, as they would be stored in a
+	/// `VecBit<C, u8>`. Its bit length is not recorded.
+	#[cfg(feature = "wasm")]
+	pub fn to_uint8array(&self) -> Uint8Array {
+		Uint8Array::from(&packed(self)[..])
+	}
+}
+
+impl<C> VecBit<C, u8>
+where C: Cursor {
+	/// Reads a vector back from the text form described in the [module docs].
+	///
+	/// # Parameters
+	///
+	/// - `text`: The text form, as produced by [`SliceBit::to_base64`].
+	///
+	/// # Returns
+	///
+	/// The vector that the text describes, or the reason that it could not be
+	/// read.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use vecbit::prelude::*;
+	/// use vecbit::web::Base64Error;
+	///
+	/// let bv = VecBit::<BigEndian, u8>::from_base64("6:2A").unwrap();
+	/// assert_eq!(bv, vecbit![1, 1, 0, 1, 1, 0]);
+	/// assert_eq!(
+	///   VecBit::<BigEndian, u8>::from_base64("6:2g"),
+	///   Err(Base64Error::DeadBits),
+	/// );
+	/// assert_eq!(
+	///   VecBit::<BigEndian, u8>::from_base64("9:2A"),
+	///   Err(Base64Error::Length { found: 1, expected: 2 }),
+	/// );
+	/// ```
+	///
+	/// [`SliceBit::to_base64`]: ../slice/struct.SliceBit.html#method.to_base64
+	/// [module docs]: ../web/index.html
+	pub fn from_base64(text: &str) -> Result<Self, Base64Error> {
+		let colon = text.find(':').ok_or(Base64Error::Format)?;
+		let len = text[.. colon].parse::<usize>()
+			.map_err(|_| Base64Error::Format)?;
+		if len > BitPtr::<u8>::MAX_BITS {
+			return Err(Base64Error::Format);
+		}
+		let syms = &text.as_bytes()[colon + 1 ..];
+		if syms.len() % 4 == 1 {
+			return Err(Base64Error::Format);
+		}
+		let mut bytes = Vec::with_capacity(syms.len() * 3 / 4);
+		for (n, chunk) in syms.chunks(4).enumerate() {
+			let mut word = 0u32;
+			for (k, &sym) in chunk.iter().enumerate() {
+				let val = decode(sym)
+					.ok_or(Base64Error::Symbol(colon + 1 + n * 4 + k))?;
+				word |= u32::from(val) << (18 - k * 6);
+			}
+			for k in 0 .. chunk.len() - 1 {
+				bytes.push((word >> (16 - k * 8)) as u8);
+			}
+		}
+		let expected = len / 8 + (len % 8 != 0) as usize;
+		if bytes.len() != expected {
+			return Err(Base64Error::Length { found: bytes.len(), expected });
+		}
+		let last = bytes.last().cloned();
+		let mut out = Self::from_vec(bytes);
+		unsafe { out.set_len(len); }
+		out.mask_dead_bits();
+		if out.as_slice().last().cloned() != last {
+			return Err(Base64Error::DeadBits);
+		}
+		Ok(out)
+	}
+
+	/// Copies a JavaScript byte array into a vector.
+	///
+	/// # Parameters
+	///
+	/// - `array`: The bytes of a `VecBit<C, u8>`.
+	/// - `len`: The number of bits to take from `array`.
+	///
+	/// # Returns
+	///
+	/// A vector of the first `len` bits of `array`. Any bits after `len` in
+	/// the last byte are cleared.
+	///
+	/// # Panics
+	///
+	/// Panics if `array` holds fewer than `len` bits.
+	#[cfg(feature = "wasm")]
+	pub fn from_uint8array(array: &Uint8Array, len: usize) -> Self {
+		let bytes = array.length() as usize;
+		assert!(
+			len <= bytes * 8,
+			"Cannot take {} bits from {} bytes",
+			len,
+			bytes,
+		);
+		let mut bytes = array.to_vec();
+		bytes.truncate(len / 8 + (len % 8 != 0) as usize);
+		let mut out = Self::from_vec(bytes);
+		unsafe { out.set_len(len); }
+		out.mask_dead_bits();
+		out
+	}
+}
+
+/// Copies a slice into the bytes of a vector, with the dead bits cleared.
+fn packed<C>(bits: &SliceBit<C, u8>) -> Vec<u8>
+where C: Cursor {
+	let mut bv = VecBit::from_bitslice(bits);
+	bv.mask_dead_bits();
+	bv.into_vec()
+}
+
+/// Looks up the value of a URL-safe Base64 character.
+fn decode(sym: u8) -> Option<u8> {
+	match sym {
+		b'A' ..= b'Z' => Some(sym - b'A'),
+		b'a' ..= b'z' => Some(sym - b'a' + 26),
+		b'0' ..= b'9' => Some(sym - b'0' + 52),
+		b'-' => Some(62),
+		b'_' => Some(63),
+		_ => None,
+	}
+}
@@ -0,0 +1,61 @@
+/*! Bit arrays behave as slices over their storage, held by value.
+
+These tests check that `BitArray` is sized by its storage, that copies are
+independent, and that it compares and hashes as its bits do.
+!*/
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{
+	Hash,
+	Hasher,
+};
+
+use vecbit::prelude::*;
+
+#[test]
+fn sizes() {
+	assert_eq!(BitArray::<BigEndian, [u8; 0]>::default().len(), 0);
+	assert_eq!(BitArray::<BigEndian, u16>::default().len(), 16);
+	assert_eq!(BitArray::<LittleEndian, [u32; 3]>::default().len(), 96);
+	assert_eq!(
+		core::mem::size_of::<BitArray<LittleEndian, [u32; 3]>>(),
+		core::mem::size_of::<[u32; 3]>(),
+	);
+}
+
+#[test]
+fn copies() {
+	let mut a = BitArray::<LittleEndian, _>::from([0u8; 3]);
+	let b = a;
+	a.set(9, true);
+	a[16 ..].set_all(true);
+	assert_eq!(a.into_inner(), [0, 2, 0xFF]);
+	assert!(b.not_any());
+	assert_ne!(a, b);
+	assert!(b < a);
+	*b.clone().as_mut_inner() = [1, 2, 3];
+	assert_eq!(b.as_inner(), &[0, 0, 0]);
+}
+
+#[test]
+fn compare() {
+	let src = [0x5Au8, 0xC3];
+	let a = BitArray::<BigEndian, _>::new(src);
+	let b = BitArray::<LittleEndian, _>::new(0xC35Au16);
+	assert_eq!(a, src.bits::<BigEndian>());
+	assert_eq!(a, b);
+	assert_eq!(*src.bits::<BigEndian>(), a);
+	assert_eq!(format!("{}", a), format!("{}", src.bits::<BigEndian>()));
+	assert_eq!(
+		format!("{:?}", a),
+		format!("BitArray<BigEndian, u8> {}", a),
+	);
+	let hash = |bits: &SliceBit<BigEndian, u8>| {
+		let mut hasher = DefaultHasher::new();
+		bits.hash(&mut hasher);
+		hasher.finish()
+	};
+	let mut hasher = DefaultHasher::new();
+	a.hash(&mut hasher);
+	assert_eq!(hasher.finish(), hash(src.bits()));
+}
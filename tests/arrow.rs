@@ -0,0 +1,70 @@
+/*! Conversions to and from Arrow buffers keep their bits, and avoid copying.
+
+Arrow buffers are viewed in place, and vectors of `LittleEndian` bytes are moved
+into Arrow buffers without copying. Every other slice is copied, and must read
+back the same.
+!*/
+
+#![cfg(feature = "arrow")]
+
+use arrow_buffer::{
+	BooleanBuffer,
+	Buffer,
+	NullBuffer,
+};
+
+use vecbit::prelude::*;
+
+#[test]
+fn view_in_place() {
+	let bytes = vec![0x5Au8, 0xC3, 0x0F];
+	let addr = bytes.as_ptr();
+	let buffer = BooleanBuffer::new(Buffer::from_vec(bytes), 3, 17);
+	let bits = SliceBit::from_arrow(&buffer);
+	assert_eq!(bits.len(), 17);
+	assert_eq!(bits.as_total_slice().as_ptr() as *const u8, addr);
+	for n in 0 .. 17 {
+		assert_eq!(bits[n], buffer.value(n));
+	}
+
+	let nulls = NullBuffer::new(buffer.slice(5, 9));
+	let valid = SliceBit::from_validity(&nulls);
+	assert_eq!(valid, &bits[5 .. 14]);
+}
+
+#[test]
+fn move_without_copy() {
+	let mut bv = vecbit![LittleEndian, u8; 1; 13];
+	bv.truncate(10);
+	let addr = bv.as_slice().as_ptr();
+	let buffer = BooleanBuffer::from(bv);
+	assert_eq!(buffer.values().as_ptr(), addr);
+	assert_eq!(buffer.len(), 10);
+	assert_eq!(buffer.values(), &[0xFF, 0x03]);
+
+	let nulls = NullBuffer::from(vecbit![LittleEndian, u8; 1, 0, 1]);
+	assert!(nulls.is_valid(0) && !nulls.is_valid(1) && nulls.is_valid(2));
+}
+
+fn round_trip<C>(bits: &SliceBit<C, u32>)
+where C: Cursor {
+	let buffer = bits.to_arrow();
+	assert_eq!(buffer.offset(), 0);
+	assert_eq!(buffer.values().len(), (bits.len() + 7) / 8);
+	assert_eq!(SliceBit::from_arrow(&buffer), bits);
+}
+
+#[test]
+fn copy_other_layouts() {
+	let words = [0x1234_5678u32, 0x9ABC_DEF0];
+	let le = words.bits::<LittleEndian>();
+	let be = words.bits::<BigEndian>();
+	for &(from, upto) in &[(0, 64), (0, 37), (5, 64), (11, 30)] {
+		round_trip(&le[from .. upto]);
+		round_trip(&be[from .. upto]);
+	}
+	assert_eq!(
+		le.to_arrow().values(),
+		&[0x78, 0x56, 0x34, 0x12, 0xF0, 0xDE, 0xBC, 0x9A],
+	);
+}
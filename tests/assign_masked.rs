@@ -0,0 +1,52 @@
+/*! Masked assignment agrees with a bit-by-bit model.
+
+These tests compare `assign_masked` against `(dst & !mask) | (src & mask)`
+computed one index at a time, for slices that share a starting bit index
+within their elements and for slices that do not, and for short masks.
+!*/
+
+use vecbit::prelude::*;
+
+const DST: [u16; 4] = [0x5AC3, 0x0FF0, 0x9669, 0x1234];
+const SRC: [u16; 4] = [0xA53C, 0xF00F, 0x6996, 0xEDCB];
+const MASK: [u16; 4] = [0xFF00, 0x0F0F, 0x3333, 0xFFFF];
+
+#[test]
+fn matches_model() {
+	let (src, mask) = (SRC.bits::<BigEndian>(), MASK.bits::<BigEndian>());
+	for start in 0 .. 20 {
+		for end in start .. 40 {
+			for &(ss, ms) in &[(0, 0), (1, 0), (0, 3), (5, 7)] {
+				let len = end - start;
+				let s = &src[start + ss .. start + ss + len];
+				for m in &[
+					&mask[start + ms .. start + ms + len],
+					&mask[start + ms .. start + ms + len / 2],
+				] {
+					let mut data = DST;
+					let dst = &mut data.bits_mut::<BigEndian>()[start .. end];
+					let model = (0 .. len).map(|n| {
+						if n < m.len() && m[n] { s[n] } else { dst[n] }
+					}).collect::<Vec<_>>();
+					dst.assign_masked(s, m);
+					assert_eq!(dst.iter().collect::<Vec<_>>(), model);
+					let outside = data.bits::<BigEndian>();
+					let orig = DST;
+					let orig = orig.bits::<BigEndian>();
+					assert_eq!(outside[.. start], orig[.. start]);
+					assert_eq!(outside[end ..], orig[end ..]);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+#[should_panic]
+fn length_mismatch() {
+	let mut data = DST;
+	let src = SRC;
+	let mask = MASK;
+	data.bits_mut::<BigEndian>()[.. 10]
+		.assign_masked(&src.bits()[.. 9], &mask.bits()[.. 10]);
+}
@@ -0,0 +1,48 @@
+/*! Conversions with the `fixedbitset` and `bit-set` crates keep every member.
+
+Each conversion is checked against the membership test of the other crate, at
+lengths on and off the edges of their `u32` blocks, and then converted back.
+!*/
+
+#![cfg(any(feature = "bit-set", feature = "fixedbitset"))]
+
+use vecbit::prelude::*;
+
+fn patterns() -> Vec<VecBit<BigEndian, u16>> {
+	let words = [0xF00Fu16, 0x0000, 0xA5A5, 0xFFFF, 0x8001];
+	[0, 1, 31, 32, 33, 64, 80].iter()
+		.map(|&len| words.bits::<BigEndian>()[.. len].to_owned())
+		.collect()
+}
+
+#[test]
+#[cfg(feature = "fixedbitset")]
+fn fixedbitset() {
+	use fixedbitset::FixedBitSet;
+
+	for bv in patterns() {
+		let set = FixedBitSet::from(bv.as_bitslice());
+		assert_eq!(set.len(), bv.len());
+		for n in 0 .. bv.len() {
+			assert_eq!(set.contains(n), bv[n]);
+		}
+		assert_eq!(VecBit::<BigEndian, u16>::from(&set), bv);
+		assert_eq!(VecBit::<LittleEndian, u64>::from(&set), bv);
+	}
+}
+
+#[test]
+#[cfg(feature = "bit-set")]
+fn bit_set() {
+	use bit_set::BitSet;
+
+	for bv in patterns() {
+		let set = BitSet::from(bv.as_bitslice());
+		assert_eq!(set.len(), bv.count_ones());
+		for n in 0 .. bv.len() {
+			assert_eq!(set.contains(n), bv[n]);
+		}
+		let back = VecBit::<BigEndian, u16>::from(&set);
+		assert_eq!(back, bv);
+	}
+}
@@ -0,0 +1,57 @@
+/*! `bytes` buffers are read and narrowed as bits without copying.
+
+`BitBytes` regions share the `Bytes` buffer they were made from. These tests
+check that every narrowing sees the right bits, and that writes through a
+`BytesMut` view land in the buffer.
+!*/
+
+#![cfg(all(feature = "alloc", feature = "bytes"))]
+
+use bytes::{
+	Bytes,
+	BytesMut,
+};
+
+use vecbit::{
+	buffers::BitBytes,
+	prelude::*,
+};
+
+#[test]
+fn regions() {
+	let src = [0x5Au8, 0xC3, 0x0F];
+	let all = BitBytes::<LittleEndian>::from(Bytes::from(src.to_vec()));
+	let bits = src.bits::<LittleEndian>();
+	assert_eq!(all, bits);
+	for from in 0 .. bits.len() {
+		for upto in from .. bits.len() + 1 {
+			let part = all.slice(from .. upto);
+			assert_eq!(part, &bits[from .. upto]);
+			let mut front = part.clone();
+			let mid = (upto - from) / 3;
+			let back = front.split_off(mid);
+			assert_eq!(front, &bits[from .. from + mid]);
+			assert_eq!(back, &bits[from + mid .. upto]);
+			let mut rest = part.clone();
+			assert_eq!(rest.split_to(mid), &bits[from .. from + mid]);
+			assert_eq!(rest, &bits[from + mid .. upto]);
+			assert_eq!(rest.bytes().as_ptr(), all.bytes().as_ptr());
+		}
+	}
+}
+
+#[test]
+fn write_through() {
+	let mut buf = BytesMut::from(&[0u8; 3][..]);
+	buf.bits_mut::<BigEndian>().set_multiples(1, 4);
+	let frozen = BitBytes::<BigEndian>::from(buf.freeze());
+	assert_eq!(&frozen.bytes()[..], &[0x44, 0x44, 0x44]);
+	assert_eq!(frozen.count_ones(), 6);
+}
+
+#[test]
+fn portable_default() {
+	let frame: BitBytes = BitBytes::from(Bytes::from(vec![0x01u8, 0x80]));
+	assert!(frame[0] && frame[15]);
+	assert_eq!(frame.count_ones(), 2);
+}
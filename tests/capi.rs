@@ -0,0 +1,70 @@
+/*! The C ABI functions behave as their Rust counterparts.
+
+These tests call the exported functions directly, as foreign code would, and
+check their results against the same operations on a `VecBit`.
+!*/
+
+#![cfg(feature = "capi")]
+
+use vecbit::{
+	capi::*,
+	prelude::*,
+};
+
+use std::ptr;
+
+#[test]
+fn lifecycle() {
+	unsafe {
+		let bits = vecbit_new(10);
+		assert_eq!(vecbit_len(bits), 10);
+		assert_eq!(vecbit_count_ones(bits), 0);
+		assert!(vecbit_set(bits, 3, true));
+		assert!(vecbit_set(bits, 9, true));
+		assert!(!vecbit_set(bits, 10, true));
+		assert_eq!(vecbit_get(bits, 3), 1);
+		assert_eq!(vecbit_get(bits, 4), 0);
+		assert_eq!(vecbit_get(bits, 10), -1);
+		assert!(vecbit_push(bits, true));
+		assert_eq!(vecbit_len(bits), 11);
+		assert_eq!(vecbit_count_ones(bits), 3);
+
+		let mut len = 0;
+		let bytes = vecbit_as_bytes(bits, &mut len);
+		assert_eq!(std::slice::from_raw_parts(bytes, len), &[0x08, 0x06]);
+		vecbit_free(bits);
+		vecbit_free(ptr::null_mut());
+	}
+}
+
+#[test]
+fn from_bytes() {
+	let src = [0xA5u8, 0xFF];
+	unsafe {
+		let bits = vecbit_from_bytes(src.as_ptr(), 12);
+		assert_eq!(
+			&*bits,
+			&src.bits::<LittleEndian>()[.. 12],
+		);
+		//  The bits after the end of the buffer are not copied.
+		let bytes = vecbit_as_bytes(bits, ptr::null_mut());
+		assert_eq!(*bytes.add(1), 0x0F);
+		vecbit_free(bits);
+
+		let empty = vecbit_from_bytes(ptr::null(), 0);
+		assert_eq!(vecbit_len(empty), 0);
+		assert!(vecbit_as_bytes(empty, ptr::null_mut()).is_null());
+		vecbit_free(empty);
+	}
+}
+
+#[test]
+fn oversized() {
+	let max_bits = !0usize >> 3;
+	unsafe {
+		assert!(vecbit_new(max_bits + 1).is_null());
+		assert!(vecbit_new(!0).is_null());
+		assert!(vecbit_from_bytes(ptr::null(), !0).is_null());
+		assert!(vecbit_from_bytes(ptr::null(), max_bits + 1).is_null());
+	}
+}
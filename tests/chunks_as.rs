@@ -0,0 +1,153 @@
+/*! Decoding fixed-width groups agrees with chunking and reading each bit.
+
+These tests compare `iter_chunks_as` against a bit-by-bit model for every
+combination of width and tail policy, on slices that do not start at an element
+edge, and check that it reverses `VecBitBuilder::push_bits` and
+`VecBit::from_symbols`.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	fields::{
+		ChunkTail,
+		ChunksAsError,
+		SymbolError,
+	},
+	prelude::*,
+	vec::VecBitBuilder,
+};
+
+fn model(bits: &SliceBit<LittleEndian, u16>, width: usize, tail: ChunkTail)
+-> Vec<u16> {
+	bits.chunks(width)
+		.filter(|c| c.len() == width || tail != ChunkTail::Drop)
+		.map(|c| {
+			let v = c.iter().fold(0u16, |acc, b| acc << 1 | b as u16);
+			if tail == ChunkTail::Pad {
+				v << (width - c.len())
+			}
+			else {
+				v
+			}
+		})
+		.collect()
+}
+
+#[test]
+fn matches_model() {
+	let data = [0x5AC3u16, 0x0FF0, 0x1234];
+	let all = data.bits::<LittleEndian>();
+	for start in 0 .. 5 {
+		let bits = &all[start .. 45];
+		for width in 1 ..= 16u8 {
+			for &tail in &[ChunkTail::Drop, ChunkTail::Pad, ChunkTail::Keep] {
+				let iter = bits.iter_chunks_as::<u16>(width, tail).unwrap();
+				let expected = model(bits, width as usize, tail);
+				assert_eq!(iter.len(), expected.len());
+				assert_eq!(iter.collect::<Vec<_>>(), expected);
+			}
+			let exact = bits.iter_chunks_as::<u16>(width, ChunkTail::Exact);
+			let rem = bits.len() % width as usize;
+			match exact {
+				Ok(iter) => {
+					assert_eq!(rem, 0);
+					assert_eq!(
+						iter.collect::<Vec<_>>(),
+						model(bits, width as usize, ChunkTail::Exact),
+					);
+				},
+				Err(err) => assert_eq!(
+					err,
+					ChunksAsError::Inexact { len: bits.len(), rem },
+				),
+			}
+		}
+	}
+}
+
+#[test]
+fn reverses_push_bits() {
+	let symbols = [3u64, 0, 7, 5, 1, 6, 2, 4];
+	let mut builder = VecBitBuilder::<BigEndian, u8>::new();
+	for &sym in &symbols {
+		builder.push_bits(sym, 3);
+	}
+	let bits = builder.build();
+	let back = bits.iter_chunks_as::<u32>(3, ChunkTail::Exact)
+		.unwrap()
+		.map(u64::from)
+		.collect::<Vec<_>>();
+	assert_eq!(back, symbols);
+}
+
+#[test]
+fn widths() {
+	let bits = [!0u64].bits::<BigEndian>();
+	let full = bits.iter_chunks_as::<u64>(64, ChunkTail::Exact).unwrap();
+	assert_eq!(full.collect::<Vec<_>>(), [!0]);
+	assert_eq!(
+		bits.iter_chunks_as::<u8>(0, ChunkTail::Keep).err(),
+		Some(ChunksAsError::Width { width: 0, max: 8 }),
+	);
+	assert_eq!(
+		bits.iter_chunks_as::<u32>(33, ChunkTail::Keep).err(),
+		Some(ChunksAsError::Width { width: 33, max: 32 }),
+	);
+	assert_eq!(
+		SliceBit::<BigEndian, u8>::empty()
+			.iter_chunks_as::<u8>(4, ChunkTail::Exact)
+			.unwrap()
+			.count(),
+		0,
+	);
+}
+
+#[test]
+fn symbols_round_trip() {
+	let symbols = (0 .. 100u16).map(|n| n * 37 % 1000).collect::<Vec<_>>();
+	for width in 10 ..= 16u8 {
+		let bv = VecBit::<LittleEndian, u32>::from_symbols(
+			symbols.iter().cloned(),
+			width,
+		).unwrap();
+		assert_eq!(bv.len(), symbols.len() * width as usize);
+		let back = bv.iter_chunks_as::<u16>(width, ChunkTail::Exact)
+			.unwrap()
+			.collect::<Vec<_>>();
+		assert_eq!(back, symbols);
+	}
+	assert_eq!(
+		VecBit::<LittleEndian, u32>::from_symbols(symbols.clone(), 9),
+		Err(SymbolError::Overflow { index: 14, value: 518 }),
+	);
+	assert_eq!(
+		VecBit::<LittleEndian, u32>::from_symbols(symbols, 17),
+		Err(SymbolError::Width { width: 17, max: 16 }),
+	);
+	let full = VecBit::<BigEndian, u8>::from_symbols(vec![!0u64, 1], 64).unwrap();
+	assert_eq!(full.count_ones(), 65);
+}
+
+#[test]
+fn symbols_exact_size_hint() {
+	//  An exact size hint makes `from_symbols` allocate before it pushes.
+	for count in 0 .. 40 {
+		let symbols = (0 .. count).map(|n| (n * 5 % 8) as u8);
+		assert_eq!(symbols.size_hint(), (count, Some(count)));
+		let bv = VecBit::<BigEndian, u8>::from_symbols(symbols.clone(), 3)
+			.unwrap();
+		let mut model = VecBit::<BigEndian, u8>::new();
+		for sym in symbols.clone() {
+			model.extend(&sym.bits::<BigEndian>()[5 ..]);
+		}
+		assert_eq!(bv, model);
+		let bv = VecBit::<LittleEndian, u16>::from_symbols(symbols.clone(), 3)
+			.unwrap();
+		assert_eq!(bv, model);
+		let back = bv.iter_chunks_as::<u8>(3, ChunkTail::Exact)
+			.unwrap()
+			.collect::<Vec<_>>();
+		assert_eq!(back, symbols.collect::<Vec<_>>());
+	}
+}
@@ -0,0 +1,73 @@
+/*! Numeric comparison agrees with the integers that slices represent.
+
+These tests read every short sub-slice of a buffer as an unsigned and as a
+2’s-complement integer, with the first bit most significant, and check that
+`cmp_numeric` and `cmp_numeric_signed` order each pair as those integers do,
+across slices of different lengths, cursors, and storage types.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+
+fn unsigned<C, T>(bits: &SliceBit<C, T>) -> i64
+where C: Cursor, T: BitStore {
+	bits.iter().fold(0, |acc, bit| acc << 1 | bit as i64)
+}
+
+fn signed<C, T>(bits: &SliceBit<C, T>) -> i64
+where C: Cursor, T: BitStore {
+	match bits.first() {
+		Some(true) => unsigned(bits) - (1 << bits.len()),
+		_ => unsigned(bits),
+	}
+}
+
+#[test]
+fn matches_integers() {
+	let src = [0x5Au8, 0xC3, 0x0F];
+	let other = [0x96A5u16];
+	let a = src.bits::<BigEndian>();
+	let b = other.bits::<LittleEndian>();
+	for a_start in 0 .. 12 {
+		for a_end in a_start .. a_start + 13 {
+			let x = &a[a_start .. a_end];
+			for b_start in 0 .. 4 {
+				for b_end in b_start .. 16 {
+					let y = &b[b_start .. b_end];
+					assert_eq!(
+						x.cmp_numeric(y),
+						unsigned(x).cmp(&unsigned(y)),
+						"{} vs {}",
+						x,
+						y,
+					);
+					assert_eq!(
+						x.cmp_numeric_signed(y),
+						signed(x).cmp(&signed(y)),
+						"{} vs {}",
+						x,
+						y,
+					);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn sorting() {
+	let mut nums = vec![
+		vecbit![BigEndian, u8; 1, 1],
+		vecbit![BigEndian, u8; 0, 1, 1, 1],
+		vecbit![BigEndian, u8; 1, 0, 0],
+		vecbit![BigEndian, u8;],
+		vecbit![BigEndian, u8; 0, 0, 0, 1],
+	];
+	nums.sort_by(|a, b| a.cmp_numeric(b));
+	let values = nums.iter().map(|n| unsigned(n)).collect::<Vec<_>>();
+	assert_eq!(values, [0, 1, 3, 4, 7]);
+	nums.sort_by(|a, b| a.cmp_numeric_signed(b));
+	let values = nums.iter().map(|n| signed(n)).collect::<Vec<_>>();
+	assert_eq!(values, [-4, -1, 0, 1, 7]);
+}
@@ -0,0 +1,91 @@
+/*! Comparisons between slices, vectors, and boxes agree with a bit-by-bit model.
+
+Slices that share a layout are compared a whole element at a time. These tests
+check that path against a plain comparison of the bits, with the dead bits in
+the edge elements deliberately set differently on each side.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+
+fn model<A, B, C, D>(l: &SliceBit<A, B>, r: &SliceBit<C, D>) -> bool
+where A: Cursor, B: BitStore, C: Cursor, D: BitStore {
+	l.len() == r.len() && l.iter().zip(r.iter()).all(|(l, r)| l == r)
+}
+
+#[test]
+fn dead_bits_ignored() {
+	let lsrc = [0x5Au8, 0xC3, 0x0F, 0xFF];
+	let mut rsrc = lsrc;
+	rsrc[0] ^= 0b1110_0000;
+	rsrc[3] ^= 0b0000_0111;
+	let l = lsrc.bits::<BigEndian>();
+	let r = rsrc.bits::<BigEndian>();
+	for from in 3 .. 8 {
+		for upto in 24 .. 30 {
+			assert_eq!(l[from .. upto], r[from .. upto], "{} .. {}", from, upto);
+		}
+	}
+	assert_ne!(l[2 .. 29], r[2 .. 29]);
+	assert_ne!(l[3 .. 30], r[3 .. 30]);
+}
+
+#[test]
+fn matches_model() {
+	let lsrc = [0x1234_5678u32, 0x9ABC_DEF0, 0x0F0F_F0F0];
+	let mut rsrc = lsrc;
+	rsrc[1] ^= 1 << 17;
+	let l = lsrc.bits::<LittleEndian>();
+	let r = rsrc.bits::<LittleEndian>();
+	let rb = rsrc.bits::<BigEndian>();
+	for from in (0 .. 40).step_by(3) {
+		for upto in (from + 2 .. 96).step_by(5) {
+			for shift in 0 .. 3 {
+				let rhs = &r[from + shift .. upto];
+				let lhs = &l[from .. upto - shift];
+				assert_eq!(lhs == rhs, model(lhs, rhs));
+				let rhs = &rb[from .. upto];
+				assert_eq!(&l[from .. upto] == rhs, model(&l[from .. upto], rhs));
+			}
+			assert_eq!(l[from .. upto] == r[from .. upto], upto <= 49 || from > 49);
+		}
+	}
+}
+
+#[test]
+fn owned_and_borrowed() {
+	let bv = vecbit![LittleEndian, u16; 1, 0, 1, 1, 0, 0, 1];
+	let bb = bitbox![BigEndian, u8; 1, 0, 1, 1, 0, 0, 1];
+	let bits = bv.as_bitslice();
+
+	assert!(bb == bv && bv == bb);
+	assert!(bb == bits && bits == bb);
+	assert!(bb == *bits && *bits == bb);
+	assert!(bv == bits && bits == bv);
+
+	let longer = bitbox![LittleEndian, u32; 1, 0, 1, 1, 0, 0, 1, 0];
+	assert!(bv < longer && longer > bv);
+	assert!(bits < longer && longer > bits);
+}
+
+#[test]
+fn count_common() {
+	let lsrc = [0x1234_5678u32, 0x9ABC_DEF0, 0x0F0F_F0F0];
+	let rsrc = [0xFEDC_BA98u32, 0x7654_3210, 0x3333_CCCC];
+	let l = lsrc.bits::<BigEndian>();
+	let r = rsrc.bits::<BigEndian>();
+	for from in (0 .. 40).step_by(3) {
+		for upto in (from + 2 .. 96).step_by(5) {
+			for shift in 0 .. 3 {
+				let (lhs, rhs) = (&l[from .. upto], &r[from + shift .. upto]);
+				let model = lhs.iter()
+					.zip(rhs.iter())
+					.filter(|&(a, b)| a && b)
+					.count();
+				assert_eq!(lhs.count_common(rhs), model);
+				assert_eq!(rhs.count_common(lhs), model);
+			}
+		}
+	}
+}
@@ -0,0 +1,61 @@
+/*! Constant-time access agrees with the ordinary accessors.
+
+These tests compare `get_ct`, `set_ct`, and `ct_eq` against `get`, `set`, and
+`==`, at every offset and length within a few elements, for slices that share a
+starting bit index within their elements and for slices that do not.
+!*/
+
+use vecbit::prelude::*;
+
+#[test]
+fn get_set() {
+	let mut a = [0x5AC3u16, 0x0FF0, 0x9669];
+	let mut b = a;
+	for n in 0 .. 48 {
+		assert_eq!(a.bits::<BigEndian>().get_ct(n), a.bits::<BigEndian>()[n]);
+		assert_eq!(
+			a.bits::<LittleEndian>().get_ct(n),
+			a.bits::<LittleEndian>()[n],
+		);
+	}
+	for start in 0 .. 20 {
+		for n in 0 .. 48 - start {
+			let value = n % 3 == 0;
+			a.bits_mut::<BigEndian>()[start ..].set_ct(n, value);
+			b.bits_mut::<BigEndian>()[start ..].set(n, value);
+			assert_eq!(a, b);
+			a.bits_mut::<LittleEndian>()[start ..].set_ct(n, !value);
+			b.bits_mut::<LittleEndian>()[start ..].set(n, !value);
+			assert_eq!(a, b);
+		}
+	}
+}
+
+#[test]
+#[should_panic]
+fn get_out_of_bounds() {
+	(&0u8.bits::<BigEndian>()[.. 3]).get_ct(3);
+}
+
+#[test]
+fn eq() {
+	let a = [0x5AC3u16, 0x0FF0, 0x9669, 0x1234];
+	let b = [0x5AC3u16, 0x0FF1, 0x9669, 0x1234];
+	let (a, b) = (a.bits::<LittleEndian>(), b.bits::<LittleEndian>());
+	for start in 0 .. 20 {
+		for end in start .. 64 {
+			for shift in 0 .. 3 {
+				let x = &a[start .. end];
+				let y = &b[start .. end];
+				assert_eq!(x.ct_eq(y), x == y);
+				assert!(x.ct_eq(x));
+				let y = &b[start + shift .. 64];
+				let y = &y[.. y.len().min(end - start)];
+				assert_eq!(x.ct_eq(y), x == y);
+				let z = &a[start + shift .. 64];
+				let z = &z[.. z.len().min(end - start)];
+				assert_eq!(x.ct_eq(z), x == z);
+			}
+		}
+	}
+}
@@ -0,0 +1,78 @@
+/*! Counting representations agree with a vector of integer counters.
+
+These tests accumulate bitmaps into counters of several widths, as a counting
+Bloom filter does, and compare against plain saturating integer arithmetic.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	fields::ChunkTail,
+	prelude::*,
+};
+
+fn bitmap(seed: usize, len: usize) -> VecBit<LittleEndian, u16> {
+	(0 .. len).map(|n| (n * 7 + seed * 13) % 11 < 4).collect()
+}
+
+#[test]
+fn accumulate() {
+	let len = 37;
+	for &width in &[1u8, 2, 3, 4, 7, 64] {
+		let max = !0u64 >> (64 - width);
+		let mut counts = VecBit::<BigEndian, u32>::new();
+		counts.resize(len * width as usize, false);
+		let mut model = vec![0u64; len];
+		for seed in 0 .. 20 {
+			let key = bitmap(seed, len);
+			let widened = key.to_counts(width);
+			assert_eq!(widened.len(), len * width as usize);
+			let narrowed = VecBit::<LittleEndian, u16>::from_counts(
+				&widened,
+				width,
+			);
+			assert_eq!(narrowed, key);
+
+			let mut expected = 0;
+			for (slot, bit) in model.iter_mut().zip(key.iter()) {
+				if bit && *slot == max {
+					expected += 1;
+				}
+				*slot = (*slot + bit as u64).min(max);
+			}
+			assert_eq!(counts.saturating_add_counts(&widened, width), expected);
+		}
+		let back = counts.iter_chunks_as::<u64>(width, ChunkTail::Exact)
+			.unwrap()
+			.collect::<Vec<_>>();
+		assert_eq!(back, model);
+		let present = model.iter().map(|&c| c != 0).collect::<Vec<_>>();
+		assert_eq!(
+			VecBit::<BigEndian, u8>::from_counts(&counts, width),
+			VecBit::<BigEndian, u8>::from_bools(&present),
+		);
+	}
+}
+
+#[test]
+fn wide_addends() {
+	let mut counts = VecBit::<BigEndian, u8>::from_symbols(vec![5u8, 250], 8)
+		.unwrap();
+	let add = VecBit::<LittleEndian, u64>::from_symbols(vec![9u8, 9], 8)
+		.unwrap();
+	assert_eq!(counts.saturating_add_counts(&add, 8), 1);
+	assert_eq!(counts.as_slice(), &[14, 255]);
+}
+
+#[test]
+#[should_panic]
+fn ragged() {
+	let mut counts = vecbit![0; 7];
+	counts.saturating_add_counts(&vecbit![0; 7], 2);
+}
+
+#[test]
+#[should_panic]
+fn zero_width() {
+	vecbit![1].to_counts(0);
+}
@@ -0,0 +1,59 @@
+/*! Cyclic views read the same bits as indexing modulo the period.
+
+These tests compare every accessor and iterator of a `CycleBits` against
+`bits[n % len]`, across unaligned slices and runs longer than several periods.
+!*/
+
+use vecbit::prelude::*;
+
+#[test]
+fn wrapping() {
+	let data = [0x5Au8, 0xC3, 0x0F];
+	let all = data.bits::<LittleEndian>();
+	for start in 0 .. 4 {
+		for len in 1 .. 13 {
+			let bits = &all[start .. start + len];
+			let ring = bits.cycle();
+			assert_eq!(ring.period(), len);
+			for n in 0 .. 5 * len {
+				assert_eq!(ring.get(n), bits[n % len]);
+				assert_eq!(ring[n], bits[n % len]);
+				let last = if n < len { n } else { len - 1 };
+				assert_eq!(ring.get_saturating(n), bits[last]);
+			}
+			let three = ring.iter(3).collect::<Vec<_>>();
+			assert_eq!(three.len(), 3 * len);
+			for (n, &bit) in three.iter().enumerate() {
+				assert_eq!(bit, bits[n % len]);
+			}
+			for from in 0 .. 2 * len {
+				let run = ring.iter_from(from, 2 * len + 1);
+				assert_eq!(run.len(), 2 * len + 1);
+				for (n, bit) in run.enumerate() {
+					assert_eq!(bit, bits[(from + n) % len]);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn nth() {
+	let bits = 0b1001_0110u8.bits::<BigEndian>();
+	let ring = bits.cycle();
+	for skip in 0 .. 30 {
+		let mut iter = ring.iter_from(3, 25);
+		let expected = if skip < 25 { Some(ring[3 + skip]) } else { None };
+		assert_eq!(iter.nth(skip), expected);
+		assert_eq!(iter.len(), 25usize.saturating_sub(skip + 1));
+		let after = if skip < 24 { Some(ring[4 + skip]) } else { None };
+		assert_eq!(iter.next(), after);
+	}
+	assert_eq!(ring.iter(0).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn empty() {
+	SliceBit::<BigEndian, u8>::empty().cycle();
+}
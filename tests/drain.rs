@@ -0,0 +1,54 @@
+/*! Draining and splicing keep the vector consistent, even when leaked.
+
+A leaked `Drain` or `Splice` never runs its destructor, so the vector must
+already be in a valid state while the iterator is alive. Both truncate the
+vector to the start of the removed range up front, and so a leaked iterator
+leaves only the bits before that range (plus, for `Splice`, any replacement bits
+already written).
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use std::mem;
+use vecbit::prelude::*;
+
+#[test]
+fn leak_drain() {
+	let mut bv = vecbit![BigEndian, u8; 1; 20];
+	let mut drain = bv.drain(5 .. 12);
+	assert_eq!(drain.next(), Some(true));
+	mem::forget(drain);
+	assert_eq!(bv.len(), 5);
+	assert!(bv.all());
+
+	bv.push(false);
+	assert_eq!(bv, vecbit![BigEndian, u8; 1, 1, 1, 1, 1, 0]);
+}
+
+#[test]
+fn leak_splice() {
+	let mut bv = vecbit![LittleEndian, u16; 0; 20];
+	let mut splice = bv.splice(3 .. 10, vecbit![1; 5]);
+	splice.next();
+	splice.next();
+	mem::forget(splice);
+	assert_eq!(bv, vecbit![LittleEndian, u16; 0, 0, 0, 1, 1]);
+}
+
+#[test]
+fn drain_tails() {
+	let src: Vec<bool> = (0 .. 150).map(|n| n % 3 == 0 || n % 7 == 0).collect();
+	for &(from, upto) in &[(0, 0), (0, 150), (3, 70), (8, 16), (13, 149), (64, 65)] {
+		let mut bv: VecBit<BigEndian, u32> = src.iter().copied().collect();
+		let mut model = src.clone();
+		let out: Vec<bool> = bv.drain(from .. upto).collect();
+		assert_eq!(out, model.drain(from .. upto).collect::<Vec<_>>());
+		assert_eq!(bv.iter().collect::<Vec<_>>(), model);
+
+		let mut bv: VecBit<LittleEndian, u8> = src.iter().copied().collect();
+		let mut model = src.clone();
+		bv.splice(from .. upto, src[.. 37].iter().copied());
+		model.splice(from .. upto, src[.. 37].iter().copied());
+		assert_eq!(bv.iter().collect::<Vec<_>>(), model);
+	}
+}
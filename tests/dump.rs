@@ -0,0 +1,49 @@
+/*! Multi-line dumps lay out every bit under an aligned offset label.
+
+These tests render dumps of unaligned slices, and check the labels, the
+grouping, and that reading the bits back out of the dump reproduces the slice.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+
+#[test]
+fn layout() {
+	let data = [0x5AC3u16, 0x0FF0, 0x9669, 0x1234, 0xFEDC, 0xBA98, 0x7654];
+	let all = data.bits::<LittleEndian>();
+	for &(width, group) in &[(8, 4), (16, 8), (10, 3), (7, 7), (1, 1), (64, 5)] {
+		for start in 0 .. 5 {
+			for end in start .. all.len() {
+				let bits = &all[start .. end];
+				let text = format!("{}", bits.dump(width, group));
+				if bits.is_empty() {
+					assert!(text.is_empty());
+					continue;
+				}
+				let lines = text.split('\n').collect::<Vec<_>>();
+				assert_eq!(lines.len(), (bits.len() + width - 1) / width);
+				let label = lines.last().unwrap().find(':').unwrap();
+				let mut seen = Vec::new();
+				for (n, line) in lines.iter().enumerate() {
+					let (head, body) = line.split_at(label);
+					assert_eq!(head.trim_start().parse::<usize>(), Ok(n * width));
+					let groups = body[1 ..].split(' ').skip(1).collect::<Vec<_>>();
+					let row = width.min(bits.len() - n * width);
+					assert_eq!(groups.len(), (row + group - 1) / group);
+					for g in &groups[.. groups.len() - 1] {
+						assert_eq!(g.len(), group);
+					}
+					seen.extend(groups.concat().chars().map(|c| c == '1'));
+				}
+				assert_eq!(seen, bits.iter().collect::<Vec<_>>());
+			}
+		}
+	}
+}
+
+#[test]
+#[should_panic]
+fn zero_group() {
+	0u8.bits::<BigEndian>().dump(8, 0);
+}
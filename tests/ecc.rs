@@ -0,0 +1,89 @@
+/*! The block codes correct and detect the errors that they promise to.
+
+These tests encode every nibble, flip every single bit and every pair of bits in
+its code block, and check what each decoder reports.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	ecc::{
+		self,
+		EccError,
+	},
+	prelude::*,
+};
+
+type Bv = VecBit<BigEndian, u8>;
+
+fn flipped(code: &Bv, at: &[usize]) -> Bv {
+	let mut out = code.clone();
+	for &n in at {
+		let bit = out[n];
+		out.set(n, !bit);
+	}
+	out
+}
+
+#[test]
+fn hamming() {
+	for nibble in 0 .. 16u8 {
+		let data = &nibble.bits::<BigEndian>()[4 ..];
+		let code = ecc::hamming_encode(data);
+		assert_eq!(code.len(), 7);
+		assert_eq!(ecc::hamming_decode(&code), Ok((data.to_owned(), 0)));
+		for n in 0 .. 7 {
+			let bad = flipped(&code, &[n]);
+			assert_eq!(ecc::hamming_decode(&bad), Ok((data.to_owned(), 1)));
+		}
+	}
+	assert_eq!(
+		ecc::hamming_decode(&vecbit![BigEndian, u8; 0; 8]),
+		Err(EccError::Length { len: 8, block: 7 }),
+	);
+}
+
+#[test]
+fn secded() {
+	for nibble in 0 .. 16u8 {
+		let data = &nibble.bits::<BigEndian>()[4 ..];
+		let mut data = data.to_owned();
+		data.extend(data.clone());
+		let code = ecc::secded_encode(&data);
+		assert_eq!(code.len(), 16);
+		assert_eq!(ecc::secded_decode(&code), Ok((data.clone(), 0)));
+		for n in 0 .. 16 {
+			let bad = flipped(&code, &[n]);
+			assert_eq!(ecc::secded_decode(&bad), Ok((data.clone(), 1)));
+			for m in n + 1 .. 16 {
+				let bad = flipped(&code, &[n, m]);
+				let expected = if n / 8 == m / 8 {
+					Err(EccError::Uncorrectable(n / 8))
+				}
+				else {
+					Ok((data.clone(), 2))
+				};
+				assert_eq!(ecc::secded_decode(&bad), expected, "{} {}", n, m);
+			}
+		}
+	}
+}
+
+#[test]
+fn parity() {
+	let data = 0xA5C3u16.bits::<LittleEndian>();
+	let code = ecc::parity_encode(data, 4);
+	assert_eq!(code.len(), 20);
+	assert_eq!(code.count_ones() % 2, 0);
+	let code = code.iter().collect::<VecBit<BigEndian, u8>>();
+	let back = ecc::parity_decode(&code, 4).unwrap();
+	assert_eq!(back, data);
+	assert_eq!(
+		ecc::parity_decode(&flipped(&code, &[12]), 4),
+		Err(EccError::Uncorrectable(2)),
+	);
+	assert_eq!(
+		ecc::parity_decode(&code[1 ..], 4),
+		Err(EccError::Length { len: 19, block: 5 }),
+	);
+}
@@ -0,0 +1,58 @@
+/*! Masked comparison agrees with a bit-by-bit model.
+
+These tests compare `eq_ignoring` against a comparison of each masked index,
+for slices that share a starting bit index within their elements and for
+slices that do not, and for masks shorter than the slices.
+!*/
+
+use vecbit::prelude::*;
+
+fn model(
+	a: &SliceBit<LittleEndian, u16>,
+	b: &SliceBit<LittleEndian, u16>,
+	m: &SliceBit<LittleEndian, u16>,
+) -> bool {
+	a.len() == b.len()
+		&& (0 .. a.len().min(m.len())).all(|n| !m[n] || a[n] == b[n])
+}
+
+#[test]
+fn matches_model() {
+	let a = [0x5AC3u16, 0x0FF0, 0x9669, 0x1234];
+	let b = [0x5AC3u16, 0x0FF1, 0x9669, 0x1234];
+	let m = [0xFFFFu16, 0xFFFE, 0xFFFF, 0x0000];
+	let (a, b, m) = (
+		a.bits::<LittleEndian>(),
+		b.bits::<LittleEndian>(),
+		m.bits::<LittleEndian>(),
+	);
+	for start in 0 .. 20 {
+		for end in start .. 64 {
+			for shift in 0 .. 3 {
+				let x = &a[start .. end];
+				let y = &b[start .. end];
+				let mask = &m[start + shift .. 64];
+				assert_eq!(x.eq_ignoring(y, mask), model(x, y, mask));
+				let mask = &m[start .. end - shift.min(end - start)];
+				assert_eq!(x.eq_ignoring(y, mask), model(x, y, mask));
+				let y = &b[start + shift .. 64];
+				let y = &y[.. y.len().min(end - start)];
+				assert_eq!(x.eq_ignoring(y, mask), model(x, y, mask));
+			}
+		}
+	}
+}
+
+#[test]
+fn masked_difference() {
+	let a = [0u8; 4];
+	let mut b = [0u8; 4];
+	let mut mask = [0xFFu8; 4];
+	b.bits_mut::<BigEndian>().set(19, true);
+	let (x, y) = (a.bits::<BigEndian>(), b.bits::<BigEndian>());
+	assert!(!x.eq_ignoring(y, mask.bits()));
+	mask.bits_mut::<BigEndian>().set(19, false);
+	assert!(x.eq_ignoring(y, mask.bits()));
+	assert!(x[3 ..].eq_ignoring(&y[3 ..], &mask.bits()[3 ..]));
+	assert!(!x[3 ..].eq_ignoring(&y[3 ..], &mask.bits()[4 ..]));
+}
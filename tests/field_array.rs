@@ -0,0 +1,68 @@
+/*! Strided field views agree with loading each field by hand.
+
+These tests build tables of packed records, read and write one column through a
+field array, and compare against indexing the fields directly.
+!*/
+
+use vecbit::prelude::*;
+
+#[test]
+fn columns() {
+	let mut data = [0u32; 13];
+	let bits = data.bits_mut::<BigEndian>();
+	//  Records of 13 bits: a 4-bit kind, then a 9-bit length.
+	let count = bits.len() / 13;
+	{
+		let mut kinds = bits.field_array_mut(0, 4, 13);
+		assert_eq!(kinds.len(), count);
+		for n in 0 .. count {
+			kinds.set(n, n as u32 % 16);
+		}
+	}
+	{
+		let mut lens = bits.field_array_mut(4, 9, 13);
+		assert_eq!(lens.len(), count);
+		for n in 0 .. count {
+			lens.set(n, n as u32 * 37 + 1000);
+		}
+		assert_eq!(lens.get(count), None);
+	}
+	for n in 0 .. count {
+		let rec = &bits[n * 13 .. n * 13 + 13];
+		assert_eq!(rec[.. 4].load(), Some(n as u32 % 16));
+		assert_eq!(rec[4 ..].load(), Some((n as u32 * 37 + 1000) & 0x1FF));
+	}
+	let kinds = bits.field_array(0, 4, 13);
+	let expected = (0 .. count as u32).map(|n| n % 16).collect::<Vec<_>>();
+	assert_eq!(kinds.iter().collect::<Vec<_>>(), expected);
+	assert_eq!(
+		kinds.iter().rev().collect::<Vec<_>>(),
+		expected.iter().rev().cloned().collect::<Vec<_>>(),
+	);
+	assert_eq!(kinds.iter().len(), count);
+	assert_eq!(kinds.field(2), Some(&bits[26 .. 30]));
+}
+
+#[test]
+fn bounds() {
+	let data = [0xA5u8; 3];
+	let bits = data.bits::<LittleEndian>();
+	assert_eq!(bits.field_array(0, 8, 8).len(), 3);
+	assert_eq!(bits.field_array(1, 8, 8).len(), 2);
+	assert_eq!(bits.field_array(16, 8, 100).len(), 1);
+	assert!(bits.field_array(17, 8, 8).is_empty());
+	assert!(bits.field_array(30, 1, 1).is_empty());
+	assert_eq!(bits[.. 0].field_array(0, 1, 1).iter().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn overlapping() {
+	[0u8; 2].bits::<BigEndian>().field_array(0, 4, 3);
+}
+
+#[test]
+#[should_panic]
+fn too_wide() {
+	[0u8; 2].bits::<BigEndian>().field_array(0, 9, 9);
+}
@@ -0,0 +1,55 @@
+/*! Filling a preallocated slice from a bit iterator.
+
+These tests check that `SliceBit::fill_from_iter` writes exactly the bits that
+fit, reports the count, and leaves the rest of the slice and the iterator alone.
+!*/
+
+use vecbit::prelude::*;
+
+#[test]
+fn short_source() {
+	let mut buf = [0xFFFFu16; 2];
+	let bits = buf.bits_mut::<LittleEndian>();
+	let written = bits[3 ..].fill_from_iter([false, true, false].iter().cloned());
+	assert_eq!(written, 3);
+	assert_eq!(buf, [!0b0010_1000, 0xFFFF]);
+}
+
+#[test]
+fn long_source() {
+	let mut buf = [0u8; 3];
+	let mut src = (0 .. 100).map(|n| n % 2 == 1);
+	let bits = buf.bits_mut::<BigEndian>();
+	assert_eq!(bits[4 .. 20].fill_from_iter(src.by_ref()), 16);
+	assert_eq!(bits.as_ref(), &[0b0000_0101, 0x55, 0b0101_0000]);
+	//  Only the bits that fit were taken from the iterator.
+	assert_eq!(src.next(), Some(false));
+	assert_eq!(src.count(), 83);
+}
+
+#[test]
+fn chunks() {
+	let src = [0x12u8, 0x34, 0x56, 0x78, 0x9A];
+	let mut iter = src.bits::<BigEndian>().iter();
+	let mut buf = [0u16; 1];
+	let mut seen = 0;
+	loop {
+		let bits = buf.bits_mut::<BigEndian>();
+		let written = bits.fill_from_iter(iter.by_ref());
+		assert_eq!(bits[.. written], src.bits::<BigEndian>()[seen ..][.. written]);
+		seen += written;
+		if written < 16 {
+			break;
+		}
+	}
+	assert_eq!(seen, 40);
+}
+
+#[test]
+fn empty() {
+	let mut elt = 0u32;
+	let bits = elt.bits_mut::<Local>();
+	assert_eq!(bits[.. 0].fill_from_iter(std::iter::repeat(true)), 0);
+	assert_eq!(bits.fill_from_iter(std::iter::empty()), 0);
+	assert_eq!(elt, 0);
+}
@@ -0,0 +1,88 @@
+/*! Frame splitting does not depend on how the stream is chunked.
+
+A `FrameSplitter` must yield the same frames whether its input arrives in one
+piece or one bit at a time. These tests split known streams at every chunk size,
+and compare the frames against those cut out of the whole stream by hand.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	frames::FrameSplitter,
+	prelude::*,
+};
+
+fn split(
+	mut splitter: FrameSplitter<BigEndian, u8>,
+	stream: &SliceBit<BigEndian, u8>,
+	chunk: usize,
+) -> (Vec<VecBit<BigEndian, u8>>, Option<VecBit<BigEndian, u8>>) {
+	let mut frames = Vec::new();
+	for piece in stream.chunks(chunk) {
+		splitter.push(piece);
+		frames.extend(splitter.by_ref());
+	}
+	(frames, splitter.finish())
+}
+
+#[test]
+fn fixed() {
+	let data = [0x3Cu8, 0xA5, 0x0F, 0x96, 0x71];
+	let stream = data.bits::<BigEndian>();
+	for chunk in 1 ..= stream.len() {
+		let (frames, rest) = split(FrameSplitter::fixed(7), stream, chunk);
+		assert_eq!(frames.len(), 5, "Chunk size {}", chunk);
+		for (n, frame) in frames.iter().enumerate() {
+			assert_eq!(frame, &stream[n * 7 .. n * 7 + 7], "Chunk size {}", chunk);
+		}
+		assert_eq!(rest.unwrap(), &stream[35 ..], "Chunk size {}", chunk);
+	}
+}
+
+#[test]
+fn sync() {
+	let sync = vecbit![BigEndian, u8; 0, 1, 1, 1, 1, 1, 1, 0];
+	let mut stream = vecbit![BigEndian, u8; 1, 1, 1, 0, 1];
+	let bodies = [
+		vecbit![BigEndian, u8; 1, 0, 1],
+		vecbit![BigEndian, u8;],
+		vecbit![BigEndian, u8; 0, 0, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0],
+	];
+	let mut starts = Vec::new();
+	for body in &bodies {
+		starts.push(stream.len());
+		stream.extend(sync.iter());
+		stream.extend(body.iter());
+	}
+	for chunk in 1 ..= stream.len() {
+		let (frames, last) =
+			split(FrameSplitter::sync(sync.clone()), &stream, chunk);
+		assert_eq!(frames.len(), 2, "Chunk size {}", chunk);
+		for (n, frame) in frames.iter().enumerate() {
+			assert_eq!(
+				frame,
+				&stream[starts[n] .. starts[n + 1]],
+				"Chunk size {}",
+				chunk,
+			);
+		}
+		assert_eq!(last.unwrap(), &stream[starts[2] ..], "Chunk size {}", chunk);
+	}
+}
+
+#[test]
+fn sync_garbage() {
+	let sync = vecbit![BigEndian, u8; 1, 1, 0];
+	let mut splitter = FrameSplitter::sync(sync);
+	splitter.push(&vecbit![BigEndian, u8; 0, 0, 1, 0, 1, 0, 0, 1]);
+	assert!(splitter.next_frame().is_none());
+	//  Only the bits that may begin a sync word are kept.
+	assert_eq!(splitter.buffered(), &vecbit![BigEndian, u8; 0, 1][..]);
+	assert!(splitter.finish().is_none());
+}
+
+#[test]
+#[should_panic]
+fn empty_sync() {
+	FrameSplitter::<BigEndian, u8>::sync(VecBit::new());
+}
@@ -0,0 +1,67 @@
+/*! Growth policies decide the reallocations of `VecBit::reserve_with`.
+
+These tests reserve under each policy, and check the element capacity after
+each step against what the policy requests.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	prelude::*,
+	vec::Growth,
+};
+
+#[test]
+fn exact() {
+	let mut bv = VecBit::<LittleEndian, u16>::new();
+	for n in 1 ..= 100 {
+		bv.reserve_with(1, Growth::Exact);
+		assert_eq!(bv.capacity_elements(), (n + 15) / 16);
+		bv.push(n % 3 == 0);
+		assert_eq!(bv.capacity_elements(), (n + 15) / 16);
+	}
+	bv.reserve_with(40, Growth::Exact);
+	assert_eq!(bv.capacity_elements(), (140 + 15) / 16);
+	bv.extend((0 .. 40).map(|n| n % 2 == 0));
+	assert_eq!(bv.capacity_elements(), (140 + 15) / 16);
+	assert_eq!(bv.len(), 140);
+	assert_eq!(bv.count_ones(), 33 + 20);
+}
+
+#[test]
+fn custom() {
+	fn quarter(cap: usize, _: usize) -> usize {
+		cap + cap / 4 + 1
+	}
+	let mut bv = VecBit::<BigEndian, u8>::new();
+	let mut caps = vec![];
+	for _ in 0 .. 200 {
+		bv.reserve_with(1, Growth::Custom(quarter));
+		bv.push(true);
+		if caps.last() != Some(&bv.capacity_elements()) {
+			caps.push(bv.capacity_elements());
+		}
+	}
+	assert_eq!(caps, [1, 2, 3, 4, 6, 8, 11, 14, 18, 23, 29]);
+	assert!(bv.all());
+
+	let low = |_: usize, _: usize| 0;
+	bv.reserve_with(100, Growth::Custom(low));
+	assert_eq!(bv.capacity_elements(), 38);
+}
+
+#[test]
+fn doubling() {
+	let mut bv = VecBit::<BigEndian, u8>::new();
+	let mut grows = 0;
+	let mut cap = 0;
+	for _ in 0 .. 8000 {
+		bv.reserve_with(1, Growth::default());
+		bv.push(false);
+		if bv.capacity_elements() != cap {
+			cap = bv.capacity_elements();
+			grows += 1;
+		}
+	}
+	assert!(grows < 16);
+}
@@ -0,0 +1,78 @@
+/*! Block interleaving moves every bit where the matrix model says it goes.
+
+These tests compare the interleaver against a direct index computation, check
+that deinterleaving undoes it, and check that a burst of errors is spread out.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	interleave::Interleaver,
+	prelude::*,
+};
+
+type Bv = VecBit<BigEndian, u8>;
+
+fn sample(len: usize) -> Bv {
+	(0 .. len).map(|n| (n * 7 + n / 3) % 5 < 2).collect()
+}
+
+#[test]
+fn matches_model() {
+	for &(rows, cols, blocks) in &[(1, 1, 3), (2, 3, 2), (4, 5, 3), (7, 1, 1)] {
+		let order: Vec<usize> = (0 .. cols).rev().collect();
+		let il = Interleaver::new(rows, cols).with_column_order(order.clone());
+		let block = rows * cols;
+		let bits = sample(block * blocks);
+		let out = il.interleave(&bits);
+		assert_eq!(out.len(), bits.len());
+		for b in 0 .. blocks {
+			for (k, &col) in order.iter().enumerate() {
+				for row in 0 .. rows {
+					assert_eq!(
+						out[b * block + k * rows + row],
+						bits[b * block + row * cols + col],
+					);
+				}
+			}
+		}
+		assert_eq!(il.deinterleave(&out), bits);
+	}
+}
+
+#[test]
+fn round_trip_unaligned() {
+	let bits = sample(40);
+	let part = &bits[3 .. 39];
+	assert_eq!(part.interleave(4, 3).deinterleave(4, 3), part);
+	assert_eq!(part.deinterleave(6, 6).interleave(6, 6), part);
+	assert!(Bv::new().interleave(2, 2).is_empty());
+}
+
+#[test]
+fn spreads_bursts() {
+	let (rows, cols) = (8, 8);
+	let mut sent = Bv::new();
+	sent.resize(rows * cols, false);
+	let mut recv = sent.interleave(rows, cols);
+	for n in 20 .. 20 + rows {
+		recv.set(n, true);
+	}
+	let errors = recv.deinterleave(rows, cols);
+	for row in errors.chunks(cols) {
+		assert!(row.count_ones() <= 1);
+	}
+	assert_eq!(errors.count_ones(), rows);
+}
+
+#[test]
+#[should_panic]
+fn partial_block() {
+	sample(10).interleave(3, 3);
+}
+
+#[test]
+#[should_panic]
+fn not_a_permutation() {
+	Interleaver::new(2, 3).with_column_order(vec![0, 2, 2]);
+}
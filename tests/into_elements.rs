@@ -0,0 +1,32 @@
+/*! Consuming a vector by element yields its canonical storage.
+
+These tests compare `into_elements` against `canonicalize` followed by
+`into_vec`, after edits that leave stale values in the dead bits.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+
+#[test]
+fn masks_dead_bits() {
+	for len in 0 .. 40 {
+		let mut bv = vecbit![LittleEndian, u16; 1; 40];
+		bv.truncate(len);
+		let mut canon = bv.clone();
+		canon.canonicalize(false);
+		let elts = bv.into_elements();
+		assert_eq!(elts.len(), (len + 15) / 16);
+		assert_eq!(elts.collect::<Vec<_>>(), canon.into_vec());
+	}
+}
+
+#[test]
+fn both_ends() {
+	let mut bv = vecbit![BigEndian, u8; 1; 30];
+	bv.truncate(19);
+	let mut elts = bv.into_elements();
+	assert_eq!(elts.next_back(), Some(0b1110_0000));
+	assert_eq!(elts.nth(1), Some(0xFF));
+	assert!(elts.next().is_none());
+}
@@ -0,0 +1,79 @@
+/*! Element-walking iterator consumers agree with bit-by-bit iteration.
+
+These tests compare `fold`, `all`, `any`, and `position` on `Iter` against the
+same computation driven through `next`, across unaligned slices that start and
+end in the middle of elements, and check where the iterator resumes afterward.
+!*/
+
+use vecbit::prelude::*;
+
+fn model_position(bits: &SliceBit<BigEndian, u16>, bit: bool) -> Option<usize> {
+	let mut n = 0;
+	let mut iter = bits.iter();
+	while let Some(b) = iter.next() {
+		if b == bit {
+			return Some(n);
+		}
+		n += 1;
+	}
+	None
+}
+
+#[test]
+fn fold() {
+	let data = [0x5AC3u16, 0x0FF0, 0x9669, 0x1234];
+	let all = data.bits::<BigEndian>();
+	for start in 0 .. 20 {
+		for end in start .. all.len() {
+			let bits = &all[start .. end];
+			let mut model = Vec::new();
+			let mut iter = bits.iter();
+			while let Some(bit) = iter.next() {
+				model.push(bit);
+			}
+			let folded = bits.iter().fold(Vec::new(), |mut acc, bit| {
+				acc.push(bit);
+				acc
+			});
+			assert_eq!(folded, model);
+			let mut iter = bits.iter();
+			iter.next();
+			iter.next_back();
+			let rest = iter.fold(0, |acc, bit| acc + bit as usize);
+			let expected = model.iter()
+				.skip(1)
+				.take(model.len().saturating_sub(2))
+				.filter(|&&b| b)
+				.count();
+			assert_eq!(rest, expected);
+		}
+	}
+}
+
+#[test]
+fn searches() {
+	let data = [0x0000u16, 0x0100, 0xFFFF, 0xFEFF];
+	let all = data.bits::<BigEndian>();
+	for start in 0 .. 20 {
+		for end in start .. all.len() {
+			let bits = &all[start .. end];
+			for &bit in &[false, true] {
+				let found = model_position(bits, bit);
+
+				let mut iter = bits.iter();
+				assert_eq!(iter.position(|b| b == bit), found);
+				let resume = found.map_or(bits.len(), |n| n + 1);
+				assert_eq!(iter.len(), bits.len() - resume);
+				assert!(iter.eq(bits[resume ..].iter()));
+
+				let mut iter = bits.iter();
+				assert_eq!(iter.any(|b| b == bit), found.is_some());
+				assert_eq!(iter.len(), bits.len() - resume);
+
+				let mut iter = bits.iter();
+				assert_eq!(iter.all(|b| b != bit), found.is_none());
+				assert_eq!(iter.len(), bits.len() - resume);
+			}
+		}
+	}
+}
@@ -0,0 +1,92 @@
+/*! Runtime layouts read and write the fields of a real protocol header.
+
+These tests describe the first word of an IPv4 header with a `BitLayout`, and
+check field access against the header’s known byte values, under a cursor that
+does not match the fields’ bit order.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	layout::{
+		BitLayout,
+		Field,
+		FieldValue,
+		LayoutError,
+	},
+	prelude::*,
+};
+
+fn ipv4() -> BitLayout {
+	BitLayout::from_fields(vec![
+		Field::unsigned("version", 0, 4),
+		Field::unsigned("ihl", 4, 4),
+		Field::unsigned("dscp", 8, 6),
+		Field::unsigned("ecn", 14, 2),
+		Field::unsigned("length", 16, 16),
+	]).unwrap()
+}
+
+#[test]
+fn header() {
+	let layout = ipv4();
+	assert_eq!(layout.len(), 32);
+	let mut header = VecBit::<BigEndian, u8>::from_vec(vec![0x45, 0xB8, 0x05, 0xDC]);
+	assert_eq!(layout.get(&header, "version"), Ok(FieldValue::Unsigned(4)));
+	assert_eq!(layout.get(&header, "dscp"), Ok(FieldValue::Unsigned(46)));
+	assert_eq!(layout.get(&header, "length"), Ok(FieldValue::Unsigned(1500)));
+
+	layout.set(&mut header, "length", 40u64).unwrap();
+	layout.set(&mut header, "ecn", 3u64).unwrap();
+	assert_eq!(header.as_slice(), &[0x45, 0xBB, 0x00, 0x28]);
+
+	//  The field values do not depend on the cursor.
+	let other = header.iter().collect::<VecBit<LittleEndian, u16>>();
+	assert!(layout.values(&header).eq(layout.values(&other)));
+}
+
+#[test]
+fn errors() {
+	let mut layout = ipv4();
+	assert_eq!(
+		layout.insert(Field::signed("ecn", 40, 3)),
+		Err(LayoutError::Duplicate("ecn".to_string())),
+	);
+	let mut bits = vecbit![BigEndian, u8; 0; 20];
+	assert_eq!(
+		layout.get(&bits, "ttl"),
+		Err(LayoutError::Unknown("ttl".to_string())),
+	);
+	assert_eq!(
+		layout.get(&bits, "length"),
+		Err(LayoutError::OutOfBounds { end: 32, len: 20 }),
+	);
+	assert_eq!(
+		layout.set(&mut bits, "ecn", 4u64),
+		Err(LayoutError::Overflow("ecn".to_string())),
+	);
+	assert_eq!(
+		layout.set(&mut bits, "ecn", -1i64),
+		Err(LayoutError::Overflow("ecn".to_string())),
+	);
+	assert!(bits.not_any());
+}
+
+#[test]
+fn signed() {
+	let wide = Field::signed("wide", 0, 64);
+	let narrow = Field::signed("narrow", 60, 4);
+	let mut bits = vecbit![LittleEndian, u64; 0; 64];
+	for &v in &[i64::min_value(), -1, 0, 1, i64::max_value()] {
+		wide.write(&mut bits, v.into()).unwrap();
+		assert_eq!(wide.read(&bits), Ok(FieldValue::Signed(v)));
+	}
+	for v in -8 .. 8i64 {
+		narrow.write(&mut bits, v.into()).unwrap();
+		assert_eq!(narrow.read(&bits), Ok(FieldValue::Signed(v)));
+	}
+	assert!(narrow.write(&mut bits, 8i64.into()).is_err());
+	assert!(narrow.write(&mut bits, (-9i64).into()).is_err());
+	assert!(narrow.write(&mut bits, 7u64.into()).is_ok());
+	assert!(narrow.write(&mut bits, 8u64.into()).is_err());
+}
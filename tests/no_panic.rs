@@ -0,0 +1,29 @@
+/*! Release builds with the `no_panic` feature do not panic in hot paths.
+
+Debug builds keep their bounds checks as `debug_assert!`s, so these tests only
+run in release builds, where out-of-bounds writes are ignored and out-of-bounds
+reads produce `false`.
+!*/
+
+#![cfg(all(feature = "no_panic", not(debug_assertions)))]
+
+use vecbit::prelude::*;
+
+#[test]
+fn out_of_bounds() {
+	let mut data = [0u8; 2];
+	{
+		let bits = &mut data.bits_mut::<BigEndian>()[3 .. 9];
+		bits.set(6, true);
+		bits.set(100, true);
+		assert!(!bits[6]);
+		assert!(!bits[!0]);
+		bits.set(5, true);
+		assert!(bits[5]);
+	}
+	assert_eq!(data, [0, 0b1000_0000]);
+
+	let bv = vecbit![1; 10];
+	assert!(bv[9]);
+	assert!(!bv[10]);
+}
@@ -0,0 +1,56 @@
+/*! Conversions between the owning handles and boxed slices keep the allocation.
+
+Each conversion that is documented as not reallocating is checked by comparing
+the address of the first element before and after.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+
+#[test]
+fn boxed_slice_to_bitbox() {
+	let boxed: Box<[u16]> = vec![1, 2, 3].into_boxed_slice();
+	let addr = boxed.as_ptr();
+	let bb = BitBox::<LittleEndian, u16>::from_boxed_slice(boxed);
+	assert_eq!(bb.as_slice().as_ptr(), addr);
+	let boxed = bb.into_boxed_slice();
+	assert_eq!(boxed.as_ptr(), addr);
+	assert_eq!(&*boxed, &[1, 2, 3]);
+}
+
+#[test]
+fn boxed_slice_to_vecbit() {
+	let boxed: Box<[u32]> = vec![!0; 4].into_boxed_slice();
+	let addr = boxed.as_ptr();
+	let bv = VecBit::<BigEndian, u32>::from(boxed);
+	assert_eq!(bv.as_slice().as_ptr(), addr);
+	assert_eq!(bv.capacity(), 128);
+	let boxed: Box<[u32]> = bv.into();
+	assert_eq!(boxed.as_ptr(), addr);
+}
+
+#[test]
+fn bitbox_round_trip() {
+	let bb = bitbox![BigEndian, u8; 1; 20];
+	let addr = bb.as_slice().as_ptr();
+	let mut bv = VecBit::from(bb);
+	assert_eq!(bv.as_slice().as_ptr(), addr);
+	bv.set(3, false);
+	let bb = BitBox::from(bv);
+	assert_eq!(bb.as_slice().as_ptr(), addr);
+	assert_eq!(bb.len(), 20);
+	assert!(!bb[3]);
+}
+
+#[test]
+fn freeze_with_spare_capacity() {
+	//  Freezing a vector with spare capacity may move it, and the frozen box
+	//  must follow the move.
+	let mut bv = VecBit::<LittleEndian, u8>::with_capacity(4096);
+	bv.extend(vec![true; 10]);
+	let bb = bv.into_boxed_bitslice();
+	assert_eq!(bb.len(), 10);
+	assert!(bb.all());
+	assert_eq!(bb.as_slice().len(), 2);
+}
@@ -0,0 +1,82 @@
+/*! Packed symbol vectors behave like vectors of `u8`.
+
+These tests drive a `PackedVec` and a `Vec<u8>` through the same edits, and
+check that every read agrees, for both symbol widths.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::packed::{
+	PackedVec,
+	SymbolWidth,
+	Vec2,
+	Vec4,
+};
+
+fn exercise<W>()
+where W: SymbolWidth {
+	let limit = 1u8 << W::BITS;
+	let mut model = (0 .. 37u8).map(|n| n * 7 % limit).collect::<Vec<_>>();
+	let mut packed = model.iter().cloned().collect::<PackedVec<W>>();
+	assert_eq!(packed.len(), model.len());
+	assert_eq!(packed.as_bits().len(), model.len() * W::BITS as usize);
+
+	for n in (0 .. model.len()).step_by(3) {
+		let value = (n as u8 + 1) % limit;
+		model[n] = value;
+		packed.set(n, value);
+	}
+	packed.push(limit - 1);
+	model.push(limit - 1);
+	assert_eq!(packed.pop(), model.pop());
+	packed.extend(vec![1, 0, 1]);
+	model.extend(vec![1, 0, 1]);
+
+	assert_eq!(packed.iter().collect::<Vec<_>>(), model);
+	for (n, &value) in model.iter().enumerate() {
+		assert_eq!(packed.get(n), Some(value));
+	}
+	assert_eq!(packed.get(model.len()), None);
+
+	let part = packed.slice(5 .. 20);
+	assert_eq!(part.len(), 15);
+	assert_eq!(part.iter().collect::<Vec<_>>(), &model[5 .. 20]);
+	assert_eq!(part.slice(3 ..).get(0), Some(model[8]));
+	assert_eq!(
+		part.to_packed_vec().iter().collect::<Vec<_>>(),
+		&model[5 .. 20],
+	);
+
+	let bits = packed.clone().into_bits();
+	assert_eq!(PackedVec::<W>::from_bits(bits), Some(packed));
+}
+
+#[test]
+fn bits2() {
+	exercise::<vecbit::packed::Bits2>();
+}
+
+#[test]
+fn bits4() {
+	exercise::<vecbit::packed::Bits4>();
+}
+
+#[test]
+fn layout() {
+	let hex = vec![0xD, 0xE, 0xA, 0xD].into_iter().collect::<Vec4>();
+	assert_eq!(hex.as_bits().as_slice(), &[0xDE, 0xAD]);
+	assert_eq!(format!("{:?}", hex.slice(1 ..= 2)), "[14, 10]");
+
+	let mut bits = hex.into_bits();
+	bits.pop();
+	assert!(Vec4::from_bits(bits.clone()).is_none());
+	bits.pop();
+	assert_eq!(Vec2::from_bits(bits).map(|v| v.len()), Some(7));
+	assert!(Vec2::new().pop().is_none());
+}
+
+#[test]
+#[should_panic]
+fn overflow() {
+	Vec2::new().push(4);
+}
@@ -0,0 +1,151 @@
+/*! Patches reproduce the new sequence, including through serialization.
+
+These tests diff pseudo-random pairs of sequences of differing lengths and
+alignments, apply each patch both directly and after a round trip through its
+byte form, and check that the result matches the new sequence exactly.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	patch::{
+		BitPatch,
+		PatchError,
+	},
+	prelude::*,
+};
+
+/// Produces a deterministic stream of pseudo-random words.
+fn words(seed: u64) -> impl Iterator<Item = u64> {
+	let mut state = seed;
+	std::iter::repeat_with(move || {
+		state = state
+			.wrapping_mul(6_364_136_223_846_793_005)
+			.wrapping_add(1_442_695_040_888_963_407);
+		state
+	})
+}
+
+#[test]
+fn round_trip() {
+	let mut rng = words(0x5EED);
+	for _ in 0 .. 200 {
+		let old = rng.by_ref().take(4).collect::<VecBit<BigEndian, u64>>();
+		let mut new = old.clone();
+		//  Flip a few scattered bits and a short run.
+		for _ in 0 .. (rng.next().unwrap() % 6) {
+			let at = rng.next().unwrap() as usize % new.len();
+			let bit = new[at];
+			new.set(at, !bit);
+		}
+		let from = rng.next().unwrap() as usize % 64;
+		let to = new.len() - rng.next().unwrap() as usize % 64;
+		let old = &old[from ..];
+		let new = &new[.. to];
+
+		let patch = old.diff(new);
+		let mut copy = old.to_owned();
+		copy.apply(&patch).unwrap();
+		assert_eq!(copy, new);
+
+		let bytes = patch.to_bytes();
+		let back = BitPatch::from_bytes(&bytes).unwrap();
+		assert_eq!(back, patch);
+		let mut copy = old.to_owned();
+		copy.apply(&back).unwrap();
+		assert_eq!(copy, new);
+	}
+}
+
+#[test]
+fn small() {
+	let bits = vecbit![LittleEndian, u8; 0; 4096];
+	let mut next = bits.clone();
+	next.set(1000, true);
+	next.set(1010, true);
+	next.set(3000, true);
+	let patch = bits.diff(&next);
+	let ranges = patch.changed_ranges().collect::<Vec<_>>();
+	assert_eq!(ranges, [1000 .. 1011, 3000 .. 3001]);
+	assert_eq!(patch.count_changed(), 3);
+	assert!(patch.to_bytes().len() < 16);
+	assert!(bits.diff(&bits).is_empty());
+}
+
+#[test]
+fn errors() {
+	let patch = vecbit![0, 1, 1].diff(&vecbit![1, 1]);
+	let mut short = vecbit![0, 1];
+	assert_eq!(
+		short.apply(&patch),
+		Err(PatchError::Length { expected: 3, found: 2 }),
+	);
+	assert_eq!(short, vecbit![0, 1]);
+
+	let mut bytes = patch.to_bytes();
+	bytes.push(0);
+	assert_eq!(BitPatch::from_bytes(&bytes), Err(PatchError::Trailing));
+	bytes[0] = 9;
+	assert_eq!(BitPatch::from_bytes(&bytes), Err(PatchError::Version(9)));
+	//  A hunk that runs past the new length.
+	assert_eq!(
+		BitPatch::from_bytes(&[1, 2, 2, 1, 1, 2, 0]),
+		Err(PatchError::Range),
+	);
+	//  A length wider than 64 bits.
+	let mut huge = vec![1];
+	huge.extend_from_slice(&[0xFF; 9]);
+	huge.push(0x7F);
+	assert_eq!(BitPatch::from_bytes(&huge), Err(PatchError::Overflow));
+}
+
+/// Appends `value` to `out` in the patch's variable-length integer form.
+fn push_uint(out: &mut Vec<u8>, mut value: usize) {
+	while value >= 0x80 {
+		out.push(value as u8 | 0x80);
+		value >>= 7;
+	}
+	out.push(value as u8);
+}
+
+#[test]
+fn malformed_lengths() {
+	let max_bits = !0usize >> 3;
+
+	//  A new length that no sequence can have.
+	for &len in &[max_bits + 1, !0] {
+		let mut bytes = vec![1];
+		push_uint(&mut bytes, 0);
+		push_uint(&mut bytes, len);
+		push_uint(&mut bytes, 0);
+		assert_eq!(BitPatch::from_bytes(&bytes), Err(PatchError::Overflow));
+	}
+
+	//  An old length that no sequence can have.
+	let mut bytes = vec![1];
+	push_uint(&mut bytes, !0);
+	push_uint(&mut bytes, 0);
+	push_uint(&mut bytes, 0);
+	assert_eq!(BitPatch::from_bytes(&bytes), Err(PatchError::Overflow));
+
+	//  A hunk claiming every possible bit, without the data to back it.
+	let mut bytes = vec![1];
+	push_uint(&mut bytes, 0);
+	push_uint(&mut bytes, max_bits);
+	push_uint(&mut bytes, 1);
+	push_uint(&mut bytes, 0);
+	push_uint(&mut bytes, max_bits);
+	assert_eq!(BitPatch::from_bytes(&bytes), Err(PatchError::Truncated));
+
+	//  A hunk whose start overflows after a previous hunk.
+	let mut bytes = vec![1];
+	push_uint(&mut bytes, 0);
+	push_uint(&mut bytes, max_bits);
+	push_uint(&mut bytes, 2);
+	push_uint(&mut bytes, 0);
+	push_uint(&mut bytes, 8);
+	bytes.push(0xFF);
+	push_uint(&mut bytes, !0);
+	push_uint(&mut bytes, 1);
+	assert_eq!(BitPatch::from_bytes(&bytes), Err(PatchError::Overflow));
+}
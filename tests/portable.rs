@@ -0,0 +1,48 @@
+/*! The portable aliases name the same types on every target.
+
+Data written under `Portable` and `PortableWord` must have the same bytes on
+every platform, while data written under `Local` and `Word` must be rejected
+by readers whose aliases name other types, rather than misread.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+use vecbit::snapshot::SnapshotError;
+
+#[test]
+fn numeric_order() {
+	let bits = 0b0000_1101u8.bits::<Portable>();
+	assert!(bits[0] && !bits[1] && bits[2] && bits[3]);
+	assert_eq!(bits.count_ones(), 3);
+}
+
+#[test]
+fn snapshot_bytes() {
+	let bv = vecbit![Portable, PortableWord; 1, 0, 1, 1, 0, 0, 0, 0, 1];
+	let mut expected = b"VBIT\x01\x0CLittleEndian\x08".to_vec();
+	expected.extend_from_slice(&[9, 0, 0, 0, 0, 0, 0, 0]);
+	expected.extend_from_slice(&[0x0D, 0x01]);
+	assert_eq!(bv.to_snapshot(), expected);
+	assert_eq!(
+		VecBit::<Portable, PortableWord>::from_snapshot(&expected),
+		Ok(bv),
+	);
+}
+
+#[test]
+fn local_is_checked() {
+	let snap = vecbit![Local, Word; 0, 1, 1].to_snapshot();
+	assert!(VecBit::<Local, Word>::from_snapshot(&snap).is_ok());
+	assert_eq!(
+		VecBit::<Local, PortableWord>::from_snapshot(&snap),
+		Err(SnapshotError::Width { found: Word::BITS, expected: 8 }),
+	);
+	let other = if Local::TYPENAME == "BigEndian" {
+		VecBit::<LittleEndian, Word>::from_snapshot(&snap).map(drop)
+	}
+	else {
+		VecBit::<BigEndian, Word>::from_snapshot(&snap).map(drop)
+	};
+	assert_eq!(other, Err(SnapshotError::Cursor));
+}
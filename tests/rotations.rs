@@ -0,0 +1,54 @@
+/*! Rotation queries agree with rotating a copy.
+
+These tests compare the rotation methods against a model that tries every
+rotation of a copy in turn, over every pattern of a few short lengths.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+
+/// Produces every rotation of a slice, by index.
+fn rotations(bits: &SliceBit<BigEndian, u8>) -> Vec<VecBit<BigEndian, u8>> {
+	(0 .. bits.len().max(1)).map(|by| {
+		let mut out = bits.to_owned();
+		out.rotate_left(by);
+		out
+	}).collect()
+}
+
+#[test]
+fn is_rotation_of() {
+	for len in 0 ..= 7 {
+		for a in 0 .. 1u16 << len {
+			let a = &a.bits::<LittleEndian>()[.. len];
+			let a = a.iter().collect::<VecBit<BigEndian, u8>>();
+			let all = rotations(&a);
+			for b in 0 .. 1u8 << len {
+				let b = &b.bits::<BigEndian>()[8 - len ..];
+				assert_eq!(
+					a.is_rotation_of(b),
+					all.iter().any(|r| r == b),
+					"{} and {}",
+					a,
+					b,
+				);
+			}
+		}
+	}
+	assert!(!vecbit![0, 1].is_rotation_of(&vecbit![0, 1, 0]));
+}
+
+#[test]
+fn min_rotation() {
+	for len in 0 ..= 10 {
+		for a in 0 .. 1u16 << len {
+			let a = &a.bits::<LittleEndian>()[.. len];
+			let a = a.iter().collect::<VecBit<BigEndian, u8>>();
+			let all = rotations(&a);
+			let least = all.iter().min().unwrap();
+			let by = all.iter().position(|r| r == least).unwrap();
+			assert_eq!(a.min_rotation(), by, "{}", a);
+		}
+	}
+}
@@ -16,11 +16,24 @@ use vecbit::prelude::*;
 fn serdes() {
 	let bv = vecbit![BigEndian, u8; 1, 0, 1, 1, 0, 0, 1, 0];
 	let json = serde_json::to_string(&bv).expect("cannot fail to serialize");
-	assert_eq!(json.trim(), r#"{"head":0,"bits":8,"data":[178]}"#);
+	assert_eq!(json.trim(), r#""10110010""#);
 
 	let bb: BitBox<BigEndian, u8> = serde_json::from_str(&json)
 		.expect("cannot fail to deserialize");
 
 	assert!(bb[0]);
 	assert_eq!(bb.as_slice()[0], 178);
+
+	//  Hand-edited strings may group their digits.
+	let bv: VecBit<LittleEndian, u16> = serde_json::from_str(r#""1011_0010_1""#)
+		.expect("cannot fail to deserialize");
+	assert_eq!(bv, vecbit![1, 0, 1, 1, 0, 0, 1, 0, 1]);
+
+	//  The structured form is still accepted.
+	let bb: BitBox<BigEndian, u8> =
+		serde_json::from_str(r#"{"head":0,"bits":8,"data":[178]}"#)
+			.expect("cannot fail to deserialize");
+	assert_eq!(bb.as_slice()[0], 178);
+
+	assert!(serde_json::from_str::<VecBit>(r#""0120""#).is_err());
 }
@@ -0,0 +1,57 @@
+/*! Shared bit handles divide one buffer among threads without copying.
+
+`SharedBits` handles are cloned and narrowed by adjusting a reference count and
+a region. These tests check that every narrowing sees the right bits, that the
+handles share one buffer, and that they can be sent to other threads.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	prelude::*,
+	shared::SharedBits,
+};
+
+use std::thread;
+
+#[test]
+fn regions() {
+	let src = [0x5Au8, 0xC3, 0x0F, 0xF0];
+	let bits = src.bits::<BigEndian>();
+	let all = SharedBits::from(bits);
+	for from in 0 .. bits.len() {
+		for upto in from .. bits.len() + 1 {
+			let part = all.slice(from .. upto);
+			assert_eq!(part, bits[from .. upto]);
+			let mut front = part.clone();
+			let mid = (upto - from) / 2;
+			let back = front.split_off(mid);
+			assert_eq!(front, bits[from .. from + mid]);
+			assert_eq!(back, bits[from + mid .. upto]);
+			let mut rest = part.clone();
+			let head = rest.split_to(mid);
+			assert!(SharedBits::ptr_eq(&head, &front));
+			assert!(SharedBits::ptr_eq(&rest, &back));
+		}
+	}
+	assert!(!SharedBits::ptr_eq(&all, &SharedBits::from(bits)));
+}
+
+#[test]
+fn across_threads() {
+	let mut stream = SharedBits::from(vecbit![LittleEndian, u32; 1; 4096]);
+	let workers = (0 .. 8).map(|_| {
+		let part = stream.split_to(512);
+		thread::spawn(move || part.count_ones())
+	}).collect::<Vec<_>>();
+	assert!(stream.is_empty());
+	let total = workers.into_iter().map(|w| w.join().unwrap()).sum::<usize>();
+	assert_eq!(total, 4096);
+}
+
+#[test]
+#[should_panic]
+fn slice_out_of_bounds() {
+	let bits = SharedBits::from(vecbit![BigEndian, u8; 0; 10]);
+	bits.slice(4 .. 11);
+}
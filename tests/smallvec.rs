@@ -0,0 +1,86 @@
+/*! Small bit vectors hold short contents inline, and spill to the heap.
+
+These tests check that a `SmallVecBit` keeps the same bits through spilling and
+shrinking, and that it behaves as a `VecBit` with the same contents.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+
+type Small = SmallVecBit<BigEndian, [u8; 2]>;
+
+#[test]
+fn inline_capacity() {
+	assert_eq!(Small::inline_capacity(), 16);
+	assert_eq!(SmallVecBit::<LittleEndian, u64>::inline_capacity(), 64);
+	let bv = Small::new();
+	assert!(!bv.spilled());
+	assert_eq!(bv.capacity(), 16);
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn spill_and_shrink() {
+	let pattern = |n: usize| n % 3 == 0;
+	let mut bv = Small::new();
+	let mut model = VecBit::<BigEndian, u8>::new();
+	for n in 0 .. 40 {
+		bv.push(pattern(n));
+		model.push(pattern(n));
+		assert_eq!(bv.spilled(), n >= 16);
+		assert_eq!(bv, model);
+	}
+	assert!(bv.capacity() >= 40);
+
+	bv.truncate(10);
+	model.truncate(10);
+	assert!(bv.spilled());
+	bv.shrink_to_fit();
+	assert!(!bv.spilled());
+	assert_eq!(bv, model);
+
+	assert_eq!(bv.pop(), model.pop());
+	assert_eq!(bv.pop(), model.pop());
+	assert_eq!(bv.len(), 8);
+	bv.clear();
+	assert_eq!(bv.pop(), None);
+}
+
+#[test]
+fn bulk() {
+	let src = [0xA5u8, 0x3C, 0x0F];
+	let bits = src.bits::<BigEndian>();
+
+	let inline = Small::from_bitslice(&bits[.. 12]);
+	assert!(!inline.spilled());
+	assert_eq!(inline, &bits[.. 12]);
+
+	let mut bv = Small::from(&bits[4 .. 12]);
+	bv.extend_from_bitslice(&bits[12 ..]);
+	assert!(bv.spilled());
+	assert_eq!(bv, &bits[4 ..]);
+
+	bv.resize(24, true);
+	assert_eq!(bv[20 ..].count_ones(), 4);
+	bv.resize(4, false);
+	assert_eq!(bv, &bits[4 .. 8]);
+
+	let collected = bits.iter().collect::<Small>();
+	assert_eq!(collected.clone().into_vecbit(), bits);
+	assert_eq!(
+		format!("{:?}", collected),
+		format!("SmallVecBit<BigEndian, u8> {}", bits),
+	);
+}
+
+#[test]
+fn mutate_inline() {
+	let mut bv = Small::new();
+	bv.resize(12, false);
+	bv.set(3, true);
+	bv[8 ..].set_all(true);
+	assert!(!bv.spilled());
+	assert_eq!(bv.count_ones(), 5);
+	assert_eq!(bv.into_vecbit().as_slice(), &[0b0001_0000, 0xF0]);
+}
@@ -0,0 +1,71 @@
+/*! Snapshots have a fixed byte layout, and are checked strictly on load.
+
+The layout must not depend on the platform, so these tests compare against the
+exact bytes that the format specifies.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::prelude::*;
+use vecbit::snapshot::SnapshotError;
+
+#[test]
+fn layout() {
+	let bv = vecbit![LittleEndian, u16; 1, 1, 0, 0, 0, 0, 0, 0, 0, 1];
+	let snap = bv.to_snapshot();
+	let mut expected = b"VBIT\x01\x0CLittleEndian\x10".to_vec();
+	expected.extend_from_slice(&[10, 0, 0, 0, 0, 0, 0, 0]);
+	expected.extend_from_slice(&[0x03, 0x02]);
+	assert_eq!(snap, expected);
+	assert_eq!(VecBit::<LittleEndian, u16>::from_snapshot(&snap), Ok(bv));
+}
+
+#[test]
+fn empty() {
+	let snap = VecBit::<BigEndian, u32>::new().to_snapshot();
+	assert_eq!(snap.len(), 4 + 1 + 1 + 9 + 1 + 8);
+	assert!(VecBit::<BigEndian, u32>::from_snapshot(&snap).unwrap().is_empty());
+}
+
+#[test]
+fn rejects() {
+	let snap = vecbit![BigEndian, u8; 1, 0, 1].to_snapshot();
+	let load = |bytes: &[u8]| VecBit::<BigEndian, u8>::from_snapshot(bytes);
+
+	assert_eq!(load(b"VBOT"), Err(SnapshotError::Magic));
+	assert_eq!(load(b"VB"), Err(SnapshotError::Truncated));
+
+	let mut bad = snap.clone();
+	bad[4] = 9;
+	assert_eq!(load(&bad), Err(SnapshotError::Version(9)));
+
+	let mut bad = snap.clone();
+	*bad.last_mut().unwrap() |= 1;
+	assert_eq!(load(&bad), Err(SnapshotError::DeadBits));
+
+	let mut bad = snap.clone();
+	bad.push(0);
+	assert_eq!(load(&bad), Err(SnapshotError::Trailing));
+
+	let mut bad = snap.clone();
+	let at = bad.len() - 9;
+	bad[at .. at + 8].copy_from_slice(&[0xFF; 8]);
+	assert_eq!(load(&bad), Err(SnapshotError::Length(!0)));
+}
+
+#[test]
+fn layout_tags() {
+	use vecbit::cursor::Reversed;
+
+	let bv = vecbit![Reversed<LittleEndian>, u8; 1, 1, 0, 1];
+	let snap = bv.to_snapshot();
+	assert_eq!(&snap[5 .. 15], b"\x09BigEndian");
+	let load = VecBit::<BigEndian, u8>::from_snapshot(&snap).unwrap();
+	assert_eq!(load, bv);
+	let load = VecBit::<Reversed<LittleEndian>, u8>::from_snapshot(&snap);
+	assert_eq!(load, Ok(bv));
+	assert_eq!(
+		VecBit::<Reversed<BigEndian>, u8>::from_snapshot(&snap),
+		Err(SnapshotError::Cursor),
+	);
+}
@@ -0,0 +1,55 @@
+/*! Fallible constructors report overflow and allocator failure as errors.
+
+Requests beyond the `BitPtr` limits must be rejected before allocating, and
+requests within the limits but beyond the address space must come back from the
+allocator as errors rather than aborting the process.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	prelude::*,
+	vec::CapacityError,
+};
+
+#[test]
+fn within_limits() {
+	for &bits in &[0, 1, 63, 64, 65, 1000] {
+		let mut bv = VecBit::<LittleEndian, u64>::try_with_capacity(bits)
+			.unwrap();
+		assert!(bv.is_empty());
+		assert!(bv.capacity() >= bits);
+		let cap = bv.capacity_elements();
+		bv.extend((0 .. bits).map(|n| n % 2 == 0));
+		assert_eq!(bv.capacity_elements(), cap);
+		assert_eq!(bv.count_ones(), (bits + 1) / 2);
+	}
+	let bv = VecBit::<BigEndian, u16>::try_with_capacity_in_elements(3).unwrap();
+	assert!(bv.capacity_elements() >= 3);
+	let bv = VecBit::<BigEndian, u16>::try_from_vec(vec![1, 2, 3]).unwrap();
+	assert_eq!(bv.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn overflow() {
+	assert_eq!(
+		VecBit::<BigEndian, u8>::try_with_capacity(!0).err(),
+		Some(CapacityError::Overflow),
+	);
+	assert_eq!(
+		VecBit::<BigEndian, u32>::try_with_capacity_in_elements(!0).err(),
+		Some(CapacityError::Overflow),
+	);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn allocator_failure() {
+	//  Sixty-four pebibytes is within the `BitPtr` limits, but not within any
+	//  current address space.
+	let elements = 1 << 56;
+	assert_eq!(
+		VecBit::<BigEndian, u8>::try_with_capacity_in_elements(elements).err(),
+		Some(CapacityError::Alloc { elements }),
+	);
+}
@@ -0,0 +1,60 @@
+/*! Copy-on-write bitmaps agree with a plain vector.
+
+These tests drive a `SnapshotBits` and a pair of `VecBit` models, one committed
+and one pending, through the same pseudo-random sequence of writes, commits,
+and rollbacks, and compare them after every step.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	prelude::*,
+	versioned::SnapshotBits,
+};
+
+#[test]
+fn transactions() {
+	let len = 300;
+	let mut bits = SnapshotBits::new(bitbox![LittleEndian, u16; 0; len]);
+	let mut committed = vecbit![LittleEndian, u16; 0; len];
+	let mut pending = committed.clone();
+	let mut state = 0x2545_F491_4F6C_DD1Du64;
+	let mut version = 0;
+	for step in 0 .. 2000 {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		match state % 50 {
+			0 => {
+				if pending != committed {
+					version += 1;
+				}
+				bits.commit();
+				committed = pending.clone();
+			},
+			1 => {
+				bits.rollback();
+				pending = committed.clone();
+			},
+			_ => {
+				let at = (state >> 8) as usize % len;
+				let value = state & 0x80 != 0;
+				bits.set(at, value);
+				pending.set(at, value);
+			},
+		}
+		assert_eq!(bits.base(), &committed, "Step {}", step);
+		assert_eq!(bits.to_vecbit(), pending, "Step {}", step);
+		assert_eq!(bits.get(step % len), Some(pending[step % len]));
+		assert!(bits.version() >= version);
+	}
+	assert_eq!(bits.get(len), None);
+	assert_eq!(bits.into_base(), committed);
+}
+
+#[test]
+#[should_panic]
+fn set_out_of_bounds() {
+	let mut bits = SnapshotBits::new(bitbox![BigEndian, u8; 0; 5]);
+	bits.set(5, true);
+}
@@ -0,0 +1,50 @@
+/*! The text form round-trips every byte-stored vector.
+
+These tests write slices of every length and alignment in the text form, read
+them back, and check that malformed text is rejected rather than misread.
+!*/
+
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use vecbit::{
+	prelude::*,
+	web::Base64Error,
+};
+
+#[test]
+fn round_trip() {
+	let data = [0x3Cu8, 0xA5, 0x0F, 0x96, 0x71, 0xFF, 0x08];
+	let bits = data.bits::<LittleEndian>();
+	for from in 0 .. 9 {
+		for to in from .. bits.len() {
+			let text = bits[from .. to].to_base64();
+			let back = VecBit::<LittleEndian, u8>::from_base64(&text).unwrap();
+			assert_eq!(back, &bits[from .. to], "Text {}", text);
+		}
+	}
+	assert_eq!(
+		data.bits::<BigEndian>().to_base64(),
+		"56:PKUPlnH_CA",
+	);
+}
+
+#[test]
+fn rejects() {
+	type Bv = VecBit<BigEndian, u8>;
+	assert_eq!(Bv::from_base64(""), Err(Base64Error::Format));
+	assert_eq!(Bv::from_base64("x:AA"), Err(Base64Error::Format));
+	assert_eq!(Bv::from_base64("8:AAAAA"), Err(Base64Error::Format));
+	assert_eq!(Bv::from_base64("8:A="), Err(Base64Error::Symbol(3)));
+	assert_eq!(
+		Bv::from_base64("0:AA"),
+		Err(Base64Error::Length { found: 1, expected: 0 }),
+	);
+	assert_eq!(Bv::from_base64("0:"), Ok(Bv::new()));
+	//  Lengths no vector can have are rejected before any arithmetic on them.
+	let max = (!0usize >> 3).to_string();
+	assert!(Bv::from_base64(&format!("{}:", max)).is_err());
+	let over = ((!0usize >> 3) + 1).to_string();
+	assert_eq!(Bv::from_base64(&format!("{}:", over)), Err(Base64Error::Format));
+	let huge = format!("{}:", !0usize);
+	assert_eq!(Bv::from_base64(&huge), Err(Base64Error::Format));
+}
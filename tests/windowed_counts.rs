@@ -0,0 +1,29 @@
+/*! Sliding-window counts agree with counting each window.
+
+`windowed_counts` carries each count forward from the one before it. These
+tests check every window width over slices of several alignments against
+counting each window from scratch.
+!*/
+
+use vecbit::prelude::*;
+
+#[test]
+fn matches_windows() {
+	let data = [0xA5u8, 0x0F, 0x3C, 0xFF, 0x00, 0x96];
+	let bits = data.bits::<LittleEndian>();
+	for from in 0 .. 8 {
+		let bits = &bits[from ..];
+		for width in 1 ..= bits.len() + 2 {
+			let counts = bits.windowed_counts(width);
+			assert_eq!(counts.len(), bits.windows(width).len());
+			let model = bits.windows(width).map(SliceBit::count_ones);
+			assert!(counts.eq(model), "{} at {}", width, from);
+		}
+	}
+}
+
+#[test]
+#[should_panic]
+fn zero_width() {
+	0u8.bits::<BigEndian>().windowed_counts(0);
+}